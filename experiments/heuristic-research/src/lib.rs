@@ -85,9 +85,22 @@ pub enum ObservationData {
         #[serde(skip)]
         graph: DataGraph,
         point: DataPoint3D,
+        /// Full fitness vector: it allows to plot arbitrary dimensions when there are more than three objectives.
+        #[serde(default)]
+        fitness: Vec<Float>,
     },
 }
 
+impl ObservationData {
+    /// Returns full fitness vector of the observation.
+    pub fn fitness(&self) -> &[Float] {
+        match self {
+            ObservationData::Function(DataPoint3D(_, fitness, _)) => std::slice::from_ref(fitness),
+            ObservationData::Vrp { fitness, .. } => fitness.as_slice(),
+        }
+    }
+}
+
 lazy_static! {
     /// Keeps track of data used by the solver population.
     static ref EXPERIMENT_DATA: Mutex<ExperimentData> = Mutex::new(ExperimentData::default());
@@ -139,6 +152,12 @@ pub fn get_generation() -> usize {
     EXPERIMENT_DATA.lock().unwrap().generation
 }
 
+/// Gets hyper heuristic search transitions as CSV.
+#[wasm_bindgen]
+pub fn get_heuristic_state_csv() -> String {
+    EXPERIMENT_DATA.lock().unwrap().heuristic_state.to_csv()
+}
+
 /// Saves state of experiment data.
 pub fn save_state(state_file_path: &str) {
     let file = File::create(state_file_path).expect("cannot create file");
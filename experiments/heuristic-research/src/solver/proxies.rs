@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/proxies_test.rs"]
+mod proxies_test;
+
 use crate::*;
 use rosomaxa::example::VectorSolution;
 use rosomaxa::population::{RosomaxaWeighted, Shuffled};
@@ -23,6 +27,16 @@ pub struct ExperimentData {
     pub population_state: HashMap<usize, PopulationState>,
     /// Keeps track of heuristic state at specific generation.
     pub heuristic_state: HyperHeuristicState,
+    /// Keeps track of objective index which dominated the displaced best known individual
+    /// for each accepted individual. `None` means that no objective improved.
+    #[serde(default)]
+    pub dominating_objectives: HashMap<usize, Vec<Option<usize>>>,
+    /// Keeps track of non-dominated set (pareto front) size at specific generation.
+    #[serde(default)]
+    pub pareto_front_sizes: HashMap<usize, usize>,
+    /// Keeps track of population diversity (mean pairwise fitness distance) at specific generation.
+    #[serde(default)]
+    pub diversity: HashMap<usize, Float>,
 }
 
 impl ExperimentData {
@@ -32,6 +46,91 @@ impl ExperimentData {
         self.on_add.clear();
         self.on_select.clear();
         self.on_generation.clear();
+        self.dominating_objectives.clear();
+        self.pareto_front_sizes.clear();
+        self.diversity.clear();
+    }
+
+    /// Records which objective dominated the displaced individual when the new one is accepted.
+    pub(crate) fn on_accept(&mut self, generation: usize, accepted: &[Float], displaced: Option<&[Float]>) {
+        let dominating = displaced.and_then(|displaced| get_dominating_objective(accepted, displaced));
+
+        self.dominating_objectives.entry(generation).or_default().push(dominating);
+    }
+
+    /// Records which objective dominated the displaced individual for each individual from the batch
+    /// which improves the best known one at the moment of its addition.
+    pub(crate) fn on_accept_all(&mut self, generation: usize, individuals: &[Vec<Float>], displaced: Option<&[Float]>) {
+        let dominating = get_accepted_dominating_objectives(individuals, displaced);
+
+        self.dominating_objectives.entry(generation).or_default().extend(dominating);
+    }
+
+    /// Records population diversity at given generation using fitness vectors of its individuals.
+    pub(crate) fn on_diversity<'a>(&mut self, generation: usize, fitness: impl Iterator<Item = &'a [Float]>) {
+        self.diversity.insert(generation, get_fitness_diversity(fitness.collect::<Vec<_>>().as_slice()));
+    }
+}
+
+/// Returns index of the first objective (in priority order) where the individual is better than the
+/// displaced one, assuming lexicographic comparison. Returns `None` if there is no such objective or
+/// the individual is worse on the first differing objective.
+pub(crate) fn get_dominating_objective(individual: &[Float], displaced: &[Float]) -> Option<usize> {
+    individual
+        .iter()
+        .zip(displaced.iter())
+        .position(|(a, b)| a.total_cmp(b) != Ordering::Equal)
+        .filter(|&idx| individual[idx] < displaced[idx])
+}
+
+/// Returns dominating objective for each individual which is better than the best known one at the
+/// moment of its addition. Each accepted individual displaces the best known one for the rest of the batch.
+pub(crate) fn get_accepted_dominating_objectives(
+    individuals: &[Vec<Float>],
+    displaced: Option<&[Float]>,
+) -> Vec<Option<usize>> {
+    individuals
+        .iter()
+        .fold((displaced, Vec::new()), |(displaced, mut accepted), individual| match displaced {
+            Some(displaced) => match get_dominating_objective(individual, displaced) {
+                Some(idx) => {
+                    accepted.push(Some(idx));
+                    (Some(individual.as_slice()), accepted)
+                }
+                None => (Some(displaced), accepted),
+            },
+            None => {
+                accepted.push(None);
+                (Some(individual.as_slice()), accepted)
+            }
+        })
+        .1
+}
+
+/// Returns amount of individuals which are not dominated by any other one, assuming minimization
+/// of all objectives.
+pub(crate) fn get_pareto_front_size(fitness: &[Vec<Float>]) -> usize {
+    let dominates = |a: &[Float], b: &[Float]| {
+        a.iter().zip(b.iter()).all(|(a, b)| a <= b) && a.iter().zip(b.iter()).any(|(a, b)| a < b)
+    };
+
+    fitness.iter().filter(|candidate| !fitness.iter().any(|other| dominates(other, candidate))).count()
+}
+
+/// Returns mean pairwise euclidean distance between fitness vectors. Returns zero when there are
+/// less than two individuals.
+pub(crate) fn get_fitness_diversity(fitness: &[&[Float]]) -> Float {
+    let (total, pairs) = fitness.iter().enumerate().fold((0., 0), |(total, pairs), (idx, a)| {
+        fitness.iter().skip(idx + 1).fold((total, pairs), |(total, pairs), b| {
+            let distance = a.iter().zip(b.iter()).map(|(a, b)| (a - b) * (a - b)).sum::<Float>().sqrt();
+            (total + distance, pairs + 1)
+        })
+    });
+
+    if pairs == 0 {
+        0.
+    } else {
+        total / pairs as Float
     }
 }
 
@@ -73,7 +172,7 @@ where
             // NOTE temporarily disable graph as it is not really used, but consumes resources
             let graph = Default::default(); // insertion_ctx.into()
 
-            return ObservationData::Vrp { graph, point: DataPoint3D(x, y, z) };
+            return ObservationData::Vrp { graph, point: DataPoint3D(x, y, z), fitness };
         }
 
         unreachable!()
@@ -106,6 +205,10 @@ where
     fn acquire(&self) -> MutexGuard<ExperimentData> {
         EXPERIMENT_DATA.lock().unwrap()
     }
+
+    fn get_best_fitness(&self) -> Option<Vec<Float>> {
+        self.inner.ranked().next().map(|best| best.fitness().collect())
+    }
 }
 
 impl<P, O, S> HeuristicPopulation for ProxyPopulation<P, O, S>
@@ -120,24 +223,45 @@ where
     fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
         self.acquire().on_add.entry(self.generation).or_default().extend(individuals.iter().map(|i| i.into()));
 
-        self.inner.add_all(individuals)
+        let displaced = self.get_best_fitness();
+        let fitness = individuals.iter().map(|i| i.fitness().collect::<Vec<_>>()).collect::<Vec<_>>();
+
+        let is_accepted = self.inner.add_all(individuals);
+        if is_accepted {
+            self.acquire().on_accept_all(self.generation, fitness.as_slice(), displaced.as_deref());
+        }
+
+        is_accepted
     }
 
     fn add(&mut self, individual: Self::Individual) -> bool {
         self.acquire().on_add.entry(self.generation).or_default().push((&individual).into());
 
-        self.inner.add(individual)
+        let displaced = self.get_best_fitness();
+        let fitness = individual.fitness().collect::<Vec<_>>();
+
+        let is_accepted = self.inner.add(individual);
+        if is_accepted {
+            self.acquire().on_accept(self.generation, fitness.as_slice(), displaced.as_deref());
+        }
+
+        is_accepted
     }
 
     fn on_generation(&mut self, statistics: &HeuristicStatistics) {
         self.generation = statistics.generation;
         self.acquire().generation = statistics.generation;
 
-        let individuals = self.inner.all().map(|individual| individual.into()).collect();
+        let individuals: Vec<ObservationData> = self.inner.all().map(|individual| individual.into()).collect();
+        self.acquire().on_diversity(self.generation, individuals.iter().map(ObservationData::fitness));
         self.acquire().on_generation.insert(self.generation, ((), individuals));
 
         self.acquire().population_state.insert(self.generation, get_population_state(&self.inner));
 
+        let fitness =
+            self.inner.ranked().map(|individual| individual.fitness().collect::<Vec<_>>()).collect::<Vec<_>>();
+        self.acquire().pareto_front_sizes.insert(self.generation, get_pareto_front_size(fitness.as_slice()));
+
         self.inner.on_generation(statistics)
     }
 
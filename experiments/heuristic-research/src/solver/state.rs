@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/state_test.rs"]
+mod state_test;
+
 use crate::{Coordinate, MatrixData};
 use rosomaxa::algorithms::gsom::NetworkState;
 use rosomaxa::population::{Rosomaxa, RosomaxaWeighted, Shuffled};
@@ -214,4 +218,31 @@ impl HyperHeuristicState {
             None
         }
     }
+
+    /// Returns search state transitions as CSV with one row per transition ordered by generation.
+    pub fn to_csv(&self) -> String {
+        fn reverse(map: &HashMap<String, usize>) -> HashMap<usize, &str> {
+            map.iter().map(|(key, &idx)| (idx, key.as_str())).collect()
+        }
+
+        let (names, states) = (reverse(&self.names), reverse(&self.states));
+
+        let mut generations = self.search_states.keys().copied().collect::<Vec<_>>();
+        generations.sort_unstable();
+
+        generations.into_iter().fold("generation,heuristic,from,to,reward\n".to_string(), |mut csv, generation| {
+            self.search_states[&generation].iter().for_each(|SearchResult(name, reward, (from, to), _)| {
+                csv.push_str(&format!(
+                    "{},{},{},{},{}\n",
+                    generation,
+                    names.get(name).copied().unwrap_or_default(),
+                    states.get(from).copied().unwrap_or_default(),
+                    states.get(to).copied().unwrap_or_default(),
+                    reward
+                ));
+            });
+
+            csv
+        })
+    }
 }
@@ -0,0 +1,166 @@
+use super::*;
+
+#[test]
+fn can_get_dominating_objective() {
+    let cases: Vec<(&[Float], &[Float], Option<usize>)> = vec![
+        (&[8., 7.], &[10., 5.], Some(0)),
+        (&[10., 3.], &[10., 5.], Some(1)),
+        (&[12., 1.], &[10., 5.], None),
+        (&[10., 5.], &[10., 5.], None),
+    ];
+
+    cases.into_iter().for_each(|(individual, displaced, expected)| {
+        assert_eq!(get_dominating_objective(individual, displaced), expected);
+    });
+}
+
+#[test]
+fn can_record_dominating_objectives_for_two_objective_sequence() {
+    let mut data = ExperimentData::default();
+    let sequence: Vec<[Float; 2]> = vec![[10., 5.], [10., 3.], [8., 4.], [8., 4.], [7., 9.]];
+
+    sequence.iter().enumerate().fold(None, |displaced: Option<&[Float]>, (generation, fitness)| {
+        data.on_accept(generation, fitness.as_slice(), displaced);
+        Some(fitness.as_slice())
+    });
+
+    let mut recorded = data.dominating_objectives.iter().collect::<Vec<_>>();
+    recorded.sort_by_key(|(generation, _)| **generation);
+    let recorded = recorded.into_iter().flat_map(|(_, dominating)| dominating.iter().cloned()).collect::<Vec<_>>();
+    assert_eq!(recorded, vec![None, Some(1), Some(0), None, Some(0)]);
+
+    data.clear();
+    assert!(data.dominating_objectives.is_empty());
+}
+
+#[test]
+fn can_attribute_acceptance_per_individual_in_batch() {
+    let individuals: Vec<Vec<Float>> = vec![vec![12., 1.], vec![10., 3.], vec![10., 4.], vec![7., 9.], vec![8., 1.]];
+    let mut data = ExperimentData::default();
+
+    data.on_accept_all(0, individuals.as_slice(), Some(&[10., 5.]));
+    data.on_accept_all(1, &individuals[..2], None);
+
+    assert_eq!(data.dominating_objectives[&0], vec![Some(1), Some(0)]);
+    assert_eq!(data.dominating_objectives[&1], vec![None, Some(0)]);
+}
+
+#[test]
+fn can_get_pareto_front_size_for_two_objective_population() {
+    let fitness: Vec<Vec<Float>> = vec![
+        vec![1., 9.],
+        vec![2., 7.],
+        vec![3., 8.],
+        vec![4., 4.],
+        vec![5., 5.],
+        vec![6., 2.],
+        vec![6., 2.],
+        vec![9., 1.],
+        vec![9., 3.],
+    ];
+    let manual_count = fitness
+        .iter()
+        .filter(|a| !fitness.iter().any(|b| (b[0] <= a[0] && b[1] < a[1]) || (b[0] < a[0] && b[1] <= a[1])))
+        .count();
+
+    let front_size = get_pareto_front_size(fitness.as_slice());
+
+    assert_eq!(manual_count, 6);
+    assert_eq!(front_size, manual_count);
+    assert_eq!(get_pareto_front_size(&[]), 0);
+}
+
+#[test]
+fn can_record_full_fitness_vector_for_vrp_observation() {
+    use vrp_scientific::core::prelude::*;
+    use vrp_scientific::tsplib::TsplibProblem;
+
+    struct ConstantObjective(Float);
+
+    impl FeatureObjective for ConstantObjective {
+        fn fitness(&self, _: &InsertionContext) -> Cost {
+            self.0
+        }
+
+        fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+            Cost::default()
+        }
+    }
+
+    let problem = r#"NAME : SMALL
+COMMENT : two nodes
+TYPE : CVRP
+DIMENSION : 2
+EDGE_WEIGHT_TYPE : EUC_2D
+CAPACITY : 100
+NODE_COORD_SECTION
+ 1 82 76
+ 2 96 44
+DEMAND_SECTION
+1 0
+2 19
+DEPOT_SECTION
+ 1
+ -1
+EOF
+"#
+    .to_string()
+    .read_tsplib(true)
+    .unwrap();
+    let features = (1..=5)
+        .map(|idx| {
+            FeatureBuilder::default()
+                .with_name(format!("objective_{idx}").as_str())
+                .with_objective(ConstantObjective(idx as Float))
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let goal = GoalContextBuilder::with_features(&features).unwrap().build().unwrap();
+    let problem = Arc::new(Problem { goal: Arc::new(goal), ..problem });
+    let insertion_ctx = InsertionContext::new_empty(problem, Arc::new(Environment::default()));
+
+    let observation = ObservationData::from(&insertion_ctx);
+
+    assert_eq!(observation.fitness(), &[1., 2., 3., 4., 5.]);
+    match observation {
+        ObservationData::Vrp { point: DataPoint3D(x, y, z), .. } => assert_eq!((x, y, z), (1., 2., 3.)),
+        ObservationData::Function(_) => unreachable!("unexpected observation type"),
+    }
+}
+
+#[test]
+fn can_get_fitness_diversity() {
+    let cases: Vec<(Vec<&[Float]>, Float)> = vec![
+        (vec![], 0.),
+        (vec![&[1., 2.]], 0.),
+        (vec![&[0., 0.], &[3., 4.]], 5.),
+        (vec![&[0., 0.], &[3., 4.], &[0., 0.]], 10. / 3.),
+    ];
+
+    cases.into_iter().for_each(|(fitness, expected)| {
+        assert!((get_fitness_diversity(fitness.as_slice()) - expected).abs() < 1E-9);
+    });
+}
+
+#[test]
+fn can_record_diversity_series_for_several_generations() {
+    let mut data = ExperimentData::default();
+    let populations: Vec<Vec<Vec<Float>>> = vec![
+        vec![vec![10., 5.], vec![12., 3.], vec![8., 9.]],
+        vec![vec![10., 5.], vec![10., 5.]],
+        vec![vec![7., 1.]],
+        vec![vec![7., 1.], vec![1E6, 0.], vec![6., 2.], vec![6., 3.]],
+    ];
+
+    populations.iter().enumerate().for_each(|(generation, population)| {
+        data.on_diversity(generation, population.iter().map(|fitness| fitness.as_slice()));
+    });
+
+    assert_eq!(data.diversity.len(), populations.len());
+    assert!(data.diversity.values().all(|diversity| diversity.is_finite() && *diversity >= 0.));
+    assert_eq!(data.diversity.get(&1), Some(&0.));
+
+    data.clear();
+    assert!(data.diversity.is_empty());
+}
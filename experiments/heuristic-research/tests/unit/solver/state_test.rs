@@ -0,0 +1,31 @@
+use super::*;
+
+const TELEMETRY: &str = r"TELEMETRY
+search:
+name,generation,reward,from,to,duration
+local_search,0,1.5,BestKnown,Diverse,10
+ruin_recreate,0,0,Diverse,Stagnated,20
+local_search,1,-0.5,Stagnated,BestKnown,15
+heuristic:
+generation,state,name,alpha,beta,mu,v,n
+0,BestKnown,local_search,1,1,0.5,0.1,3
+";
+
+#[test]
+fn can_convert_heuristic_state_to_csv() {
+    let state = HyperHeuristicState::try_parse_all(TELEMETRY).expect("cannot parse telemetry");
+
+    let csv = state.to_csv();
+
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "generation,heuristic,from,to,reward");
+    assert_eq!(lines[3], "1,local_search,Stagnated,BestKnown,-0.5");
+}
+
+#[test]
+fn can_convert_empty_heuristic_state_to_csv() {
+    let csv = HyperHeuristicState::default().to_csv();
+
+    assert_eq!(csv, "generation,heuristic,from,to,reward\n");
+}
@@ -2,6 +2,7 @@ use crate::evolution::*;
 use crate::hyper::*;
 use crate::termination::*;
 use std::hash::Hash;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 /// A configuration which controls evolution execution.
@@ -86,6 +87,7 @@ where
     max_time: Option<usize>,
     min_cv: Option<(String, usize, Float, bool, K)>,
     target_proximity: Option<(Vec<Float>, Float)>,
+    cancellation: Option<Arc<AtomicBool>>,
     heuristic: Option<Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>>,
     context: Option<C>,
     termination: Option<Box<dyn Termination<Context = C, Objective = O>>>,
@@ -98,6 +100,8 @@ where
 
     initial: InitialConfig<C, O, S>,
     processing: ProcessingConfig<C, O, S>,
+
+    result_size: usize,
 }
 
 impl<C, O, S, K> Default for EvolutionConfigBuilder<C, O, S, K>
@@ -113,6 +117,7 @@ where
             max_time: None,
             min_cv: None,
             target_proximity: None,
+            cancellation: None,
             heuristic: None,
             context: None,
             termination: None,
@@ -122,6 +127,7 @@ where
             objective: None,
             initial: InitialConfig { operators: vec![], max_size: 4, quota: 0.05, individuals: vec![] },
             processing: ProcessingConfig { context: vec![], solution: vec![] },
+            result_size: 1,
         }
     }
 }
@@ -157,6 +163,20 @@ where
         self
     }
 
+    /// Sets a cancellation flag which is checked on each generation: once it is set, evolution stops
+    /// and returns the best known solution(-s). Default is None which means that evolution is never cancelled.
+    pub fn with_cancellation(mut self, is_cancelled: Option<Arc<AtomicBool>>) -> Self {
+        self.cancellation = is_cancelled;
+        self
+    }
+
+    /// Sets max amount of the best solutions taken from the final population as the result.
+    /// Default is 1. It is ignored when a custom strategy is used.
+    pub fn with_result_size(mut self, size: usize) -> Self {
+        self.result_size = size.max(1);
+        self
+    }
+
     /// Sets initial parameters used to construct initial population.
     pub fn with_initial(mut self, max_size: usize, quota: Float, operators: InitialOperators<C, O, S>) -> Self {
         self.initial.max_size = max_size;
@@ -238,8 +258,9 @@ where
         max_time: Option<usize>,
         min_cv: Option<(String, usize, Float, bool, K)>,
         target_proximity: Option<(Vec<Float>, Float)>,
+        cancellation: Option<Arc<AtomicBool>>,
     ) -> Result<Box<dyn Termination<Context = C, Objective = O>>, GenericError> {
-        let terminations: Vec<Box<dyn Termination<Context = C, Objective = O>>> = match (
+        let mut terminations: Vec<Box<dyn Termination<Context = C, Objective = O>>> = match (
             max_generations,
             max_time,
             &min_cv,
@@ -297,6 +318,12 @@ where
             }
         };
 
+        if let Some(is_cancelled) = cancellation {
+            (logger)("configured to use cancellation flag");
+            // NOTE cancellation goes first to be reported as termination reason
+            terminations.insert(0, Box::new(Cancellation::new(is_cancelled)));
+        }
+
         Ok(Box::new(CompositeTermination::new(terminations)))
     }
 
@@ -304,8 +331,14 @@ where
     pub fn build(self) -> Result<EvolutionConfig<C, O, S>, GenericError> {
         let context = self.context.ok_or_else(|| "missing heuristic context".to_string())?;
         let logger = context.environment().logger.clone();
-        let termination =
-            Self::get_termination(&logger, self.max_generations, self.max_time, self.min_cv, self.target_proximity)?;
+        let termination = Self::get_termination(
+            &logger,
+            self.max_generations,
+            self.max_time,
+            self.min_cv,
+            self.target_proximity,
+            self.cancellation,
+        )?;
 
         Ok(EvolutionConfig {
             initial: self.initial,
@@ -323,7 +356,7 @@ where
                         context.environment(),
                     ))
                 };
-                Box::new(strategies::Iterative::new(heuristic, 1))
+                Box::new(strategies::Iterative::new(heuristic, self.result_size))
             },
             context,
             termination,
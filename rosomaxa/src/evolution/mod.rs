@@ -1,6 +1,7 @@
 //! Contains functionality to run evolution simulation.
 
 use crate::prelude::*;
+use crate::termination::TerminationReason;
 
 mod config;
 pub use self::config::*;
@@ -15,7 +16,30 @@ pub mod objectives;
 pub mod strategies;
 
 /// Defines evolution result type.
-pub type EvolutionResult<S> = Result<(Vec<S>, Option<TelemetryMetrics>), GenericError>;
+pub type EvolutionResult<S> = Result<EvolutionOutput<S>, GenericError>;
+
+/// An output of evolution run.
+pub struct EvolutionOutput<S> {
+    /// Solutions found by evolution, the best one goes first.
+    pub solutions: Vec<S>,
+    /// Telemetry metrics, if they are requested.
+    pub metrics: Option<TelemetryMetrics>,
+    /// A summary of evolution run.
+    pub summary: SolveSummary,
+}
+
+/// A machine-readable summary of evolution run.
+#[derive(Clone, Debug)]
+pub struct SolveSummary {
+    /// Total amount of generations run.
+    pub generations: usize,
+    /// Wall time of evolution in milliseconds.
+    pub duration: usize,
+    /// A reason why evolution has stopped.
+    pub termination: TerminationReason,
+    /// Fitness values of the best solution. Empty if there is no solution.
+    pub fitness: Vec<Float>,
+}
 
 /// Provides the way to preprocess context before using it.
 pub trait HeuristicContextProcessing {
@@ -1,5 +1,11 @@
 //! Specifies objective functions.
 
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/objectives_test.rs"]
+mod objectives_test;
+
+use crate::algorithms::math::{get_mean_slice, get_stdev};
+use crate::utils::Float;
 use std::cmp::Ordering;
 
 /// A *heuristic objective* function defines a *total ordering relation* between any two solutions
@@ -45,3 +51,71 @@ where
         Ordering::Equal
     }
 }
+
+/// Specifies a per objective normalization method of fitness values.
+#[derive(Clone, Copy, Debug)]
+pub enum FitnessNormalization {
+    /// Scales values to `[0, 1]` range using min and max values.
+    MinMax,
+    /// Standardizes values using mean and standard deviation.
+    ZScore,
+}
+
+/// Normalizes fitness vectors per objective using statistics collected over a population, so
+/// objectives with different scales (e.g. cost in thousands, tours in single digits) contribute
+/// comparably into a scalarized comparison.
+/// NOTE dominance order is not affected by normalization as it is monotonic per objective.
+pub struct FitnessNormalizer {
+    /// Shift and scale parameters per objective.
+    parameters: Vec<(Float, Float)>,
+}
+
+impl FitnessNormalizer {
+    /// Creates a new instance of `FitnessNormalizer` using fitness vectors of the population.
+    pub fn new(normalization: FitnessNormalization, population: &[Vec<Float>]) -> Self {
+        let dimension = population.iter().map(|fitness| fitness.len()).max().unwrap_or(0);
+
+        let parameters = (0..dimension)
+            .map(|idx| {
+                let values = population.iter().filter_map(|fitness| fitness.get(idx).copied()).collect::<Vec<_>>();
+
+                let (shift, scale) = match normalization {
+                    FitnessNormalization::MinMax => {
+                        let min = values.iter().copied().fold(Float::MAX, Float::min);
+                        let max = values.iter().copied().fold(Float::MIN, Float::max);
+                        (min, max - min)
+                    }
+                    FitnessNormalization::ZScore => (get_mean_slice(values.as_slice()), get_stdev(values.as_slice())),
+                };
+
+                // NOTE degenerated objective with the same values keeps its original scale
+                (shift, if scale > 0. { scale } else { 1. })
+            })
+            .collect();
+
+        Self { parameters }
+    }
+
+    /// Returns normalized fitness vector.
+    pub fn normalize<I>(&self, fitness: I) -> Vec<Float>
+    where
+        I: Iterator<Item = Float>,
+    {
+        fitness
+            .enumerate()
+            .map(|(idx, value)| self.parameters.get(idx).map_or(value, |(shift, scale)| (value - shift) / scale))
+            .collect()
+    }
+
+    /// Compares two fitness vectors using the sum of their normalized values.
+    pub fn scalarized_order<A, B>(&self, a: A, b: B) -> Ordering
+    where
+        A: Iterator<Item = Float>,
+        B: Iterator<Item = Float>,
+    {
+        let a = self.normalize(a).into_iter().sum::<Float>();
+        let b = self.normalize(b).into_iter().sum::<Float>();
+
+        a.total_cmp(&b)
+    }
+}
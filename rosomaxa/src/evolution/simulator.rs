@@ -1,4 +1,4 @@
-use crate::evolution::EvolutionResult;
+use crate::evolution::{EvolutionOutput, EvolutionResult};
 use crate::prelude::*;
 use crate::utils::Timer;
 
@@ -84,13 +84,14 @@ where
 
         (logger)(&format!("created initial population in {}ms", init_time.elapsed_millis()));
 
-        config.strategy.run(heuristic_ctx, config.termination).map(|(solutions, metrics)| {
-            let solutions = solutions
+        config.strategy.run(heuristic_ctx, config.termination).map(|output| {
+            let solutions = output
+                .solutions
                 .into_iter()
                 .map(|solution| hooks.solution.iter().fold(solution, |s, hook| hook.post_process(s)))
                 .collect();
 
-            (solutions, metrics)
+            EvolutionOutput { solutions, ..output }
         })
     }
 }
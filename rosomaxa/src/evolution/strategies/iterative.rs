@@ -45,12 +45,16 @@ where
         let mut heuristic_ctx = heuristic_ctx;
         let heuristic = &mut self.heuristic;
 
-        loop {
+        let termination_reason = loop {
             let is_terminated = termination.is_termination(&mut heuristic_ctx);
             let is_quota_reached = heuristic_ctx.environment().quota.as_ref().map_or(false, |q| q.is_reached());
 
-            if is_terminated || is_quota_reached {
-                break;
+            if is_terminated {
+                break termination.reason();
+            }
+
+            if is_quota_reached {
+                break TerminationReason::Budget;
             }
 
             let generation_time = Timer::start();
@@ -70,16 +74,26 @@ where
             let termination_estimate = termination.estimate(&heuristic_ctx);
 
             heuristic_ctx.on_generation(offspring, termination_estimate, generation_time);
-        }
+        };
 
         // NOTE give a chance to report internal state of heuristic
         (heuristic_ctx.environment().logger)(&format!("{heuristic}"));
 
+        let statistics = heuristic_ctx.statistics();
+        let (generations, duration) = (statistics.generation, statistics.time.elapsed_millis() as usize);
+
         let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
 
+        let summary = SolveSummary {
+            generations,
+            duration,
+            termination: termination_reason,
+            fitness: population.ranked().next().map(|solution| solution.fitness().collect()).unwrap_or_default(),
+        };
+
         let solutions =
             population.ranked().map(|solution| solution.deep_copy()).take(self.desired_solutions_amount).collect();
 
-        Ok((solutions, telemetry_metrics))
+        Ok(EvolutionOutput { solutions, metrics: telemetry_metrics, summary })
     }
 }
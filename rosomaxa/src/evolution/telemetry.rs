@@ -20,6 +20,8 @@ pub struct TelemetryMetrics {
     pub speed: Float,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// Best individual fitness per generation. Empty if trajectory tracking is not enabled.
+    pub trajectory: Vec<Vec<Float>>,
 }
 
 /// Represents information about generation.
@@ -70,6 +72,8 @@ pub enum TelemetryMode {
     OnlyMetrics {
         /// Specifies how often population is tracked.
         track_population: usize,
+        /// Specifies whether best individual fitness is tracked on each generation.
+        track_trajectory: bool,
     },
     /// Both logging and metrics collection.
     All {
@@ -81,6 +85,8 @@ pub enum TelemetryMode {
         log_population: usize,
         /// Specifies how often population is tracked.
         track_population: usize,
+        /// Specifies whether best individual fitness is tracked on each generation.
+        track_trajectory: bool,
     },
 }
 
@@ -109,7 +115,13 @@ where
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: TelemetryMetrics {
+                duration: 0,
+                generations: 0,
+                speed: 0.0,
+                evolution: vec![],
+                trajectory: vec![],
+            },
             mode,
             statistics: Default::default(),
             improvement_tracker: ImprovementTracker::new(1000),
@@ -161,12 +173,16 @@ where
             termination_estimate,
         };
 
-        let (log_best, log_population, track_population) = match &self.mode {
+        let (log_best, log_population, track_population, track_trajectory) = match &self.mode {
             TelemetryMode::None => return,
-            TelemetryMode::OnlyLogging { log_best, log_population, .. } => (Some(log_best), Some(log_population), None),
-            TelemetryMode::OnlyMetrics { track_population, .. } => (None, None, Some(track_population)),
-            TelemetryMode::All { log_best, log_population, track_population, .. } => {
-                (Some(log_best), Some(log_population), Some(track_population))
+            TelemetryMode::OnlyLogging { log_best, log_population, .. } => {
+                (Some(log_best), Some(log_population), None, false)
+            }
+            TelemetryMode::OnlyMetrics { track_population, track_trajectory } => {
+                (None, None, Some(track_population), *track_trajectory)
+            }
+            TelemetryMode::All { log_best, log_population, track_population, track_trajectory, .. } => {
+                (Some(log_best), Some(log_population), Some(track_population), *track_trajectory)
             }
         };
 
@@ -175,6 +191,10 @@ where
             let should_log_population = generation % *log_population.unwrap_or(&usize::MAX) == 0;
             let should_track_population = generation % *track_population.unwrap_or(&usize::MAX) == 0;
 
+            if track_trajectory {
+                self.metrics.trajectory.push(best_individual.fitness().collect());
+            }
+
             if should_log_best {
                 self.log_individual(
                     &self.get_individual_metrics(population, best_individual),
@@ -463,7 +463,7 @@ impl Solver {
             .build()?;
 
         // solve the problem
-        let (solutions, metrics) = EvolutionSimulator::new(config)?.run()?;
+        let EvolutionOutput { solutions, metrics, .. } = EvolutionSimulator::new(config)?.run()?;
 
         let solutions = solutions
             .into_iter()
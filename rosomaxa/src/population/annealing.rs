@@ -0,0 +1,159 @@
+#[cfg(test)]
+#[path = "../../tests/unit/population/annealing_test.rs"]
+mod annealing_test;
+
+use super::*;
+use crate::algorithms::math::relative_distance;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::iter::{empty, repeat_n};
+use std::sync::Arc;
+
+/// Specifies simulated annealing configuration settings.
+pub struct AnnealingConfig {
+    /// Selection size.
+    pub selection_size: usize,
+    /// An initial temperature.
+    pub initial_temperature: Float,
+    /// A cooling rate applied to the temperature on each generation.
+    pub cooling_rate: Float,
+    /// A minimum temperature.
+    pub min_temperature: Float,
+}
+
+impl AnnealingConfig {
+    /// Creates an instance of `AnnealingConfig` using default parameters.
+    pub fn new_with_defaults(selection_size: usize) -> Self {
+        Self { selection_size, initial_temperature: 0.1, cooling_rate: 0.999, min_temperature: 1E-6 }
+    }
+}
+
+/// A population which uses simulated annealing acceptance criterion: a better individual is always
+/// accepted as current one, a worse one is accepted with probability `exp(-delta / temperature)`,
+/// where delta is a relative fitness distance and temperature decays with generations.
+/// The best known individual is always kept.
+pub struct Annealing<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    objective: Arc<O>,
+    random: Arc<dyn Random>,
+    config: AnnealingConfig,
+    temperature: Float,
+    best_known: Option<S>,
+    current: Option<S>,
+}
+
+impl<O, S> HeuristicPopulation for Annealing<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Objective = O;
+    type Individual = S;
+
+    fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
+        // NOTE all individuals have to be added, so short-circuiting `any` cannot be used here
+        individuals.into_iter().map(|individual| self.add(individual) as usize).sum::<usize>() > 0
+    }
+
+    fn add(&mut self, individual: Self::Individual) -> bool {
+        let is_best = self
+            .best_known
+            .as_ref()
+            .is_none_or(|best_known| self.objective.total_order(best_known, &individual) == Ordering::Greater);
+
+        if is_best {
+            self.best_known = Some(individual);
+            self.current = None;
+
+            return true;
+        }
+
+        let reference = self.current.as_ref().or(self.best_known.as_ref()).expect("no best known individual");
+        if self.is_accepted(reference, &individual) {
+            self.current = Some(individual);
+        }
+
+        false
+    }
+
+    fn on_generation(&mut self, statistics: &HeuristicStatistics) {
+        self.temperature = (self.config.initial_temperature
+            * self.config.cooling_rate.powi(statistics.generation as i32))
+        .max(self.config.min_temperature);
+    }
+
+    fn cmp(&self, a: &Self::Individual, b: &Self::Individual) -> Ordering {
+        self.objective.total_order(a, b)
+    }
+
+    fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        if let Some(current) = self.current.as_ref().or(self.best_known.as_ref()) {
+            Box::new(repeat_n(current, self.config.selection_size))
+        } else {
+            Box::new(empty())
+        }
+    }
+
+    fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        Box::new(self.best_known.iter().chain(self.current.iter()))
+    }
+
+    fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        Box::new(self.best_known.iter().chain(self.current.iter()))
+    }
+
+    fn size(&self) -> usize {
+        usize::from(self.best_known.is_some()) + usize::from(self.current.is_some())
+    }
+
+    fn selection_phase(&self) -> SelectionPhase {
+        SelectionPhase::Exploration
+    }
+}
+
+impl<O, S> Display for Annealing<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = self
+            .ranked()
+            .map(|individual| individual.fitness().map(|v| format!("{v:.7}")).collect::<Vec<_>>().join(","))
+            .collect::<Vec<_>>()
+            .join("],[");
+
+        write!(f, "[{values}], t={:.7}", self.temperature)
+    }
+}
+
+impl<O, S> Annealing<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `Annealing`.
+    pub fn new(objective: Arc<O>, random: Arc<dyn Random>, config: AnnealingConfig) -> Self {
+        let temperature = config.initial_temperature;
+        Self { objective, random, config, temperature, best_known: None, current: None }
+    }
+
+    /// Returns current temperature.
+    pub fn temperature(&self) -> Float {
+        self.temperature
+    }
+
+    fn is_accepted(&self, reference: &S, candidate: &S) -> bool {
+        if self.objective.total_order(reference, candidate) == Ordering::Greater {
+            return true;
+        }
+
+        let delta = relative_distance(reference.fitness(), candidate.fitness());
+        let probability = (-delta / self.temperature).exp();
+
+        self.random.is_hit(probability)
+    }
+}
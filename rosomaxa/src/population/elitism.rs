@@ -4,6 +4,7 @@ mod elitism_test;
 
 use super::*;
 use crate::algorithms::math::relative_distance;
+use crate::evolution::objectives::{FitnessNormalization, FitnessNormalizer};
 use crate::utils::Random;
 use crate::{HeuristicSpeed, HeuristicStatistics};
 use std::cmp::Ordering;
@@ -32,6 +33,7 @@ where
     individuals: Vec<S>,
     speed: Option<HeuristicSpeed>,
     dedup_fn: DedupFn<O, S>,
+    normalization: Option<FitnessNormalization>,
 }
 
 // TODO rename Shuffled to Alternative
@@ -148,7 +150,24 @@ where
         dedup_fn: DedupFn<O, S>,
     ) -> Self {
         assert!(max_population_size > 0);
-        Self { objective, random, selection_size, max_population_size, individuals: vec![], speed: None, dedup_fn }
+        Self {
+            objective,
+            random,
+            selection_size,
+            max_population_size,
+            individuals: vec![],
+            speed: None,
+            dedup_fn,
+            normalization: None,
+        }
+    }
+
+    /// Sets per objective fitness normalization: when specified, individuals are sorted by the sum
+    /// of their normalized fitness values, using objective's total order to break ties.
+    /// NOTE scalarized order ignores hierarchy of objectives defined by the total order.
+    pub fn with_normalization(mut self, normalization: FitnessNormalization) -> Self {
+        self.normalization = Some(normalization);
+        self
     }
 
     /// Shuffles objective function.
@@ -165,7 +184,17 @@ where
     }
 
     fn sort(&mut self) {
-        self.individuals.sort_by(|a, b| self.objective.total_order(a, b));
+        if let Some(normalization) = self.normalization {
+            let population =
+                self.individuals.iter().map(|individual| individual.fitness().collect()).collect::<Vec<_>>();
+            let normalizer = FitnessNormalizer::new(normalization, population.as_slice());
+
+            self.individuals.sort_by(|a, b| {
+                normalizer.scalarized_order(a.fitness(), b.fitness()).then_with(|| self.objective.total_order(a, b))
+            });
+        } else {
+            self.individuals.sort_by(|a, b| self.objective.total_order(a, b));
+        }
         self.individuals.dedup_by(|a, b| (self.dedup_fn)(&self.objective, a, b));
     }
 
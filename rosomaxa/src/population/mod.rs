@@ -1,5 +1,8 @@
 //! Specifies population types.
 
+mod annealing;
+pub use self::annealing::{Annealing, AnnealingConfig};
+
 mod elitism;
 pub use self::elitism::{Elitism, Shuffled};
 
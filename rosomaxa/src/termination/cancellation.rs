@@ -0,0 +1,58 @@
+#[cfg(test)]
+#[path = "../../tests/unit/termination/cancellation_test.rs"]
+mod cancellation_test;
+
+use super::*;
+use std::marker::PhantomData;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+/// A termination criteria which is in terminated state when external cancellation flag is set,
+/// e.g. by a service which wants to stop solving and get the best known solution.
+pub struct Cancellation<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    is_cancelled: Arc<AtomicBool>,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> Cancellation<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `Cancellation`.
+    pub fn new(is_cancelled: Arc<AtomicBool>) -> Self {
+        Self { is_cancelled, _marker: (Default::default(), Default::default(), Default::default()) }
+    }
+}
+
+impl<C, O, S> Termination for Cancellation<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+
+    fn is_termination(&self, _: &mut Self::Context) -> bool {
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+
+    fn estimate(&self, _: &Self::Context) -> Float {
+        if self.is_cancelled.load(Ordering::Relaxed) {
+            1.
+        } else {
+            0.
+        }
+    }
+
+    fn reason(&self) -> TerminationReason {
+        TerminationReason::Cancelled
+    }
+}
@@ -169,4 +169,8 @@ where
     fn estimate(&self, _: &Self::Context) -> Float {
         0.
     }
+
+    fn reason(&self) -> TerminationReason {
+        TerminationReason::Stagnation
+    }
 }
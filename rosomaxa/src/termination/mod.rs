@@ -1,7 +1,25 @@
 //! The termination module contains logic which defines termination criteria for metaheuristic,
 //! e.g. when to stop evolution in evolutionary algorithms.
 
+#[cfg(test)]
+#[path = "../../tests/unit/termination/termination_test.rs"]
+mod termination_test;
+
 use crate::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Specifies a reason why metaheuristic has stopped searching for improved solution.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TerminationReason {
+    /// Search has converged, e.g. a target objective value is reached.
+    Converged,
+    /// A computational budget is exhausted, e.g. max generations, max time or quota.
+    Budget,
+    /// Search has stagnated: no significant improvement is observed for some time.
+    Stagnation,
+    /// Search is cancelled externally.
+    Cancelled,
+}
 
 /// A trait which specifies criteria when metaheuristic should stop searching for improved solution.
 pub trait Termination: Send + Sync {
@@ -16,8 +34,17 @@ pub trait Termination: Send + Sync {
 
     /// Returns a relative estimation till termination. Value is in the `[0, 1]` range.
     fn estimate(&self, heuristic_ctx: &Self::Context) -> Float;
+
+    /// Returns a reason of termination. It is meaningful only after termination condition is met.
+    /// By default, termination is considered as an exhausted computational budget.
+    fn reason(&self) -> TerminationReason {
+        TerminationReason::Budget
+    }
 }
 
+mod cancellation;
+pub use self::cancellation::Cancellation;
+
 mod min_variation;
 pub use self::min_variation::MinVariation;
 
@@ -38,6 +65,7 @@ where
     S: HeuristicSolution,
 {
     terminations: Vec<Box<dyn Termination<Context = C, Objective = O>>>,
+    fired: AtomicUsize,
 }
 
 impl<C, O, S> CompositeTermination<C, O, S>
@@ -48,7 +76,7 @@ where
 {
     /// Creates a new instance of `CompositeTermination`.
     pub fn new(terminations: Vec<Box<dyn Termination<Context = C, Objective = O>>>) -> Self {
-        Self { terminations }
+        Self { terminations, fired: AtomicUsize::new(usize::MAX) }
     }
 }
 
@@ -62,10 +90,19 @@ where
     type Objective = O;
 
     fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
-        self.terminations.iter().any(|t| t.is_termination(heuristic_ctx))
+        self.terminations.iter().position(|t| t.is_termination(heuristic_ctx)).map_or(false, |idx| {
+            self.fired.store(idx, Ordering::Relaxed);
+            true
+        })
     }
 
     fn estimate(&self, heuristic_ctx: &Self::Context) -> Float {
         self.terminations.iter().map(|t| t.estimate(heuristic_ctx)).max_by(|a, b| a.total_cmp(b)).unwrap_or_default()
     }
+
+    fn reason(&self) -> TerminationReason {
+        self.terminations
+            .get(self.fired.load(Ordering::Relaxed))
+            .map_or(TerminationReason::Budget, |termination| termination.reason())
+    }
 }
@@ -54,4 +54,8 @@ where
     fn estimate(&self, _: &Self::Context) -> Float {
         0.
     }
+
+    fn reason(&self) -> TerminationReason {
+        TerminationReason::Converged
+    }
 }
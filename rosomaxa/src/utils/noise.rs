@@ -1,37 +1,89 @@
 //! Specifies some logic to work with noise.
 
-use crate::prelude::Random;
-use crate::utils::Float;
+#[cfg(test)]
+#[path = "../../tests/unit/utils/noise_test.rs"]
+mod noise_test;
+
+use crate::prelude::{DefaultRandom, Random};
+use crate::utils::{Float, GenericResult};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 /// Provides way to generate some noise to floating point value.
 #[derive(Clone)]
 pub struct Noise {
     probability: Float,
-    range: (Float, Float),
+    ranges: Arc<Vec<(Float, (Float, Float))>>,
     is_addition: bool,
     random: Arc<dyn Random>,
+    samples: Option<Arc<NoiseSamples>>,
 }
 
 impl Noise {
     /// Creates a new instance of `Noise` which will add some noise in given range
     /// to the target value: `value = value + value * sample_from(range)`
     pub fn new_with_addition(probability: Float, range: (Float, Float), random: Arc<dyn Random>) -> Self {
-        Self { probability, range, is_addition: true, random }
+        Self { probability, ranges: Arc::new(vec![(1., range)]), is_addition: true, random, samples: None }
     }
 
     /// Creates a new instance of `Noise` which will apply noise by multiplying target value
     /// by value from given range: `value = value * sample_from(range)`
     pub fn new_with_ratio(probability: Float, range: (Float, Float), random: Arc<dyn Random>) -> Self {
-        Self { probability, range, is_addition: false, random }
+        Self { probability, ranges: Arc::new(vec![(1., range)]), is_addition: false, random, samples: None }
+    }
+
+    /// Creates a new instance of `Noise` which samples a range from given `(weight, (min, max))` buckets
+    /// proportionally to their weights and then samples noise from the selected range uniformly.
+    /// Noise is applied in the same way as in `new_with_addition` or `new_with_ratio` depending on
+    /// `is_addition` flag. This allows to model, e.g., mostly small perturbations with occasional large ones.
+    /// Returns an error if buckets are empty, have negative weights or all weights are zero.
+    pub fn new_with_distribution(
+        probability: Float,
+        buckets: &[(Float, (Float, Float))],
+        is_addition: bool,
+        random: Arc<dyn Random>,
+    ) -> GenericResult<Self> {
+        if buckets.is_empty() {
+            return Err("noise buckets should not be empty".into());
+        }
+
+        if buckets.iter().any(|(weight, _)| *weight < 0.) {
+            return Err("noise bucket weights should not be negative".into());
+        }
+
+        if buckets.iter().all(|(weight, _)| *weight == 0.) {
+            return Err("noise bucket weights should not be all zero".into());
+        }
+
+        Ok(Self { probability, ranges: Arc::new(buckets.to_vec()), is_addition, random, samples: None })
+    }
+
+    /// Creates a new instance of `Noise` which takes noise values from the provided samples in order,
+    /// starting over when the end of the sequence is reached, instead of sampling them from the range.
+    /// Probability is tested using repeatable random generator, so the output is fully determined
+    /// by the samples when probability is either `0.` or `1.`. Clones share the sample position.
+    /// Returns an error if samples are empty as there is nothing to draw noise from, the same way as
+    /// `new_with_distribution` rejects invalid buckets.
+    pub fn from_samples(samples: Vec<Float>, probability: Float, is_addition: bool) -> GenericResult<Self> {
+        if samples.is_empty() {
+            return Err("noise samples should not be empty".into());
+        }
+
+        Ok(Self {
+            probability,
+            ranges: Arc::new(vec![(1., (0., 0.))]),
+            is_addition,
+            random: Arc::new(DefaultRandom::new_repeatable()),
+            samples: Some(Arc::new(NoiseSamples { values: samples, index: AtomicUsize::new(0) })),
+        })
     }
 
-    /// Generates an iterator with noise.
+    /// Generates an iterator with noise applied to each value in the same way as `generate` does.
     pub fn generate_multi<'a, Iter: Iterator<Item = Float> + 'a>(
         &'a self,
         values: Iter,
     ) -> impl Iterator<Item = Float> + 'a {
-        values.map(|value| value + self.generate(value))
+        values.map(|value| self.generate(value))
     }
 
     /// Generate some noise based on given value.
@@ -39,9 +91,9 @@ impl Noise {
         if self.random.is_hit(self.probability) {
             // NOTE if value is zero, then noise is not applied which causes some troubles in edge cases
             if value == 0. {
-                self.random.uniform_real(self.range.0, self.range.1)
+                self.sample()
             } else {
-                value * self.random.uniform_real(self.range.0, self.range.1) + if self.is_addition { value } else { 0. }
+                value * self.sample() + if self.is_addition { value } else { 0. }
             }
         } else {
             value
@@ -52,4 +104,41 @@ impl Noise {
     pub fn random(&self) -> &(dyn Random) {
         self.random.as_ref()
     }
+
+    fn sample(&self) -> Float {
+        if let Some(samples) = &self.samples {
+            let index = samples.index.fetch_add(1, Ordering::Relaxed);
+            samples.values[index % samples.values.len()]
+        } else {
+            let (min, max) = self.select_range();
+            self.random.uniform_real(min, max)
+        }
+    }
+
+    fn select_range(&self) -> (Float, Float) {
+        // NOTE do not consume random for a single range to keep the same sequence as before
+        if let [(_, range)] = self.ranges.as_slice() {
+            return *range;
+        }
+
+        let total = self.ranges.iter().map(|(weight, _)| *weight).sum::<Float>();
+        let mut target = self.random.uniform_real(0., total);
+
+        self.ranges
+            .iter()
+            .filter(|(weight, _)| *weight > 0.)
+            .find(|(weight, _)| {
+                target -= *weight;
+                target < 0.
+            })
+            .or_else(|| self.ranges.iter().rev().find(|(weight, _)| *weight > 0.))
+            .map(|(_, range)| *range)
+            .expect("at least one bucket has positive weight")
+    }
+}
+
+/// Keeps predefined noise samples and position of the next one.
+struct NoiseSamples {
+    values: Vec<Float>,
+    index: AtomicUsize,
 }
@@ -3,6 +3,7 @@
 mod parallel_test;
 
 pub use self::actual::cartesian_product;
+pub use self::actual::current_thread_index;
 pub use self::actual::fold_reduce;
 pub use self::actual::map_reduce;
 pub use self::actual::parallel_collect;
@@ -102,6 +103,11 @@ mod actual {
     {
         source.par_iter_mut().for_each(action)
     }
+
+    /// Returns index of the current thread within thread pool or `None` if called outside of it.
+    pub fn current_thread_index() -> Option<usize> {
+        rayon::current_thread_index()
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -189,4 +195,9 @@ mod actual {
     {
         source.iter_mut().for_each(action)
     }
+
+    /// Returns index of the current thread within thread pool (dummy).
+    pub fn current_thread_index() -> Option<usize> {
+        None
+    }
 }
@@ -2,13 +2,15 @@
 #[path = "../../tests/unit/utils/random_test.rs"]
 mod random_test;
 
-use crate::utils::Float;
+use crate::utils::{current_thread_index, Float};
 use rand::prelude::*;
 use rand::Error;
 use rand_distr::{Gamma, Normal};
 use std::cell::RefCell;
 use std::cmp::Ordering;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread::ThreadId;
 
 /// Provides the way to sample from different distributions.
 pub trait DistributionSampler {
@@ -81,12 +83,22 @@ impl DistributionSampler for DefaultDistributionSampler {
 #[derive(Default)]
 pub struct DefaultRandom {
     use_repeatable: bool,
+    seeded: Option<Arc<SeededRngs>>,
 }
 
 impl DefaultRandom {
     /// Creates an instance of `DefaultRandom` with repeatable (predictable) random generation.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { use_repeatable: true, seeded: None }
+    }
+
+    /// Creates an instance of `DefaultRandom` which owns random generators seeded with the given value.
+    /// Unlike repeatable one, its sequence doesn't depend on other instances used in the same thread.
+    /// Each thread gets its own generator seeded from the given value and thread pool index, so threads
+    /// don't affect each other, but sequences within one thread are reproducible only when the same
+    /// work is scheduled on it. Generators are owned by the instance and released together with it.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { use_repeatable: false, seeded: Some(Arc::new(SeededRngs::new(seed))) }
     }
 }
 
@@ -129,7 +141,7 @@ impl Random for DefaultRandom {
     }
 
     fn get_rng(&self) -> RandomGen {
-        RandomGen { use_repeatable: self.use_repeatable }
+        RandomGen { use_repeatable: self.use_repeatable, seeded: self.seeded.clone() }
     }
 }
 
@@ -141,56 +153,89 @@ thread_local! {
     static REPEATABLE_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(0));
 }
 
+/// Keeps random generators of seeded `DefaultRandom` instance, one per thread.
+#[derive(Debug)]
+struct SeededRngs {
+    seed: u64,
+    rngs: RwLock<HashMap<ThreadId, Mutex<SmallRng>>>,
+}
+
+impl SeededRngs {
+    fn new(seed: u64) -> Self {
+        Self { seed, rngs: RwLock::new(HashMap::default()) }
+    }
+
+    /// Runs operation with the random generator of the current thread, creating it if necessary.
+    fn with_rng<R>(&self, op: impl FnOnce(&mut SmallRng) -> R) -> R {
+        let thread_id = std::thread::current().id();
+
+        if let Some(rng) = self.rngs.read().expect("cannot read seeded rngs").get(&thread_id) {
+            return op(&mut rng.lock().expect("cannot lock seeded rng"));
+        }
+
+        let mut rngs = self.rngs.write().expect("cannot write seeded rngs");
+        let rng = rngs
+            .entry(thread_id)
+            .or_insert_with(|| Mutex::new(self.create_rng()))
+            .get_mut()
+            .expect("cannot get seeded rng");
+
+        op(rng)
+    }
+
+    /// Creates a random generator for the current thread.
+    fn create_rng(&self) -> SmallRng {
+        // NOTE mix thread index in to avoid the same sequence in all threads
+        let thread_idx = current_thread_index().map_or(0, |idx| idx as u64 + 1);
+        SmallRng::seed_from_u64(self.seed ^ thread_idx.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+}
+
 /// Provides underlying random generator API.
 #[derive(Clone, Debug)]
 pub struct RandomGen {
     use_repeatable: bool,
+    seeded: Option<Arc<SeededRngs>>,
 }
 
 impl RandomGen {
     /// Creates an instance of `RandomGen` using random generator with fixed seed.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { use_repeatable: true, seeded: None }
     }
 
     /// Creates an instance of `RandomGen` using random generator with randomized seed.
     pub fn new_randomized() -> Self {
-        Self { use_repeatable: false }
+        Self { use_repeatable: false, seeded: None }
     }
-}
 
-impl RngCore for RandomGen {
-    fn next_u32(&mut self) -> u32 {
+    fn with_rng<R>(&self, op: impl FnOnce(&mut SmallRng) -> R) -> R {
         // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u32())
+        if let Some(seeded) = self.seeded.as_ref() {
+            seeded.with_rng(op)
+        } else if self.use_repeatable {
+            REPEATABLE_RNG.with(|t| op(&mut t.borrow_mut()))
         } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u32())
+            RANDOMIZED_RNG.with(|t| op(&mut t.borrow_mut()))
         }
     }
+}
+
+impl RngCore for RandomGen {
+    fn next_u32(&mut self) -> u32 {
+        self.with_rng(|rng| rng.next_u32())
+    }
 
     fn next_u64(&mut self) -> u64 {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u64())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u64())
-        }
+        self.with_rng(|rng| rng.next_u64())
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.fill_bytes(dest))
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.try_fill_bytes(dest))
     }
 }
 
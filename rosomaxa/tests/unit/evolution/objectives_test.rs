@@ -0,0 +1,56 @@
+use super::*;
+
+fn create_population() -> Vec<Vec<Float>> {
+    vec![vec![1000., 1.], vec![1010., 5.], vec![1020., 9.], vec![1030., 3.]]
+}
+
+fn get_raw_order(a: &[Float], b: &[Float]) -> Ordering {
+    a.iter().sum::<Float>().total_cmp(&b.iter().sum::<Float>())
+}
+
+parameterized_test! {can_change_order_with_normalization, normalization, {
+    can_change_order_with_normalization_impl(normalization);
+}}
+
+can_change_order_with_normalization! {
+    case01_min_max: FitnessNormalization::MinMax,
+    case02_z_score: FitnessNormalization::ZScore,
+}
+
+fn can_change_order_with_normalization_impl(normalization: FitnessNormalization) {
+    let a = vec![1000., 9.];
+    let b = vec![1010., 1.];
+    let normalizer = FitnessNormalizer::new(normalization, create_population().as_slice());
+
+    let raw_order = get_raw_order(a.as_slice(), b.as_slice());
+    let normalized_order = normalizer.scalarized_order(a.into_iter(), b.into_iter());
+
+    assert_eq!(raw_order, Ordering::Less);
+    assert_eq!(normalized_order, Ordering::Greater);
+}
+
+#[test]
+fn can_normalize_with_min_max() {
+    let normalizer = FitnessNormalizer::new(FitnessNormalization::MinMax, create_population().as_slice());
+
+    assert_eq!(normalizer.normalize(vec![1000., 1.].into_iter()), vec![0., 0.]);
+    assert_eq!(normalizer.normalize(vec![1030., 9.].into_iter()), vec![1., 1.]);
+    assert_eq!(normalizer.normalize(vec![1015., 5.].into_iter()), vec![0.5, 0.5]);
+}
+
+#[test]
+fn can_normalize_with_z_score() {
+    let normalizer = FitnessNormalizer::new(FitnessNormalization::ZScore, create_population().as_slice());
+
+    let normalized = normalizer.normalize(vec![1015., 4.5].into_iter());
+
+    assert_eq!(normalized, vec![0., 0.]);
+}
+
+#[test]
+fn can_keep_scale_of_degenerated_objective() {
+    let population = vec![vec![1., 2.], vec![1., 4.]];
+    let normalizer = FitnessNormalizer::new(FitnessNormalization::MinMax, population.as_slice());
+
+    assert_eq!(normalizer.normalize(vec![3., 3.].into_iter()), vec![2., 0.5]);
+}
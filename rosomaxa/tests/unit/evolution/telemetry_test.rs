@@ -38,3 +38,28 @@ fn can_update_statistic() {
     telemetry.on_generation(population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_track_trajectory() {
+    let run_telemetry = |track_trajectory: bool| {
+        let environment = Arc::new(Environment::default());
+        let objective = create_example_objective();
+        let selection_size = get_default_selection_size(environment.as_ref());
+        let mut population = get_default_population(objective.clone(), environment, selection_size);
+
+        let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1000, track_trajectory });
+        for data in [vec![2., 2.], vec![3., 3.], vec![1.5, 1.5], vec![1., 1.]] {
+            population.add(VectorSolution::new_with_objective(data, objective.as_ref()));
+            telemetry.on_generation(population.as_ref(), 0., Timer::start(), true);
+        }
+
+        telemetry.take_metrics().expect("no metrics").trajectory
+    };
+
+    let trajectory = run_telemetry(true);
+    assert_eq!(trajectory.len(), 4);
+    assert!(trajectory.windows(2).all(|pair| pair[1][0] <= pair[0][0]));
+    assert_eq!(trajectory.last().unwrap()[0], 0.);
+
+    assert!(run_telemetry(false).is_empty());
+}
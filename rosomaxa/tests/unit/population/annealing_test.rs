@@ -0,0 +1,73 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::example::create_example_objective;
+
+fn create_population(initial_temperature: Float) -> Annealing<VectorObjective, VectorSolution> {
+    let config = AnnealingConfig { initial_temperature, ..AnnealingConfig::new_with_defaults(1) };
+
+    Annealing::new(create_example_objective(), Arc::new(DefaultRandom::new_repeatable()), config)
+}
+
+fn get_accepted_worse_count(initial_temperature: Float, attempts: usize) -> usize {
+    let objective = create_example_objective();
+
+    (0..attempts)
+        .filter(|_| {
+            let mut population = create_population(initial_temperature);
+            population.add(VectorSolution::new_with_objective(vec![0., 0.], objective.as_ref()));
+            population.add(VectorSolution::new_with_objective(vec![-1., -1.], objective.as_ref()));
+
+            population.size() == 2
+        })
+        .count()
+}
+
+#[test]
+fn can_keep_best_solution() {
+    let objective = create_example_objective();
+    let mut population = create_population(1.);
+
+    assert!(population.add(VectorSolution::new_with_objective(vec![-1., -1.], objective.as_ref())));
+    assert!(population.add(VectorSolution::new_with_objective(vec![0., 0.], objective.as_ref())));
+    assert!(!population.add(VectorSolution::new_with_objective(vec![2., 2.], objective.as_ref())));
+
+    assert_eq!(population.ranked().next().unwrap().fitness().next().unwrap(), 1.);
+}
+
+#[test]
+fn can_accept_worse_solutions_depending_on_temperature() {
+    let attempts = 100;
+
+    let high_temperature_count = get_accepted_worse_count(100., attempts);
+    let low_temperature_count = get_accepted_worse_count(0.01, attempts);
+
+    assert!(high_temperature_count > attempts / 2);
+    assert!(low_temperature_count < 5);
+}
+
+#[test]
+fn can_select_current_solution() {
+    let objective = create_example_objective();
+    let mut population = create_population(Float::MAX);
+
+    assert_eq!(population.select().count(), 0);
+
+    population.add(VectorSolution::new_with_objective(vec![0., 0.], objective.as_ref()));
+    population.add(VectorSolution::new_with_objective(vec![0.5, 0.5], objective.as_ref()));
+
+    assert_eq!(population.select().count(), 1);
+    assert_eq!(population.select().next().unwrap().fitness().next().unwrap(), 6.5);
+}
+
+#[test]
+fn can_decay_temperature() {
+    let mut population = create_population(1.);
+
+    population.on_generation(&HeuristicStatistics { generation: 1000, ..HeuristicStatistics::default() });
+    let decayed = population.temperature();
+    population.on_generation(&HeuristicStatistics { generation: 100_000, ..HeuristicStatistics::default() });
+    let min = population.temperature();
+
+    assert!((decayed - Float::powi(0.999, 1000)).abs() < 1E-9);
+    assert_eq!(min, 1E-6);
+}
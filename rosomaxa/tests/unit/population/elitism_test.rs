@@ -151,3 +151,66 @@ fn can_detect_improvement_impl(new_individuals: Vec<Vec<Float>>, expected: bool)
         expected
     )
 }
+
+#[derive(Clone)]
+struct MultiFitnessSolution(Vec<Float>);
+
+impl HeuristicSolution for MultiFitnessSolution {
+    fn fitness(&self) -> impl Iterator<Item = Float> {
+        self.0.iter().copied()
+    }
+
+    fn deep_copy(&self) -> Self {
+        self.clone()
+    }
+}
+
+struct LexicographicObjective;
+
+impl HeuristicObjective for LexicographicObjective {
+    type Solution = MultiFitnessSolution;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        a.fitness().zip(b.fitness()).map(|(a, b)| a.total_cmp(&b)).find(|ord| ord.is_ne()).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl Shuffled for LexicographicObjective {
+    fn get_shuffled(&self, _: &dyn Random) -> Self {
+        LexicographicObjective
+    }
+}
+
+parameterized_test! {can_sort_individuals_with_normalization, (normalization, expected), {
+    can_sort_individuals_with_normalization_impl(normalization, expected);
+}}
+
+can_sort_individuals_with_normalization! {
+    case01_no_normalization: (None, vec![vec![1000., 9.], vec![1010., 1.], vec![1030., 5.]]),
+    case02_min_max: (Some(FitnessNormalization::MinMax), vec![vec![1010., 1.], vec![1000., 9.], vec![1030., 5.]]),
+}
+
+fn can_sort_individuals_with_normalization_impl(
+    normalization: Option<FitnessNormalization>,
+    expected: Vec<Vec<Float>>,
+) {
+    let population = Elitism::new_with_dedup(
+        Arc::new(LexicographicObjective),
+        Environment::default().random,
+        3,
+        1,
+        Box::new(|_, _, _| false),
+    );
+    let mut population = match normalization {
+        Some(normalization) => population.with_normalization(normalization),
+        None => population,
+    };
+
+    population.add_all(vec![
+        MultiFitnessSolution(vec![1030., 5.]),
+        MultiFitnessSolution(vec![1010., 1.]),
+        MultiFitnessSolution(vec![1000., 9.]),
+    ]);
+
+    assert_eq!(population.ranked().map(|individual| individual.0.clone()).collect::<Vec<_>>(), expected);
+}
@@ -0,0 +1,18 @@
+use super::*;
+use crate::helpers::example::create_default_heuristic_context;
+
+#[test]
+fn can_detect_cancellation() {
+    let mut context = create_default_heuristic_context();
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+    let termination = Cancellation::<_, _, _>::new(is_cancelled.clone());
+
+    assert!(!termination.is_termination(&mut context));
+    assert_eq!(termination.estimate(&context), 0.);
+
+    is_cancelled.store(true, Ordering::Relaxed);
+
+    assert!(termination.is_termination(&mut context));
+    assert_eq!(termination.estimate(&context), 1.);
+    assert_eq!(termination.reason(), TerminationReason::Cancelled);
+}
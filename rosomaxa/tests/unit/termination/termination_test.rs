@@ -0,0 +1,41 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective};
+use crate::helpers::example::create_heuristic_context_with_solutions;
+
+type VectorTermination = Box<dyn Termination<Context = VectorContext, Objective = VectorObjective>>;
+
+fn create_max_generation(limit: usize) -> VectorTermination {
+    Box::new(MaxGeneration::new(limit))
+}
+
+fn create_target_proximity(target_fitness: Float) -> VectorTermination {
+    Box::new(TargetProximity::new(vec![target_fitness], 0.1))
+}
+
+parameterized_test! {can_detect_composite_termination_reason, (terminations, expected), {
+    can_detect_composite_termination_reason_impl(terminations, expected);
+}}
+
+can_detect_composite_termination_reason! {
+    case01_converged: (vec![create_max_generation(100), create_target_proximity(0.91)], Some(TerminationReason::Converged)),
+    case02_budget: (vec![create_target_proximity(0.), create_max_generation(0)], Some(TerminationReason::Budget)),
+    case03_not_terminated: (vec![create_target_proximity(0.), create_max_generation(100)], None),
+}
+
+fn can_detect_composite_termination_reason_impl(
+    terminations: Vec<VectorTermination>,
+    expected: Option<TerminationReason>,
+) {
+    let mut context = create_heuristic_context_with_solutions(vec![vec![0., 0.]]);
+    let termination = CompositeTermination::new(terminations);
+
+    let result = termination.is_termination(&mut context).then(|| termination.reason());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_return_reason_of_single_termination() {
+    assert_eq!(create_max_generation(1).reason(), TerminationReason::Budget);
+    assert_eq!(create_target_proximity(0.).reason(), TerminationReason::Converged);
+}
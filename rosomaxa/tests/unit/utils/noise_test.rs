@@ -0,0 +1,149 @@
+use super::*;
+use crate::prelude::RandomGen;
+use rand::prelude::*;
+use rand::rngs::SmallRng;
+use std::sync::Mutex;
+
+/// A random generator with a fixed seed which is not shared with other instances.
+struct SeededRandom {
+    rng: Mutex<SmallRng>,
+}
+
+impl SeededRandom {
+    fn new(seed: u64) -> Self {
+        Self { rng: Mutex::new(SmallRng::seed_from_u64(seed)) }
+    }
+}
+
+impl Random for SeededRandom {
+    fn uniform_int(&self, min: i32, max: i32) -> i32 {
+        self.rng.lock().unwrap().gen_range(min..=max)
+    }
+
+    fn uniform_real(&self, min: Float, max: Float) -> Float {
+        self.rng.lock().unwrap().gen_range(min..max)
+    }
+
+    fn is_head_not_tails(&self) -> bool {
+        self.rng.lock().unwrap().gen_bool(0.5)
+    }
+
+    fn is_hit(&self, probability: Float) -> bool {
+        self.rng.lock().unwrap().gen_bool(probability.clamp(0., 1.))
+    }
+
+    fn weighted(&self, _: &[usize]) -> usize {
+        unimplemented!()
+    }
+
+    fn get_rng(&self) -> RandomGen {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn can_generate_noise_from_samples_with_ratio() {
+    let noise = Noise::from_samples(vec![0.5, 2., 1.5], 1., false).unwrap();
+
+    let values = (0..5).map(|_| noise.generate(10.)).collect::<Vec<_>>();
+
+    assert_eq!(values, vec![5., 20., 15., 5., 20.]);
+}
+
+#[test]
+fn can_generate_noise_from_samples_with_addition() {
+    let noise = Noise::from_samples(vec![0.1, -0.2], 1., true).unwrap();
+
+    assert_eq!(noise.generate(10.), 11.);
+    assert_eq!(noise.generate(10.), 8.);
+    assert_eq!(noise.generate(0.), 0.1);
+}
+
+#[test]
+fn can_skip_samples_when_probability_is_not_hit() {
+    let noise = Noise::from_samples(vec![0.5], 0., false).unwrap();
+
+    assert_eq!(noise.generate(10.), 10.);
+}
+
+#[test]
+fn can_reject_empty_noise_samples() {
+    assert!(Noise::from_samples(vec![], 1., false).is_err());
+}
+
+parameterized_test! {can_generate_noise_with_distribution, (buckets, is_addition), {
+    can_generate_noise_with_distribution_impl(buckets, is_addition);
+}}
+
+can_generate_noise_with_distribution! {
+    case01_ratio_mostly_small: (&[(0.8, (0., 0.1)), (0.2, (1., 2.))], false),
+    case02_addition_mostly_small: (&[(0.8, (0., 0.1)), (0.2, (1., 2.))], true),
+    case03_ratio_three_buckets: (&[(0.5, (0., 0.1)), (0.3, (1., 2.)), (0.2, (5., 6.))], false),
+    case04_zero_weight_bucket: (&[(0., (0., 0.1)), (1., (1., 2.))], false),
+}
+
+fn can_generate_noise_with_distribution_impl(buckets: &[(Float, (Float, Float))], is_addition: bool) {
+    let total = 10000;
+    let noise = Noise::new_with_distribution(1., buckets, is_addition, Arc::new(SeededRandom::new(42)))
+        .expect("cannot create noise");
+
+    let samples = (0..total).map(|_| noise.generate(1.) - if is_addition { 1. } else { 0. }).collect::<Vec<_>>();
+
+    buckets.iter().for_each(|&(weight, (min, max))| {
+        let count = samples.iter().filter(|&&sample| sample >= min && sample < max).count();
+        let ratio = count as Float / total as Float;
+        assert!((ratio - weight).abs() < 0.02, "expected ratio {weight}, got {ratio}");
+    });
+}
+
+#[test]
+fn can_generate_noise_with_distribution_for_zero_value() {
+    let noise = Noise::new_with_distribution(1., &[(1., (2., 3.))], true, Arc::new(SeededRandom::new(42)))
+        .expect("cannot create noise");
+
+    let sample = noise.generate(0.);
+
+    assert!((2. ..3.).contains(&sample));
+}
+
+parameterized_test! {can_reject_invalid_noise_distribution, buckets, {
+    let result = Noise::new_with_distribution(1., buckets, true, Arc::new(SeededRandom::new(42)));
+
+    assert!(result.is_err());
+}}
+
+can_reject_invalid_noise_distribution! {
+    case01_empty: &[],
+    case02_negative_weight: &[(-0.5, (0., 0.1)), (1., (1., 2.))],
+    case03_all_zero_weights: &[(0., (0., 0.1)), (0., (1., 2.))],
+}
+
+type NoiseFactory = fn(Float, (Float, Float), Arc<dyn Random>) -> Noise;
+
+parameterized_test! {can_generate_multi_same_as_single, (factory, values), {
+    can_generate_multi_same_as_single_impl(factory, values);
+}}
+
+can_generate_multi_same_as_single! {
+    case01_addition: (Noise::new_with_addition, vec![10., -5., 0., 3.5, 100.]),
+    case02_ratio: (Noise::new_with_ratio, vec![10., -5., 0., 3.5, 100.]),
+}
+
+fn can_generate_multi_same_as_single_impl(factory: NoiseFactory, values: Vec<Float>) {
+    let create_noise = || factory(0.75, (0.5, 1.5), Arc::new(SeededRandom::new(42)));
+    let (multi_noise, single_noise) = (create_noise(), create_noise());
+
+    let multi = multi_noise.generate_multi(values.clone().into_iter()).collect::<Vec<_>>();
+    let single = values.iter().map(|value| single_noise.generate(*value)).collect::<Vec<_>>();
+
+    assert_eq!(multi, single);
+}
+
+#[test]
+fn can_generate_multi_from_samples_without_double_addition() {
+    let addition = Noise::from_samples(vec![0.1], 1., true).unwrap();
+    let ratio = Noise::from_samples(vec![0.5], 1., false).unwrap();
+
+    assert_eq!(addition.generate_multi(vec![10., 20.].into_iter()).collect::<Vec<_>>(), vec![11., 22.]);
+    assert_eq!(ratio.generate_multi(vec![10., 20.].into_iter()).collect::<Vec<_>>(), vec![5., 10.]);
+}
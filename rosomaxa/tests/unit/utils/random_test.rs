@@ -20,3 +20,48 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+#[test]
+fn can_reproduce_sequence_with_same_seed() {
+    let generate = |random: &DefaultRandom| (0..100).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>();
+    let (first, second, other) =
+        (DefaultRandom::new_with_seed(42), DefaultRandom::new_with_seed(42), DefaultRandom::new_with_seed(7));
+
+    let first_values = generate(&first);
+    // NOTE interleave with repeatable generator to ensure that seeded sequence doesn't depend on it
+    let _ = generate(&DefaultRandom::new_repeatable());
+    let second_values = generate(&second);
+
+    assert_eq!(first_values, second_values);
+    assert_ne!(first_values, generate(&other));
+}
+
+#[test]
+fn can_use_own_seeded_generator_per_thread() {
+    let generate = |random: &DefaultRandom| (0..100).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>();
+    let random = Arc::new(DefaultRandom::new_with_seed(42));
+
+    let thread_values = std::thread::scope(|scope| scope.spawn(|| generate(&random)).join().unwrap());
+    let main_values = generate(&random);
+
+    // NOTE threads outside of thread pool start from the same state, but don't affect each other
+    assert_eq!(thread_values, main_values);
+    assert_eq!(main_values, generate(&DefaultRandom::new_with_seed(42)));
+}
+
+#[test]
+fn can_keep_seeded_generators_within_instance() {
+    let random = DefaultRandom::new_with_seed(42);
+    let get_rngs_size = |random: &DefaultRandom| random.seeded.as_ref().unwrap().rngs.read().unwrap().len();
+
+    std::thread::scope(|scope| {
+        (0..3).for_each(|_| {
+            scope.spawn(|| random.uniform_int(0, 1000));
+        })
+    });
+    assert_eq!(get_rngs_size(&random), 3);
+
+    let other = DefaultRandom::new_with_seed(42);
+    other.uniform_int(0, 1000);
+    assert_eq!(get_rngs_size(&other), 1);
+}
@@ -18,6 +18,7 @@ use vrp_core::prelude::*;
 use vrp_core::rosomaxa::{evolution::*, get_default_population, get_default_selection_size};
 use vrp_core::solver::*;
 use vrp_core::utils::*;
+use vrp_pragmatic::format::solution::PragmaticOutputOptions;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -26,6 +27,7 @@ const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const MIN_CV_ARG_NAME: &str = "min-cv";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
+const EXTRA_OUTPUT_ARG_NAME: &str = "extra-output";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const INIT_SIZE_ARG_NAME: &str = "init-size";
@@ -116,6 +118,14 @@ pub fn get_solve_app() -> Command {
                 .long(GEO_JSON_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(EXTRA_OUTPUT_ARG_NAME)
+                .help(
+                    "Specifies comma separated list of optional pragmatic solution properties: unused-vehicles, detours, utilizations, slacks, energies, arrivals, schedules, summary",
+                )
+                .long(EXTRA_OUTPUT_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(CONFIG_ARG_NAME)
                 .help("Specifies path to algorithm configuration file")
@@ -186,7 +196,8 @@ pub fn run_solve(
     let environment = get_environment(matches)?;
 
     let is_rounded = matches.get_one::<bool>(ROUNDED_ARG_NAME).copied().unwrap_or(false);
-    let formats = get_formats(is_rounded, environment.random.clone());
+    let output_options = get_output_options(matches)?;
+    let formats = get_formats(is_rounded, output_options, environment.random.clone());
 
     let problem_path = matches
         .get_one::<String>(PROBLEM_ARG_NAME)
@@ -365,6 +376,13 @@ fn get_init_size(matches: &ArgMatches) -> GenericResult<Option<usize>> {
         .unwrap_or(Ok(None))
 }
 
+fn get_output_options(matches: &ArgMatches) -> GenericResult<PragmaticOutputOptions> {
+    matches
+        .get_one::<String>(EXTRA_OUTPUT_ARG_NAME)
+        .map(|arg| PragmaticOutputOptions::from_names(arg.split(',').map(|name| name.trim())))
+        .unwrap_or_else(|| Ok(PragmaticOutputOptions::default()))
+}
+
 fn get_environment(matches: &ArgMatches) -> GenericResult<Arc<Environment>> {
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
     let quota = Some(create_interruption_quota(max_time));
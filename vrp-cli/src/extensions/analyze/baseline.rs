@@ -0,0 +1,85 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/baseline_test.rs"]
+mod baseline_test;
+
+use std::collections::{BTreeSet, HashMap};
+use std::io::{BufReader, Read};
+use vrp_core::prelude::{Float, GenericResult};
+use vrp_pragmatic::format::solution::{deserialize_solution, Solution};
+
+/// A result of solution comparison against the baseline one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BaselineComparison {
+    /// Cost difference between the solution and the baseline: negative value means improvement.
+    pub cost_delta: Float,
+    /// Difference in amount of unassigned jobs: positive value means that more jobs are unassigned.
+    pub unassigned_delta: i64,
+    /// Ids of jobs which are served by another vehicle or changed their assigned/unassigned state.
+    pub changed_jobs: Vec<String>,
+    /// Ids of baseline jobs which are neither assigned nor unassigned in the solution.
+    pub missing_jobs: Vec<String>,
+    /// True if the solution is not worse than the baseline within the tolerance.
+    pub is_passed: bool,
+}
+
+/// Compares the solution against the baseline solution read from the reader. The comparison passes
+/// when the solution has no more unassigned jobs than the baseline, has no baseline jobs missing and
+/// its cost exceeds the baseline cost by no more than the `tolerance` ratio (e.g. `0.01` allows 1% worse
+/// cost). Changes in job assignments are reported, but do not affect the result.
+pub fn compare_with_baseline<R: Read>(
+    baseline: BufReader<R>,
+    solution: &Solution,
+    tolerance: Float,
+) -> GenericResult<BaselineComparison> {
+    if tolerance < 0. {
+        return Err(format!("tolerance should be non-negative, got: {tolerance}").into());
+    }
+
+    let baseline = deserialize_solution(baseline).map_err(|err| format!("cannot read baseline solution: {err}"))?;
+
+    let cost_delta = solution.statistic.cost - baseline.statistic.cost;
+    let unassigned_delta = get_unassigned_count(solution) as i64 - get_unassigned_count(&baseline) as i64;
+
+    let (baseline_assignments, assignments) = (get_assignments(&baseline), get_assignments(solution));
+    let changed_jobs = baseline_assignments
+        .keys()
+        .chain(assignments.keys())
+        .filter(|&job_id| baseline_assignments.get(job_id) != assignments.get(job_id))
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    let missing_jobs = baseline_assignments
+        .keys()
+        .filter(|&job_id| !assignments.contains_key(job_id))
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let is_passed =
+        unassigned_delta <= 0 && missing_jobs.is_empty() && cost_delta <= baseline.statistic.cost.abs() * tolerance;
+
+    Ok(BaselineComparison { cost_delta, unassigned_delta, changed_jobs, missing_jobs, is_passed })
+}
+
+fn get_unassigned_count(solution: &Solution) -> usize {
+    solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len())
+}
+
+/// Returns vehicle id which serves the job or `None` if the job is unassigned.
+fn get_assignments(solution: &Solution) -> HashMap<String, Option<String>> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| !matches!(activity.activity_type.as_str(), "departure" | "arrival"))
+                .map(move |activity| (activity.job_id.clone(), Some(tour.vehicle_id.clone())))
+        })
+        .chain(solution.unassigned.iter().flatten().map(|unassigned| (unassigned.job_id.clone(), None)))
+        .collect()
+}
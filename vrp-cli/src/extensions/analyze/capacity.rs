@@ -0,0 +1,72 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/capacity_test.rs"]
+mod capacity_test;
+
+use super::solve_problem;
+use vrp_core::models::common::Cost;
+use vrp_core::prelude::{Float, GenericResult};
+use vrp_pragmatic::format::problem::{Matrix, Problem};
+
+/// Contains results of solution sensitivity analysis to vehicle capacity change.
+#[derive(Clone, Debug)]
+pub struct CapacitySensitivity {
+    /// A factor used to scale vehicle capacities.
+    pub factor: Float,
+    /// A cost of the solution with original capacities.
+    pub original_cost: Cost,
+    /// A cost of the solution with scaled capacities.
+    pub scaled_cost: Cost,
+    /// Amount of unassigned jobs with original capacities.
+    pub original_unassigned: usize,
+    /// Amount of unassigned jobs with scaled capacities.
+    pub scaled_unassigned: usize,
+}
+
+impl CapacitySensitivity {
+    /// Returns cost change caused by capacity scaling.
+    pub fn cost_change(&self) -> Cost {
+        self.scaled_cost - self.original_cost
+    }
+
+    /// Returns change in amount of unassigned jobs caused by capacity scaling.
+    pub fn unassigned_change(&self) -> i64 {
+        self.scaled_unassigned as i64 - self.original_unassigned as i64
+    }
+}
+
+/// Solves the problem with original vehicle capacities and with capacities scaled by the given
+/// factor (e.g. 1.1 means 10% bigger vehicles) and reports changes in cost and unassigned jobs.
+/// Scaled capacities are rounded to the nearest integer.
+pub fn get_capacity_sensitivity(
+    problem: &Problem,
+    matrices: Option<Vec<Matrix>>,
+    factor: Float,
+    max_generations: usize,
+) -> GenericResult<CapacitySensitivity> {
+    if factor <= 0. {
+        return Err("capacity factor should be positive".into());
+    }
+
+    let scaled_problem = get_problem_with_scaled_capacity(problem, factor);
+
+    let original = solve_problem(problem.clone(), matrices.clone(), max_generations)?;
+    let scaled = solve_problem(scaled_problem, matrices, max_generations)?;
+
+    Ok(CapacitySensitivity {
+        factor,
+        original_cost: original.cost,
+        scaled_cost: scaled.cost,
+        original_unassigned: original.unassigned.len(),
+        scaled_unassigned: scaled.unassigned.len(),
+    })
+}
+
+fn get_problem_with_scaled_capacity(problem: &Problem, factor: Float) -> Problem {
+    let mut problem = problem.clone();
+
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        vehicle.capacity.iter_mut().for_each(|value| *value = (*value as Float * factor).round() as i32);
+    });
+
+    problem
+}
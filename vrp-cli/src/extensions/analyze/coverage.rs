@@ -0,0 +1,79 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/coverage_test.rs"]
+mod coverage_test;
+
+use std::collections::HashSet;
+use vrp_core::algorithms::geometry::{is_inside_polygon, Point};
+use vrp_pragmatic::format::problem::{Job, Problem};
+use vrp_pragmatic::format::solution::Solution;
+use vrp_pragmatic::format::Location;
+
+/// A named region specified by polygon of `(lat, lng)` points.
+#[derive(Clone, Debug)]
+pub struct Region {
+    /// Region name.
+    pub name: String,
+    /// Polygon vertices as `(lat, lng)` pairs. The polygon is closed implicitly.
+    pub polygon: Vec<(f64, f64)>,
+}
+
+/// A job coverage within the region.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegionCoverage {
+    /// Region name.
+    pub name: String,
+    /// Amount of region jobs served in the solution.
+    pub served: usize,
+    /// Amount of region jobs which are unassigned in the solution.
+    pub unassigned: usize,
+}
+
+/// Reports how many jobs of each region are served and unassigned in the solution. A job belongs to
+/// the region when its first place with coordinate location is inside the region polygon.
+/// Jobs which belong to many (overlapping) regions are counted in each of them.
+pub fn get_region_coverage(problem: &Problem, solution: &Solution, regions: &[Region]) -> Vec<RegionCoverage> {
+    let served = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.as_str())
+        .collect::<HashSet<_>>();
+    let unassigned =
+        solution.unassigned.iter().flatten().map(|unassigned| unassigned.job_id.as_str()).collect::<HashSet<_>>();
+
+    regions
+        .iter()
+        .map(|region| {
+            let polygon = region.polygon.iter().map(|&(lat, lng)| Point::new(lat, lng)).collect::<Vec<_>>();
+            let (served, unassigned) = problem
+                .plan
+                .jobs
+                .iter()
+                .filter(|job| get_job_coordinate(job).is_some_and(|point| is_inside_polygon(&point, &polygon)))
+                .fold((0, 0), |(served_count, unassigned_count), job| {
+                    if served.contains(job.id.as_str()) {
+                        (served_count + 1, unassigned_count)
+                    } else if unassigned.contains(job.id.as_str()) {
+                        (served_count, unassigned_count + 1)
+                    } else {
+                        (served_count, unassigned_count)
+                    }
+                });
+
+            RegionCoverage { name: region.name.clone(), served, unassigned }
+        })
+        .collect()
+}
+
+fn get_job_coordinate(job: &Job) -> Option<Point> {
+    [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .flat_map(|task| task.places.iter())
+        .find_map(|place| match place.location {
+            Location::Coordinate { lat, lng } => Some(Point::new(lat, lng)),
+            _ => None,
+        })
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/fleet_size_test.rs"]
+mod fleet_size_test;
+
+use super::solve_problem;
+use vrp_core::models::common::Cost;
+use vrp_core::prelude::GenericResult;
+use vrp_pragmatic::format::problem::{Matrix, Problem};
+
+/// Solves the problem with incrementally growing fleet: first with one vehicle, then with two, and so on
+/// up to all vehicles defined in the problem. Vehicles are taken in the order of their definition.
+/// Returns a list of `(vehicle_count, cost, unassigned)` which can be used as a diminishing-returns curve
+/// to estimate a marginal value of each additional vehicle.
+pub fn get_vehicle_marginal_values(
+    problem: &Problem,
+    matrices: Option<Vec<Matrix>>,
+    max_generations: usize,
+) -> GenericResult<Vec<(usize, Cost, usize)>> {
+    let total_vehicles = problem.fleet.vehicles.iter().map(|vehicle| vehicle.vehicle_ids.len()).sum::<usize>();
+
+    (1..=total_vehicles)
+        .map(|vehicle_count| {
+            let solution =
+                solve_problem(get_problem_with_vehicles(problem, vehicle_count), matrices.clone(), max_generations)?;
+
+            Ok((vehicle_count, solution.cost, solution.unassigned.len()))
+        })
+        .collect()
+}
+
+/// An impact of removing a vehicle type from the fleet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VehicleTypeImpact {
+    /// Cost difference between solutions without and with the vehicle type: positive value means
+    /// that the solution without the vehicle type is more expensive.
+    pub cost_delta: Cost,
+    /// Difference in amount of unassigned jobs: positive value means that more jobs are unassigned
+    /// without the vehicle type.
+    pub unassigned_delta: i64,
+}
+
+/// Solves the problem twice: with the whole fleet and with the given vehicle type excluded.
+/// Returns the change in cost and amount of unassigned jobs caused by vehicle type removal.
+pub fn get_vehicle_type_removal_impact(
+    problem: &Problem,
+    matrices: Option<Vec<Matrix>>,
+    type_id: &str,
+    max_generations: usize,
+) -> GenericResult<VehicleTypeImpact> {
+    if !problem.fleet.vehicles.iter().any(|vehicle| vehicle.type_id == type_id) {
+        return Err(format!("cannot find vehicle type with id: '{type_id}'").into());
+    }
+
+    let mut reduced = problem.clone();
+    reduced.fleet.vehicles.retain(|vehicle| vehicle.type_id != type_id);
+
+    let original = solve_problem(problem.clone(), matrices.clone(), max_generations)?;
+    let reduced = solve_problem(reduced, matrices, max_generations)?;
+
+    Ok(VehicleTypeImpact {
+        cost_delta: reduced.cost - original.cost,
+        unassigned_delta: reduced.unassigned.len() as i64 - original.unassigned.len() as i64,
+    })
+}
+
+fn get_problem_with_vehicles(problem: &Problem, vehicle_count: usize) -> Problem {
+    let mut problem = problem.clone();
+
+    let mut available = vehicle_count;
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        vehicle.vehicle_ids.truncate(available);
+        available -= vehicle.vehicle_ids.len();
+    });
+    problem.fleet.vehicles.retain(|vehicle| !vehicle.vehicle_ids.is_empty());
+
+    problem
+}
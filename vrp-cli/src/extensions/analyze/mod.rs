@@ -1,4 +1,39 @@
 //! Provides functionality for problem/solution analysis.
 
+mod baseline;
+pub use self::baseline::{compare_with_baseline, BaselineComparison};
+
+mod capacity;
+pub use self::capacity::{get_capacity_sensitivity, CapacitySensitivity};
+
 mod clusters;
 pub use self::clusters::get_clusters;
+
+mod coverage;
+pub use self::coverage::{get_region_coverage, Region, RegionCoverage};
+
+mod fleet_size;
+pub use self::fleet_size::{get_vehicle_marginal_values, get_vehicle_type_removal_impact, VehicleTypeImpact};
+
+mod overlap;
+pub use self::overlap::{get_route_overlaps, RouteOverlap};
+
+mod skills;
+pub use self::skills::{get_skills_requirement, SkillsRequirement};
+
+use std::sync::Arc;
+use vrp_core::models::Solution;
+use vrp_core::prelude::{GenericResult, Solver, VrpConfigBuilder};
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem};
+
+/// Solves the problem using default configuration limited by the given amount of generations.
+fn solve_problem(problem: Problem, matrices: Option<Vec<Matrix>>, max_generations: usize) -> GenericResult<Solution> {
+    let problem = Arc::new((problem, matrices).read_pragmatic().map_err(|errs| errs.to_string())?);
+
+    VrpConfigBuilder::new(problem.clone())
+        .prebuild()?
+        .with_max_generations(Some(max_generations))
+        .build()
+        .map(|config| Solver::new(problem, config))?
+        .solve()
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/overlap_test.rs"]
+mod overlap_test;
+
+use vrp_core::algorithms::geometry::{get_convex_hull, Point};
+use vrp_pragmatic::format::solution::{Solution, Tour};
+use vrp_pragmatic::format::Location;
+
+/// A spatial overlap between two tours.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteOverlap {
+    /// Vehicle id and shift index of the first tour.
+    pub first: (String, usize),
+    /// Vehicle id and shift index of the second tour.
+    pub second: (String, usize),
+    /// An intersection area of tours' convex hulls divided by the smaller hull area, in `[0, 1]`.
+    pub overlap: f64,
+}
+
+/// Returns pairs of tours whose spatial overlap is not less than `threshold`. A tour area is a convex
+/// hull of its job activity locations (departure and arrival are ignored). Tours with less than three
+/// distinct non-collinear points have no area and never overlap.
+pub fn get_route_overlaps(solution: &Solution, threshold: f64) -> Vec<RouteOverlap> {
+    let hulls = solution
+        .tours
+        .iter()
+        .map(|tour| ((tour.vehicle_id.clone(), tour.shift_index), get_convex_hull(&get_tour_points(tour))))
+        .collect::<Vec<_>>();
+
+    hulls
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, (first, first_hull))| {
+            hulls.iter().skip(idx + 1).filter_map(move |(second, second_hull)| {
+                let min_area = get_area(first_hull).min(get_area(second_hull));
+                if min_area <= 0. {
+                    return None;
+                }
+
+                let overlap = (get_area(&get_intersection(first_hull, second_hull)) / min_area).min(1.);
+
+                if overlap >= threshold {
+                    Some(RouteOverlap { first: first.clone(), second: second.clone(), overlap })
+                } else {
+                    None
+                }
+            })
+        })
+        .collect()
+}
+
+fn get_tour_points(tour: &Tour) -> Vec<Point> {
+    tour.stops
+        .iter()
+        .flat_map(|stop| {
+            stop.activities()
+                .iter()
+                .filter(|activity| activity.activity_type != "departure" && activity.activity_type != "arrival")
+                .filter_map(move |activity| match activity.location.as_ref().or(stop.location()) {
+                    Some(Location::Coordinate { lat, lng }) => Some(Point::new(*lat, *lng)),
+                    _ => None,
+                })
+        })
+        .collect()
+}
+
+/// Calculates polygon area using shoelace formula.
+fn get_area(polygon: &[Point]) -> f64 {
+    if polygon.len() < 3 {
+        return 0.;
+    }
+
+    let sum = polygon.iter().zip(polygon.iter().cycle().skip(1)).map(|(a, b)| a.x * b.y - b.x * a.y).sum::<f64>();
+
+    sum.abs() / 2.
+}
+
+/// Clips subject convex polygon by another convex polygon (both counter-clockwise) using
+/// Sutherland-Hodgman algorithm.
+fn get_intersection(subject: &[Point], clip: &[Point]) -> Vec<Point> {
+    if subject.len() < 3 || clip.len() < 3 {
+        return vec![];
+    }
+
+    clip.iter().zip(clip.iter().cycle().skip(1)).fold(subject.to_vec(), |input, (edge_start, edge_end)| {
+        if input.is_empty() {
+            return input;
+        }
+
+        let is_inside = |point: &Point| Point::cross_product(edge_start, edge_end, point) >= 0.;
+        let get_crossing = |a: &Point, b: &Point| {
+            let (a_side, b_side) =
+                (Point::cross_product(edge_start, edge_end, a), Point::cross_product(edge_start, edge_end, b));
+            let ratio = a_side / (a_side - b_side);
+
+            Point::new(a.x + (b.x - a.x) * ratio, a.y + (b.y - a.y) * ratio)
+        };
+
+        input.iter().zip(input.iter().cycle().skip(1)).fold(Vec::new(), |mut output, (current, next)| {
+            match (is_inside(current), is_inside(next)) {
+                (true, true) => output.push(next.clone()),
+                (true, false) => output.push(get_crossing(current, next)),
+                (false, true) => {
+                    output.push(get_crossing(current, next));
+                    output.push(next.clone());
+                }
+                (false, false) => {}
+            }
+            output
+        })
+    })
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/skills_test.rs"]
+mod skills_test;
+
+use std::collections::{BTreeSet, HashSet};
+use vrp_pragmatic::format::problem::{JobSkills, Problem};
+
+/// A fleet skills requirement to serve all jobs of the problem.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkillsRequirement {
+    /// A minimal set of skills required to serve all jobs, sorted by name.
+    pub required: Vec<String>,
+    /// Ids of jobs which cannot be served by any vehicle type of the fleet due to skills.
+    pub uncoverable: Vec<String>,
+}
+
+/// Analyzes skills of the problem: returns a minimal union of skills required across all jobs and
+/// a list of jobs which skills cannot be satisfied by any vehicle type. Each `allOf` skill is required,
+/// while `oneOf` skills are selected greedily preferring a skill which satisfies most of the jobs.
+pub fn get_skills_requirement(problem: &Problem) -> SkillsRequirement {
+    let job_skills = problem.plan.jobs.iter().filter_map(|job| job.skills.as_ref().map(|skills| (job, skills)));
+
+    let vehicle_skills = problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            vehicle
+                .skills
+                .iter()
+                .chain(problem.fleet.skills.iter())
+                .flatten()
+                .map(|skill| skill.as_str())
+                .collect::<HashSet<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let uncoverable = job_skills
+        .clone()
+        .filter(|(_, skills)| !vehicle_skills.iter().any(|vehicle_skills| is_satisfied(skills, vehicle_skills)))
+        .map(|(job, _)| job.id.clone())
+        .collect();
+
+    let mut required = job_skills
+        .clone()
+        .flat_map(|(_, skills)| skills.all_of.iter().flatten().map(|skill| skill.as_str()))
+        .collect::<BTreeSet<_>>();
+
+    let mut one_of_groups = job_skills
+        .filter_map(|(_, skills)| skills.one_of.as_ref())
+        .filter(|one_of| !one_of.is_empty())
+        .collect::<Vec<_>>();
+
+    loop {
+        one_of_groups.retain(|one_of| !one_of.iter().any(|skill| required.contains(skill.as_str())));
+
+        let candidates = one_of_groups.iter().flat_map(|one_of| one_of.iter()).collect::<BTreeSet<_>>();
+        let best = candidates.into_iter().max_by_key(|&candidate| {
+            let count = one_of_groups.iter().filter(|one_of| one_of.contains(candidate)).count();
+            // NOTE prefer lexicographically smaller skill on tie to have deterministic result
+            (count, std::cmp::Reverse(candidate))
+        });
+
+        match best {
+            Some(skill) => required.insert(skill.as_str()),
+            None => break,
+        };
+    }
+
+    SkillsRequirement { required: required.into_iter().map(|skill| skill.to_string()).collect(), uncoverable }
+}
+
+fn is_satisfied(skills: &JobSkills, vehicle_skills: &HashSet<&str>) -> bool {
+    let has_all_of = skills.all_of.iter().flatten().all(|skill| vehicle_skills.contains(skill.as_str()));
+    let has_one_of = skills
+        .one_of
+        .iter()
+        .all(|one_of| one_of.is_empty() || one_of.iter().any(|skill| vehicle_skills.contains(skill.as_str())));
+    let has_none_of = skills.none_of.iter().flatten().any(|skill| vehicle_skills.contains(skill.as_str()));
+
+    has_all_of && has_one_of && !has_none_of
+}
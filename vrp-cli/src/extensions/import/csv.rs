@@ -129,10 +129,12 @@ mod actual {
                         breaks: None,
                         reloads: None,
                         recharges: None,
+                        limits: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
                     limits: None,
+                    consumption: None,
                 }
             })
             .collect();
@@ -164,6 +166,8 @@ mod actual {
                 vehicles,
                 profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
                 resources: None,
+                skills: None,
+                day_horizon: None,
             },
             objectives: None,
         })
@@ -14,6 +14,7 @@ use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::GoalContext;
 use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::objectives::FitnessNormalization;
 use vrp_core::rosomaxa::evolution::{InitialOperator, TelemetryMode};
 use vrp_core::rosomaxa::get_default_selection_size;
 use vrp_core::rosomaxa::prelude::*;
@@ -68,6 +69,8 @@ pub enum PopulationType {
         max_size: Option<usize>,
         /// Selection size. Default is number of cpus.
         selection_size: Option<usize>,
+        /// Per objective fitness normalization used to sort individuals. Default is none.
+        fitness_normalization: Option<FitnessNormalizationType>,
     },
 
     /// A population algorithm based on SOM.
@@ -89,6 +92,31 @@ pub enum PopulationType {
         /// An exploration phase ratio. Default is 0.9.
         exploration_ratio: Option<Float>,
     },
+
+    /// A population which uses simulated annealing acceptance criterion.
+    #[serde(rename(deserialize = "annealing"))]
+    #[serde(rename_all = "camelCase")]
+    Annealing {
+        /// Selection size. Default is number of cpus.
+        selection_size: Option<usize>,
+        /// An initial temperature. Default is 0.1.
+        initial_temperature: Option<Float>,
+        /// A cooling rate applied on each generation. Default is 0.999.
+        cooling_rate: Option<Float>,
+        /// A minimum temperature. Default is 0.000001.
+        min_temperature: Option<Float>,
+    },
+}
+
+/// Specifies per objective fitness normalization type.
+#[derive(Clone, Deserialize, Debug)]
+pub enum FitnessNormalizationType {
+    /// Scales values to `[0, 1]` range using min and max values.
+    #[serde(rename(deserialize = "min-max"))]
+    MinMax,
+    /// Standardizes values using mean and standard deviation.
+    #[serde(rename(deserialize = "z-score"))]
+    ZScore,
 }
 
 /// An initial solution configuration.
@@ -266,6 +294,9 @@ pub enum RuinMethod {
     #[serde(rename(deserialize = "cluster"))]
     #[serde(rename_all = "camelCase")]
     Cluster { probability: Float, min: usize, max: usize },
+    /// Vicinity cluster split removal method.
+    #[serde(rename(deserialize = "vicinity-cluster"))]
+    VicinityCluster { probability: Float, min: usize, max: usize },
 }
 
 /// Specifies recreate methods with their probability weight and specific parameters.
@@ -373,6 +404,8 @@ pub struct MetricsConfig {
     enabled: bool,
     /// Specifies how often population is tracked. Default is 1000 (generations).
     track_population: Option<usize>,
+    /// Specifies whether best individual fitness is tracked on each generation. Default is false.
+    track_trajectory: Option<bool>,
 }
 
 /// An environment specific configuration.
@@ -387,6 +420,9 @@ pub struct EnvironmentConfig {
 
     /// Specifies experimental behavior flag.
     pub is_experimental: Option<bool>,
+
+    /// Specifies a seed of random generator to get reproducible results in single threaded mode.
+    pub seed: Option<u64>,
 }
 
 /// Data parallelism configuration.
@@ -427,6 +463,8 @@ pub struct NameWeight {
 pub struct OutputConfig {
     /// True if the solution, serialized as geojson features, should be included in solution.extras.
     pub include_geojson: Option<bool>,
+    /// Specifies optional pragmatic solution properties to be calculated, e.g. `unused-vehicles`.
+    pub extra_output: Option<Vec<String>>,
 }
 
 fn configure_from_evolution(
@@ -474,12 +512,24 @@ fn configure_from_evolution(
                     selection_size.unwrap_or(default_selection_size),
                     None,
                 )),
-                PopulationType::Elitism { max_size, selection_size } => Box::new(ElitismPopulation::new(
-                    problem.goal.clone(),
-                    environment.random.clone(),
-                    max_size.unwrap_or(4),
-                    selection_size.unwrap_or(default_selection_size),
-                )) as TargetPopulation,
+                PopulationType::Elitism { max_size, selection_size, fitness_normalization } => {
+                    let population = ElitismPopulation::new(
+                        problem.goal.clone(),
+                        environment.random.clone(),
+                        max_size.unwrap_or(4),
+                        selection_size.unwrap_or(default_selection_size),
+                    );
+
+                    Box::new(match fitness_normalization {
+                        Some(FitnessNormalizationType::MinMax) => {
+                            population.with_normalization(FitnessNormalization::MinMax)
+                        }
+                        Some(FitnessNormalizationType::ZScore) => {
+                            population.with_normalization(FitnessNormalization::ZScore)
+                        }
+                        None => population,
+                    }) as TargetPopulation
+                }
                 PopulationType::Rosomaxa {
                     max_elite_size,
                     max_node_size,
@@ -514,6 +564,23 @@ fn configure_from_evolution(
 
                     Box::new(RosomaxaPopulation::new(problem.goal.clone(), environment.clone(), config)?)
                 }
+                PopulationType::Annealing { selection_size, initial_temperature, cooling_rate, min_temperature } => {
+                    let mut config = AnnealingConfig::new_with_defaults(default_selection_size);
+                    if let Some(selection_size) = selection_size {
+                        config.selection_size = *selection_size;
+                    }
+                    if let Some(initial_temperature) = initial_temperature {
+                        config.initial_temperature = *initial_temperature;
+                    }
+                    if let Some(cooling_rate) = cooling_rate {
+                        config.cooling_rate = *cooling_rate;
+                    }
+                    if let Some(min_temperature) = min_temperature {
+                        config.min_temperature = *min_temperature;
+                    }
+
+                    Box::new(AnnealingPopulation::new(problem.goal.clone(), environment.random.clone(), config))
+                }
             };
 
             builder = builder.with_context(RefinementContext::new(problem, population, telemetry_mode, environment));
@@ -682,6 +749,9 @@ fn create_ruin_method(problem: &Arc<Problem>, method: &RuinMethod) -> (Arc<dyn R
             Arc::new(ClusterRemoval::new(problem.clone(), get_limits(*min, *max)).unwrap()),
             *probability,
         ),
+        RuinMethod::VicinityCluster { probability, min, max } => {
+            (Arc::new(VicinityClusterRemoval::new(get_limits(*min, *max))), *probability)
+        }
         RuinMethod::CloseRoute { probability } => (Arc::new(CloseRouteRemoval::new(limits)), *probability),
         RuinMethod::WorstRoute { probability } => (Arc::new(WorstRouteRemoval::new(limits)), *probability),
     }
@@ -717,9 +787,11 @@ fn get_telemetry_mode(environment: Arc<Environment>, telemetry_config: &Option<T
     const LOG_POPULATION: usize = 1000;
     const TRACK_POPULATION: usize = 1000;
 
-    let create_metrics = |track_population: &Option<usize>| TelemetryMode::OnlyMetrics {
-        track_population: track_population.unwrap_or(TRACK_POPULATION),
-    };
+    let create_metrics =
+        |track_population: &Option<usize>, track_trajectory: &Option<bool>| TelemetryMode::OnlyMetrics {
+            track_population: track_population.unwrap_or(TRACK_POPULATION),
+            track_trajectory: track_trajectory.unwrap_or(false),
+        };
 
     let create_progress = |log_best: &Option<usize>, log_population: &Option<usize>| TelemetryMode::OnlyLogging {
         logger: environment.logger.clone(),
@@ -728,22 +800,25 @@ fn get_telemetry_mode(environment: Arc<Environment>, telemetry_config: &Option<T
     };
 
     match telemetry_config.as_ref().map(|t| (&t.progress, &t.metrics)) {
-        Some((None, Some(MetricsConfig { enabled, track_population }))) if *enabled => create_metrics(track_population),
+        Some((None, Some(MetricsConfig { enabled, track_population, track_trajectory }))) if *enabled => {
+            create_metrics(track_population, track_trajectory)
+        }
         Some((Some(ProgressConfig { enabled, log_best, log_population }), None)) if *enabled => {
             create_progress(log_best, log_population)
         }
         Some((
             Some(ProgressConfig { enabled: progress_enabled, log_best, log_population }),
-            Some(MetricsConfig { enabled: metrics_enabled, track_population }),
+            Some(MetricsConfig { enabled: metrics_enabled, track_population, track_trajectory }),
         )) => match (progress_enabled, metrics_enabled) {
             (true, true) => TelemetryMode::All {
                 logger: environment.logger.clone(),
                 log_best: log_best.unwrap_or(LOG_BEST),
                 log_population: log_population.unwrap_or(LOG_POPULATION),
                 track_population: track_population.unwrap_or(TRACK_POPULATION),
+                track_trajectory: track_trajectory.unwrap_or(false),
             },
             (true, false) => create_progress(log_best, log_population),
-            (false, true) => create_metrics(track_population),
+            (false, true) => create_metrics(track_population, track_trajectory),
             _ => TelemetryMode::None,
         },
         _ => TelemetryMode::None,
@@ -773,6 +848,10 @@ fn configure_from_environment(
         environment.is_experimental = is_experimental;
     }
 
+    if let Some(seed) = environment_config.as_ref().and_then(|c| c.seed) {
+        environment.random = Arc::new(DefaultRandom::new_with_seed(seed));
+    }
+
     Arc::new(environment)
 }
 
@@ -7,7 +7,9 @@ use std::io::{BufReader, BufWriter, Write};
 use std::sync::Arc;
 use vrp_core::models::{Problem, Solution};
 use vrp_core::prelude::{GenericError, Random};
-use vrp_pragmatic::format::solution::{write_pragmatic, PragmaticOutputType};
+use vrp_pragmatic::format::solution::{
+    write_pragmatic, write_pragmatic_with_options, PragmaticOutputOptions, PragmaticOutputType,
+};
 use vrp_scientific::tsplib::{TsplibProblem, TsplibSolution};
 
 /// A reader for problem.
@@ -37,12 +39,16 @@ pub struct LocationWriter(pub Box<dyn Fn(File, BufWriter<Box<dyn Write>>) -> Res
 #[allow(clippy::type_complexity)]
 type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter)>;
 
-/// Gets available format readers/writers.
-pub fn get_formats<'a>(is_rounded: bool, random: Arc<dyn Random>) -> FormatMap<'a> {
+/// Gets available format readers/writers. Output options are applied only to pragmatic format.
+pub fn get_formats<'a>(
+    is_rounded: bool,
+    output_options: PragmaticOutputOptions,
+    random: Arc<dyn Random>,
+) -> FormatMap<'a> {
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats, is_rounded, random.clone());
-    add_pragmatic(&mut formats, random);
+    add_pragmatic(&mut formats, output_options, random);
 
     formats
 }
@@ -97,7 +103,7 @@ fn add_scientific(formats: &mut FormatMap, is_rounded: bool, random: Arc<dyn Ran
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random>) {
+fn add_pragmatic(formats: &mut FormatMap, output_options: PragmaticOutputOptions, random: Arc<dyn Random>) {
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
 
@@ -116,12 +122,20 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random>) {
             InitSolutionReader(Box::new(move |file, problem| {
                 read_init_pragmatic(BufReader::new(file), problem, random.clone())
             })),
-            SolutionWriter(Box::new(|problem, solution, mut default_writer, geojson_writer| {
+            SolutionWriter(Box::new(move |problem, solution, mut default_writer, geojson_writer| {
                 geojson_writer
                     .map_or(Ok(()), |mut geojson_writer| {
                         write_pragmatic(problem, &solution, PragmaticOutputType::OnlyGeoJson, &mut geojson_writer)
                     })
-                    .and_then(|_| write_pragmatic(problem, &solution, Default::default(), &mut default_writer))
+                    .and_then(|_| {
+                        write_pragmatic_with_options(
+                            problem,
+                            &solution,
+                            Default::default(),
+                            &output_options,
+                            &mut default_writer,
+                        )
+                    })
             })),
             LocationWriter(Box::new(|problem, writer| {
                 let mut writer = writer;
@@ -41,7 +41,7 @@ use std::sync::Arc;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::prelude::{GenericError, Solver};
 use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
-use vrp_pragmatic::format::solution::{write_pragmatic, PragmaticOutputType};
+use vrp_pragmatic::format::solution::{write_pragmatic_with_options, PragmaticOutputOptions, PragmaticOutputType};
 use vrp_pragmatic::format::FormatError;
 use vrp_pragmatic::get_unique_locations;
 use vrp_pragmatic::validation::ValidationContext;
@@ -172,8 +172,8 @@ mod c_interop {
                     Err(MultiFormatError::from(errors1.into_iter().chain(errors2).collect::<Vec<_>>()))
                 }
             }
-            .map_err(From::from)
-            .map(|_| "[]".to_string());
+            .map(|warnings| MultiFormatError::from(warnings).to_json())
+            .map_err(From::from);
 
             call_back(result, success, failure);
         });
@@ -421,7 +421,7 @@ mod wasm {
 
     use super::*;
     use vrp_pragmatic::format::problem::Matrix;
-    use vrp_pragmatic::format::CoordIndex;
+    use vrp_pragmatic::format::{CoordIndex, MultiFormatError};
     use wasm_bindgen::prelude::*;
 
     /// Returns a list of unique locations which can be used to request a routing matrix.
@@ -449,7 +449,7 @@ mod wasm {
         ValidationContext::new(&problem, matrices, &coord_index)
             .validate()
             .map_err(|errs| JsValue::from_str(errs.to_json().as_str()))
-            .map(|_| JsValue::from_str("[]"))
+            .map(|warnings| JsValue::from_str(MultiFormatError::from(warnings).to_json().as_str()))
     }
 
     /// Converts `problem` from format specified by `format` to `pragmatic` format.
@@ -521,14 +521,20 @@ pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Res
             .to_json()
         })?;
 
-    let output_type = if config.output.and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
+    let output_type = if config.output.as_ref().and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
         PragmaticOutputType::Combined
     } else {
         Default::default()
     };
+    let output_options = config
+        .output
+        .as_ref()
+        .and_then(|output_cfg| output_cfg.extra_output.as_ref())
+        .map(|names| PragmaticOutputOptions::from_names(names.iter().map(|name| name.as_str())))
+        .unwrap_or_else(|| Ok(PragmaticOutputOptions::default()))?;
 
     let mut writer = BufWriter::new(Vec::new());
-    write_pragmatic(problem.as_ref(), &solution, output_type, &mut writer)?;
+    write_pragmatic_with_options(problem.as_ref(), &solution, output_type, &output_options, &mut writer)?;
 
     let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
     let result = String::from_utf8(bytes).map_err(|err| format!("{err}"))?;
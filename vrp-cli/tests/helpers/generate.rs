@@ -43,10 +43,12 @@ pub fn create_test_vehicle_type() -> VehicleType {
             breaks: None,
             reloads: None,
             recharges: None,
+            limits: None,
         }],
         capacity: vec![10],
         skills: None,
         limits: None,
+        consumption: None,
     }
 }
 
@@ -73,6 +75,25 @@ pub fn create_test_job(lat: f64, lng: f64) -> Job {
     }
 }
 
+/// Creates jobs with `job<idx>` ids placed close to each other along the same line.
+pub fn create_test_jobs(size: usize) -> Vec<Job> {
+    (1..=size).map(|idx| Job { id: format!("job{idx}"), ..create_test_job(0., 0.001 * idx as f64) }).collect()
+}
+
+pub fn create_test_problem(jobs: Vec<Job>, vehicles: Vec<VehicleType>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles,
+            profiles: vec![create_test_vehicle_profile()],
+            resources: None,
+            skills: None,
+            day_horizon: None,
+        },
+        objectives: None,
+    }
+}
+
 pub const SIMPLE_PROBLEM: &str = r#"
 {
   "plan": {
@@ -4,3 +4,5 @@
 pub mod macros;
 
 pub mod generate;
+
+pub mod solution;
@@ -0,0 +1,77 @@
+use vrp_pragmatic::format::solution::*;
+use vrp_pragmatic::format::Location;
+
+pub fn create_test_activity(job_id: &str, activity_type: &str) -> Activity {
+    Activity {
+        job_id: job_id.to_string(),
+        activity_type: activity_type.to_string(),
+        location: None,
+        time: None,
+        job_tag: None,
+        commute: None,
+        cluster: None,
+    }
+}
+
+pub fn create_test_stop(location: Location, activities: Vec<Activity>) -> Stop {
+    Stop::Point(PointStop {
+        location,
+        time: Schedule { arrival: "2020-05-01T09:00:00Z".to_string(), departure: "2020-05-01T09:00:00Z".to_string() },
+        distance: 0,
+        load: vec![0],
+        parking: None,
+        activities,
+    })
+}
+
+/// Creates a tour which starts and ends at depot and has a delivery stop per job.
+pub fn create_test_tour(vehicle_id: &str, depot: Location, jobs: &[(&str, Location)]) -> Tour {
+    let create_stop = |job_id: &str, activity_type: &str, location: Location| {
+        create_test_stop(location, vec![create_test_activity(job_id, activity_type)])
+    };
+
+    Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "vehicle".to_string(),
+        shift_index: 0,
+        stops: std::iter::once(create_stop("departure", "departure", depot.clone()))
+            .chain(jobs.iter().map(|(job_id, location)| create_stop(job_id, "delivery", location.clone())))
+            .chain(std::iter::once(create_stop("arrival", "arrival", depot)))
+            .collect(),
+        statistic: Default::default(),
+    }
+}
+
+pub fn create_test_solution(tours: Vec<Tour>, unassigned: &[&str]) -> Solution {
+    Solution {
+        tours,
+        unassigned: if unassigned.is_empty() {
+            None
+        } else {
+            Some(
+                unassigned
+                    .iter()
+                    .map(|job_id| UnassignedJob {
+                        job_id: job_id.to_string(),
+                        reasons: vec![UnassignedJobReason {
+                            code: "NO_REASON_FOUND".to_string(),
+                            description: "unknown".to_string(),
+                            details: None,
+                        }],
+                    })
+                    .collect(),
+            )
+        },
+        statistic: Default::default(),
+        violations: None,
+        partial: None,
+        unused_vehicles: None,
+        detours: None,
+        utilizations: None,
+        slacks: None,
+        energies: None,
+        arrivals: None,
+        schedules: None,
+        extras: None,
+    }
+}
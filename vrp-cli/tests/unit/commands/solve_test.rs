@@ -150,3 +150,26 @@ fn can_specify_cv() {
         assert_eq!(min_cv, result);
     }
 }
+
+#[test]
+fn can_specify_extra_output() {
+    for (params, result) in [
+        (
+            vec!["--extra-output", "unused-vehicles"],
+            Ok(PragmaticOutputOptions { unused_vehicles: true, ..Default::default() }),
+        ),
+        (
+            vec!["--extra-output", "unused-vehicles,detours"],
+            Ok(PragmaticOutputOptions { unused_vehicles: true, detours: true, ..Default::default() }),
+        ),
+        (vec!["--extra-output", "unknown"], Err("unknown output option: 'unknown'".into())),
+        (vec![], Ok(PragmaticOutputOptions::default())),
+    ] {
+        let args = [&["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH], params.as_slice()].concat();
+        let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+        let options = get_output_options(&matches);
+
+        assert_eq!(options, result);
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::helpers::solution::*;
+use std::io::BufWriter;
+use vrp_pragmatic::format::solution::*;
+use vrp_pragmatic::format::Location;
+
+fn create_tour(vehicle_id: &str, job_ids: &[&str]) -> Tour {
+    let location = Location::Coordinate { lat: 0., lng: 0. };
+    let jobs = job_ids.iter().map(|&job_id| (job_id, location.clone())).collect::<Vec<_>>();
+
+    create_test_tour(vehicle_id, location, jobs.as_slice())
+}
+
+fn create_solution(cost: Float, tours: Vec<Tour>, unassigned: &[&str]) -> Solution {
+    Solution { statistic: Statistic { cost, ..Statistic::default() }, ..create_test_solution(tours, unassigned) }
+}
+
+fn create_baseline() -> Solution {
+    create_solution(100., vec![create_tour("v1", &["job1", "job2"]), create_tour("v2", &["job3"])], &[])
+}
+
+fn compare(solution: &Solution, tolerance: Float) -> BaselineComparison {
+    let mut writer = BufWriter::new(Vec::new());
+    serialize_solution(&create_baseline(), &mut writer).expect("cannot serialize baseline");
+    let baseline = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+    compare_with_baseline(BufReader::new(baseline.as_bytes()), solution, tolerance).expect("cannot compare")
+}
+
+#[test]
+fn can_compare_identical_solution() {
+    let result = compare(&create_baseline(), 0.);
+
+    assert_eq!(
+        result,
+        BaselineComparison {
+            cost_delta: 0.,
+            unassigned_delta: 0,
+            changed_jobs: vec![],
+            missing_jobs: vec![],
+            is_passed: true
+        }
+    );
+}
+
+#[test]
+fn can_compare_improved_solution() {
+    let solution = create_solution(90., vec![create_tour("v1", &["job1", "job2", "job3"])], &[]);
+
+    let result = compare(&solution, 0.);
+
+    assert_eq!(
+        result,
+        BaselineComparison {
+            cost_delta: -10.,
+            unassigned_delta: 0,
+            changed_jobs: vec!["job3".to_string()],
+            missing_jobs: vec![],
+            is_passed: true
+        }
+    );
+}
+
+parameterized_test! {can_compare_regressed_solution, (cost, job3_vehicle, unassigned, tolerance, expected_changed, expected_missing, expected_passed), {
+    let tours = std::iter::once(create_tour("v1", &["job1", "job2"]))
+        .chain(job3_vehicle.map(|vehicle_id| create_tour(vehicle_id, &["job3"])))
+        .collect();
+    let solution = create_solution(cost, tours, unassigned);
+    let to_ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+    let result = compare(&solution, tolerance);
+
+    assert_eq!(result.cost_delta, cost - 100.);
+    assert_eq!(result.changed_jobs, to_ids(expected_changed));
+    assert_eq!(result.missing_jobs, to_ids(expected_missing));
+    assert_eq!(result.is_passed, expected_passed);
+}}
+
+can_compare_regressed_solution! {
+    case01_worse_cost: (110., None, &["job3"], 0.05, &["job3"], &[], false),
+    case02_within_tolerance_but_unassigned: (101., None, &["job3"], 0.05, &["job3"], &[], false),
+    case03_job_missing_within_tolerance: (101., None, &[], 0.05, &["job3"], &["job3"], false),
+    case04_worse_cost_without_tolerance: (101., Some("v2"), &[], 0., &[], &[], false),
+    case05_worse_cost_within_tolerance: (101., Some("v2"), &[], 0.05, &[], &[], true),
+}
+
+#[test]
+fn can_return_error_for_invalid_baseline() {
+    let result = compare_with_baseline(BufReader::new("not a solution".as_bytes()), &create_baseline(), 0.);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,25 @@
+use super::*;
+use crate::helpers::generate::*;
+use vrp_pragmatic::format::problem::VehicleType;
+
+fn create_capacity_constrained_problem() -> Problem {
+    create_test_problem(create_test_jobs(3), vec![VehicleType { capacity: vec![1], ..create_test_vehicle_type() }])
+}
+
+#[test]
+fn can_reduce_unassigned_jobs_with_bigger_capacity() {
+    let problem = create_capacity_constrained_problem();
+
+    let sensitivity = get_capacity_sensitivity(&problem, None, 3., 10).expect("cannot get capacity sensitivity");
+
+    assert_eq!(sensitivity.original_unassigned, 2);
+    assert_eq!(sensitivity.scaled_unassigned, 0);
+    assert_eq!(sensitivity.unassigned_change(), -2);
+}
+
+#[test]
+fn can_reject_non_positive_factor() {
+    let problem = create_capacity_constrained_problem();
+
+    assert!(get_capacity_sensitivity(&problem, None, 0., 10).is_err());
+}
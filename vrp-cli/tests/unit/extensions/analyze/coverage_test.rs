@@ -0,0 +1,55 @@
+use super::*;
+use crate::helpers::generate::*;
+use crate::helpers::solution::*;
+
+fn create_region(name: &str, min: (f64, f64), max: (f64, f64)) -> Region {
+    Region { name: name.to_string(), polygon: vec![(min.0, min.1), (min.0, max.1), (max.0, max.1), (max.0, min.1)] }
+}
+
+#[test]
+fn can_get_region_coverage() {
+    let problem = create_test_problem(
+        vec![
+            Job { id: "job1".to_string(), ..create_test_job(0.5, 0.5) },
+            Job { id: "job2".to_string(), ..create_test_job(0.2, 0.8) },
+            Job { id: "job3".to_string(), ..create_test_job(1.5, 0.5) },
+            Job { id: "job4".to_string(), ..create_test_job(1.2, 0.2) },
+            Job { id: "job5".to_string(), ..create_test_job(1.8, 0.8) },
+            Job { id: "job6".to_string(), ..create_test_job(5., 5.) },
+        ],
+        vec![create_test_vehicle_type()],
+    );
+    let location = Location::Coordinate { lat: 0., lng: 0. };
+    let jobs = ["job1", "job3", "job6"].map(|job_id| (job_id, location.clone()));
+    let solution =
+        create_test_solution(vec![create_test_tour("vehicle_1", location.clone(), &jobs)], &["job2", "job4", "job5"]);
+    let regions = vec![create_region("west", (0., 0.), (1., 1.)), create_region("east", (1., 0.), (2., 1.))];
+
+    let coverage = get_region_coverage(&problem, &solution, regions.as_slice());
+
+    assert_eq!(
+        coverage,
+        vec![
+            RegionCoverage { name: "west".to_string(), served: 1, unassigned: 1 },
+            RegionCoverage { name: "east".to_string(), served: 1, unassigned: 2 },
+        ]
+    );
+}
+
+#[test]
+fn can_get_region_coverage_for_non_convex_region() {
+    let problem = create_test_problem(
+        vec![
+            Job { id: "job1".to_string(), ..create_test_job(1., 2.) },
+            Job { id: "job2".to_string(), ..create_test_job(3., 3.) },
+            Job { id: "job3".to_string(), ..create_test_job(2., 1.) },
+        ],
+        vec![create_test_vehicle_type()],
+    );
+    let solution = create_test_solution(vec![], &["job1", "job2", "job3"]);
+    let region = Region { name: "notch".to_string(), polygon: vec![(0., 0.), (0., 4.), (4., 4.), (4., 0.), (2., 2.)] };
+
+    let coverage = get_region_coverage(&problem, &solution, &[region]);
+
+    assert_eq!(coverage, vec![RegionCoverage { name: "notch".to_string(), served: 0, unassigned: 2 }]);
+}
@@ -0,0 +1,49 @@
+use super::*;
+use crate::helpers::generate::*;
+use vrp_pragmatic::format::problem::VehicleType;
+
+#[test]
+fn can_get_flattening_vehicle_marginal_values() {
+    let problem = create_test_problem(
+        create_test_jobs(3),
+        vec![VehicleType {
+            vehicle_ids: (1..=4).map(|idx| format!("vehicle_{idx}")).collect(),
+            capacity: vec![1],
+            ..create_test_vehicle_type()
+        }],
+    );
+
+    let values = get_vehicle_marginal_values(&problem, None, 10).expect("cannot get marginal values");
+
+    assert_eq!(
+        values.iter().map(|(count, _, unassigned)| (*count, *unassigned)).collect::<Vec<_>>(),
+        vec![(1, 2), (2, 1), (3, 0), (4, 0)]
+    );
+    assert_eq!(values[2].1, values[3].1);
+}
+
+#[test]
+fn can_get_vehicle_type_removal_impact() {
+    let problem = create_test_problem(
+        create_test_jobs(3),
+        vec![
+            VehicleType {
+                type_id: "small".to_string(),
+                vehicle_ids: vec!["small_1".to_string()],
+                capacity: vec![1],
+                ..create_test_vehicle_type()
+            },
+            VehicleType {
+                type_id: "large".to_string(),
+                vehicle_ids: vec!["large_1".to_string()],
+                capacity: vec![2],
+                ..create_test_vehicle_type()
+            },
+        ],
+    );
+
+    let impact = get_vehicle_type_removal_impact(&problem, None, "large", 10).expect("cannot get impact");
+
+    assert_eq!(impact.unassigned_delta, 2);
+    assert!(get_vehicle_type_removal_impact(&problem, None, "unknown", 10).is_err());
+}
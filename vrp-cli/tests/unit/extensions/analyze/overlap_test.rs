@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::solution::*;
+
+fn create_tour(vehicle_id: &str, points: &[(f64, f64)]) -> Tour {
+    let jobs = points.iter().map(|&(lat, lng)| ("job", Location::Coordinate { lat, lng })).collect::<Vec<_>>();
+
+    create_test_tour(vehicle_id, Location::Coordinate { lat: 10., lng: 10. }, jobs.as_slice())
+}
+
+#[test]
+fn can_detect_overlapping_routes() {
+    let solution = create_test_solution(
+        vec![
+            create_tour("v1", &[(0., 0.), (0., 2.), (2., 2.), (2., 0.), (1., 1.)]),
+            create_tour("v2", &[(0., 1.), (0., 3.), (2., 3.), (2., 1.)]),
+            create_tour("v3", &[(5., 5.), (5., 6.), (6., 6.), (6., 5.)]),
+        ],
+        &[],
+    );
+
+    let overlaps = get_route_overlaps(&solution, 0.3);
+
+    assert_eq!(
+        overlaps,
+        vec![RouteOverlap { first: ("v1".to_string(), 0), second: ("v2".to_string(), 0), overlap: 0.5 }]
+    );
+}
+
+#[test]
+fn can_detect_no_overlap_for_separate_routes() {
+    let solution = create_test_solution(
+        vec![
+            create_tour("v1", &[(0., 0.), (0., 1.), (1., 1.), (1., 0.)]),
+            create_tour("v2", &[(3., 3.), (3., 4.), (4., 4.), (4., 3.)]),
+        ],
+        &[],
+    );
+
+    let overlaps = get_route_overlaps(&solution, 0.);
+
+    assert_eq!(
+        overlaps,
+        vec![RouteOverlap { first: ("v1".to_string(), 0), second: ("v2".to_string(), 0), overlap: 0. }]
+    );
+    assert!(get_route_overlaps(&solution, 0.01).is_empty());
+}
+
+#[test]
+fn can_ignore_routes_without_area() {
+    let solution = create_test_solution(
+        vec![
+            create_tour("v1", &[(0., 0.), (1., 1.), (2., 2.)]),
+            create_tour("v2", &[(0., 0.), (0., 2.), (2., 2.), (2., 0.)]),
+        ],
+        &[],
+    );
+
+    assert!(get_route_overlaps(&solution, 0.).is_empty());
+}
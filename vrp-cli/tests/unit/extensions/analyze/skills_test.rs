@@ -0,0 +1,57 @@
+use super::*;
+use crate::helpers::generate::*;
+use vrp_pragmatic::format::problem::{Job, VehicleType};
+
+fn to_strings(values: &[&str]) -> Option<Vec<String>> {
+    Some(values.iter().map(|value| value.to_string()).collect())
+}
+
+fn create_job_with_skills(id: &str, all_of: &[&str], one_of: &[&str], none_of: &[&str]) -> Job {
+    let skills = JobSkills {
+        all_of: if all_of.is_empty() { None } else { to_strings(all_of) },
+        one_of: if one_of.is_empty() { None } else { to_strings(one_of) },
+        none_of: if none_of.is_empty() { None } else { to_strings(none_of) },
+    };
+
+    Job { id: id.to_string(), skills: Some(skills), ..create_test_job(0., 0.) }
+}
+
+fn create_vehicle_with_skills(type_id: &str, skills: &[&str]) -> VehicleType {
+    VehicleType { type_id: type_id.to_string(), skills: to_strings(skills), ..create_test_vehicle_type() }
+}
+
+#[test]
+fn can_list_uncoverable_jobs_with_skill_no_vehicle_provides() {
+    let problem = create_test_problem(
+        vec![
+            create_job_with_skills("job1", &["fridge"], &[], &[]),
+            create_job_with_skills("job2", &["crane"], &[], &[]),
+            create_job_with_skills("job3", &["fridge"], &[], &["smoker"]),
+            Job { id: "job4".to_string(), ..create_test_job(0., 0.) },
+        ],
+        vec![create_vehicle_with_skills("v1", &["fridge", "smoker"]), create_vehicle_with_skills("v2", &["fridge"])],
+    );
+
+    let requirement = get_skills_requirement(&problem);
+
+    assert_eq!(requirement.required, vec!["crane".to_string(), "fridge".to_string()]);
+    assert_eq!(requirement.uncoverable, vec!["job2".to_string()]);
+}
+
+#[test]
+fn can_select_minimal_one_of_skills() {
+    let problem = create_test_problem(
+        vec![
+            create_job_with_skills("job1", &[], &["a", "b"], &[]),
+            create_job_with_skills("job2", &[], &["b", "c"], &[]),
+            create_job_with_skills("job3", &[], &["c", "d"], &[]),
+            create_job_with_skills("job4", &["d"], &[], &[]),
+        ],
+        vec![create_vehicle_with_skills("v1", &["a"]), create_vehicle_with_skills("v2", &["d"])],
+    );
+
+    let requirement = get_skills_requirement(&problem);
+
+    assert_eq!(requirement.required, vec!["b".to_string(), "d".to_string()]);
+    assert_eq!(requirement.uncoverable, vec!["job2".to_string()]);
+}
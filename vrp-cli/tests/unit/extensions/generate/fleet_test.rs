@@ -10,6 +10,8 @@ fn can_generate_fleet_of_specific_size() {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None }],
             resources: None,
+            skills: None,
+            day_horizon: None,
         },
         objectives: None,
     };
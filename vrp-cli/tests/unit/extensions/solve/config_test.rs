@@ -119,6 +119,7 @@ fn can_read_full_config() {
 
     let output_cfg = config.output.expect("cannot read output config");
     assert_eq!(output_cfg.include_geojson, Some(true));
+    assert_eq!(output_cfg.extra_output, Some(vec!["unused-vehicles".to_string()]));
 }
 
 #[test]
@@ -140,7 +141,7 @@ fn can_configure_telemetry_metrics() {
         environment: None,
         telemetry: Some(TelemetryConfig {
             progress: None,
-            metrics: Some(MetricsConfig { enabled: true, track_population: Some(10) }),
+            metrics: Some(MetricsConfig { enabled: true, track_population: Some(10), track_trajectory: None }),
         }),
         output: None,
     };
@@ -156,6 +157,60 @@ fn can_configure_telemetry_metrics() {
     assert_eq!(metrics.evolution.len(), 10 + 1);
 }
 
+#[test]
+fn can_solve_with_annealing_population() {
+    let config = Config {
+        evolution: Some(EvolutionConfig {
+            initial: None,
+            population: Some(PopulationType::Annealing {
+                selection_size: Some(1),
+                initial_temperature: Some(1.),
+                cooling_rate: Some(0.9),
+                min_temperature: None,
+            }),
+        }),
+        hyper: None,
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        environment: None,
+        telemetry: None,
+        output: None,
+    };
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert!(solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_solve_with_normalized_elitism_population() {
+    let config = Config {
+        evolution: Some(EvolutionConfig {
+            initial: None,
+            population: Some(PopulationType::Elitism {
+                max_size: Some(4),
+                selection_size: Some(1),
+                fitness_normalization: Some(FitnessNormalizationType::MinMax),
+            }),
+        }),
+        hyper: None,
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        environment: None,
+        telemetry: None,
+        output: None,
+    };
+
+    let result = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve());
+
+    assert!(result.is_ok());
+}
+
 fn as_scalar_probability(probability: &OperatorProbabilityType) -> Float {
     match probability {
         OperatorProbabilityType::Scalar { scalar } => *scalar,
@@ -7,7 +7,13 @@ use vrp_pragmatic::format::MultiFormatError;
 fn can_get_locations_serialized() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], ..create_empty_plan() },
-        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], resources: None },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![],
+            resources: None,
+            skills: None,
+            day_horizon: None,
+        },
         objectives: None,
     };
 
@@ -24,6 +30,8 @@ fn can_get_solution_serialized() {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
             resources: None,
+            skills: None,
+            day_horizon: None,
         },
         objectives: None,
     };
@@ -0,0 +1,67 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/geometry/hull_test.rs"]
+mod hull_test;
+
+use super::Point;
+use rosomaxa::prelude::Float;
+use std::cmp::Ordering;
+
+/// Builds a convex hull of given points using monotone chain algorithm. Returns hull points in
+/// counter-clockwise order without repeating the first one. Duplicate and collinear points are excluded.
+pub fn get_convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then_with(|| a.y.total_cmp(&b.y)));
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    let build_chain = |chain: &mut Vec<Point>, point: &Point| {
+        while chain.len() >= 2
+            && Point::cross_product(&chain[chain.len() - 2], &chain[chain.len() - 1], point).total_cmp(&0.)
+                != Ordering::Greater
+        {
+            chain.pop();
+        }
+        chain.push(point.clone());
+    };
+
+    let mut lower = Vec::with_capacity(points.len());
+    points.iter().for_each(|point| build_chain(&mut lower, point));
+
+    let mut upper = Vec::with_capacity(points.len());
+    points.iter().rev().for_each(|point| build_chain(&mut upper, point));
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+
+    lower
+}
+
+/// Computes a perimeter of the polygon defined by given points.
+pub fn get_perimeter(points: &[Point]) -> Float {
+    match points.len() {
+        0 | 1 => 0.,
+        _ => points.iter().zip(points.iter().cycle().skip(1)).map(|(a, b)| a.distance_to_point(b)).sum(),
+    }
+}
+
+/// Checks whether the point is inside the polygon defined by given points using ray casting.
+/// Points on the polygon boundary might be considered as either inside or outside.
+pub fn is_inside_polygon(point: &Point, polygon: &[Point]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .filter(|(a, b)| {
+            (a.y > point.y) != (b.y > point.y) && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        })
+        .count()
+        % 2
+        == 1
+}
@@ -1,4 +1,7 @@
 //! A module with geometry primitives.
 
+mod hull;
+pub use self::hull::{get_convex_hull, get_perimeter, is_inside_polygon};
+
 mod point;
 pub use self::point::Point;
@@ -3,18 +3,21 @@
 mod estimations_test;
 
 use super::*;
+use crate::construction::features::JobSkillsDimension;
 use crate::models::common::*;
-use crate::models::problem::{Place, Single, TransportCost};
+use crate::models::problem::{JobIdDimension, Place, Single, TransportCost};
 use crate::models::solution::CommuteInfo;
 use crate::models::GoalContext;
-use rosomaxa::utils::parallel_foreach_mut;
+use rosomaxa::utils::{parallel_collect, parallel_foreach_mut};
 use std::collections::{HashMap, HashSet};
 
 type PlaceInfo = (PlaceIndex, Location, Duration, Vec<TimeWindow>);
 type PlaceIndex = usize;
 type Reachable = bool;
 type DissimilarityInfo = (Reachable, PlaceIndex, ClusterInfo);
-type DissimilarityIndex = HashMap<Job, Vec<DissimilarityInfo>>;
+
+/// Keeps dissimilarities of the job to other jobs.
+pub type DissimilarityIndex = HashMap<Job, Vec<DissimilarityInfo>>;
 
 /// Gets job clusters.
 pub(crate) fn get_clusters(
@@ -51,8 +54,10 @@ pub(crate) fn get_clusters(
             }
         });
 
+        // NOTE use center job id as a secondary key to make result independent of estimates iteration order
         cluster_estimates.sort_unstable_by(|(a_job, (_, a_can)), (b_job, (_, b_can))| {
             (config.building.ordering_global_fn)((b_job, b_can), (a_job, a_can))
+                .then_with(|| a_job.dimens().get_job_id().cmp(&b_job.dimens().get_job_id()))
         });
 
         let new_cluster = cluster_estimates.first().and_then(|(_, (cluster, _))| cluster.as_ref()).cloned();
@@ -94,28 +99,97 @@ pub(crate) fn get_clusters(
 }
 
 /// Gets jobs dissimilarities.
-pub(crate) fn get_jobs_dissimilarities(
+/// NOTE: complexity is still O(n²), but dissimilarities of each job are estimated in parallel.
+pub fn get_jobs_dissimilarities(
     jobs: &[Job],
     transport: &(dyn TransportCost),
     config: &ClusterConfig,
 ) -> HashMap<Job, DissimilarityIndex> {
-    jobs.iter()
-        .map(|outer| {
-            let dissimilarities = jobs
-                .iter()
-                .filter(|inner| outer != *inner)
-                .filter_map(|inner| {
-                    let dissimilarities = get_dissimilarities(outer, inner, transport, config);
-                    if dissimilarities.is_empty() {
-                        None
-                    } else {
-                        Some((inner.clone(), dissimilarities))
-                    }
-                })
-                .collect::<HashMap<_, _>>();
-            (outer.clone(), dissimilarities)
+    parallel_collect(jobs, |outer| {
+        let dissimilarities = jobs
+            .iter()
+            .filter(|inner| outer != *inner)
+            .filter_map(|inner| {
+                let dissimilarities = get_dissimilarities(outer, inner, transport, config);
+                if dissimilarities.is_empty() {
+                    None
+                } else {
+                    Some((inner.clone(), dissimilarities))
+                }
+            })
+            .collect::<HashMap<_, _>>();
+        (outer.clone(), dissimilarities)
+    })
+    .into_iter()
+    .collect::<HashMap<_, _>>()
+}
+
+/// Updates jobs dissimilarities with a new job: only dissimilarities from and to the new job are
+/// estimated, so the whole index is not rebuilt.
+pub fn update_dissimilarities(
+    index: &mut HashMap<Job, DissimilarityIndex>,
+    new_job: &Job,
+    transport: &dyn TransportCost,
+    config: &ClusterConfig,
+) {
+    let dissimilarities = index
+        .keys()
+        .filter(|job| *job != new_job)
+        .filter_map(|job| {
+            let dissimilarities = get_dissimilarities(new_job, job, transport, config);
+            if dissimilarities.is_empty() {
+                None
+            } else {
+                Some((job.clone(), dissimilarities))
+            }
         })
-        .collect::<HashMap<_, _>>()
+        .collect::<HashMap<_, _>>();
+
+    index.iter_mut().filter(|(job, _)| *job != new_job).for_each(|(job, job_index)| {
+        let dissimilarities = get_dissimilarities(job, new_job, transport, config);
+        if dissimilarities.is_empty() {
+            job_index.remove(new_job);
+        } else {
+            job_index.insert(new_job.clone(), dissimilarities);
+        }
+    });
+
+    index.insert(new_job.clone(), dissimilarities);
+}
+
+/// Splits jobs into groups where each job is reachable from at least one other job of its group.
+pub(crate) fn get_job_groups(jobs: &[Job], estimates: &HashMap<Job, DissimilarityIndex>) -> Vec<Vec<Job>> {
+    let is_reachable = |from: &Job, to: &Job| {
+        estimates
+            .get(from)
+            .and_then(|index| index.get(to))
+            .map_or(false, |infos| infos.iter().any(|(reachable, ..)| *reachable))
+    };
+
+    let mut used_jobs = HashSet::new();
+
+    jobs.iter().fold(Vec::new(), |mut groups, job| {
+        if used_jobs.insert(job.clone()) {
+            let mut group = vec![job.clone()];
+            let mut idx = 0;
+
+            while idx < group.len() {
+                let current = group[idx].clone();
+                jobs.iter().filter(|other| is_reachable(&current, other) || is_reachable(other, &current)).for_each(
+                    |other| {
+                        if used_jobs.insert(other.clone()) {
+                            group.push(other.clone());
+                        }
+                    },
+                );
+                idx += 1;
+            }
+
+            groups.push(group);
+        }
+
+        groups
+    })
 }
 
 fn get_dissimilarities(
@@ -124,60 +198,83 @@ fn get_dissimilarities(
     transport: &(dyn TransportCost),
     config: &ClusterConfig,
 ) -> Vec<DissimilarityInfo> {
-    let min_shared_time = config.threshold.min_shared_time.unwrap_or(0.);
+    let threshold = &config.threshold;
+    // NOTE absolute threshold has priority, ratio one is relative to the shorter service time
+    let get_min_shared_time = |outer_duration: Duration, inner_duration: Duration| {
+        threshold
+            .min_shared_time
+            .or_else(|| threshold.min_shared_time_ratio.map(|ratio| ratio * outer_duration.min(inner_duration)))
+            .unwrap_or(0.)
+    };
+
     outer
         .to_single()
         .places
         .iter()
         .enumerate()
         .filter_map(map_place)
-        .flat_map(|(outer_place_idx, outer_loc, _, outer_times)| {
+        .flat_map(|(outer_place_idx, outer_loc, outer_duration, outer_times)| {
             inner.to_single().places.iter().enumerate().filter_map(map_place).filter_map(
                 move |(inner_place_idx, inner_loc, inner_duration, inner_times)| {
-                    let shared_time = outer_times
+                    let min_shared_time = get_min_shared_time(outer_duration, inner_duration);
+                    // NOTE use start of the largest shared time window as a representative departure,
+                    // it stays zero for unbounded time windows
+                    let (shared_time, departure) = outer_times
                         .iter()
                         .flat_map(|outer_time| {
                             inner_times.iter().filter_map(move |inner_time| {
-                                outer_time.overlapping(inner_time).map(|tw| tw.duration())
+                                outer_time.overlapping(inner_time).map(|tw| (tw.duration(), tw.start))
                             })
                         })
-                        .max_by(|a, b| a.total_cmp(b))
-                        .unwrap_or(0.);
+                        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+                        .unwrap_or((0., 0.));
 
                     if shared_time > min_shared_time {
-                        let fwd_distance = transport.distance_approx(&config.profile, outer_loc, inner_loc);
-                        let fwd_duration = transport.duration_approx(&config.profile, outer_loc, inner_loc);
+                        let profile = &config.profile;
+                        let speed_factor = config.speed_factors.get(&profile.index).copied().unwrap_or(1.);
+
+                        let fwd_distance = transport.distance_at(profile, outer_loc, inner_loc, departure);
+                        let fwd_duration =
+                            transport.duration_at(profile, outer_loc, inner_loc, departure) / speed_factor;
+
+                        let bck_distance = transport.distance_at(profile, inner_loc, outer_loc, departure);
+                        let bck_duration =
+                            transport.duration_at(profile, inner_loc, outer_loc, departure) / speed_factor;
+
+                        let commute = Commute {
+                            forward: CommuteInfo {
+                                location: outer_loc,
+                                distance: fwd_distance,
+                                duration: fwd_duration,
+                            },
+                            backward: CommuteInfo {
+                                location: outer_loc,
+                                distance: bck_distance,
+                                duration: bck_duration,
+                            },
+                        };
 
-                        let bck_distance = transport.distance_approx(&config.profile, inner_loc, outer_loc);
-                        let bck_duration = transport.duration_approx(&config.profile, inner_loc, outer_loc);
+                        let similarity_ctx = SimilarityContext {
+                            outer,
+                            outer_place_idx,
+                            inner,
+                            inner_place_idx,
+                            shared_time,
+                            commute: &commute,
+                            threshold: &config.threshold,
+                        };
 
                         let reachable = fwd_distance >= 0. && bck_distance >= 0.;
-
                         let reachable = reachable
-                            && (fwd_duration - config.threshold.moving_duration < 0.)
-                            && (fwd_distance - config.threshold.moving_distance < 0.)
-                            && (bck_duration - config.threshold.moving_duration < 0.)
-                            && (bck_distance - config.threshold.moving_distance < 0.);
+                            && config.similarity_fn.as_ref().map_or_else(
+                                || is_similar_by_thresholds(&similarity_ctx),
+                                |func| (func)(&similarity_ctx),
+                            );
 
                         let (service_time, _) = get_service_time(inner_duration, &config.serving);
 
-                        let info = ClusterInfo {
-                            job: inner.clone(),
-                            service_time,
-                            place_idx: inner_place_idx,
-                            commute: Commute {
-                                forward: CommuteInfo {
-                                    location: outer_loc,
-                                    distance: fwd_distance,
-                                    duration: fwd_duration,
-                                },
-                                backward: CommuteInfo {
-                                    location: outer_loc,
-                                    distance: bck_distance,
-                                    duration: bck_duration,
-                                },
-                            },
-                        };
+                        let info =
+                            ClusterInfo { job: inner.clone(), service_time, place_idx: inner_place_idx, commute };
 
                         Some((reachable, outer_place_idx, info))
                     } else {
@@ -201,13 +298,17 @@ fn build_job_cluster(
     let center = center_job.to_single();
     let center_estimates = estimates.get(center_job).expect("missing job in estimates");
 
-    // iterate through all places and choose the one with most jobs clustered
+    let get_score = |cluster: &Job, count: usize| {
+        config.building.cluster_score_fn.as_ref().map_or(count as Float, |score_fn| (score_fn)(cluster))
+    };
+
+    // iterate through all places and choose the one with most jobs clustered (or the best score)
     center
         .places
         .iter()
         .enumerate()
         .filter_map(map_place)
-        .try_fold(Option::<(Job, usize)>::None, |best_cluster, center_place_info| {
+        .try_fold(Option::<(Job, usize, Float)>::None, |best_cluster, center_place_info| {
             let (center_place_idx, center_location, center_duration, center_times) = center_place_info;
             let (new_duration, parking) = get_service_time(center_duration, &config.serving);
             let new_duration = new_duration + parking;
@@ -243,6 +344,15 @@ fn build_job_cluster(
             };
 
             let is_max_jobs = |count| config.threshold.max_jobs_per_cluster.map_or(false, |max| max <= count);
+            let is_demand_fit = |cluster: &Job, candidate: &Job| {
+                config.demand_fit_fn.as_ref().is_none_or(|demand_fit_fn| {
+                    let cluster_info = cluster.dimens().get_cluster_info().expect("missing cluster info");
+                    let jobs =
+                        cluster_info.iter().map(|info| &info.job).chain(std::iter::once(candidate)).collect::<Vec<_>>();
+
+                    (demand_fit_fn)(jobs.as_slice())
+                })
+            };
 
             // allow jobs only from reachable candidates
             let mut cluster_candidates = center_estimates
@@ -283,22 +393,27 @@ fn build_job_cluster(
                 let addition_result = job_estimates
                     .iter()
                     .try_fold(None, |_, candidate| {
-                        try_add_job(
-                            variant,
-                            last_place_idx,
-                            &cluster,
-                            (candidate.0, candidate.1),
-                            config,
-                            center_commute,
-                            check_insertion,
-                        )
-                        .map_or_else(
-                            || {
-                                cluster_candidates.remove(candidate.0);
-                                ControlFlow::Continue(None)
-                            },
-                            |data| ControlFlow::Break(Some(data)),
-                        )
+                        // NOTE skip candidates exceeding max capacity as they would be rejected by constraints anyway
+                        is_demand_fit(&cluster, candidate.0)
+                            .then(|| {
+                                try_add_job(
+                                    variant,
+                                    last_place_idx,
+                                    &cluster,
+                                    (candidate.0, candidate.1),
+                                    config,
+                                    center_commute,
+                                    check_insertion,
+                                )
+                            })
+                            .flatten()
+                            .map_or_else(
+                                || {
+                                    cluster_candidates.remove(candidate.0);
+                                    ControlFlow::Continue(None)
+                                },
+                                |data| ControlFlow::Break(Some(data)),
+                            )
                     })
                     .unwrap_value();
 
@@ -322,15 +437,19 @@ fn build_job_cluster(
                 cluster = finish_cluster(cluster, config, center_commute);
             }
 
+            let score = get_score(&cluster, count);
+
             match (&best_cluster, count) {
-                (_, count) if is_max_jobs(count) => ControlFlow::Break(Some((cluster, count))),
-                (Some((_, best_count)), _) if *best_count < count => ControlFlow::Continue(Some((cluster, count))),
-                (None, _) if count > 1 => ControlFlow::Continue(Some((cluster, count))),
+                (_, count) if is_max_jobs(count) => ControlFlow::Break(Some((cluster, count, score))),
+                (Some((_, _, best_score)), count) if count > 1 && *best_score < score => {
+                    ControlFlow::Continue(Some((cluster, count, score)))
+                }
+                (None, _) if count > 1 => ControlFlow::Continue(Some((cluster, count, score))),
                 _ => ControlFlow::Continue(best_cluster),
             }
         })
         .unwrap_value()
-        .map(|(cluster, _)| cluster)
+        .map(|(cluster, ..)| cluster)
 }
 
 fn try_add_job<F>(
@@ -365,6 +484,7 @@ where
         });
 
     let job = candidate.0.to_single();
+    let (cluster_dimens, job_dimens) = get_dimens_with_united_skills(config, cluster, job)?;
     let ordering = config.building.ordering_local_fn.as_ref();
     let include_unreachable = true;
     let dissimilarities = get_cluster_info_sorted(center_place_idx, candidate, include_unreachable, ordering);
@@ -436,9 +556,9 @@ where
             let new_cluster_duration = cluster_place.duration + movement + info.service_time;
 
             let updated_cluster =
-                create_single_job(cluster_place.location, new_cluster_duration, &new_cluster_times, &cluster.dimens);
+                create_single_job(cluster_place.location, new_cluster_duration, &new_cluster_times, &cluster_dimens);
             let updated_candidate =
-                create_single_job(place.location, new_cluster_duration, &new_cluster_times, &job.dimens);
+                create_single_job(place.location, new_cluster_duration, &new_cluster_times, &job_dimens);
 
             variant
                 .merge(updated_cluster, updated_candidate)
@@ -522,6 +642,31 @@ where
     }
 }
 
+/// Returns dimensions of cluster and candidate jobs with united skill requirements if it is
+/// enabled, or `None` if jobs have incompatible skills.
+fn get_dimens_with_united_skills(
+    config: &ClusterConfig,
+    cluster: &Single,
+    candidate: &Single,
+) -> Option<(Dimensions, Dimensions)> {
+    let (mut cluster_dimens, mut candidate_dimens) = (cluster.dimens.clone(), candidate.dimens.clone());
+
+    if !config.unite_skills {
+        return Some((cluster_dimens, candidate_dimens));
+    }
+
+    let skills = match (cluster.dimens.get_job_skills(), candidate.dimens.get_job_skills()) {
+        (Some(cluster_skills), Some(candidate_skills)) => cluster_skills.try_union(candidate_skills)?,
+        (Some(skills), None) | (None, Some(skills)) => skills.clone(),
+        (None, None) => return Some((cluster_dimens, candidate_dimens)),
+    };
+
+    cluster_dimens.set_job_skills(skills.clone());
+    candidate_dimens.set_job_skills(skills);
+
+    Some((cluster_dimens, candidate_dimens))
+}
+
 fn create_single_job(location: Option<Location>, duration: Duration, times: &[TimeWindow], dimens: &Dimensions) -> Job {
     Job::Single(Arc::new(Single {
         places: vec![Place {
@@ -534,9 +679,10 @@ fn create_single_job(location: Option<Location>, duration: Duration, times: &[Ti
 }
 
 fn get_service_time(original: Duration, policy: &ServingPolicy) -> (Duration, Duration) {
-    match *policy {
-        ServingPolicy::Original { parking } => (original, parking),
-        ServingPolicy::Multiplier { multiplier, parking } => (original * multiplier, parking),
-        ServingPolicy::Fixed { value, parking } => (value, parking),
+    match policy {
+        ServingPolicy::Original { parking } => (original, *parking),
+        ServingPolicy::Multiplier { multiplier, parking } => (original * multiplier, *parking),
+        ServingPolicy::Fixed { value, parking } => (*value, *parking),
+        ServingPolicy::Dynamic { service_time_fn, parking } => (service_time_fn(original), *parking),
     }
 }
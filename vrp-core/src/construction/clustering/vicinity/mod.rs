@@ -4,20 +4,23 @@
 #[path = "../../../../tests/unit/construction/clustering/vicinity/vicinity_test.rs"]
 mod vicinity_test;
 
+use crate::algorithms::geometry::Point;
+use crate::construction::features::JobDemandDimension;
 use crate::construction::heuristics::*;
 use crate::models::common::Dimensions;
 use crate::models::common::*;
-use crate::models::problem::{Actor, Job};
-use crate::models::Problem;
+use crate::models::problem::{Actor, Job, VehicleIdDimension};
+use crate::models::{Problem, Solution};
 use rosomaxa::prelude::*;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ops::ControlFlow;
 use std::sync::Arc;
 
 mod estimations;
 use self::estimations::*;
-use crate::models::solution::Commute;
+pub use self::estimations::{get_jobs_dissimilarities, update_dissimilarities, DissimilarityIndex};
+use crate::models::solution::{Activity, Commute, Place, Route};
 use crate::prelude::ViolationCode;
 
 custom_dimension!(ClusterInfo typeof Vec<ClusterInfo>);
@@ -32,6 +35,9 @@ type CheckInsertionFn = (dyn Fn(&Job) -> Result<(), ViolationCode> + Send + Sync
 pub struct ClusterConfig {
     /// A matrix profile used to calculate traveling durations and distances.
     pub profile: Profile,
+    /// Speed factors per profile index applied to traveling durations before thresholds are checked,
+    /// e.g. a factor less than one makes clusters tighter for slow vehicles. Default factor is one.
+    pub speed_factors: HashMap<usize, Float>,
     /// A thresholds for job clustering.
     pub threshold: ThresholdPolicy,
     /// Job visiting policy
@@ -42,6 +48,16 @@ pub struct ClusterConfig {
     pub filtering: FilterPolicy,
     /// Specifies building policy.
     pub building: BuilderPolicy,
+    /// Specifies a custom similarity function used to decide whether two job places can be clustered.
+    /// If not set, moving duration and distance thresholds are used.
+    pub similarity_fn: Option<SimilarityFn>,
+    /// Specifies whether jobs with different, but compatible skills can be clustered together. If
+    /// set, the clustered job requires the union of skills, which the serving vehicle must satisfy.
+    /// Otherwise, merge rules of the skills feature are used.
+    pub unite_skills: bool,
+    /// Specifies a function which checks whether cluster jobs' total demand fits the max capacity.
+    /// If set, candidates exceeding it are skipped without evaluating constraints.
+    pub demand_fit_fn: Option<DemandFitFn>,
 }
 
 /// Defines a various thresholds to control cluster size.
@@ -53,6 +69,9 @@ pub struct ThresholdPolicy {
     pub moving_distance: Distance,
     /// Minimum shared time for jobs (non-inclusive).
     pub min_shared_time: Option<Duration>,
+    /// Minimum shared time for jobs (non-inclusive) as a fraction of the shorter job's service time.
+    /// Used only when `min_shared_time` is not set.
+    pub min_shared_time_ratio: Option<Float>,
     /// The smallest time window of the cluster after service time shrinking.
     pub smallest_time_window: Option<Duration>,
     /// The maximum amount of jobs per cluster.
@@ -105,6 +124,66 @@ pub enum ServingPolicy {
         /// Parking time.
         parking: Duration,
     },
+    /// Calculate service time from original job's duration using a custom function, e.g. to model
+    /// diminishing per-stop overhead.
+    Dynamic {
+        /// A function which returns service time for original job's duration.
+        service_time_fn: ServiceTimeFn,
+        /// Parking time.
+        parking: Duration,
+    },
+}
+
+/// A function type which calculates clustered job's service time from its original duration.
+pub type ServiceTimeFn = Arc<dyn Fn(Duration) -> Duration + Send + Sync>;
+
+/// Keeps information about a pair of job places considered for clustering.
+pub struct SimilarityContext<'a> {
+    /// An outer job (potential cluster center).
+    pub outer: &'a Job,
+    /// A place index of the outer job.
+    pub outer_place_idx: usize,
+    /// An inner job (potential cluster member).
+    pub inner: &'a Job,
+    /// A place index of the inner job.
+    pub inner_place_idx: usize,
+    /// The biggest time window overlap between two places.
+    pub shared_time: Duration,
+    /// Commute information between two places.
+    pub commute: &'a Commute,
+    /// Threshold policy used by clustering.
+    pub threshold: &'a ThresholdPolicy,
+}
+
+/// A function type which checks whether two job places are similar enough to be clustered together.
+pub type SimilarityFn = Arc<dyn Fn(&SimilarityContext) -> bool + Send + Sync>;
+
+/// Checks whether two job places are within moving duration and distance thresholds. This is the
+/// default similarity used when no custom function is specified.
+pub fn is_similar_by_thresholds(ctx: &SimilarityContext) -> bool {
+    let (forward, backward) = (&ctx.commute.forward, &ctx.commute.backward);
+    let threshold = ctx.threshold;
+
+    (forward.duration - threshold.moving_duration < 0.)
+        && (forward.distance - threshold.moving_distance < 0.)
+        && (backward.duration - threshold.moving_duration < 0.)
+        && (backward.distance - threshold.moving_distance < 0.)
+}
+
+/// A function type which checks whether total demand of the given jobs fits the max capacity.
+pub type DemandFitFn = Arc<dyn Fn(&[&Job]) -> bool + Send + Sync>;
+
+/// Creates a function which checks whether total static demand of the jobs fits the given max capacity,
+/// e.g. capacity of the largest vehicle. Exceeding it means that jobs cannot be served as one cluster.
+pub fn create_demand_fit_fn<T: LoadOps>(max_capacity: T) -> DemandFitFn {
+    Arc::new(move |jobs| {
+        let demand = jobs
+            .iter()
+            .filter_map(|job| job.dimens().get_job_demand::<T>())
+            .fold(Demand::<T>::default(), |acc, demand| acc + demand.clone());
+
+        max_capacity.can_fit(&demand.pickup.0) && max_capacity.can_fit(&demand.delivery.0)
+    })
 }
 
 /// A function type which orders visiting clusters based on their estimated size.
@@ -112,6 +191,9 @@ pub type OrderingGlobalFn = Arc<dyn Fn(ClusterCandidate, ClusterCandidate) -> Or
 /// A function type which orders visiting jobs in a cluster based on their visit info.
 pub type OrderingLocalFn = Arc<dyn Fn(&ClusterInfo, &ClusterInfo) -> Ordering + Send + Sync>;
 
+/// A function type which scores a built cluster job, e.g. by travel saved using its cluster info.
+pub type ClusterScoreFn = Arc<dyn Fn(&Job) -> Float + Send + Sync>;
+
 /// Allows to control how clusters are built.
 #[derive(Clone)]
 pub struct BuilderPolicy {
@@ -119,6 +201,9 @@ pub struct BuilderPolicy {
     pub ordering_global_fn: OrderingGlobalFn,
     /// Orders visiting jobs in a cluster based on their visit info.
     pub ordering_local_fn: OrderingLocalFn,
+    /// Scores clusters built from different places of the center job to select the best one (higher is better).
+    /// If not set, a cluster with the most jobs is selected.
+    pub cluster_score_fn: Option<ClusterScoreFn>,
 }
 
 /// Keeps track of information specific for job in the cluster.
@@ -134,6 +219,17 @@ pub struct ClusterInfo {
     pub commute: Commute,
 }
 
+/// Keeps information about the vehicle which served a job folded into a cluster.
+#[derive(Clone, Debug)]
+pub struct ClusterServedBy {
+    /// An original job.
+    pub job: Job,
+    /// An id of the vehicle which served the job.
+    pub vehicle_id: String,
+    /// An arrival time at the job's activity.
+    pub arrival: Timestamp,
+}
+
 /// Creates clusters of jobs grouping them together best on vicinity properties.
 /// Limitations:
 /// - only single jobs are clustered
@@ -162,6 +258,143 @@ pub fn create_job_clusters(
     get_clusters(&constraint, estimates, config, &check_insertion)
 }
 
+/// Unfolds a cluster activity into the activities of original jobs using their cluster information.
+pub fn unfold_cluster_activity(
+    config: &ClusterConfig,
+    cluster_activity: &Activity,
+    cluster: &[ClusterInfo],
+) -> Vec<Activity> {
+    let cluster_time = cluster_activity.place.time.clone();
+    let cluster_arrival = cluster_activity.schedule.arrival;
+    let last_job = cluster.last().map(|info| info.job.clone());
+
+    let (_, activities) = cluster.iter().fold((cluster_arrival, Vec::new()), |(arrival, mut activities), info| {
+        // NOTE assumption: no waiting time possible in between of clustered jobs
+        let job = info.job.to_single().clone();
+        let place_idx = 0;
+        let place = &job.places[place_idx];
+
+        let backward = match config.visiting {
+            VisitPolicy::Return => info.commute.backward.duration,
+            VisitPolicy::ClosedContinuation if Some(&info.job) == last_job.as_ref() => info.commute.backward.duration,
+            _ => 0.,
+        };
+
+        let service_time = info.service_time;
+        let service_start = (arrival + info.commute.forward.duration).max(cluster_time.start);
+        let departure = service_start + service_time + backward;
+
+        activities.push(Activity {
+            place: Place {
+                idx: place_idx,
+                location: place.location.unwrap(),
+                duration: info.service_time,
+                time: cluster_time.clone(),
+            },
+            schedule: Schedule::new(arrival, departure),
+            job: Some(job),
+            commute: Some(info.commute.clone()),
+        });
+
+        (departure, activities)
+    });
+
+    activities
+}
+
+/// Dissolves a cluster job into the original jobs using its cluster information, discarding the synthesized
+/// cluster geometry: original jobs keep their locations, durations and time windows. Returns the job
+/// itself if it is not a cluster.
+pub fn dissolve_cluster(cluster: &Job) -> Vec<Job> {
+    cluster
+        .dimens()
+        .get_cluster_info()
+        .map(|cluster| cluster.iter().map(|info| info.job.clone()).collect())
+        .unwrap_or_else(|| vec![cluster.clone()])
+}
+
+/// Returns served-by information for every job folded into a cluster which is served by the route.
+pub fn get_cluster_served_by(config: &ClusterConfig, route: &Route) -> Vec<ClusterServedBy> {
+    let vehicle_id = route.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap_or_default();
+
+    route
+        .tour
+        .all_activities()
+        .filter_map(|activity| {
+            activity
+                .retrieve_job()
+                .and_then(|job| job.dimens().get_cluster_info().cloned())
+                .map(|cluster| (activity, cluster))
+        })
+        .flat_map(|(activity, cluster)| {
+            unfold_cluster_activity(config, activity, cluster.as_slice())
+                .into_iter()
+                .zip(cluster)
+                .map(|(activity, info)| ClusterServedBy {
+                    job: info.job,
+                    vehicle_id: vehicle_id.clone(),
+                    arrival: activity.schedule.arrival,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Represents a group of unassigned jobs located close to each other.
+pub struct UnassignedJobGroup {
+    /// Jobs in the group.
+    pub jobs: Vec<Job>,
+    /// A centroid of the job locations. Absent if no job location has a coordinate.
+    pub centroid: Option<Point>,
+}
+
+/// Groups unassigned jobs of the solution by proximity to simplify their manual review. Two jobs
+/// belong to the same group if they are similar using the same rules as job clustering: moving
+/// thresholds (or similarity function) and shared time. Groups are sorted by size in descending order.
+/// `coord_index` maps a location to its coordinate and is used to calculate a group centroid.
+/// NOTE multi-job is not supported.
+pub fn get_unassigned_job_groups(
+    problem: &Problem,
+    solution: &Solution,
+    config: &ClusterConfig,
+    coord_index: &dyn Fn(Location) -> Option<Point>,
+) -> Vec<UnassignedJobGroup> {
+    let jobs = solution
+        .unassigned
+        .iter()
+        .map(|(job, _)| job)
+        .filter(|job| job.as_single().is_some())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let estimates = get_jobs_dissimilarities(jobs.as_slice(), problem.transport.as_ref(), config);
+
+    let mut groups = get_job_groups(jobs.as_slice(), &estimates)
+        .into_iter()
+        .map(|jobs| {
+            let points = jobs
+                .iter()
+                .flat_map(|job| job.to_single().places.iter().filter_map(|place| place.location))
+                .filter_map(coord_index)
+                .collect::<Vec<_>>();
+
+            let centroid = if points.is_empty() {
+                None
+            } else {
+                let size = points.len() as Float;
+                let (x, y) = points.iter().fold((0., 0.), |(x, y), point| (x + point.x, y + point.y));
+                Some(Point::new(x / size, y / size))
+            };
+
+            UnassignedJobGroup { jobs, centroid }
+        })
+        .collect::<Vec<_>>();
+
+    groups.sort_by(|a, b| b.jobs.len().cmp(&a.jobs.len()));
+
+    groups
+}
+
 /// Gets function which checks possibility of cluster insertion.
 fn get_check_insertion_fn(
     insertion_ctx: InsertionContext,
@@ -205,6 +438,7 @@ impl ServingPolicy {
             Self::Original { parking } => *parking,
             Self::Multiplier { parking, .. } => *parking,
             Self::Fixed { parking, .. } => *parking,
+            Self::Dynamic { parking, .. } => *parking,
         }
     }
 }
@@ -0,0 +1,69 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/co_located_stops_test.rs"]
+mod co_located_stops_test;
+
+use crate::models::common::{Cost, Duration, Timestamp};
+use crate::models::problem::{ActivityCost, Single};
+use crate::models::solution::{Activity, Route};
+use std::sync::Arc;
+
+/// A function which specifies whether a given single job is a stop of specific kind (e.g. break or refuel).
+pub type StopSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+
+/// Combines break and refuel stops which are done at the same facility: when one of them directly
+/// follows another at the same location, they are done simultaneously, so the shorter stop time is
+/// counted only once. Only activities which are already part of the route's tour are considered,
+/// so insertion estimates stay conservative.
+pub struct CoLocatedStopsActivityCost {
+    inner: Arc<dyn ActivityCost>,
+    is_break_fn: StopSingleFn,
+    is_refuel_fn: StopSingleFn,
+}
+
+impl CoLocatedStopsActivityCost {
+    /// Creates a new instance of `CoLocatedStopsActivityCost`.
+    pub fn new(inner: Arc<dyn ActivityCost>, is_break_fn: StopSingleFn, is_refuel_fn: StopSingleFn) -> Self {
+        Self { inner, is_break_fn, is_refuel_fn }
+    }
+
+    /// Returns a stop duration which overlaps with the previous co-located stop of another kind.
+    fn get_overlap(&self, route: &Route, activity: &Activity) -> Duration {
+        let is_break = |activity: &Activity| activity.job.as_ref().map_or(false, |single| (self.is_break_fn)(single));
+        let is_refuel = |activity: &Activity| activity.job.as_ref().map_or(false, |single| (self.is_refuel_fn)(single));
+
+        if !is_break(activity) && !is_refuel(activity) {
+            return Duration::default();
+        }
+
+        get_activity_index(route, activity)
+            .filter(|&idx| idx > 0)
+            .and_then(|idx| route.tour.get(idx - 1))
+            .filter(|prev| prev.place.location == activity.place.location)
+            .filter(|prev| (is_break(prev) && is_refuel(activity)) || (is_refuel(prev) && is_break(activity)))
+            .map_or(Duration::default(), |prev| prev.place.duration.min(activity.place.duration))
+    }
+}
+
+/// Returns index of the activity in the route's tour. Returns `None` if the activity is not part
+/// of the tour (e.g. it is a candidate for insertion).
+fn get_activity_index(route: &Route, activity: &Activity) -> Option<usize> {
+    route.tour.all_activities().position(|other| std::ptr::eq(other, activity))
+}
+
+impl ActivityCost for CoLocatedStopsActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        self.inner.cost(route, activity, arrival)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        let departure = self.inner.estimate_departure(route, activity, arrival);
+
+        (departure - self.get_overlap(route, activity)).max(arrival)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        let arrival = self.inner.estimate_arrival(route, activity, departure);
+
+        (arrival + self.get_overlap(route, activity)).min(activity.place.time.end).min(departure)
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/day_horizon_test.rs"]
+mod day_horizon_test;
+
+use super::ReservedTimeSpan;
+use crate::models::common::*;
+use rosomaxa::prelude::{Float, GenericResult};
+
+/// Specifies a planning horizon split into days of the same length. Each day has a rest period
+/// which starts at the given offset from the day start and can span over the day boundary,
+/// e.g. overnight rest from 22:00 till 06:00 of the next day.
+///
+/// Rests are modeled as reserved times (see [`DayHorizon::get_reserved_times`]), so they are inserted
+/// into the route schedule by `DynamicTransportCost` and `DynamicActivityCost` and the whole route is
+/// checked by the timing constraint.
+#[derive(Clone, Debug)]
+pub struct DayHorizon {
+    day_length: Duration,
+    rest_start: Duration,
+    rest_duration: Duration,
+}
+
+impl DayHorizon {
+    /// Creates a new instance of `DayHorizon`.
+    pub fn new(day_length: Duration, rest_start: Duration, rest_duration: Duration) -> GenericResult<Self> {
+        if day_length <= 0. {
+            return Err("day length should be positive".into());
+        }
+
+        if rest_start < 0. || rest_start >= day_length {
+            return Err("rest start should be within a day".into());
+        }
+
+        if rest_duration <= 0. || rest_duration >= day_length {
+            return Err("rest duration should be positive and shorter than a day".into());
+        }
+
+        Ok(Self { day_length, rest_start, rest_duration })
+    }
+
+    /// Returns mandatory rests which intersect the given time window, e.g. a vehicle's shift time,
+    /// as reserved times which have to start exactly at the rest start.
+    pub fn get_reserved_times(&self, time: &TimeWindow) -> Vec<ReservedTimeSpan> {
+        self.get_rests(time.start, time.end)
+            .filter(|&(rest_start, rest_end)| rest_start < time.end && rest_end > time.start)
+            .map(|(rest_start, _)| ReservedTimeSpan {
+                time: TimeSpan::Window(TimeWindow::new(rest_start, rest_start)),
+                duration: self.rest_duration,
+            })
+            .collect()
+    }
+
+    /// Returns true if given time interval intersects any of rest periods.
+    pub fn intersects_rest(&self, start: Timestamp, end: Timestamp) -> bool {
+        self.get_rests(start, end).any(|(rest_start, rest_end)| start < rest_end && end > rest_start)
+    }
+
+    /// Returns rest periods which might intersect the given time interval.
+    fn get_rests(&self, start: Timestamp, end: Timestamp) -> impl Iterator<Item = (Timestamp, Timestamp)> + '_ {
+        let first = ((start - self.rest_start - self.rest_duration) / self.day_length).floor() as i64;
+        let last = ((end - self.rest_start) / self.day_length).floor() as i64;
+
+        (first..=last).map(move |day| {
+            let rest_start = day as Float * self.day_length + self.rest_start;
+            (rest_start, rest_start + self.rest_duration)
+        })
+    }
+}
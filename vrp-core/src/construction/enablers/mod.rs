@@ -1,9 +1,15 @@
 //! This module contains feature extension functionality which can be used to work with the same aspects
 //! from different features.
 
+mod co_located_stops;
+pub use self::co_located_stops::*;
+
 mod conditional_job;
 pub use self::conditional_job::*;
 
+mod day_horizon;
+pub use self::day_horizon::*;
+
 mod departure_time;
 pub use self::departure_time::*;
 
@@ -25,6 +31,9 @@ pub use self::reserved_time::*;
 mod schedule_update;
 pub use self::schedule_update::*;
 
+mod time_rounding;
+pub use self::time_rounding::*;
+
 mod travel_info;
 pub use self::travel_info::*;
 
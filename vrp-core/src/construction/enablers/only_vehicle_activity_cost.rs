@@ -1,5 +1,5 @@
 use crate::models::common::{Cost, Timestamp};
-use crate::models::problem::{ActivityCost, SimpleActivityCost};
+use crate::models::problem::{get_service_duration, ActivityCost, SimpleActivityCost};
 use crate::models::solution::Activity;
 use crate::models::solution::Route;
 
@@ -14,7 +14,7 @@ impl ActivityCost for OnlyVehicleActivityCost {
         let actor = route.actor.as_ref();
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0.0 };
-        let service = activity.place.duration;
+        let service = get_service_duration(route, activity);
 
         waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
     }
@@ -3,7 +3,7 @@
 mod reserved_time_test;
 
 use crate::models::common::*;
-use crate::models::problem::{ActivityCost, Actor, TransportCost, TravelTime};
+use crate::models::problem::{get_service_duration, ActivityCost, Actor, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
 use rosomaxa::prelude::{Float, GenericError};
 use std::collections::HashMap;
@@ -56,7 +56,7 @@ impl DynamicActivityCost {
 impl ActivityCost for DynamicActivityCost {
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
         let activity_start = arrival.max(activity.place.time.start);
-        let departure = activity_start + activity.place.duration;
+        let departure = activity_start + get_service_duration(route, activity);
         let schedule = TimeWindow::new(arrival, departure);
 
         (self.reserved_times_fn)(route, &schedule).map_or(departure, |reserved_time| {
@@ -89,8 +89,10 @@ impl ActivityCost for DynamicActivityCost {
     }
 
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        let arrival = activity.place.time.end.min(departure - activity.place.duration);
-        let schedule = TimeWindow::new(arrival, departure);
+        let service_duration = get_service_duration(route, activity);
+        let arrival = activity.place.time.end.min(departure - service_duration);
+        // NOTE consider only service time as waiting time till departure can be used for reserved time
+        let schedule = TimeWindow::new(arrival, arrival + service_duration);
 
         (self.reserved_times_fn)(route, &schedule)
             .map_or(arrival, |reserved_time| (arrival - reserved_time.duration).max(activity.place.time.start))
@@ -119,6 +121,14 @@ impl TransportCost for DynamicTransportCost {
         self.inner.distance_approx(profile, from, to)
     }
 
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.inner.duration_at(profile, from, to, departure)
+    }
+
+    fn distance_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.inner.distance_at(profile, from, to, departure)
+    }
+
     fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
         let duration = self.inner.duration(route, from, to, travel_time);
 
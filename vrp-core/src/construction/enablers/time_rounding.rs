@@ -0,0 +1,100 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/time_rounding_test.rs"]
+mod time_rounding_test;
+
+use crate::models::common::{Cost, Distance, Duration, Location, Profile, Timestamp};
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use rosomaxa::prelude::Float;
+use std::sync::Arc;
+
+/// Rounds travel durations so that arrival (or departure when computed backwards) times are
+/// multiples of the given granularity. Times are rounded up when computed forward and down when
+/// computed backwards, so rounded schedule never promises earlier arrival than the original one.
+/// Time-independent durations are rounded up as is.
+pub struct TimeRoundingTransportCost {
+    inner: Arc<dyn TransportCost>,
+    granularity: Duration,
+}
+
+impl TimeRoundingTransportCost {
+    /// Creates a new instance of `TimeRoundingTransportCost`.
+    pub fn new(inner: Arc<dyn TransportCost>, granularity: Duration) -> Self {
+        Self { inner, granularity }
+    }
+}
+
+impl TransportCost for TimeRoundingTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        ceil_time(self.inner.duration_approx(profile, from, to), self.granularity)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        let duration = self.inner.duration_at(profile, from, to, departure);
+
+        (ceil_time(departure + duration, self.granularity) - departure).max(0.)
+    }
+
+    fn distance_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.inner.distance_at(profile, from, to, departure)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let duration = self.inner.duration(route, from, to, travel_time);
+
+        let duration = match travel_time {
+            TravelTime::Departure(departure) => ceil_time(departure + duration, self.granularity) - departure,
+            TravelTime::Arrival(arrival) => arrival - floor_time(arrival - duration, self.granularity),
+        };
+
+        duration.max(0.)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.inner.distance(route, from, to, travel_time)
+    }
+}
+
+/// Rounds activity departure and latest arrival estimates to multiples of the given granularity.
+pub struct TimeRoundingActivityCost {
+    inner: Arc<dyn ActivityCost>,
+    granularity: Duration,
+}
+
+impl TimeRoundingActivityCost {
+    /// Creates a new instance of `TimeRoundingActivityCost`.
+    pub fn new(inner: Arc<dyn ActivityCost>, granularity: Duration) -> Self {
+        Self { inner, granularity }
+    }
+}
+
+impl ActivityCost for TimeRoundingActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        self.inner.cost(route, activity, arrival)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        ceil_time(self.inner.estimate_departure(route, activity, arrival), self.granularity).max(arrival)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        floor_time(self.inner.estimate_arrival(route, activity, departure), self.granularity).min(departure)
+    }
+}
+
+/// A tolerance used to ignore float accumulation errors when rounding.
+const ROUNDING_TOLERANCE: Float = 1E-6;
+
+/// Rounds time up to the nearest multiple of granularity.
+fn ceil_time(time: Timestamp, granularity: Duration) -> Timestamp {
+    (time / granularity - ROUNDING_TOLERANCE).ceil() * granularity
+}
+
+/// Rounds time down to the nearest multiple of granularity.
+fn floor_time(time: Timestamp, granularity: Duration) -> Timestamp {
+    (time / granularity + ROUNDING_TOLERANCE).floor() * granularity
+}
@@ -6,7 +6,7 @@ mod breaks_test;
 
 use super::*;
 use crate::construction::enablers::*;
-use crate::models::solution::Route;
+use crate::models::solution::{Activity, Route};
 use std::collections::HashSet;
 use std::iter::once;
 
@@ -28,6 +28,7 @@ pub struct BreakFeatureBuilder {
     belongs_to_route_fn: Option<BelongsToRouteFn>,
     is_break_single_fn: Option<BreakSingleFn>,
     policy_fn: Option<BreakPolicyFn>,
+    min_interval_fn: Option<BreakIntervalFn>,
 }
 
 impl BreakFeatureBuilder {
@@ -39,6 +40,7 @@ impl BreakFeatureBuilder {
             belongs_to_route_fn: None,
             is_break_single_fn: None,
             policy_fn: None,
+            min_interval_fn: None,
         }
     }
 
@@ -77,6 +79,17 @@ impl BreakFeatureBuilder {
         self
     }
 
+    /// Sets a function which returns a minimum interval between two consecutive breaks in the given
+    /// route, e.g. when a long shift has multiple breaks. If not set or returns `None`, breaks can be
+    /// scheduled back-to-back.
+    pub fn set_min_interval<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Route) -> Option<Duration> + Send + Sync + 'static,
+    {
+        self.min_interval_fn = Some(Arc::new(func));
+        self
+    }
+
     /// Builds a optional break feature.
     pub fn build(mut self) -> GenericResult<Feature> {
         let is_break_single_fn =
@@ -91,7 +104,9 @@ impl BreakFeatureBuilder {
             })
         });
 
-        let break_fns = BreakFns { is_break_single_fn, belongs_to_route_fn, policy_fn };
+        let min_interval_fn = self.min_interval_fn.take();
+
+        let break_fns = BreakFns { is_break_single_fn, belongs_to_route_fn, policy_fn, min_interval_fn };
 
         let context_transition = ConcreteJobContextTransition {
             remove_required: {
@@ -122,12 +137,24 @@ impl BreakFeatureBuilder {
 type BreakSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
 type BelongsToRouteFn = Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>;
 type BreakPolicyFn = Arc<dyn Fn(&Single) -> BreakPolicy + Send + Sync>;
+type BreakIntervalFn = Arc<dyn Fn(&Route) -> Option<Duration> + Send + Sync>;
 
 #[derive(Clone)]
 struct BreakFns {
     is_break_single_fn: BreakSingleFn,
     belongs_to_route_fn: BelongsToRouteFn,
     policy_fn: BreakPolicyFn,
+    min_interval_fn: Option<BreakIntervalFn>,
+}
+
+impl BreakFns {
+    fn is_break_activity(&self, activity: &Activity) -> bool {
+        activity.job.as_ref().map_or(false, |single| (self.is_break_single_fn)(single))
+    }
+
+    fn get_min_interval(&self, route: &Route) -> Option<Duration> {
+        self.min_interval_fn.as_ref().and_then(|min_interval_fn| min_interval_fn(route))
+    }
 }
 
 struct OptionalBreakConstraint {
@@ -157,13 +184,53 @@ impl OptionalBreakConstraint {
             .filter(|single| (self.break_fns.is_break_single_fn)(single) && activity_ctx.prev.job.is_none())
             .and_then(|_| ConstraintViolation::skip(self.code))
     }
+
+    fn evaluate_interval(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        if !self.break_fns.is_break_activity(activity_ctx.target) {
+            return None;
+        }
+
+        let min_interval = self.break_fns.get_min_interval(route_ctx.route())?;
+        let tour = &route_ctx.route().tour;
+
+        // NOTE travel time is ignored here, so break start is estimated optimistically
+        let start = activity_ctx.prev.schedule.departure.max(activity_ctx.target.place.time.start);
+        let end = start + activity_ctx.target.place.duration;
+
+        let prev_departure = tour
+            .activities_slice(0, activity_ctx.index)
+            .iter()
+            .rev()
+            .find(|activity| self.break_fns.is_break_activity(activity))
+            .map(|activity| activity.schedule.departure);
+        let next_arrival = tour
+            .all_activities()
+            .skip(activity_ctx.index + 1)
+            .find(|activity| self.break_fns.is_break_activity(activity))
+            .map(|activity| activity.schedule.arrival);
+
+        let is_too_close = prev_departure.map_or(false, |departure| start - departure < min_interval)
+            || next_arrival.map_or(false, |arrival| arrival - end < min_interval);
+
+        if is_too_close {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
 }
 
 impl FeatureConstraint for OptionalBreakConstraint {
     fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         match move_ctx {
             MoveContext::Route { route_ctx, job, .. } => self.evaluate_route(route_ctx, job),
-            MoveContext::Activity { activity_ctx, .. } => self.evaluate_activity(activity_ctx),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                self.evaluate_activity(activity_ctx).or_else(|| self.evaluate_interval(route_ctx, activity_ctx))
+            }
         }
     }
 
@@ -233,16 +300,19 @@ impl<JT: JobContextTransition + Send + Sync> OptionalBreakState<JT> {
     /// Removes breaks which conditions are violated after ruin:
     /// * break without location served separately when original job is removed, but break is kept.
     /// * break is defined by interval, but its time is violated. This might happen due to departure time rescheduling.
+    /// * break is scheduled closer than minimum interval to the previous one.
     fn remove_invalid_breaks(&self, solution_ctx: &mut SolutionContext) {
         let breaks_to_remove = solution_ctx
             .routes
             .iter()
             .flat_map(|route_ctx| {
+                let min_interval = self.break_fns.get_min_interval(route_ctx.route());
+
                 route_ctx
                     .route()
                     .tour
                     .all_activities()
-                    .fold((0, HashSet::new()), |(prev, mut breaks), activity| {
+                    .fold((0, None, HashSet::new()), |(prev, last_departure, mut breaks), activity| {
                         let current = activity.place.location;
 
                         let Some(break_single) = activity
@@ -251,7 +321,7 @@ impl<JT: JobContextTransition + Send + Sync> OptionalBreakState<JT> {
                             .filter(|single| (self.break_fns.is_break_single_fn)(single))
                             .filter(|&single| !solution_ctx.locked.contains(&Job::Single(single.clone())))
                         else {
-                            return (current, breaks);
+                            return (current, last_departure, breaks);
                         };
 
                         // NOTE break should have location defined for all places or for none of them
@@ -268,13 +338,18 @@ impl<JT: JobContextTransition + Send + Sync> OptionalBreakState<JT> {
                         let is_ovrp_last =
                             route_ctx.route().tour.end().map_or(false, |end| std::ptr::eq(activity, end));
 
-                        if is_orphan || is_not_on_time || is_ovrp_last {
+                        let is_too_close = last_departure
+                            .zip(min_interval)
+                            .map_or(false, |(departure, interval)| activity.schedule.arrival - departure < interval);
+
+                        if is_orphan || is_not_on_time || is_ovrp_last || is_too_close {
                             breaks.insert(Job::Single(break_single.clone()));
+                            (current, last_departure, breaks)
+                        } else {
+                            (current, Some(activity.schedule.departure), breaks)
                         }
-
-                        (current, breaks)
                     })
-                    .1
+                    .2
                     .into_iter()
             })
             .collect::<Vec<_>>();
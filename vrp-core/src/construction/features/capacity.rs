@@ -29,18 +29,31 @@ pub trait JobDemandDimension {
     fn get_job_demand<T: LoadOps>(&self) -> Option<&Demand<T>>;
 }
 
+/// Returns vehicle capacity effective for the route which departs at the given time.
+/// If `None` is returned, the static vehicle capacity is used.
+pub type TimeDependentCapacityFn<T> = Arc<dyn Fn(&Actor, Timestamp) -> Option<T> + Send + Sync>;
+
 /// Provides a way to build capacity limit feature.
 pub struct CapacityFeatureBuilder<T: LoadOps> {
     name: String,
     route_intervals: Option<RouteIntervals>,
     violation_code: Option<ViolationCode>,
+    capacity_fn: Option<TimeDependentCapacityFn<T>>,
+    max_load_ratio: Option<Float>,
     phantom_data: PhantomData<T>,
 }
 
 impl<T: LoadOps> CapacityFeatureBuilder<T> {
     /// Creates a new instance of `CapacityFeatureBuilder`
     pub fn new(name: &str) -> Self {
-        Self { name: name.to_string(), route_intervals: None, violation_code: None, phantom_data: Default::default() }
+        Self {
+            name: name.to_string(),
+            route_intervals: None,
+            violation_code: None,
+            capacity_fn: None,
+            max_load_ratio: None,
+            phantom_data: Default::default(),
+        }
     }
 
     /// Sets constraint violation code which is used to report back the reason of job's unassignment.
@@ -55,30 +68,44 @@ impl<T: LoadOps> CapacityFeatureBuilder<T> {
         self
     }
 
+    /// Sets a function which returns vehicle capacity depending on route departure time, e.g. to
+    /// model weight limits active at certain time of the day.
+    pub fn set_time_dependent_capacity(mut self, capacity_fn: TimeDependentCapacityFn<T>) -> Self {
+        self.capacity_fn = Some(capacity_fn);
+        self
+    }
+
+    /// Sets max fraction of vehicle capacity which can be used by the route, e.g. `0.8` keeps 20%
+    /// of capacity free as a headroom for jobs which might come later in dynamic planning.
+    pub fn set_max_load_ratio(mut self, ratio: Float) -> Self {
+        self.max_load_ratio = Some(ratio);
+        self
+    }
+
     /// Builds a feature.
     pub fn build(self) -> GenericResult<Feature> {
+        if let Some(ratio) = self.max_load_ratio {
+            if !(ratio > 0. && ratio <= 1.) {
+                return Err(format!("max load ratio should be in (0, 1] range, got: {ratio}").into());
+            }
+        }
+
         let name = self.name.as_str();
         let violation_code = self.violation_code.unwrap_or_default();
-
-        if let Some(route_intervals) = self.route_intervals {
-            create_multi_trip_feature(
-                name,
+        let route_intervals = self.route_intervals.unwrap_or(RouteIntervals::Single);
+
+        create_multi_trip_feature(
+            name,
+            violation_code,
+            MarkerInsertionPolicy::Last,
+            Arc::new(CapacitatedMultiTrip::<T> {
+                route_intervals,
                 violation_code,
-                MarkerInsertionPolicy::Last,
-                Arc::new(CapacitatedMultiTrip::<T> { route_intervals, violation_code, phantom: Default::default() }),
-            )
-        } else {
-            create_multi_trip_feature(
-                name,
-                violation_code,
-                MarkerInsertionPolicy::Last,
-                Arc::new(CapacitatedMultiTrip::<T> {
-                    route_intervals: RouteIntervals::Single,
-                    violation_code,
-                    phantom: Default::default(),
-                }),
-            )
-        }
+                capacity_fn: self.capacity_fn,
+                max_load_ratio: self.max_load_ratio,
+                phantom: Default::default(),
+            }),
+        )
     }
 }
 
@@ -124,6 +151,8 @@ where
 {
     route_intervals: RouteIntervals,
     violation_code: ViolationCode,
+    capacity_fn: Option<TimeDependentCapacityFn<T>>,
+    max_load_ratio: Option<Float>,
     phantom: PhantomData<T>,
 }
 
@@ -197,8 +226,8 @@ where
         route_ctx.state_mut().set_max_past_capacity_states(max_past_capacities);
         route_ctx.state_mut().set_max_future_capacity_states(max_future_capacities);
 
-        if let Some(capacity) = route_ctx.route().actor.clone().vehicle.dimens.get_vehicle_capacity::<T>() {
-            route_ctx.state_mut().set_max_vehicle_load(max_load.ratio(capacity));
+        if let Some(capacity) = self.get_capacity(route_ctx) {
+            route_ctx.state_mut().set_max_vehicle_load(max_load.ratio(&capacity));
         }
     }
 
@@ -243,7 +272,13 @@ where
                 Some(false)
             }
         } else {
-            has_demand_violation(route_ctx, activity_ctx.index, demand, !self.has_markers(route_ctx))
+            has_demand_violation(
+                route_ctx,
+                self.get_effective_capacity(route_ctx),
+                activity_ctx.index,
+                demand,
+                !self.has_markers(route_ctx),
+            )
         };
 
         violation.map(|stopped| ConstraintViolation { code: self.violation_code, stopped })
@@ -259,7 +294,9 @@ where
         demand: Option<&Demand<T>>,
         insert_idx: Option<usize>,
     ) -> bool {
-        let has_demand_violation = |activity_idx: usize| has_demand_violation(route_ctx, activity_idx, demand, true);
+        let capacity = self.get_effective_capacity(route_ctx);
+        let has_demand_violation =
+            |activity_idx: usize| has_demand_violation(route_ctx, capacity, activity_idx, demand, true);
 
         let has_demand_violation_on_borders = |start_idx: usize, end_idx: usize| {
             has_demand_violation(start_idx).is_none() || has_demand_violation(end_idx).is_none()
@@ -290,18 +327,39 @@ where
     fn get_demand<'a>(&self, activity: &'a Activity) -> Option<&'a Demand<T>> {
         activity.job.as_ref().and_then(|single| single.dimens.get_job_demand())
     }
+
+    fn get_capacity(&self, route_ctx: &RouteContext) -> Option<T> {
+        let route = route_ctx.route();
+        let capacity = route.actor.vehicle.dimens.get_vehicle_capacity::<T>().copied();
+
+        self.capacity_fn
+            .as_ref()
+            .zip(route.tour.start())
+            .and_then(|(capacity_fn, start)| (capacity_fn)(route.actor.as_ref(), start.schedule.departure))
+            .or(capacity)
+    }
+
+    /// Returns capacity which can be actually used by the route taking into account a headroom.
+    fn get_effective_capacity(&self, route_ctx: &RouteContext) -> Option<T> {
+        let capacity = self.get_capacity(route_ctx);
+
+        match self.max_load_ratio {
+            Some(ratio) => capacity.map(|capacity| capacity.scale_down(ratio)),
+            None => capacity,
+        }
+    }
 }
 
 fn has_demand_violation<T: LoadOps>(
     route_ctx: &RouteContext,
+    capacity: Option<T>,
     pivot_idx: usize,
     demand: Option<&Demand<T>>,
     stopped: bool,
 ) -> Option<bool> {
-    let capacity: Option<&T> = route_ctx.route().actor.vehicle.dimens.get_vehicle_capacity();
     let demand = demand?;
 
-    let capacity = if let Some(capacity) = capacity {
+    let capacity = if let Some(capacity) = &capacity {
         capacity
     } else {
         return Some(stopped);
@@ -0,0 +1,101 @@
+//! A cargo compatibility feature provides the way to avoid carrying incompatible cargo simultaneously.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/cargo_compatibility_test.rs"]
+mod cargo_compatibility_test;
+
+use super::*;
+use crate::models::problem::{Multi, Single};
+use crate::models::solution::Activity;
+use std::collections::{HashMap, HashSet};
+
+custom_dimension!(JobCargoClass typeof String);
+
+/// Creates a cargo compatibility feature as a hard constraint. Unlike compatibility feature, it allows
+/// incompatible cargo on the same route, but not onboard at the same time: a cargo of multi job
+/// (e.g. pickup and delivery) is considered onboard between its first and last activities.
+/// `incompatible` contains pairs of cargo classes which cannot be carried together.
+pub fn create_cargo_compatibility_feature(
+    name: &str,
+    code: ViolationCode,
+    incompatible: &[(String, String)],
+) -> Result<Feature, GenericError> {
+    let incompatible = incompatible
+        .iter()
+        .flat_map(|(first, second)| [(first.clone(), second.clone()), (second.clone(), first.clone())])
+        .collect();
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CargoCompatibilityConstraint { code, incompatible })
+        .build()
+}
+
+struct CargoCompatibilityConstraint {
+    code: ViolationCode,
+    incompatible: HashSet<(String, String)>,
+}
+
+impl CargoCompatibilityConstraint {
+    fn is_incompatible(&self, first: &str, second: &str) -> bool {
+        self.incompatible.contains(&(first.to_string(), second.to_string()))
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let target = activity_ctx.target.job.as_ref().and_then(|single| get_cargo(single))?;
+        let (target_multi, target_class) = (Arc::as_ptr(&target.0), target.1);
+
+        let intervals = get_cargo_intervals(route_ctx.route().tour.all_activities());
+
+        // NOTE insertion happens between `index` and `index + 1` positions
+        let end = activity_ctx.index;
+        let start = intervals.get(&target_multi).map_or(end, |(first, _, _)| *first);
+
+        let has_conflict =
+            intervals.iter().filter(|(&multi, _)| multi != target_multi).any(|(_, (first, last, class))| {
+                *first <= end && *last > start && self.is_incompatible(target_class.as_str(), class.as_str())
+            });
+
+        if has_conflict {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+}
+
+impl FeatureConstraint for CargoCompatibilityConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_cargo_class(), candidate.dimens().get_job_cargo_class()) {
+            (Some(s_class), Some(c_class)) if self.is_incompatible(s_class, c_class) => Err(self.code),
+            _ => Ok(source),
+        }
+    }
+}
+
+fn get_cargo(single: &Single) -> Option<(Arc<Multi>, String)> {
+    Multi::roots(single).and_then(|multi| multi.dimens.get_job_cargo_class().cloned().map(|class| (multi, class)))
+}
+
+/// Returns first and last tour positions of multi jobs with cargo class.
+fn get_cargo_intervals<'a>(
+    activities: impl Iterator<Item = &'a Activity>,
+) -> HashMap<*const Multi, (usize, usize, String)> {
+    activities.enumerate().fold(HashMap::new(), |mut acc, (idx, activity)| {
+        if let Some((multi, class)) = activity.job.as_ref().and_then(|single| get_cargo(single)) {
+            acc.entry(Arc::as_ptr(&multi)).and_modify(|(_, last, _)| *last = idx).or_insert((idx, idx, class));
+        }
+        acc
+    })
+}
@@ -0,0 +1,207 @@
+//! A feature to penalize or forbid jobs which are completed after their completion deadline.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/completion_deadlines_test.rs"]
+mod completion_deadlines_test;
+
+use super::*;
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::Activity;
+
+custom_dimension!(JobCompletionDeadline typeof Timestamp);
+// Keeps the latest arrival at activity which does not violate its own or any following completion deadline.
+custom_activity_state!(DeadlineLatestArrival typeof Timestamp);
+
+/// Creates a feature which penalizes jobs whose service ends after their completion deadline.
+/// Unlike time windows, which limit service start (arrival), the deadline limits service end, so
+/// the job can be still served within its time window but completed late. A penalty is the
+/// time passed since the deadline scaled by `cost_per_time`. It is a soft constraint.
+pub fn create_completion_deadline_feature(
+    name: &str,
+    cost_per_time: Cost,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    if cost_per_time < 0. {
+        return Err("completion deadline cost should not be negative".into());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(CompletionDeadlineObjective { cost_per_time, transport, activity })
+        .build()
+}
+
+/// Creates a feature which rejects job insertions leading to a job being completed after its
+/// completion deadline. It checks both the inserted job and jobs which are shifted later in the
+/// route by the insertion. It is a hard constraint.
+pub fn create_hard_completion_deadline_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CompletionDeadlineConstraint { code, transport: transport.clone(), activity })
+        .with_state(CompletionDeadlineState { transport })
+        .build()
+}
+
+struct CompletionDeadlineObjective {
+    cost_per_time: Cost,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl CompletionDeadlineObjective {
+    fn get_penalty(&self, activity: &Activity, departure: Timestamp) -> Cost {
+        activity
+            .job
+            .as_ref()
+            .and_then(|single| single.dimens.get_job_completion_deadline())
+            .map_or(Cost::default(), |&deadline| (departure - deadline).max(0.) * self.cost_per_time)
+    }
+}
+
+impl FeatureObjective for CompletionDeadlineObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .map(|activity| self.get_penalty(activity, activity.schedule.departure))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+                if target.job.as_ref().map_or(true, |single| single.dimens.get_job_completion_deadline().is_none()) {
+                    return Cost::default();
+                }
+
+                let route = route_ctx.route();
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let departure = self.activity.estimate_departure(route, target, arrival);
+
+                // NOTE: for simplicity, an impact on already inserted jobs is ignored
+                self.get_penalty(target, departure)
+            }
+        }
+    }
+}
+
+struct CompletionDeadlineConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureConstraint for CompletionDeadlineConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+                let route = route_ctx.route();
+
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let departure = self.activity.estimate_departure(route, target, arrival);
+
+                if get_deadline(target).map_or(false, |deadline| departure > deadline) {
+                    return ConstraintViolation::skip(self.code);
+                }
+
+                let next = next?;
+                let latest_arrival =
+                    route_ctx.state().get_deadline_latest_arrival_at(activity_ctx.index + 1).copied()?;
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(departure),
+                    );
+
+                if arrival > latest_arrival {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        // NOTE merged job keeps only source's deadline
+        if candidate.dimens().get_job_completion_deadline().is_some() {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct CompletionDeadlineState {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureState for CompletionDeadlineState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let mut latest_arrivals = vec![Timestamp::MAX; route.tour.total()];
+
+        route.tour.all_activities().enumerate().rev().fold(None, |next: Option<(Location, Timestamp)>, (idx, a)| {
+            let next_departure = next.filter(|(_, latest)| *latest < Timestamp::MAX).map(|(location, latest)| {
+                latest - self.transport.duration(route, a.place.location, location, TravelTime::Arrival(latest))
+            });
+
+            let latest_departure = match (get_deadline(a), next_departure) {
+                (Some(deadline), Some(departure)) => Some(deadline.min(departure)),
+                (deadline, departure) => deadline.or(departure),
+            };
+
+            let latest_arrival = latest_departure.map_or(Timestamp::MAX, |departure| departure - a.place.duration);
+            latest_arrivals[idx] = latest_arrival;
+
+            Some((a.place.location, latest_arrival))
+        });
+
+        route_ctx.state_mut().set_deadline_latest_arrival_states(latest_arrivals);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+fn get_deadline(activity: &Activity) -> Option<Timestamp> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_completion_deadline()).copied()
+}
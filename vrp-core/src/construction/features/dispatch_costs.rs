@@ -0,0 +1,45 @@
+//! A feature to model vehicle dispatch costs which depend on the vehicle's departure time.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/dispatch_costs_test.rs"]
+mod dispatch_costs_test;
+
+use super::*;
+use crate::models::solution::Route;
+
+custom_dimension!(VehicleDispatchCosts typeof Vec<(Timestamp, Cost)>);
+
+/// Creates a feature which adds a dispatch cost to each used vehicle. The cost is defined by the
+/// vehicle's time-cost schedule: a list of `(time, cost)` pairs sorted by time where each cost applies
+/// to departures since its time. Departures before the first entry, and vehicles without schedule,
+/// cost nothing. As the actual departure time is used, the cost is affected by start time optimization.
+pub fn create_dispatch_cost_feature(name: &str) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(DispatchCostObjective {}).build()
+}
+
+struct DispatchCostObjective {}
+
+impl FeatureObjective for DispatchCostObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| get_dispatch_cost(route_ctx.route())).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, .. } if route_ctx.route().tour.job_count() == 0 => {
+                get_dispatch_cost(route_ctx.route())
+            }
+            _ => Cost::default(),
+        }
+    }
+}
+
+fn get_dispatch_cost(route: &Route) -> Cost {
+    let departure = route.tour.start().map(|start| start.schedule.departure);
+    let schedule = route.actor.vehicle.dimens.get_vehicle_dispatch_costs();
+
+    departure
+        .zip(schedule)
+        .and_then(|(departure, schedule)| schedule.iter().rev().find(|(time, _)| *time <= departure))
+        .map_or(Cost::default(), |(_, cost)| *cost)
+}
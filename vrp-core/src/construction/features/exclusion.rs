@@ -0,0 +1,59 @@
+//! An exclusion feature provides the way to forbid specific pairs of jobs to be served in the same tour,
+//! e.g. incompatible cargo.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/exclusion_test.rs"]
+mod exclusion_test;
+
+use super::*;
+use std::collections::HashSet;
+
+// Specifies ids of jobs which cannot be served in the same tour with the given job.
+custom_dimension!(JobExclusions typeof HashSet<String>);
+
+/// Creates an exclusion feature as a hard constraint. Exclusions are symmetric: a job is rejected if it
+/// excludes any job in the tour or if any job in the tour excludes it.
+pub fn create_exclusion_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_constraint(ExclusionConstraint { code }).build()
+}
+
+struct ExclusionConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for ExclusionConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let has_conflict = route_ctx.route().tour.jobs().any(|route_job| is_excluded(job, route_job));
+
+                if has_conflict {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        // NOTE merged job keeps only source's exclusions, so candidate with exclusions cannot be merged
+        if candidate.dimens().get_job_exclusions().is_some() || is_excluded(&source, &candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+fn is_excluded(a: &Job, b: &Job) -> bool {
+    let excludes = |a: &Job, b: &Job| {
+        a.dimens()
+            .get_job_exclusions()
+            .zip(b.dimens().get_job_id())
+            .map_or(false, |(exclusions, job_id)| exclusions.contains(job_id))
+    };
+
+    excludes(a, b) || excludes(b, a)
+}
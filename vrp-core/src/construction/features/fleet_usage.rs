@@ -5,6 +5,7 @@
 mod fleet_usage_test;
 
 use super::*;
+use std::collections::HashSet;
 
 /// Creates a feature to minimize used fleet size (affects amount of tours in solution).
 pub fn create_minimize_tours_feature(name: &str) -> GenericResult<Feature> {
@@ -52,6 +53,11 @@ pub fn create_minimize_arrival_time_feature(name: &str) -> GenericResult<Feature
         .build()
 }
 
+/// Creates a feature to minimize amount of distinct vehicle types used in solution.
+pub fn create_minimize_vehicle_types_feature(name: &str, vehicle_type_fn: VehicleTypeFn) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(VehicleTypesObjective { vehicle_type_fn }).build()
+}
+
 struct FleetUsageObjective {
     route_estimate_fn: Box<dyn Fn(&RouteContext) -> Cost + Send + Sync>,
     solution_estimate_fn: Box<dyn Fn(&SolutionContext) -> Cost + Send + Sync>,
@@ -69,3 +75,35 @@ impl FeatureObjective for FleetUsageObjective {
         }
     }
 }
+
+struct VehicleTypesObjective {
+    vehicle_type_fn: VehicleTypeFn,
+}
+
+impl VehicleTypesObjective {
+    fn get_used_types(&self, solution_ctx: &SolutionContext) -> HashSet<String> {
+        solution_ctx
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route().tour.has_jobs())
+            .filter_map(|route_ctx| (self.vehicle_type_fn)(route_ctx.route().actor.as_ref()))
+            .collect()
+    }
+}
+
+impl FeatureObjective for VehicleTypesObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        self.get_used_types(&solution.solution).len() as Cost
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } if !route_ctx.route().tour.has_jobs() => {
+                (self.vehicle_type_fn)(route_ctx.route().actor.as_ref())
+                    .filter(|vehicle_type| !self.get_used_types(solution_ctx).contains(vehicle_type))
+                    .map_or(Cost::default(), |_| 1.)
+            }
+            _ => Cost::default(),
+        }
+    }
+}
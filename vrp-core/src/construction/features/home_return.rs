@@ -0,0 +1,128 @@
+//! A feature to limit amount of consecutive periods (e.g. days) a driver stays away from home
+//! in a multi-period planning horizon.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/home_return_test.rs"]
+mod home_return_test;
+
+use super::*;
+use crate::models::solution::Activity;
+use std::collections::HashSet;
+
+custom_dimension!(JobHomeReturn typeof bool);
+custom_tour_state!(AwayFromHome typeof bool);
+
+/// Creates a feature which requires a driver to return home at least every `max_away_periods` periods.
+/// Vehicles (shifts) with the same vehicle id and different periods (see [VehiclePeriodDimension]) are
+/// considered as the same driver. A used route is considered as away from home unless it contains
+/// a home return job; unused periods are considered as spent at home. A home return job has to be
+/// the last job in the route. It is a hard constraint.
+pub fn create_home_return_feature(
+    name: &str,
+    max_away_periods: usize,
+    code: ViolationCode,
+) -> Result<Feature, GenericError> {
+    if max_away_periods == 0 {
+        return Err("max away periods should be greater than zero".into());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(HomeReturnConstraint { max_away_periods, code })
+        .with_state(HomeReturnState {})
+        .build()
+}
+
+struct HomeReturnConstraint {
+    max_away_periods: usize,
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for HomeReturnConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                // NOTE only insertion of non home return job into an unused route can make a driver away
+                if is_home_return(job) || route_ctx.route().tour.has_jobs() {
+                    return None;
+                }
+
+                let vehicle = &route_ctx.route().actor.vehicle;
+                let (vehicle_id, period) = vehicle.dimens.get_vehicle_id().zip(vehicle.dimens.get_vehicle_period())?;
+                let away_periods = get_away_periods(solution_ctx, vehicle_id);
+
+                let before = (0..*period).rev().take_while(|p| away_periods.contains(p)).count();
+                let after = (period + 1..).take_while(|p| away_periods.contains(p)).count();
+
+                if before + 1 + after > self.max_away_periods {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { activity_ctx, .. } => {
+                let is_home_return_activity =
+                    |activity: &Activity| activity.job.as_ref().is_some_and(|single| is_home_return_single(single));
+
+                // NOTE driver returns home at the end of the period, so no job can be served after home return
+                let is_target_home_return = is_home_return_activity(activity_ctx.target);
+                let has_next_job = activity_ctx.next.is_some_and(|next| next.job.is_some());
+
+                if is_home_return_activity(activity_ctx.prev) || (is_target_home_return && has_next_job) {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (is_home_return(&source), is_home_return(&candidate)) {
+            (false, false) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct HomeReturnState {}
+
+impl FeatureState for HomeReturnState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let tour = &route_ctx.route().tour;
+        let is_away = tour.has_jobs() && !tour.jobs().any(is_home_return);
+
+        route_ctx.state_mut().set_away_from_home(is_away);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+fn is_home_return(job: &Job) -> bool {
+    job.dimens().get_job_home_return().copied().unwrap_or(false)
+}
+
+fn is_home_return_single(single: &Single) -> bool {
+    single.dimens.get_job_home_return().copied().unwrap_or(false)
+}
+
+fn get_away_periods(solution_ctx: &SolutionContext, vehicle_id: &String) -> HashSet<usize> {
+    solution_ctx
+        .routes
+        .iter()
+        .filter(|route_ctx| route_ctx.state().get_away_from_home().copied().unwrap_or(false))
+        .map(|route_ctx| route_ctx.route())
+        .filter(|route| route.actor.vehicle.dimens.get_vehicle_id() == Some(vehicle_id))
+        .filter_map(|route| route.actor.vehicle.dimens.get_vehicle_period().copied())
+        .collect()
+}
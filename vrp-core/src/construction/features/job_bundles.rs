@@ -0,0 +1,93 @@
+//! A feature to give a discount when jobs of the same bundle are served together.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_bundles_test.rs"]
+mod job_bundles_test;
+
+use super::*;
+use std::collections::HashMap;
+
+custom_dimension!(JobBundle typeof String);
+custom_tour_state!(BundleSizes typeof HashMap<String, usize>);
+
+/// Creates a feature which reduces the cost when jobs of the same bundle are served on the same route:
+/// a route which serves `n` jobs of the bundle gets a discount of `(n - 1) * discount`.
+/// Jobs without bundle are not affected. It is a soft constraint.
+pub fn create_job_bundle_feature(name: &str, discount: Cost) -> GenericResult<Feature> {
+    if discount < 0. {
+        return Err(format!("bundle discount should not be negative, got: {discount}").into());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(JobBundleObjective { discount })
+        .with_state(JobBundleState {})
+        .build()
+}
+
+struct JobBundleObjective {
+    discount: Cost,
+}
+
+impl FeatureObjective for JobBundleObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        let bundled = solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| get_bundle_sizes(route_ctx).into_values())
+            .map(|size| size - 1)
+            .sum::<usize>();
+
+        -(bundled as Cost) * self.discount
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let has_bundle_member = job.dimens().get_job_bundle().map_or(false, |bundle| {
+                    route_ctx.state().get_bundle_sizes().map_or(false, |sizes| sizes.contains_key(bundle))
+                });
+
+                if has_bundle_member {
+                    -self.discount
+                } else {
+                    Cost::default()
+                }
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct JobBundleState {}
+
+impl FeatureState for JobBundleState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_job_bundle().is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let sizes = get_bundle_sizes(route_ctx);
+        route_ctx.state_mut().set_bundle_sizes(sizes);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let sizes = get_bundle_sizes(route_ctx);
+            route_ctx.state_mut().set_bundle_sizes(sizes);
+        });
+    }
+}
+
+fn get_bundle_sizes(route_ctx: &RouteContext) -> HashMap<String, usize> {
+    route_ctx.route().tour.jobs().filter_map(|job| job.dimens().get_job_bundle()).fold(
+        HashMap::default(),
+        |mut acc, bundle| {
+            *acc.entry(bundle.clone()).or_insert(0) += 1;
+            acc
+        },
+    )
+}
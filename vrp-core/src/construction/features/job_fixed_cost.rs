@@ -0,0 +1,65 @@
+//! A feature to add a fixed cost of the job when it is assigned, independently of routing.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_fixed_cost_test.rs"]
+mod job_fixed_cost_test;
+
+use super::*;
+use crate::models::problem::Single;
+
+custom_dimension!(JobFixedCost typeof Cost);
+
+/// Creates a feature which adds a job's fixed cost (e.g. handling fee) to the objective when
+/// the job is assigned. Unassigned jobs incur no fixed cost. When jobs are merged, e.g. by clustering,
+/// their fixed costs are summed up; merging into a multi job is rejected with the given merge code.
+pub fn create_job_fixed_cost_feature(name: &str, merge_code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(JobFixedCostObjective {})
+        .with_constraint(JobFixedCostConstraint { merge_code })
+        .build()
+}
+
+struct JobFixedCostObjective {}
+
+impl FeatureObjective for JobFixedCostObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).map(get_fixed_cost).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { job, .. } => get_fixed_cost(job),
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct JobFixedCostConstraint {
+    merge_code: ViolationCode,
+}
+
+impl FeatureConstraint for JobFixedCostConstraint {
+    fn evaluate(&self, _: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        None
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let candidate_cost = get_fixed_cost(&candidate);
+
+        match (&source, candidate_cost > 0.) {
+            (_, false) => Ok(source),
+            (Job::Single(single), true) => {
+                let mut dimens = single.dimens.clone();
+                dimens.set_job_fixed_cost(get_fixed_cost(&source) + candidate_cost);
+
+                Ok(Job::Single(Arc::new(Single { places: single.places.clone(), dimens })))
+            }
+            (Job::Multi(_), true) => Err(self.merge_code),
+        }
+    }
+}
+
+fn get_fixed_cost(job: &Job) -> Cost {
+    job.dimens().get_job_fixed_cost().copied().unwrap_or_default()
+}
@@ -0,0 +1,45 @@
+//! A feature to pin jobs to periods (e.g. days) of a multi-period planning horizon.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_periods_test.rs"]
+mod job_periods_test;
+
+use super::*;
+
+custom_dimension!(JobPeriod typeof usize);
+custom_dimension!(VehiclePeriod typeof usize);
+
+/// Creates a feature which ensures that a job with period is served only by a vehicle (shift)
+/// of the same period. Jobs without period can be served in any period. It is a hard constraint.
+pub fn create_job_period_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_constraint(JobPeriodConstraint { code }).build()
+}
+
+struct JobPeriodConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for JobPeriodConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => job.dimens().get_job_period().and_then(|job_period| {
+                let vehicle_period = route_ctx.route().actor.vehicle.dimens.get_vehicle_period();
+
+                if vehicle_period == Some(job_period) {
+                    None
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }),
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_period(), candidate.dimens().get_job_period()) {
+            (None, None) => Ok(source),
+            (Some(s_period), Some(c_period)) if s_period == c_period => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! A feature to forbid serving jobs at the same location simultaneously by different vehicles, e.g. when
+//! a loading dock can handle only one vehicle at a time.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/location_exclusion_test.rs"]
+mod location_exclusion_test;
+
+use super::*;
+use std::collections::{HashMap, HashSet};
+
+custom_tour_state!(ExclusiveLocationServices typeof HashMap<Location, Vec<TimeWindow>>);
+// Keeps the latest arrival at activity which does not shift its own or any following exclusive service into
+// a service of another vehicle.
+custom_activity_state!(ExclusiveLatestArrival typeof Timestamp);
+
+/// Creates a location exclusion feature as a hard constraint. Service times of jobs at any of given
+/// exclusive locations must not overlap when they are served by different vehicles. Insertions which shift
+/// already scheduled exclusive services into overlap are rejected too.
+pub fn create_location_exclusion_feature(
+    name: &str,
+    locations: HashSet<Location>,
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+) -> Result<Feature, GenericError> {
+    if locations.is_empty() {
+        return Err("exclusive locations should not be empty".into());
+    }
+
+    let locations = Arc::new(locations);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(LocationExclusionConstraint {
+            locations: locations.clone(),
+            transport: transport.clone(),
+            code,
+        })
+        .with_state(LocationExclusionState { locations, transport })
+        .build()
+}
+
+struct LocationExclusionConstraint {
+    locations: Arc<HashSet<Location>>,
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for LocationExclusionConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+                let route = route_ctx.route();
+
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let service_start = arrival.max(target.place.time.start);
+                let departure = service_start + get_service_duration(route, target);
+
+                if target.job.is_some() && self.locations.contains(&target.place.location) {
+                    let service = TimeWindow::new(service_start, departure);
+                    let has_overlap = route_ctx
+                        .state()
+                        .get_exclusive_location_services()
+                        .and_then(|services| services.get(&target.place.location))
+                        .is_some_and(|others| others.iter().any(|other| other.intersects_exclusive(&service)));
+
+                    if has_overlap {
+                        return ConstraintViolation::skip(self.code);
+                    }
+                }
+
+                let next = next?;
+                let latest_arrival =
+                    route_ctx.state().get_exclusive_latest_arrival_at(activity_ctx.index + 1).copied()?;
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(departure),
+                    );
+
+                if arrival > latest_arrival {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct LocationExclusionState {
+    locations: Arc<HashSet<Location>>,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureState for LocationExclusionState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        // NOTE any insertion can shift a service at exclusive location, so all routes have to be refreshed
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let other_services = route_ctx.state().get_exclusive_location_services();
+        let mut latest_arrivals = vec![Timestamp::MAX; route.tour.total()];
+
+        route.tour.all_activities().enumerate().rev().fold(None, |next: Option<(Location, Timestamp)>, (idx, a)| {
+            let duration = get_service_duration(route, a);
+
+            let next_arrival = next.filter(|(_, latest)| *latest < Timestamp::MAX).map(|(location, latest)| {
+                let latest_departure =
+                    latest - self.transport.duration(route, a.place.location, location, TravelTime::Arrival(latest));
+
+                latest_departure - duration
+            });
+
+            // NOTE service can be delayed until it reaches the closest following service of another vehicle
+            let own_arrival = other_services
+                .filter(|_| a.job.is_some())
+                .and_then(|services| services.get(&a.place.location))
+                .and_then(|others| {
+                    let service_start = a.schedule.arrival.max(a.place.time.start);
+                    others
+                        .iter()
+                        .filter(|other| other.start >= service_start)
+                        .map(|other| other.start - duration)
+                        .min_by(|left, right| left.total_cmp(right))
+                });
+
+            let latest_arrival = match (own_arrival, next_arrival) {
+                (Some(own), Some(next)) => own.min(next),
+                (own, next) => own.or(next).unwrap_or(Timestamp::MAX),
+            };
+            latest_arrivals[idx] = latest_arrival;
+
+            Some((a.place.location, latest_arrival))
+        });
+
+        route_ctx.state_mut().set_exclusive_latest_arrival_states(latest_arrivals);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let services = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| {
+                route_ctx
+                    .route()
+                    .tour
+                    .all_activities()
+                    .filter(|activity| activity.job.is_some() && self.locations.contains(&activity.place.location))
+                    .map(move |activity| {
+                        let service_start = activity.schedule.arrival.max(activity.place.time.start);
+                        let service = TimeWindow::new(service_start, activity.schedule.departure);
+
+                        (route_idx, activity.place.location, service)
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        solution_ctx.routes.iter_mut().enumerate().for_each(|(route_idx, route_ctx)| {
+            let other_services = services.iter().filter(|(idx, _, _)| *idx != route_idx).fold(
+                HashMap::<Location, Vec<TimeWindow>>::default(),
+                |mut acc, (_, location, service)| {
+                    acc.entry(*location).or_default().push(service.clone());
+                    acc
+                },
+            );
+
+            route_ctx.state_mut().set_exclusive_location_services(other_services);
+            self.accept_route_state(route_ctx);
+        });
+    }
+}
@@ -8,16 +8,19 @@ use super::*;
 use crate::utils::Either;
 use std::iter::empty;
 
+custom_dimension!(JobAge typeof Float);
+
 /// Provides a way to build a feature to minimize amount of unassigned jobs.
 pub struct MinimizeUnassignedBuilder {
     name: String,
     job_estimator: Option<UnassignedJobEstimator>,
+    age_factor: Option<Float>,
 }
 
 impl MinimizeUnassignedBuilder {
     /// Creates a new instance of `MinimizeUnassignedBuilder`
     pub fn new(name: &str) -> Self {
-        Self { name: name.to_string(), job_estimator: None }
+        Self { name: name.to_string(), job_estimator: None, age_factor: None }
     }
 
     /// Sets a job estimator function which responsible for cost estimate of unassigned jobs.
@@ -30,10 +33,31 @@ impl MinimizeUnassignedBuilder {
         self
     }
 
+    /// Sets a factor which scales job estimate by its age (e.g. amount of planning periods the job
+    /// is pending), read from [`JobAgeDimension`]: estimate is multiplied by `1 + factor * age`.
+    /// Optional. Default is no scaling.
+    pub fn set_age_factor(mut self, factor: Float) -> Self {
+        self.age_factor = Some(factor);
+        self
+    }
+
     /// Builds a feature.
     pub fn build(mut self) -> GenericResult<Feature> {
         let unassigned_job_estimator = self.job_estimator.take().unwrap_or_else(|| Arc::new(|_, _| 1.));
 
+        let unassigned_job_estimator: UnassignedJobEstimator = if let Some(factor) = self.age_factor {
+            if factor < 0. {
+                return Err("age factor should not be negative".into());
+            }
+
+            Arc::new(move |solution_ctx: &SolutionContext, job: &Job| {
+                let age = job.dimens().get_job_age().copied().unwrap_or_default();
+                unassigned_job_estimator(solution_ctx, job) * (1. + factor * age)
+            })
+        } else {
+            unassigned_job_estimator
+        };
+
         FeatureBuilder::default()
             .with_name(self.name.as_str())
             .with_objective(MinimizeUnassignedObjective { unassigned_job_estimator })
@@ -12,12 +12,27 @@ pub use self::breaks::*;
 
 mod capacity;
 pub use self::capacity::{
-    CapacityFeatureBuilder, JobDemandDimension, MaxVehicleLoadTourState, VehicleCapacityDimension,
+    CapacityFeatureBuilder, JobDemandDimension, MaxVehicleLoadTourState, TimeDependentCapacityFn,
+    VehicleCapacityDimension,
 };
 
+mod cargo_compatibility;
+pub use self::cargo_compatibility::{create_cargo_compatibility_feature, JobCargoClassDimension};
+
 mod compatibility;
 pub use self::compatibility::{create_compatibility_feature, JobCompatibilityDimension};
 
+mod completion_deadlines;
+pub use self::completion_deadlines::{
+    create_completion_deadline_feature, create_hard_completion_deadline_feature, JobCompletionDeadlineDimension,
+};
+
+mod dispatch_costs;
+pub use self::dispatch_costs::{create_dispatch_cost_feature, VehicleDispatchCostsDimension};
+
+mod exclusion;
+pub use self::exclusion::{create_exclusion_feature, JobExclusionsDimension};
+
 mod fast_service;
 pub use self::fast_service::FastServiceFeatureBuilder;
 
@@ -27,23 +42,69 @@ pub use self::fleet_usage::*;
 mod groups;
 pub use self::groups::{create_group_feature, JobGroupDimension};
 
+mod home_return;
+pub use self::home_return::{create_home_return_feature, JobHomeReturnDimension};
+
+mod job_bundles;
+pub use self::job_bundles::{create_job_bundle_feature, JobBundleDimension};
+
+mod job_fixed_cost;
+pub use self::job_fixed_cost::{create_job_fixed_cost_feature, JobFixedCostDimension};
+
+mod job_periods;
+pub use self::job_periods::{create_job_period_feature, JobPeriodDimension, VehiclePeriodDimension};
+
+mod location_exclusion;
+pub use self::location_exclusion::create_location_exclusion_feature;
+
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
+mod overtime;
+pub use self::overtime::{create_overtime_feature, VehicleOvertime, VehicleOvertimeDimension};
+
+mod priority_lateness;
+pub use self::priority_lateness::{create_priority_lateness_feature, JobPriorityDimension};
+
 mod reachable;
 pub use self::reachable::create_reachable_feature;
 
 mod recharge;
 pub use self::recharge::RechargeFeatureBuilder;
 
+mod region_tolls;
+pub use self::region_tolls::{create_region_toll_feature, TollRegion, VehicleTollRegionsDimension};
+
 mod reloads;
 pub use self::reloads::{ReloadFeatureFactory, ReloadIntervalsTourState, SharedResource, SharedResourceId};
 
+mod rotation_direction;
+pub use self::rotation_direction::{create_rotation_direction_feature, RotationDirection};
+
+mod route_diameter;
+pub use self::route_diameter::{create_route_diameter_feature, RouteDiameterTourState};
+
+mod sharp_turns;
+pub use self::sharp_turns::{create_sharp_turns_feature, CoordIndexFn};
+
+mod skill_distance_budget;
+pub use self::skill_distance_budget::create_skill_distance_budget_feature;
+
 mod skills;
-pub use self::skills::{create_skills_feature, JobSkills, JobSkillsDimension, VehicleSkillsDimension};
+pub use self::skills::{
+    create_max_route_skills_feature, create_skills_feature, create_skills_feature_with_merge_listener,
+    create_soft_skills_feature, JobPreferredSkillsDimension, JobSkillSet, JobSkills, JobSkillsBuilder,
+    JobSkillsDimension, SkillsMergeFailureFn, VehicleSkillsDimension,
+};
+
+mod strict_sequence;
+pub use self::strict_sequence::{create_strict_sequence_feature, StrictSequenceDimension};
+
+mod synchronization;
+pub use self::synchronization::{create_synchronization_feature, JobSyncGroupDimension};
 
 mod total_value;
 pub use self::total_value::*;
@@ -57,11 +118,22 @@ pub use self::tour_limits::*;
 mod tour_order;
 pub use self::tour_order::*;
 
+mod tour_position;
+pub use self::tour_position::{create_tour_position_feature, JobTourPosition, JobTourPositionDimension};
+
 mod transport;
 pub use self::transport::*;
 
+mod vehicle_time_windows;
+pub use self::vehicle_time_windows::{
+    create_vehicle_time_windows_feature, JobVehicleTimeWindowsDimension, VehicleTypeFn,
+};
+
+mod waiting_time;
+pub use self::waiting_time::create_min_waiting_feature;
+
 mod work_balance;
 pub use self::work_balance::{
-    create_activity_balanced_feature, create_distance_balanced_feature, create_duration_balanced_feature,
-    create_max_load_balanced_feature,
+    create_activity_balanced_feature, create_balance_tour_size_feature, create_distance_balanced_feature,
+    create_duration_balanced_feature, create_max_load_balanced_feature, create_skill_balanced_feature,
 };
@@ -0,0 +1,116 @@
+//! A vehicle overtime feature which penalizes work after the regular shift end.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/overtime_test.rs"]
+mod overtime_test;
+
+use super::*;
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::Route;
+
+custom_dimension!(VehicleOvertime typeof VehicleOvertime);
+
+/// Specifies how long a vehicle is allowed to work after its regular shift end and how much it costs.
+#[derive(Clone, Debug)]
+pub struct VehicleOvertime {
+    /// A max overtime duration.
+    pub max_duration: Duration,
+    /// A cost of one overtime time unit.
+    pub cost_per_time: Cost,
+}
+
+/// Creates a feature which penalizes vehicle overtime. The vehicle shift end time has to include
+/// the overtime allowance: the regular shift end is computed as shift end minus max overtime duration.
+/// Time limit itself is still a hard constraint enforced by the transport feature, so work beyond the
+/// allowance is rejected, but the overtime within the allowance is only penalized.
+pub fn create_overtime_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(OvertimeObjective { transport, activity }).build()
+}
+
+struct OvertimeObjective {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureObjective for OvertimeObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .filter_map(|route_ctx| {
+                let route = route_ctx.route();
+                get_route_end_time(route).map(|end_time| get_penalty(route, end_time))
+            })
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let route = route_ctx.route();
+                if route.actor.vehicle.dimens.get_vehicle_overtime().is_none() {
+                    return Cost::default();
+                }
+
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let departure = self.activity.estimate_departure(route, target, arrival);
+
+                let end_time = if let Some(end_time) = get_route_end_time(route) {
+                    end_time
+                } else {
+                    return Cost::default();
+                };
+
+                let new_end_time = match activity_ctx.next {
+                    Some(next) => {
+                        let arrival = departure
+                            + self.transport.duration(
+                                route,
+                                target.place.location,
+                                next.place.location,
+                                TravelTime::Departure(departure),
+                            );
+                        let old_start = next.schedule.arrival.max(next.place.time.start);
+                        let new_start = arrival.max(next.place.time.start);
+
+                        // NOTE: waiting time after the next activity is ignored, so the estimate is pessimistic
+                        end_time + (new_start - old_start).max(0.)
+                    }
+                    None => departure.max(end_time),
+                };
+
+                get_penalty(route, new_end_time) - get_penalty(route, end_time)
+            }
+        }
+    }
+}
+
+fn get_route_end_time(route: &Route) -> Option<Timestamp> {
+    route
+        .tour
+        .end()
+        .map(|end| if route.actor.detail.end.is_some() { end.schedule.arrival } else { end.schedule.departure })
+}
+
+fn get_penalty(route: &Route, end_time: Timestamp) -> Cost {
+    route.actor.vehicle.dimens.get_vehicle_overtime().map_or(Cost::default(), |overtime| {
+        let regular_end = route.actor.detail.time.end - overtime.max_duration;
+
+        (end_time - regular_end).max(0.) * overtime.cost_per_time
+    })
+}
@@ -0,0 +1,73 @@
+//! A feature to serve high priority jobs earlier within their time windows.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/priority_lateness_test.rs"]
+mod priority_lateness_test;
+
+use super::*;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Activity;
+
+custom_dimension!(JobPriority typeof Float);
+
+/// Creates a feature which penalizes serving a job late within its (otherwise valid) time window.
+/// A penalty is the time passed since the time window start till the service start scaled by
+/// the job's priority, so jobs with higher priority are effectively given tighter time windows.
+/// Jobs without priority are not penalized. It is a soft constraint.
+pub fn create_priority_lateness_feature(name: &str, transport: Arc<dyn TransportCost>) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(PriorityLatenessObjective { transport }).build()
+}
+
+struct PriorityLatenessObjective {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureObjective for PriorityLatenessObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .map(|activity| get_penalty(activity, activity.schedule.arrival))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+                if get_priority(target).is_none() {
+                    return Cost::default();
+                }
+
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        route_ctx.route(),
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+
+                // NOTE: for simplicity, an impact on already inserted jobs is ignored
+                get_penalty(target, arrival)
+            }
+        }
+    }
+}
+
+fn get_priority(activity: &Activity) -> Option<Float> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_priority()).copied()
+}
+
+fn get_penalty(activity: &Activity, arrival: Timestamp) -> Cost {
+    get_priority(activity).map_or(Cost::default(), |priority| {
+        let time_start = activity.place.time.start;
+        let service_start = arrival.max(time_start);
+
+        priority * (service_start - time_start)
+    })
+}
@@ -0,0 +1,98 @@
+//! A feature to model tolls or surcharges applied when a vehicle enters specific regions.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/region_tolls_test.rs"]
+mod region_tolls_test;
+
+use super::*;
+use crate::algorithms::geometry::{is_inside_polygon, Point};
+use crate::models::solution::{Activity, Route};
+
+/// Specifies a tolled region.
+#[derive(Clone, Debug)]
+pub struct TollRegion {
+    /// A polygon which defines the region boundary.
+    pub polygon: Vec<Point>,
+    /// A cost multiplier applied to the legs entering the region.
+    pub multiplier: Float,
+}
+
+custom_dimension!(VehicleTollRegions typeof Vec<TollRegion>);
+
+/// Creates a feature which applies a vehicle specific cost multiplier to each leg whose destination
+/// is inside a tolled region. When the destination is inside multiple regions, the highest multiplier
+/// is used. Only an extra cost on top of the leg's transport cost is counted by the feature, so it
+/// should be combined with a transport cost objective. Locations without coordinates are not tolled.
+pub fn create_region_toll_feature(
+    name: &str,
+    coord_index: CoordIndexFn,
+    transport: Arc<dyn TransportCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(RegionTollObjective { coord_index, transport }).build()
+}
+
+struct RegionTollObjective {
+    coord_index: CoordIndexFn,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureObjective for RegionTollObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                let route = route_ctx.route();
+                let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+                activities.windows(2).map(|leg| self.get_leg_toll(route, leg[0], leg[1])).sum::<Cost>()
+            })
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let route = route_ctx.route();
+                if route.actor.vehicle.dimens.get_vehicle_toll_regions().map_or(true, |regions| regions.is_empty()) {
+                    return Cost::default();
+                }
+
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+                let new_toll = self.get_leg_toll(route, prev, target)
+                    + activity_ctx.next.map_or(Cost::default(), |next| self.get_leg_toll(route, target, next));
+                let old_toll = activity_ctx.next.map_or(Cost::default(), |next| self.get_leg_toll(route, prev, next));
+
+                new_toll - old_toll
+            }
+        }
+    }
+}
+
+impl RegionTollObjective {
+    fn get_leg_toll(&self, route: &Route, from: &Activity, to: &Activity) -> Cost {
+        let multiplier =
+            route.actor.vehicle.dimens.get_vehicle_toll_regions().zip((self.coord_index)(to.place.location)).and_then(
+                |(regions, point)| {
+                    regions
+                        .iter()
+                        .filter(|region| is_inside_polygon(&point, region.polygon.as_slice()))
+                        .map(|region| region.multiplier)
+                        .max_by(|a, b| a.total_cmp(b))
+                },
+            );
+
+        multiplier.map_or(Cost::default(), |multiplier| {
+            let cost = self.transport.cost(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            );
+
+            cost * (multiplier - 1.)
+        })
+    }
+}
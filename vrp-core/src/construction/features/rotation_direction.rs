@@ -0,0 +1,185 @@
+//! A rotation direction feature provides the way to prefer routes which sweep around the depot
+//! in a consistent rotational direction, using bearings of visited locations relative to the depot.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/rotation_direction_test.rs"]
+mod rotation_direction_test;
+
+use super::*;
+use crate::algorithms::geometry::Point;
+
+// Keeps bearings of route activities relative to the depot, `None` for activities which do not contribute.
+custom_tour_state!(RotationBearings typeof Vec<Option<Float>>);
+custom_tour_state!(RotationPenalty typeof Cost);
+
+/// Specifies a rotational direction around the depot.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RotationDirection {
+    /// Clockwise direction: bearing decreases.
+    Clockwise,
+    /// Counterclockwise direction: bearing increases.
+    CounterClockwise,
+}
+
+/// Creates a soft feature which penalizes reversals of angular progression of the route around its
+/// start (depot) location. Each reversal costs `penalty`. If `preferred` direction is specified, it is
+/// used as initial direction, so a route starting in the opposite direction is penalized too.
+/// Locations without coordinates or coinciding with the depot do not contribute to the penalty.
+pub fn create_rotation_direction_feature(
+    name: &str,
+    coord_index: CoordIndexFn,
+    preferred: Option<RotationDirection>,
+    penalty: Cost,
+) -> Result<Feature, GenericError> {
+    if penalty < 0. {
+        return Err(format!("Rotation direction: penalty should be non-negative, got: {penalty}").into());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(RotationDirectionObjective { coord_index: coord_index.clone(), preferred, penalty })
+        .with_state(RotationDirectionState { coord_index, preferred, penalty })
+        .build()
+}
+
+struct RotationDirectionObjective {
+    coord_index: CoordIndexFn,
+    preferred: Option<RotationDirection>,
+    penalty: Cost,
+}
+
+impl FeatureObjective for RotationDirectionObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                route_ctx.state().get_rotation_penalty().copied().unwrap_or_else(|| {
+                    let bearings = get_route_bearings(&self.coord_index, route_ctx);
+                    get_route_penalty(bearings.iter().flatten().copied(), self.preferred, self.penalty)
+                })
+            })
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let target = activity_ctx.target;
+                if target.job.is_none() {
+                    return Cost::default();
+                }
+
+                let state = route_ctx.state();
+                let (Some(bearings), Some(&old_penalty)) =
+                    (state.get_rotation_bearings(), state.get_rotation_penalty())
+                else {
+                    return Cost::default();
+                };
+
+                let depot = get_depot(&self.coord_index, route_ctx);
+                let Some(bearing) =
+                    depot.and_then(|depot| get_bearing(&self.coord_index, &depot, target.place.location))
+                else {
+                    return Cost::default();
+                };
+
+                let (before, after) = bearings.split_at((activity_ctx.index + 1).min(bearings.len()));
+                let bearings = before.iter().flatten().chain(std::iter::once(&bearing)).chain(after.iter().flatten());
+                let new_penalty = get_route_penalty(bearings.copied(), self.preferred, self.penalty);
+
+                new_penalty - old_penalty
+            }
+        }
+    }
+}
+
+struct RotationDirectionState {
+    coord_index: CoordIndexFn,
+    preferred: Option<RotationDirection>,
+    penalty: Cost,
+}
+
+impl FeatureState for RotationDirectionState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let bearings = get_route_bearings(&self.coord_index, route_ctx);
+        let penalty = get_route_penalty(bearings.iter().flatten().copied(), self.preferred, self.penalty);
+
+        route_ctx.state_mut().set_rotation_bearings(bearings);
+        route_ctx.state_mut().set_rotation_penalty(penalty);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+fn get_depot(coord_index: &CoordIndexFn, route_ctx: &RouteContext) -> Option<Point> {
+    route_ctx.route().tour.start().and_then(|start| (coord_index)(start.place.location))
+}
+
+/// Returns bearing (in degrees) of the location relative to the depot. Returns `None` for location
+/// without coordinates or coinciding with the depot.
+fn get_bearing(coord_index: &CoordIndexFn, depot: &Point, location: Location) -> Option<Float> {
+    (coord_index)(location)
+        .filter(|point| point.x != depot.x || point.y != depot.y)
+        .map(|point| (point.y - depot.y).atan2(point.x - depot.x).to_degrees())
+}
+
+/// Returns bearings of all route activities keeping `None` for activities without job (e.g. start or end).
+fn get_route_bearings(coord_index: &CoordIndexFn, route_ctx: &RouteContext) -> Vec<Option<Float>> {
+    let depot = get_depot(coord_index, route_ctx);
+
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .map(|activity| {
+            activity
+                .job
+                .as_ref()
+                .and(depot.as_ref())
+                .and_then(|depot| get_bearing(coord_index, depot, activity.place.location))
+        })
+        .collect()
+}
+
+/// Returns penalty for reversals of angular progression of given bearings.
+fn get_route_penalty(
+    bearings: impl Iterator<Item = Float>,
+    preferred: Option<RotationDirection>,
+    penalty: Cost,
+) -> Cost {
+    let (reversals, _, _) = bearings.fold((0, None, preferred), |(reversals, prev, last), bearing| {
+        match (prev.and_then(|prev| get_direction(prev, bearing)), last) {
+            (Some(direction), Some(last)) if last != direction => (reversals + 1, Some(bearing), Some(direction)),
+            (Some(direction), _) => (reversals, Some(bearing), Some(direction)),
+            (None, _) => (reversals, Some(bearing), last),
+        }
+    });
+
+    reversals as Cost * penalty
+}
+
+/// Returns rotation direction between two bearings (in degrees) taking the shortest angular path.
+fn get_direction(from: Float, to: Float) -> Option<RotationDirection> {
+    let delta = (to - from + 540.) % 360. - 180.;
+
+    if delta.abs() < 1E-6 || (delta.abs() - 180.).abs() < 1E-6 {
+        None
+    } else if delta > 0. {
+        Some(RotationDirection::CounterClockwise)
+    } else {
+        Some(RotationDirection::Clockwise)
+    }
+}
@@ -0,0 +1,123 @@
+//! A feature to keep routes geographically compact by limiting their diameter: a maximum distance
+//! between any two job locations served by the same route.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/route_diameter_test.rs"]
+mod route_diameter_test;
+
+use super::*;
+use crate::models::solution::Route;
+
+custom_tour_state!(RouteDiameter typeof Distance);
+
+/// Creates a route diameter feature as a hard constraint. A job is rejected if any of its locations
+/// is farther than `max_diameter` from any job location already served by the route.
+/// Distance between two locations is the longest of both travel directions.
+pub fn create_route_diameter_feature(
+    name: &str,
+    code: ViolationCode,
+    max_diameter: Distance,
+    transport: Arc<dyn TransportCost>,
+) -> Result<Feature, GenericError> {
+    if max_diameter < 0. {
+        return Err("route diameter should not be negative".into());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(RouteDiameterConstraint { code, max_diameter, transport: transport.clone() })
+        .with_state(RouteDiameterState { transport })
+        .build()
+}
+
+struct RouteDiameterConstraint {
+    code: ViolationCode,
+    max_diameter: Distance,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureConstraint for RouteDiameterConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let current = route_ctx.state().get_route_diameter().copied().unwrap_or_default();
+                let diameter = current.max(get_job_diameter(self.transport.as_ref(), route_ctx.route(), job));
+
+                if diameter > self.max_diameter {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct RouteDiameterState {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureState for RouteDiameterState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+
+        // NOTE job is already in the tour, so its distance to own locations is zero and has no effect
+        let diameter = match route_ctx.state().get_route_diameter().copied() {
+            Some(current) => current.max(get_job_diameter(self.transport.as_ref(), route_ctx.route(), job)),
+            None => get_route_diameter(self.transport.as_ref(), route_ctx.route()),
+        };
+
+        route_ctx.state_mut().set_route_diameter(diameter);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let diameter = get_route_diameter(self.transport.as_ref(), route_ctx.route());
+        route_ctx.state_mut().set_route_diameter(diameter);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx))
+    }
+}
+
+/// Returns the longest distance between given job locations and all job locations in the route.
+fn get_job_diameter(transport: &dyn TransportCost, route: &Route, job: &Job) -> Distance {
+    let route_locations = get_route_locations(route).collect::<Vec<_>>();
+    let job_locations = job.places().filter_map(|place| place.location).collect::<Vec<_>>();
+
+    job_locations
+        .iter()
+        .flat_map(|&from| route_locations.iter().chain(job_locations.iter()).map(move |&to| (from, to)))
+        .map(|(from, to)| get_distance(transport, route, from, to))
+        .fold(Distance::default(), |acc, distance| acc.max(distance))
+}
+
+fn get_route_diameter(transport: &dyn TransportCost, route: &Route) -> Distance {
+    let locations = get_route_locations(route).collect::<Vec<_>>();
+
+    locations
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, &from)| locations.iter().skip(idx + 1).map(move |&to| (from, to)))
+        .map(|(from, to)| get_distance(transport, route, from, to))
+        .fold(Distance::default(), |acc, distance| acc.max(distance))
+}
+
+fn get_route_locations(route: &Route) -> impl Iterator<Item = Location> + '_ {
+    route.tour.all_activities().filter(|activity| activity.job.is_some()).map(|activity| activity.place.location)
+}
+
+fn get_distance(transport: &dyn TransportCost, route: &Route, from: Location, to: Location) -> Distance {
+    let profile = &route.actor.vehicle.profile;
+
+    transport.distance_approx(profile, from, to).max(transport.distance_approx(profile, to, from))
+}
@@ -0,0 +1,102 @@
+//! A sharp turns feature provides the way to discourage sharp direction changes on the route,
+//! such as U-turns, using coordinates of visited locations.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/sharp_turns_test.rs"]
+mod sharp_turns_test;
+
+use super::*;
+use crate::algorithms::geometry::Point;
+use crate::models::solution::Activity;
+
+/// Returns a coordinate of the location, if it has one.
+pub type CoordIndexFn = Arc<dyn Fn(Location) -> Option<Point> + Send + Sync>;
+
+/// Creates a soft feature which penalizes sharp direction changes between consecutive legs.
+/// A turn angle is measured in degrees: `0` means moving straight ahead, `180` is a U-turn.
+/// Each degree beyond `max_angle` costs `penalty`. Legs with locations without coordinates or
+/// zero length do not contribute to the penalty.
+pub fn create_sharp_turns_feature(
+    name: &str,
+    coord_index: CoordIndexFn,
+    max_angle: Float,
+    penalty: Cost,
+) -> Result<Feature, GenericError> {
+    if !(0. ..=180.).contains(&max_angle) {
+        return Err(format!("Sharp turns: max angle should be in [0, 180] range, got: {max_angle}").into());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(SharpTurnsObjective { coord_index, max_angle, penalty })
+        .build()
+}
+
+struct SharpTurnsObjective {
+    coord_index: CoordIndexFn,
+    max_angle: Float,
+    penalty: Cost,
+}
+
+impl FeatureObjective for SharpTurnsObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_penalty(route_ctx)).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let tour = &route_ctx.route().tour;
+                let prev_prev = activity_ctx.index.checked_sub(1).and_then(|idx| tour.get(idx));
+                let next_next = activity_ctx.next.and_then(|_| tour.get(activity_ctx.index + 2));
+
+                let (prev, target, next) = (Some(activity_ctx.prev), Some(activity_ctx.target), activity_ctx.next);
+
+                let new_penalty = self.get_turn_penalty(prev_prev, prev, target)
+                    + self.get_turn_penalty(prev, target, next)
+                    + self.get_turn_penalty(target, next, next_next);
+                let old_penalty =
+                    self.get_turn_penalty(prev_prev, prev, next) + self.get_turn_penalty(prev, next, next_next);
+
+                new_penalty - old_penalty
+            }
+        }
+    }
+}
+
+impl SharpTurnsObjective {
+    fn get_route_penalty(&self, route_ctx: &RouteContext) -> Cost {
+        let activities = route_ctx.route().tour.all_activities().collect::<Vec<_>>();
+
+        activities
+            .windows(3)
+            .map(|window| self.get_turn_penalty(Some(window[0]), Some(window[1]), Some(window[2])))
+            .sum()
+    }
+
+    fn get_turn_penalty(&self, from: Option<&Activity>, via: Option<&Activity>, to: Option<&Activity>) -> Cost {
+        let get_point = |activity: Option<&Activity>| activity.and_then(|a| (self.coord_index)(a.place.location));
+
+        match (get_point(from), get_point(via), get_point(to)) {
+            (Some(from), Some(via), Some(to)) => get_turn_angle(&from, &via, &to)
+                .map_or(Cost::default(), |angle| (angle - self.max_angle).max(0.) * self.penalty),
+            _ => Cost::default(),
+        }
+    }
+}
+
+/// Returns a turn angle in degrees between (from, via) and (via, to) legs.
+fn get_turn_angle(from: &Point, via: &Point, to: &Point) -> Option<Float> {
+    let (in_x, in_y) = (via.x - from.x, via.y - from.y);
+    let (out_x, out_y) = (to.x - via.x, to.y - via.y);
+
+    let lengths = (in_x * in_x + in_y * in_y).sqrt() * (out_x * out_x + out_y * out_y).sqrt();
+    if lengths == 0. {
+        return None;
+    }
+
+    let cos = ((in_x * out_x + in_y * out_y) / lengths).clamp(-1., 1.);
+
+    Some(cos.acos().to_degrees())
+}
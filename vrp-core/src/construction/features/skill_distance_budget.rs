@@ -0,0 +1,207 @@
+//! A feature to limit total distance attributable to jobs with specific skills across the fleet.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/skill_distance_budget_test.rs"]
+mod skill_distance_budget_test;
+
+use super::*;
+use crate::models::problem::{Multi, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use std::collections::HashMap;
+
+custom_tour_state!(RouteSkillDistances typeof HashMap<String, Distance>);
+custom_tour_state!(RouteSkillDistanceLimits typeof HashMap<String, Distance>);
+custom_solution_state!(SkillDistances typeof HashMap<String, Distance>);
+
+/// Creates a feature which limits total distance attributable to jobs requiring given skill
+/// (`all_of` job skills) across the whole fleet. A distance attributed to the job activity is the
+/// distance travelled from the previous activity. It is a hard constraint.
+pub fn create_skill_distance_budget_feature(
+    name: &str,
+    code: ViolationCode,
+    budgets: HashMap<String, Distance>,
+    transport: Arc<dyn TransportCost>,
+) -> Result<Feature, GenericError> {
+    if budgets.values().any(|budget| *budget < 0.) {
+        return Err("skill distance budget should not be negative".into());
+    }
+
+    let budgets = Arc::new(budgets);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(SkillDistanceBudgetConstraint { code, budgets: budgets.clone(), transport: transport.clone() })
+        .with_state(SkillDistanceBudgetState { budgets, transport })
+        .build()
+}
+
+struct SkillDistanceBudgetConstraint {
+    code: ViolationCode,
+    budgets: Arc<HashMap<String, Distance>>,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl SkillDistanceBudgetConstraint {
+    fn evaluate_job(&self, solution_ctx: &SolutionContext, job: &Job) -> Option<ConstraintViolation> {
+        let used = solution_ctx.state.get_skill_distances()?;
+
+        let is_exhausted = get_budgeted_skills(job.dimens(), self.budgets.as_ref())
+            .any(|skill| used.get(skill).copied().unwrap_or_default() >= self.budgets[skill]);
+
+        if is_exhausted {
+            ConstraintViolation::fail(self.code)
+        } else {
+            None
+        }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+
+        let mut deltas = HashMap::<String, Distance>::new();
+        let to_target = get_distance(self.transport.as_ref(), route, prev, target);
+        get_activity_skills(target, self.budgets.as_ref()).into_iter().for_each(|skill| {
+            *deltas.entry(skill).or_default() += to_target;
+        });
+
+        if let Some(next) = next {
+            let skills = get_activity_skills(next, self.budgets.as_ref());
+            if !skills.is_empty() {
+                let change = get_distance(self.transport.as_ref(), route, target, next)
+                    - get_distance(self.transport.as_ref(), route, prev, next);
+                skills.into_iter().for_each(|skill| *deltas.entry(skill).or_default() += change);
+            }
+        }
+
+        if deltas.is_empty() {
+            return None;
+        }
+
+        // NOTE limits are set on solution level, so they might be missing for temporary route copies
+        let limits = route_ctx.state().get_route_skill_distance_limits()?;
+        let own = route_ctx.state().get_route_skill_distances();
+
+        let is_exceeded = deltas.iter().filter(|(_, delta)| **delta > 0.).any(|(skill, delta)| {
+            let own = own.and_then(|own| own.get(skill)).copied().unwrap_or_default();
+            limits.get(skill).map_or(false, |limit| own + delta > *limit)
+        });
+
+        if is_exceeded {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+}
+
+impl FeatureConstraint for SkillDistanceBudgetConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, job, .. } => self.evaluate_job(solution_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct SkillDistanceBudgetState {
+    budgets: Arc<HashMap<String, Distance>>,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureState for SkillDistanceBudgetState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let distances = route.tour.all_activities().zip(route.tour.all_activities().skip(1)).fold(
+            HashMap::<String, Distance>::new(),
+            |mut acc, (prev, activity)| {
+                let skills = get_activity_skills(activity, self.budgets.as_ref());
+                if !skills.is_empty() {
+                    let distance = get_distance(self.transport.as_ref(), route, prev, activity);
+                    skills.into_iter().for_each(|skill| *acc.entry(skill).or_default() += distance);
+                }
+                acc
+            },
+        );
+
+        route_ctx.state_mut().set_route_skill_distances(distances);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        let totals = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.state().get_route_skill_distances())
+            .flat_map(|distances| distances.iter())
+            .fold(HashMap::<String, Distance>::new(), |mut acc, (skill, distance)| {
+                *acc.entry(skill.clone()).or_default() += *distance;
+                acc
+            });
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let own = route_ctx.state().get_route_skill_distances().cloned().unwrap_or_default();
+            let limits = self
+                .budgets
+                .iter()
+                .map(|(skill, budget)| {
+                    let others =
+                        totals.get(skill).copied().unwrap_or_default() - own.get(skill).copied().unwrap_or_default();
+                    (skill.clone(), budget - others)
+                })
+                .collect();
+
+            route_ctx.state_mut().set_route_skill_distance_limits(limits);
+        });
+
+        solution_ctx.state.set_skill_distances(totals);
+    }
+}
+
+fn get_budgeted_skills<'a>(
+    dimens: &'a Dimensions,
+    budgets: &'a HashMap<String, Distance>,
+) -> impl Iterator<Item = &'a String> + 'a {
+    dimens
+        .get_job_skills()
+        .and_then(|skills| skills.all_of.as_ref())
+        .into_iter()
+        .flat_map(|all_of| all_of.iter())
+        .filter(move |skill| budgets.contains_key(*skill))
+}
+
+fn get_activity_skills(activity: &Activity, budgets: &HashMap<String, Distance>) -> Vec<String> {
+    activity.job.as_ref().map_or_else(Vec::new, |single| {
+        let skills = get_budgeted_skills(&single.dimens, budgets).cloned().collect::<Vec<_>>();
+
+        if skills.is_empty() {
+            Multi::roots(single)
+                .map(|multi| get_budgeted_skills(&multi.dimens, budgets).cloned().collect())
+                .unwrap_or_default()
+        } else {
+            skills
+        }
+    })
+}
+
+fn get_distance(transport: &(dyn TransportCost), route: &Route, from: &Activity, to: &Activity) -> Distance {
+    transport.distance(route, from.place.location, to.place.location, TravelTime::Departure(from.schedule.departure))
+}
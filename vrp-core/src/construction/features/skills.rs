@@ -5,12 +5,16 @@
 mod skills_test;
 
 use super::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 custom_dimension!(JobSkills typeof JobSkills);
+// Keeps preferred skills of the job with a penalty applied when vehicle has no such skill.
+custom_dimension!(JobPreferredSkills typeof HashMap<String, Cost>);
 custom_dimension!(VehicleSkills typeof HashSet<String>);
+custom_tour_state!(RouteRequiredSkills typeof HashSet<String>);
 
 /// A job skills limitation for a vehicle.
+#[derive(Clone)]
 pub struct JobSkills {
     /// Vehicle should have all of these skills defined.
     pub all_of: Option<HashSet<String>>,
@@ -28,15 +32,163 @@ impl JobSkills {
 
         Self { all_of: map(all_of), one_of: map(one_of), none_of: map(none_of) }
     }
+
+    /// Creates a builder which constructs [`JobSkills`] from string slices with validation.
+    pub fn builder() -> JobSkillsBuilder {
+        JobSkillsBuilder::default()
+    }
+
+    /// Checks whether the given vehicle skills satisfy all job skills requirements.
+    pub fn is_satisfied_by(&self, vehicle_skills: Option<&HashSet<String>>) -> bool {
+        check_all_of(self, &vehicle_skills)
+            && check_one_of(self, &vehicle_skills)
+            && check_none_of(self, &vehicle_skills)
+    }
+
+    /// Combines skill requirements of two jobs served together (e.g. within a cluster): `all_of`
+    /// and `none_of` skills are united, `one_of` skills are intersected. Returns `None` if jobs
+    /// do not share required skills or combined requirement is contradictory.
+    pub fn try_union(&self, other: &JobSkills) -> Option<JobSkills> {
+        let union = |left: &Option<HashSet<String>>, right: &Option<HashSet<String>>| match (left, right) {
+            (Some(left), Some(right)) => Some(left.union(right).cloned().collect::<HashSet<_>>()),
+            (Some(skills), None) | (None, Some(skills)) => Some(skills.clone()),
+            (None, None) => None,
+        };
+
+        if matches!((&self.all_of, &other.all_of), (Some(left), Some(right)) if left.is_disjoint(right)) {
+            return None;
+        }
+
+        let one_of = match (&self.one_of, &other.one_of) {
+            (Some(left), Some(right)) => {
+                let shared = left.intersection(right).cloned().collect::<HashSet<_>>();
+                if shared.is_empty() {
+                    return None;
+                }
+                Some(shared)
+            }
+            (Some(skills), None) | (None, Some(skills)) => Some(skills.clone()),
+            (None, None) => None,
+        };
+
+        let all_of = union(&self.all_of, &other.all_of);
+        let none_of = union(&self.none_of, &other.none_of);
+
+        let is_contradictory = |skills: &Option<HashSet<String>>| matches!((skills, &none_of), (Some(skills), Some(none_of)) if !skills.is_disjoint(none_of));
+
+        if is_contradictory(&all_of)
+            || matches!((&one_of, &none_of), (Some(one_of), Some(none_of)) if one_of.is_subset(none_of))
+        {
+            return None;
+        }
+
+        Some(JobSkills { all_of, one_of, none_of })
+    }
+}
+
+/// Provides a way to build [`JobSkills`] from string slices. Duplicated skills are ignored,
+/// empty skill sets are mapped to `None`.
+#[derive(Default)]
+pub struct JobSkillsBuilder {
+    all_of: Vec<String>,
+    one_of: Vec<String>,
+    none_of: Vec<String>,
 }
 
+impl JobSkillsBuilder {
+    /// Sets skills which all should be defined on a vehicle.
+    pub fn set_all_of(mut self, skills: &[&str]) -> Self {
+        self.all_of = skills.iter().map(|skill| skill.to_string()).collect();
+        self
+    }
+
+    /// Sets skills from which at least one should be defined on a vehicle.
+    pub fn set_one_of(mut self, skills: &[&str]) -> Self {
+        self.one_of = skills.iter().map(|skill| skill.to_string()).collect();
+        self
+    }
+
+    /// Sets skills which none should be defined on a vehicle.
+    pub fn set_none_of(mut self, skills: &[&str]) -> Self {
+        self.none_of = skills.iter().map(|skill| skill.to_string()).collect();
+        self
+    }
+
+    /// Builds [`JobSkills`]. Returns an error if the same skill is required by `all_of` and
+    /// forbidden by `none_of` as such a requirement cannot be satisfied by any vehicle.
+    pub fn build(self) -> Result<JobSkills, GenericError> {
+        if let Some(skill) = self.all_of.iter().find(|skill| self.none_of.contains(skill)) {
+            return Err(format!("skill '{skill}' cannot be defined in both all_of and none_of").into());
+        }
+
+        Ok(JobSkills::new(Some(self.all_of), Some(self.one_of), Some(self.none_of)))
+    }
+}
+
+/// Specifies a kind of job skill set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JobSkillSet {
+    /// A set of skills which all are required.
+    AllOf,
+    /// A set of skills where at least one is required.
+    OneOf,
+    /// A set of skills where none is allowed.
+    NoneOf,
+}
+
+impl JobSkillSet {
+    fn get(self, skills: &JobSkills) -> Option<&HashSet<String>> {
+        match self {
+            JobSkillSet::AllOf => skills.all_of.as_ref(),
+            JobSkillSet::OneOf => skills.one_of.as_ref(),
+            JobSkillSet::NoneOf => skills.none_of.as_ref(),
+        }
+    }
+}
+
+/// A function which is called when two jobs cannot be merged due to incompatible skills. It receives
+/// source and candidate jobs and the first skill set which is mismatched.
+pub type SkillsMergeFailureFn = Arc<dyn Fn(&Job, &Job, JobSkillSet) + Send + Sync>;
+
 /// Creates a skills feature as hard constraint.
 pub fn create_skills_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
-    FeatureBuilder::default().with_name(name).with_constraint(SkillsConstraint { code }).build()
+    create_skills_feature_with_merge_listener(name, code, Arc::new(|_, _, _| {}))
+}
+
+/// Creates a skills feature as hard constraint which notifies about merge failures using given function,
+/// e.g. to diagnose why jobs are not clustered together.
+pub fn create_skills_feature_with_merge_listener(
+    name: &str,
+    code: ViolationCode,
+    on_merge_failure: SkillsMergeFailureFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_constraint(SkillsConstraint { code, on_merge_failure }).build()
+}
+
+/// Creates a feature which limits amount of distinct skills required by jobs of the same route.
+/// Required skills are the ones specified in `all_of` job skills.
+pub fn create_max_route_skills_feature(
+    name: &str,
+    code: ViolationCode,
+    max_skills: usize,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(MaxRouteSkillsConstraint { code, max_skills })
+        .with_state(MaxRouteSkillsState {})
+        .build()
+}
+
+/// Creates a skills feature as soft constraint: when job is served by a vehicle which lacks some of
+/// job's preferred skills, a penalty specified per each missing skill is added to the objective.
+/// Use [`JobPreferredSkillsDimension`] to specify preferred skills. Jobs are never rejected by this feature.
+pub fn create_soft_skills_feature(name: &str) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_objective(SoftSkillsObjective {}).build()
 }
 
 struct SkillsConstraint {
     code: ViolationCode,
+    on_merge_failure: SkillsMergeFailureFn,
 }
 
 impl FeatureConstraint for SkillsConstraint {
@@ -45,10 +197,7 @@ impl FeatureConstraint for SkillsConstraint {
             MoveContext::Route { route_ctx, job, .. } => {
                 if let Some(job_skills) = job.dimens().get_job_skills() {
                     let vehicle_skills = route_ctx.route().actor.vehicle.dimens.get_vehicle_skills();
-                    let is_ok = check_all_of(job_skills, &vehicle_skills)
-                        && check_one_of(job_skills, &vehicle_skills)
-                        && check_none_of(job_skills, &vehicle_skills);
-                    if !is_ok {
+                    if !job_skills.is_satisfied_by(vehicle_skills) {
                         return ConstraintViolation::fail(self.code);
                     }
                 }
@@ -72,22 +221,138 @@ impl FeatureConstraint for SkillsConstraint {
             (Some(source_skills), Some(candidate_skills)) => candidate_skills.is_subset(source_skills),
         };
 
-        let has_comparable_skills = match (source_skills, candidate_skills) {
-            (Some(_), None) | (None, None) => true,
-            (None, Some(_)) => false,
-            (Some(source_skills), Some(candidate_skills)) => {
-                check_skill_sets(source_skills.all_of.as_ref(), candidate_skills.all_of.as_ref())
-                    && check_skill_sets(source_skills.one_of.as_ref(), candidate_skills.one_of.as_ref())
-                    && check_skill_sets(source_skills.none_of.as_ref(), candidate_skills.none_of.as_ref())
+        let mismatch = candidate_skills.and_then(|candidate_skills| {
+            [JobSkillSet::AllOf, JobSkillSet::OneOf, JobSkillSet::NoneOf]
+                .into_iter()
+                .find(|set| {
+                    !check_skill_sets(source_skills.and_then(|skills| set.get(skills)), set.get(candidate_skills))
+                })
+                // NOTE source without skills is not comparable to candidate with skills even if they are empty
+                .or_else(|| source_skills.is_none().then_some(JobSkillSet::AllOf))
+        });
+
+        if let Some(set) = mismatch {
+            (self.on_merge_failure)(&source, &candidate, set);
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct SoftSkillsObjective {}
+
+impl FeatureObjective for SoftSkillsObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.jobs().map(move |job| get_soft_skills_penalty(route_ctx, job)))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => get_soft_skills_penalty(route_ctx, job),
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct MaxRouteSkillsConstraint {
+    code: ViolationCode,
+    max_skills: usize,
+}
+
+impl FeatureConstraint for MaxRouteSkillsConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let job_skills = get_required_skills(job)?;
+                let route_skills = route_ctx.state().get_route_required_skills();
+
+                let total = match route_skills {
+                    Some(route_skills) => route_skills.len() + job_skills.difference(route_skills).count(),
+                    None => job_skills.len(),
+                };
+
+                if total > self.max_skills {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
             }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let total = match (get_required_skills(&source), get_required_skills(&candidate)) {
+            (Some(source_skills), Some(candidate_skills)) => source_skills.union(candidate_skills).count(),
+            (Some(skills), None) | (None, Some(skills)) => skills.len(),
+            (None, None) => 0,
         };
 
-        if has_comparable_skills {
+        if total > self.max_skills {
+            Err(self.code)
+        } else {
             Ok(source)
+        }
+    }
+}
+
+struct MaxRouteSkillsState {}
+
+impl FeatureState for MaxRouteSkillsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if get_required_skills(job).is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap())
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let skills = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter_map(get_required_skills)
+            .flat_map(|skills| skills.iter().cloned())
+            .collect::<HashSet<_>>();
+
+        if skills.is_empty() {
+            route_ctx.state_mut().remove_route_required_skills();
         } else {
-            Err(self.code)
+            route_ctx.state_mut().set_route_required_skills(skills);
         }
     }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx))
+    }
+}
+
+fn get_required_skills(job: &Job) -> Option<&HashSet<String>> {
+    job.dimens().get_job_skills().and_then(|skills| skills.all_of.as_ref())
+}
+
+fn get_soft_skills_penalty(route_ctx: &RouteContext, job: &Job) -> Cost {
+    let preferred = if let Some(preferred) = job.dimens().get_job_preferred_skills() {
+        preferred
+    } else {
+        return Cost::default();
+    };
+    let vehicle_skills = route_ctx.route().actor.vehicle.dimens.get_vehicle_skills();
+
+    preferred
+        .iter()
+        .filter(|(skill, _)| !vehicle_skills.map_or(false, |skills| skills.contains(*skill)))
+        .map(|(_, penalty)| *penalty)
+        .sum()
 }
 
 fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
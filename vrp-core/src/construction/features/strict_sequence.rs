@@ -0,0 +1,63 @@
+//! A strict sequence feature provides the way to enforce visiting sub-jobs of a multi-job in the
+//! order they are declared, e.g. load before unload.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/strict_sequence_test.rs"]
+mod strict_sequence_test;
+
+use super::*;
+
+custom_dimension!(StrictSequence typeof bool);
+
+/// Creates a strict sequence feature as a hard constraint: sub-jobs of a multi-job which has
+/// strict sequence flag set can be placed in the tour only in their declared order.
+pub fn create_strict_sequence_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_constraint(StrictSequenceConstraint { code }).build()
+}
+
+struct StrictSequenceConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for StrictSequenceConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => activity_ctx
+                .target
+                .job
+                .as_ref()
+                .and_then(|single| Multi::roots(single).map(|multi| (single, multi)))
+                .filter(|(_, multi)| multi.dimens.get_strict_sequence().copied().unwrap_or(false))
+                .and_then(|(single, multi)| {
+                    if is_in_declared_order(route_ctx, activity_ctx.index, single, &multi) {
+                        None
+                    } else {
+                        ConstraintViolation::skip(self.code)
+                    }
+                }),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+/// Checks that all sub-jobs declared before the target one are placed before insertion index and
+/// all sub-jobs declared after it are placed after.
+fn is_in_declared_order(route_ctx: &RouteContext, index: usize, single: &Arc<Single>, multi: &Multi) -> bool {
+    let target_order =
+        if let Some(order) = multi.jobs.iter().position(|job| Arc::ptr_eq(job, single)) { order } else { return true };
+
+    route_ctx.route().tour.all_activities().enumerate().all(|(activity_idx, activity)| {
+        let order =
+            activity.job.as_ref().and_then(|job| multi.jobs.iter().position(|sibling| Arc::ptr_eq(sibling, job)));
+
+        match order {
+            Some(order) if order < target_order => activity_idx <= index,
+            Some(order) if order > target_order => activity_idx > index,
+            _ => true,
+        }
+    })
+}
@@ -0,0 +1,220 @@
+//! A feature to synchronize services of paired jobs served by different vehicles, e.g. a crane and
+//! a truck which have to be at the same place at the same time.
+
+use super::*;
+use std::collections::HashMap;
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/synchronization_test.rs"]
+mod synchronization_test;
+
+custom_dimension!(JobSyncGroup typeof String);
+custom_tour_state!(SyncPartnerServices typeof HashMap<String, (Location, TimeWindow)>);
+// Keeps the latest arrival at activity which does not break its own or any following synchronization.
+custom_activity_state!(SyncLatestArrival typeof Timestamp);
+
+/// Creates a synchronization feature as a hard constraint. Jobs with the same sync group have to be served
+/// by different vehicles at the same location and their service times must overlap. Insertions which shift
+/// already synchronized jobs out of their partner's service are rejected too.
+/// NOTE: a sync group is assumed to contain exactly two jobs. A job whose partner is not assigned and is not
+/// going to be inserted is removed from the solution when the solution state is accepted.
+pub fn create_synchronization_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(SynchronizationConstraint { transport: transport.clone(), code })
+        .with_state(SynchronizationState { transport, code })
+        .build()
+}
+
+struct SynchronizationConstraint {
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for SynchronizationConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => job.dimens().get_job_sync_group().and_then(|group| {
+                let is_same_route =
+                    route_ctx.route().tour.jobs().any(|job| job.dimens().get_job_sync_group() == Some(group));
+
+                if is_same_route {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+                let route = route_ctx.route();
+
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let service_start = arrival.max(target.place.time.start);
+                let departure = service_start + get_service_duration(route, target);
+
+                if let Some(group) = target.job.as_ref().and_then(|single| single.dimens.get_job_sync_group()) {
+                    let (location, partner_service) = route_ctx.state().get_sync_partner_services()?.get(group)?;
+
+                    if target.place.location != *location
+                        || !TimeWindow::new(service_start, departure).intersects(partner_service)
+                    {
+                        return ConstraintViolation::skip(self.code);
+                    }
+                }
+
+                let next = next?;
+                let latest_arrival = route_ctx.state().get_sync_latest_arrival_at(activity_ctx.index + 1).copied()?;
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(departure),
+                    );
+
+                if arrival > latest_arrival {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_sync_group(), candidate.dimens().get_job_sync_group()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct SynchronizationState {
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+}
+
+impl FeatureState for SynchronizationState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        // NOTE any insertion can shift a sync job, so partner services have to be refreshed for all routes
+        self.update_partner_services(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let partner_services = route_ctx.state().get_sync_partner_services();
+        let mut latest_arrivals = vec![Timestamp::MAX; route.tour.total()];
+
+        route.tour.all_activities().enumerate().rev().fold(None, |next: Option<(Location, Timestamp)>, (idx, a)| {
+            let next_arrival = next.filter(|(_, latest)| *latest < Timestamp::MAX).map(|(location, latest)| {
+                let latest_departure =
+                    latest - self.transport.duration(route, a.place.location, location, TravelTime::Arrival(latest));
+
+                latest_departure - get_service_duration(route, a)
+            });
+
+            let partner_end = a
+                .job
+                .as_ref()
+                .and_then(|single| single.dimens.get_job_sync_group())
+                .and_then(|group| partner_services.and_then(|services| services.get(group)))
+                .map(|(_, partner_service)| partner_service.end);
+
+            let latest_arrival = match (partner_end, next_arrival) {
+                (Some(end), Some(arrival)) => end.min(arrival),
+                (end, arrival) => end.or(arrival).unwrap_or(Timestamp::MAX),
+            };
+            latest_arrivals[idx] = latest_arrival;
+
+            Some((a.place.location, latest_arrival))
+        });
+
+        route_ctx.state_mut().set_sync_latest_arrival_states(latest_arrivals);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_incomplete_pairs(solution_ctx);
+        self.update_partner_services(solution_ctx);
+    }
+}
+
+impl SynchronizationState {
+    /// Updates partner services and latest arrivals of all routes.
+    fn update_partner_services(&self, solution_ctx: &mut SolutionContext) {
+        let services = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| {
+                route_ctx.route().tour.all_activities().filter_map(move |activity| {
+                    let group = activity.job.as_ref().and_then(|single| single.dimens.get_job_sync_group())?;
+                    let service_start = activity.schedule.arrival.max(activity.place.time.start);
+                    let service = TimeWindow::new(service_start, activity.schedule.departure);
+
+                    Some((route_idx, group.clone(), (activity.place.location, service)))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        solution_ctx.routes.iter_mut().enumerate().for_each(|(route_idx, route_ctx)| {
+            let partner_services = services
+                .iter()
+                .filter(|(idx, _, _)| *idx != route_idx)
+                .map(|(_, group, service)| (group.clone(), service.clone()))
+                .collect::<HashMap<_, _>>();
+
+            route_ctx.state_mut().set_sync_partner_services(partner_services);
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    /// Removes sync jobs which partner is neither assigned nor required to be inserted.
+    fn remove_incomplete_pairs(&self, solution_ctx: &mut SolutionContext) {
+        let mut group_sizes = solution_ctx
+            .required
+            .iter()
+            .chain(solution_ctx.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()))
+            .filter_map(|job| job.dimens().get_job_sync_group())
+            .fold(HashMap::<&String, usize>::new(), |mut acc, group| {
+                *acc.entry(group).or_default() += 1;
+                acc
+            });
+        group_sizes.retain(|_, size| *size < 2);
+
+        if group_sizes.is_empty() {
+            return;
+        }
+
+        let jobs_to_remove = solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+            .filter(|job| job.dimens().get_job_sync_group().is_some_and(|group| group_sizes.contains_key(group)))
+            .filter(|job| !solution_ctx.locked.contains(job))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        jobs_to_remove.iter().for_each(|job| {
+            solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.route().tour.contains(job)).for_each(
+                |route_ctx| {
+                    assert!(route_ctx.route_mut().tour.remove(job), "cannot remove sync job from the tour");
+                },
+            )
+        });
+
+        solution_ctx
+            .unassigned
+            .extend(jobs_to_remove.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+}
@@ -8,13 +8,37 @@ use super::*;
 use crate::construction::enablers::{
     calculate_travel_delta, LimitDurationTourState, TotalDistanceTourState, TotalDurationTourState,
 };
-use crate::models::common::{Distance, Duration};
-use crate::models::problem::{Actor, TransportCost};
+use crate::models::common::{Distance, Duration, TimeSpan};
+use crate::models::problem::{Actor, Single, TransportCost};
 
 /// A function which returns activity size limit for a given actor.
 pub type ActivitySizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
-/// A function to resolve travel limit.
+/// A function to resolve travel limit for a given actor. As the actor is bound to a specific vehicle
+/// shift (vehicle detail), the limit can be different for each shift of the same vehicle.
 pub type TravelLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
+/// A function to resolve route cost limit.
+pub type RouteCostLimitFn = Arc<dyn Fn(&Actor) -> Option<Cost> + Send + Sync>;
+/// A function to resolve soft travel limit.
+pub type SoftTravelLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<SoftTravelLimit<T>> + Send + Sync>;
+
+/// Specifies a tiered travel limit: travel is free up to the soft threshold, penalized linearly
+/// between soft and hard thresholds and infeasible beyond the hard threshold.
+#[derive(Clone, Copy, Debug)]
+pub struct SoftTravelLimit<T> {
+    /// A threshold after which penalty is applied.
+    pub soft: T,
+    /// A threshold after which travel is not allowed.
+    pub hard: T,
+    /// A penalty per unit of travel exceeding the soft threshold.
+    pub penalty_slope: Cost,
+}
+
+impl SoftTravelLimit<Float> {
+    /// Returns penalty for given total travel value.
+    pub fn get_penalty(&self, total: Float) -> Cost {
+        (total - self.soft).max(0.) * self.penalty_slope
+    }
+}
 
 /// Creates a limit for activity amount in a tour.
 /// This is a hard constraint.
@@ -52,6 +76,88 @@ pub fn create_travel_limit_feature(
         .build()
 }
 
+/// Creates a tiered travel limits such as distance and/or duration: beyond soft limit a penalty is
+/// applied, beyond hard limit a tour is considered as infeasible.
+/// This is a soft constraint with a hard limit.
+pub fn create_soft_travel_limit_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    distance_code: ViolationCode,
+    duration_code: ViolationCode,
+    tour_distance_limit_fn: SoftTravelLimitFn<Distance>,
+    tour_duration_limit_fn: SoftTravelLimitFn<Duration>,
+) -> Result<Feature, GenericError> {
+    let get_hard_limit_fn = |limit_fn: SoftTravelLimitFn<Float>| -> TravelLimitFn<Float> {
+        Arc::new(move |actor: &Actor| (limit_fn)(actor).map(|limit| limit.hard))
+    };
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TravelLimitConstraint {
+            transport: transport.clone(),
+            tour_distance_limit_fn: get_hard_limit_fn(tour_distance_limit_fn.clone()),
+            tour_duration_limit_fn: get_hard_limit_fn(tour_duration_limit_fn.clone()),
+            distance_code,
+            duration_code,
+        })
+        .with_objective(SoftTravelLimitObjective {
+            transport,
+            tour_distance_limit_fn,
+            tour_duration_limit_fn: tour_duration_limit_fn.clone(),
+        })
+        .with_state(TravelLimitState { tour_duration_limit_fn: get_hard_limit_fn(tour_duration_limit_fn) })
+        .build()
+}
+
+/// Creates a limit for the total route cost which includes fixed, distance and duration costs of
+/// vehicle and driver. Insertions which push route's cost above the cap are rejected.
+/// This is a hard constraint.
+pub fn create_route_cost_limit_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+    route_cost_limit_fn: RouteCostLimitFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(RouteCostLimitConstraint { transport, code, route_cost_limit_fn })
+        .build()
+}
+
+/// Creates a minimum limit for jobs amount served by each used vehicle. A used tour with fewer jobs
+/// than the limit is penalized per each missing job, unused (empty) tours are not penalized.
+/// This is a soft constraint.
+pub fn create_min_jobs_limit_feature(
+    name: &str,
+    min_jobs_fn: ActivitySizeResolver,
+    penalty: Cost,
+) -> Result<Feature, GenericError> {
+    if penalty < 0. {
+        return Err(format!("min jobs penalty should be non-negative, got: {penalty}").into());
+    }
+
+    FeatureBuilder::default().with_name(name).with_objective(MinJobsLimitObjective { min_jobs_fn, penalty }).build()
+}
+
+/// Creates a limit for amount of jobs with tight time windows in a tour. A job is considered as tight
+/// when all time windows of all its places are narrower than `max_width`.
+/// This is a hard constraint.
+pub fn create_tight_time_window_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    max_width: Duration,
+    limit_fn: ActivitySizeResolver,
+) -> Result<Feature, GenericError> {
+    if max_width <= 0. {
+        return Err(format!("tight time window width should be positive, got: {max_width}").into());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TightTimeWindowLimitConstraint { code, max_width, limit_fn })
+        .build()
+}
+
 struct ActivityLimitConstraint {
     code: ViolationCode,
     limit_fn: ActivitySizeResolver,
@@ -85,6 +191,66 @@ impl FeatureConstraint for ActivityLimitConstraint {
     }
 }
 
+struct TightTimeWindowLimitConstraint {
+    code: ViolationCode,
+    max_width: Duration,
+    limit_fn: ActivitySizeResolver,
+}
+
+impl TightTimeWindowLimitConstraint {
+    fn is_tight(&self, single: &Single) -> bool {
+        single.places.iter().all(|place| {
+            !place.times.is_empty()
+                && place.times.iter().all(|span| {
+                    let width = match span {
+                        TimeSpan::Window(tw) => tw.end - tw.start,
+                        TimeSpan::Offset(offset) => offset.end - offset.start,
+                    };
+
+                    width < self.max_width
+                })
+        })
+    }
+}
+
+impl FeatureConstraint for TightTimeWindowLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                (self.limit_fn)(route_ctx.route().actor.as_ref()).and_then(|limit| {
+                    let job_tight = match job {
+                        Job::Single(single) => usize::from(self.is_tight(single)),
+                        Job::Multi(multi) => multi.jobs.iter().filter(|single| self.is_tight(single)).count(),
+                    };
+
+                    if job_tight == 0 {
+                        return ConstraintViolation::success();
+                    }
+
+                    let tour_tight = route_ctx
+                        .route()
+                        .tour
+                        .all_activities()
+                        .filter_map(|activity| activity.job.as_ref())
+                        .filter(|single| self.is_tight(single))
+                        .count();
+
+                    if tour_tight + job_tight > limit {
+                        ConstraintViolation::fail(self.code)
+                    } else {
+                        ConstraintViolation::success()
+                    }
+                })
+            }
+            MoveContext::Activity { .. } => ConstraintViolation::success(),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
 struct TravelLimitConstraint {
     transport: Arc<dyn TransportCost>,
     tour_distance_limit_fn: TravelLimitFn<Distance>,
@@ -137,6 +303,129 @@ impl FeatureConstraint for TravelLimitConstraint {
     }
 }
 
+struct RouteCostLimitConstraint {
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+    route_cost_limit_fn: RouteCostLimitFn,
+}
+
+impl FeatureConstraint for RouteCostLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let actor = route_ctx.route().actor.as_ref();
+                let cost_limit = (self.route_cost_limit_fn)(actor)?;
+
+                let (change_distance, change_duration) =
+                    calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
+                let distance = route_ctx.state().get_total_distance().copied().unwrap_or(0.) + change_distance;
+                let duration = route_ctx.state().get_total_duration().copied().unwrap_or(0.) + change_duration;
+
+                let total_cost = get_route_cost(actor, distance, duration);
+
+                if cost_limit < total_cost {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct SoftTravelLimitObjective {
+    transport: Arc<dyn TransportCost>,
+    tour_distance_limit_fn: SoftTravelLimitFn<Distance>,
+    tour_duration_limit_fn: SoftTravelLimitFn<Duration>,
+}
+
+impl SoftTravelLimitObjective {
+    fn get_route_penalty(&self, route_ctx: &RouteContext, change: (Distance, Duration)) -> Cost {
+        let actor = route_ctx.route().actor.as_ref();
+        let (change_distance, change_duration) = change;
+
+        let distance_penalty = (self.tour_distance_limit_fn)(actor).map_or(Cost::default(), |limit| {
+            let curr_dis = route_ctx.state().get_total_distance().copied().unwrap_or(0.);
+            limit.get_penalty(curr_dis + change_distance)
+        });
+
+        let duration_penalty = (self.tour_duration_limit_fn)(actor).map_or(Cost::default(), |limit| {
+            let curr_dur = route_ctx.state().get_total_duration().copied().unwrap_or(0.);
+            limit.get_penalty(curr_dur + change_duration)
+        });
+
+        distance_penalty + duration_penalty
+    }
+}
+
+impl FeatureObjective for SoftTravelLimitObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| self.get_route_penalty(route_ctx, (Distance::default(), Duration::default())))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let actor = route_ctx.route().actor.as_ref();
+                if (self.tour_distance_limit_fn)(actor).is_none() && (self.tour_duration_limit_fn)(actor).is_none() {
+                    return Cost::default();
+                }
+
+                let change = calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
+
+                self.get_route_penalty(route_ctx, change)
+                    - self.get_route_penalty(route_ctx, (Distance::default(), Duration::default()))
+            }
+        }
+    }
+}
+
+struct MinJobsLimitObjective {
+    min_jobs_fn: ActivitySizeResolver,
+    penalty: Cost,
+}
+
+impl MinJobsLimitObjective {
+    fn get_route_penalty(&self, route_ctx: &RouteContext, job_count: usize) -> Cost {
+        (self.min_jobs_fn)(route_ctx.route().actor.as_ref())
+            .filter(|_| job_count > 0)
+            .map_or(Cost::default(), |min_jobs| min_jobs.saturating_sub(job_count) as Float * self.penalty)
+    }
+}
+
+impl FeatureObjective for MinJobsLimitObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| self.get_route_penalty(route_ctx, route_ctx.route().tour.job_count()))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, .. } => {
+                let job_count = route_ctx.route().tour.job_count();
+
+                self.get_route_penalty(route_ctx, job_count + 1) - self.get_route_penalty(route_ctx, job_count)
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
 struct TravelLimitState {
     tour_duration_limit_fn: TravelLimitFn<Duration>,
 }
@@ -0,0 +1,72 @@
+//! A feature to enforce a job to be the first or the last job in the tour.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/tour_position_test.rs"]
+mod tour_position_test;
+
+use super::*;
+use crate::models::solution::Activity;
+
+custom_dimension!(JobTourPosition typeof JobTourPosition);
+
+/// Specifies a mandatory position of the job within the tour.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JobTourPosition {
+    /// Job should be served first, right after the tour start.
+    Start,
+    /// Job should be served last, right before the tour end.
+    End,
+}
+
+/// Creates a feature which enforces a flagged job, if assigned, to occupy the first (or the last)
+/// job position in the tour. It is a hard constraint.
+pub fn create_tour_position_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_constraint(TourPositionConstraint { code }).build()
+}
+
+struct TourPositionConstraint {
+    code: ViolationCode,
+}
+
+impl TourPositionConstraint {
+    fn evaluate_activity(&self, activity_ctx: &ActivityContext) -> Option<ConstraintViolation> {
+        let is_after_start = activity_ctx.index == 0;
+        let is_before_end = activity_ctx.next.map_or(true, |next| next.job.is_none());
+
+        let is_violated = match get_tour_position(activity_ctx.target) {
+            Some(JobTourPosition::Start) => !is_after_start,
+            Some(JobTourPosition::End) => !is_before_end,
+            None => false,
+        } || get_tour_position(activity_ctx.prev) == Some(JobTourPosition::End)
+            || activity_ctx.next.and_then(get_tour_position) == Some(JobTourPosition::Start);
+
+        if is_violated {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+}
+
+impl FeatureConstraint for TourPositionConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { activity_ctx, .. } => self.evaluate_activity(activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_position = |job: &Job| job.dimens().get_job_tour_position().is_some();
+
+        if has_position(&source) || has_position(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+fn get_tour_position(activity: &Activity) -> Option<JobTourPosition> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_tour_position()).copied()
+}
@@ -6,10 +6,12 @@ mod transport_test;
 
 use super::*;
 use crate::construction::enablers::*;
-use crate::models::common::Timestamp;
-use crate::models::problem::{ActivityCost, Single, TransportCost, TravelTime};
+use crate::models::common::{TimeWindow, Timestamp};
+use crate::models::problem::{ActivityCost, Actor, Single, TransportCost, TravelTime};
 use crate::models::solution::Activity;
 
+custom_dimension!(VehicleUnavailableTimes typeof Vec<TimeWindow>);
+
 // TODO
 //  remove get_total_cost, get_route_costs, get_max_cost methods from contexts
 //  add validation rule which ensures usage of only one of these methods.
@@ -21,12 +23,20 @@ pub struct TransportFeatureBuilder {
     activity: Option<Arc<dyn ActivityCost>>,
     code: Option<ViolationCode>,
     is_constrained: bool,
+    time_granularity: Option<Duration>,
 }
 
 impl TransportFeatureBuilder {
     /// Creates a new instance of `TransportFeatureBuilder`.
     pub fn new(name: &str) -> Self {
-        Self { name: name.to_string(), transport: None, activity: None, code: None, is_constrained: true }
+        Self {
+            name: name.to_string(),
+            transport: None,
+            activity: None,
+            code: None,
+            is_constrained: true,
+            time_granularity: None,
+        }
     }
 
     /// Sets constraint violation code which is used to report back the reason of job's unassignment.
@@ -56,6 +66,13 @@ impl TransportFeatureBuilder {
         self
     }
 
+    /// Sets a granularity (e.g. one second) to which activity schedule times are rounded up during
+    /// route construction. This reduces float drift of accumulated arrival times. Default is no rounding.
+    pub fn set_time_rounding(mut self, granularity: Duration) -> Self {
+        self.time_granularity = Some(granularity);
+        self
+    }
+
     /// Builds a flavor of transport feature which only updates activity schedules. No objective, no constraint.
     pub fn build_schedule_updater(mut self) -> GenericResult<Feature> {
         let (transport, activity) = self.get_costs()?;
@@ -121,7 +138,14 @@ impl TransportFeatureBuilder {
         let transport = self.transport.take().ok_or_else(|| GenericError::from("transport must be set"))?;
         let activity = self.activity.take().unwrap_or_else(|| Arc::new(SimpleActivityCost::default()));
 
-        Ok((transport, activity))
+        match self.time_granularity {
+            Some(granularity) if granularity <= 0. => Err("time rounding granularity must be positive".into()),
+            Some(granularity) => Ok((
+                Arc::new(TimeRoundingTransportCost::new(transport, granularity)),
+                Arc::new(TimeRoundingActivityCost::new(activity, granularity)),
+            )),
+            None => Ok((transport, activity)),
+        }
     }
 }
 
@@ -242,12 +266,19 @@ impl TransportConstraint {
             return ConstraintViolation::skip(self.time_window_code);
         }
 
+        let end_time_at_target = self.activity.estimate_departure(route, target, arr_time_at_target);
+        let service_start_at_target = arr_time_at_target.max(target.place.time.start);
+
+        if is_unavailable(actor, departure, arr_time_at_target)
+            || is_unavailable(actor, service_start_at_target, end_time_at_target)
+        {
+            return ConstraintViolation::skip(self.time_window_code);
+        }
+
         if next.is_none() {
             return ConstraintViolation::success();
         }
 
-        let end_time_at_target = self.activity.estimate_departure(route, target, arr_time_at_target);
-
         let arr_time_at_next = end_time_at_target
             + self.transport.duration(
                 route,
@@ -256,12 +287,62 @@ impl TransportConstraint {
                 TravelTime::Departure(end_time_at_target),
             );
 
-        if arr_time_at_next > latest_arr_time_at_next {
+        if arr_time_at_next > latest_arr_time_at_next
+            || is_unavailable(actor, end_time_at_target, arr_time_at_next)
+            || self.is_tail_unavailable(route_ctx, activity_ctx.index + 1, arr_time_at_next)
+        {
             ConstraintViolation::skip(self.time_window_code)
         } else {
             ConstraintViolation::success()
         }
     }
+
+    /// Checks whether shifted schedule of the activities starting from the given index overlaps
+    /// vehicle unavailable times. Stops once the schedule is not changed anymore.
+    fn is_tail_unavailable(&self, route_ctx: &RouteContext, start_idx: usize, arrival: Timestamp) -> bool {
+        let route = route_ctx.route();
+        let actor = route.actor.as_ref();
+
+        if actor.vehicle.dimens.get_vehicle_unavailable_times().is_none() {
+            return false;
+        }
+
+        let mut arrival = arrival;
+        for (idx, activity) in route.tour.all_activities().enumerate().skip(start_idx) {
+            let departure = self.activity.estimate_departure(route, activity, arrival);
+
+            if is_unavailable(actor, arrival.max(activity.place.time.start), departure) {
+                return true;
+            }
+
+            let next = match route.tour.get(idx + 1) {
+                Some(next) if departure != activity.schedule.departure => next,
+                _ => return false,
+            };
+
+            arrival = departure
+                + self.transport.duration(
+                    route,
+                    activity.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            if is_unavailable(actor, departure, arrival) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Checks whether the vehicle is unavailable at some moment of the given (travel or service) interval.
+fn is_unavailable(actor: &Actor, start: Timestamp, end: Timestamp) -> bool {
+    actor.vehicle.dimens.get_vehicle_unavailable_times().map_or(false, |times| {
+        let interval = TimeWindow::new(start, end);
+        times.iter().any(|time| time.intersects_exclusive(&interval))
+    })
 }
 
 impl FeatureConstraint for TransportConstraint {
@@ -0,0 +1,69 @@
+//! A vehicle time windows feature provides the way to restrict job visit times depending on the
+//! type of the serving vehicle, e.g. loud vehicles can serve jobs only during the day.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/vehicle_time_windows_test.rs"]
+mod vehicle_time_windows_test;
+
+use super::*;
+use std::collections::HashMap;
+
+custom_dimension!(JobVehicleTimeWindows typeof HashMap<String, Vec<TimeWindow>>);
+
+/// Returns a vehicle type used to select job's time windows.
+pub type VehicleTypeFn = Arc<dyn Fn(&Actor) -> Option<String> + Send + Sync>;
+
+/// Creates a feature which allows to serve a job only within time windows specific for the type
+/// of the serving vehicle. Job's place times should include all vehicle specific time windows:
+/// the feature rejects the ones which are not allowed for the vehicle type, so timing is checked
+/// against the vehicle specific window. Jobs without windows for the vehicle type are not restricted.
+pub fn create_vehicle_time_windows_feature(
+    name: &str,
+    code: ViolationCode,
+    vehicle_type_fn: VehicleTypeFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(VehicleTimeWindowsConstraint { code, vehicle_type_fn })
+        .build()
+}
+
+struct VehicleTimeWindowsConstraint {
+    code: ViolationCode,
+    vehicle_type_fn: VehicleTypeFn,
+}
+
+impl FeatureConstraint for VehicleTimeWindowsConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let target = activity_ctx.target;
+                let allowed = target
+                    .job
+                    .as_ref()
+                    .and_then(|single| single.dimens.get_job_vehicle_time_windows())
+                    .zip((self.vehicle_type_fn)(route_ctx.route().actor.as_ref()))
+                    .and_then(|(time_windows, vehicle_type)| time_windows.get(&vehicle_type));
+
+                match allowed {
+                    Some(time_windows)
+                        if !time_windows
+                            .iter()
+                            .any(|tw| tw.start <= target.place.time.start && target.place.time.end <= tw.end) =>
+                    {
+                        ConstraintViolation::skip(self.code)
+                    }
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_vehicle_time_windows(), candidate.dimens().get_job_vehicle_time_windows()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
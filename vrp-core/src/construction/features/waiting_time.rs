@@ -0,0 +1,71 @@
+//! A feature to minimize total waiting time.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/waiting_time_test.rs"]
+mod waiting_time_test;
+
+use super::*;
+use crate::construction::enablers::WaitingTimeActivityState;
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+
+/// Creates a feature which minimizes total waiting time: a time spent between arrival at the job's
+/// location and its time window start. Jobs with open-ended time windows contribute no waiting.
+pub fn create_min_waiting_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(MinWaitingObjective { transport, activity }).build()
+}
+
+struct MinWaitingObjective {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureObjective for MinWaitingObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            // NOTE waiting time state keeps future waiting time, so its value for the first activity is a total one
+            .filter_map(|route_ctx| route_ctx.state().get_waiting_time_at(1).copied())
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let route = route_ctx.route();
+                let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+
+                let (target_waiting, departure) = self.visit(route, prev, target, prev.schedule.departure);
+
+                // NOTE: for simplicity, only the next activity is considered, not the whole tail of the route
+                let next_waiting_delta = next.map_or(Cost::default(), |next| {
+                    let (new_waiting, _) = self.visit(route, target, next, departure);
+                    new_waiting - get_waiting(next, next.schedule.arrival)
+                });
+
+                target_waiting + next_waiting_delta
+            }
+        }
+    }
+}
+
+impl MinWaitingObjective {
+    /// Returns waiting time at the `to` activity and departure from it.
+    fn visit(&self, route: &Route, from: &Activity, to: &Activity, departure: Timestamp) -> (Duration, Timestamp) {
+        let arrival = departure
+            + self.transport.duration(route, from.place.location, to.place.location, TravelTime::Departure(departure));
+
+        (get_waiting(to, arrival), self.activity.estimate_departure(route, to, arrival))
+    }
+}
+
+fn get_waiting(activity: &Activity, arrival: Timestamp) -> Duration {
+    (activity.place.time.start - arrival).max(0.)
+}
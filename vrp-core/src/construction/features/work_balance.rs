@@ -1,10 +1,15 @@
 //! Provides the way to build one of the flavors of the work balance feature.
 
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/work_balance_test.rs"]
+mod work_balance_test;
+
 use super::*;
 use crate::construction::enablers::{TotalDistanceTourState, TotalDurationTourState};
 use crate::construction::features::capacity::MaxFutureCapacityActivityState;
+use crate::construction::features::{JobSkillsDimension, VehicleSkillsDimension};
 use crate::models::common::LoadOps;
-use rosomaxa::algorithms::math::get_cv_safe;
+use rosomaxa::algorithms::math::{get_cv_safe, get_variance};
 use std::cmp::Ordering;
 use std::marker::PhantomData;
 
@@ -61,6 +66,76 @@ pub fn create_activity_balanced_feature(name: &str) -> Result<Feature, GenericEr
     create_feature::<ActivityBalancedKey>(name, route_estimate_fn, solution_estimate_fn)
 }
 
+/// Creates a feature which balances tour sizes by minimizing variance of job activity counts
+/// across all tours in the solution. Unlike coefficient of variation, variance stays well defined
+/// when mean of activity counts is close to zero. Please note, that unused vehicles are not part of
+/// the solution, so they are not counted.
+pub fn create_balance_tour_size_feature(name: &str) -> Result<Feature, GenericError> {
+    struct TourSizeBalancedKey;
+
+    let route_estimate_fn = Arc::new(|route_ctx: &RouteContext| route_ctx.route().tour.job_activity_count() as Float);
+    let solution_estimate_fn = Arc::new({
+        let route_estimate_fn = route_estimate_fn.clone();
+        move |ctx: &SolutionContext| {
+            let sizes = ctx.routes.iter().map(|route_ctx| route_estimate_fn(route_ctx)).collect::<Vec<_>>();
+
+            if sizes.is_empty() {
+                Float::default()
+            } else {
+                get_variance(sizes.as_slice())
+            }
+        }
+    });
+
+    create_feature::<TourSizeBalancedKey>(name, route_estimate_fn, solution_estimate_fn)
+}
+
+/// Creates a feature which balances jobs requiring skills across tours of vehicles with skills.
+/// It penalizes concentrating skilled jobs on a few vehicles when others can serve them too.
+pub fn create_skill_balanced_feature(name: &str) -> Result<Feature, GenericError> {
+    struct SkillBalancedKey;
+
+    let route_estimate_fn = Arc::new(|route_ctx: &RouteContext| {
+        route_ctx.route().tour.jobs().filter(|job| requires_skills(job)).count() as Float
+    });
+    let solution_estimate_fn = Arc::new({
+        let route_estimate_fn = route_estimate_fn.clone();
+        move |solution_ctx: &SolutionContext| {
+            get_cv_safe(
+                solution_ctx
+                    .routes
+                    .iter()
+                    .filter(|route_ctx| {
+                        route_ctx.route().actor.vehicle.dimens.get_vehicle_skills().map_or(false, |s| !s.is_empty())
+                    })
+                    .map(|route_ctx| route_estimate_fn(route_ctx))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            )
+        }
+    });
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(SkillBalancedObjective {
+            inner: WorkBalanceObjective {
+                route_estimate_fn: route_estimate_fn.clone(),
+                solution_estimate_fn: solution_estimate_fn.clone(),
+                phantom_data: PhantomData::<SkillBalancedKey>,
+            },
+        })
+        .with_state(WorkBalanceState {
+            route_estimate_fn,
+            solution_estimate_fn,
+            phantom_data: PhantomData::<SkillBalancedKey>,
+        })
+        .build()
+}
+
+fn requires_skills(job: &Job) -> bool {
+    job.dimens().get_job_skills().map_or(false, |skills| skills.all_of.is_some() || skills.one_of.is_some())
+}
+
 /// Creates a feature which which balances travelled durations across all tours.
 pub fn create_duration_balanced_feature(name: &str) -> Result<Feature, GenericError> {
     struct DurationBalancedKey;
@@ -144,6 +219,24 @@ impl<K: Send + Sync + 'static> FeatureObjective for WorkBalanceObjective<K> {
     }
 }
 
+/// Estimates only jobs which require skills, other jobs do not affect skill balance.
+struct SkillBalancedObjective<K: Send + Sync + 'static> {
+    inner: WorkBalanceObjective<K>,
+}
+
+impl<K: Send + Sync + 'static> FeatureObjective for SkillBalancedObjective<K> {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        self.inner.fitness(solution)
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { job, .. } if requires_skills(job) => self.inner.estimate(move_ctx),
+            _ => Cost::default(),
+        }
+    }
+}
+
 struct WorkBalanceState<K: Send + Sync + 'static> {
     route_estimate_fn: Arc<dyn Fn(&RouteContext) -> Float + Send + Sync>,
     solution_estimate_fn: Arc<dyn Fn(&SolutionContext) -> Float + Send + Sync>,
@@ -59,26 +59,13 @@ impl InsertionContext {
     ///
     /// Returns None if cost cannot be calculate as the context is in non-consistent state.
     pub fn get_total_cost(&self) -> Option<Cost> {
-        let get_cost = |costs: &Costs, distance: Float, duration: Float| {
-            costs.fixed
-                + costs.per_distance * distance
-                // NOTE this is incorrect when timing costs are different: fitness value will be
-                // different from actual cost. However we accept this so far as it is simpler for
-                // implementation and pragmatic format does not expose this feature
-                // .
-                // TODO calculate actual cost
-                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
-        };
-
         self.solution.routes.iter().try_fold(Cost::default(), |acc, route_ctx| {
-            let actor = &route_ctx.route.actor;
             let distance = route_ctx.state.get_total_distance();
             let duration = route_ctx.state.get_total_duration();
 
-            distance.zip(duration).map(|(&distance, &duration)| {
-                acc + get_cost(&actor.vehicle.costs, distance, duration)
-                    + get_cost(&actor.driver.costs, distance, duration)
-            })
+            distance
+                .zip(duration)
+                .map(|(&distance, &duration)| acc + get_route_cost(&route_ctx.route.actor, distance, duration))
         })
     }
 
@@ -89,6 +76,22 @@ impl InsertionContext {
     }
 }
 
+/// Gets cost of the route served by given actor with given total distance and duration.
+pub(crate) fn get_route_cost(actor: &Actor, distance: Float, duration: Float) -> Cost {
+    let get_cost = |costs: &Costs| {
+        costs.fixed
+            + costs.per_distance * distance
+            // NOTE this is incorrect when timing costs are different: fitness value will be
+            // different from actual cost. However we accept this so far as it is simpler for
+            // implementation and pragmatic format does not expose this feature
+            // .
+            // TODO calculate actual cost
+            + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+    };
+
+    get_cost(&actor.vehicle.costs) + get_cost(&actor.driver.costs)
+}
+
 impl HeuristicSolution for InsertionContext {
     fn fitness(&self) -> impl Iterator<Item = Float> {
         self.problem.goal.fitness(self)
@@ -223,6 +226,8 @@ impl From<(InsertionContext, Option<TelemetryMetrics>)> for Solution {
                 .chain(solution_ctx.required.iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)))
                 .collect(),
             telemetry,
+            summary: None,
+            is_partial: false,
         }
     }
 }
@@ -242,6 +247,18 @@ impl SolutionState {
     pub fn set_value<K: 'static, V: 'static + Sync + Send>(&mut self, value: V) {
         self.index.insert(TypeId::of::<K>(), Arc::new(value));
     }
+
+    /// Gets the mutable value from solution state using the key type provided. The value is copied
+    /// only when it is shared with another solution state (e.g. after solution's deep copy).
+    pub fn get_value_mut<K: 'static, V: Clone + Send + Sync + 'static>(&mut self) -> Option<&mut V> {
+        let value = self.index.get_mut(&TypeId::of::<K>())?;
+
+        if Arc::get_mut(value).is_none() {
+            *value = Arc::new(value.downcast_ref::<V>()?.clone());
+        }
+
+        Arc::get_mut(value).and_then(|any| any.downcast_mut::<V>())
+    }
 }
 
 /// Specifies insertion context for route.
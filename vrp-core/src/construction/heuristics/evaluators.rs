@@ -7,7 +7,7 @@ use std::ops::ControlFlow;
 use std::sync::Arc;
 
 use crate::construction::heuristics::*;
-use crate::models::common::Timestamp;
+use crate::models::common::{Location, Timestamp};
 use crate::models::problem::{Job, Multi, Single};
 use crate::models::solution::{Activity, Leg, Place};
 use crate::models::{ConstraintViolation, GoalContext, ViolationCode};
@@ -97,6 +97,35 @@ pub fn eval_job_constraint_in_route(
     }
 }
 
+/// Evaluates job insertion cost at each leg of the route and maps it to the location of the leg's
+/// start activity. Infeasible positions are skipped. Can be used to build an insertion cost heatmap.
+pub fn eval_job_insertion_costs_by_location(
+    insertion_ctx: &InsertionContext,
+    eval_ctx: &EvaluationContext,
+    route_ctx: &RouteContext,
+) -> Vec<(Location, InsertionCost)> {
+    let goal = &insertion_ctx.problem.goal;
+    let move_ctx = MoveContext::route(&insertion_ctx.solution, route_ctx, eval_ctx.job);
+
+    if goal.evaluate(&move_ctx).is_some() {
+        return vec![];
+    }
+
+    let route_costs = goal.estimate(&move_ctx);
+    let tour = &route_ctx.route().tour;
+
+    tour.legs()
+        .filter_map(|(items, leg_idx)| {
+            let location = items.first()?.place.location;
+            let position = InsertionPosition::Concrete(leg_idx);
+
+            eval_job_constraint_in_route(eval_ctx, route_ctx, position, route_costs.clone(), None)
+                .as_success()
+                .map(|success| (location, success.cost.clone()))
+        })
+        .collect()
+}
+
 pub(crate) fn eval_single_constraint_in_route(
     insertion_ctx: &InsertionContext,
     eval_ctx: &EvaluationContext,
@@ -1,10 +1,14 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/factories_test.rs"]
+mod factories_test;
+
 use super::*;
 use crate::models::common::{Cost, Schedule};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::OP_START_MSG;
 use crate::models::{LockOrder, Problem, Solution};
-use rosomaxa::prelude::Environment;
+use rosomaxa::prelude::{Environment, GenericResult};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -17,7 +21,7 @@ pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environm
     let mut ignored: HashSet<Job> = Default::default();
     let mut unassigned: HashMap<Job, UnassignmentInfo> = Default::default();
     let mut routes: Vec<RouteContext> = Default::default();
-    let mut registry = Registry::new(&problem.fleet, environment.random.clone());
+    let mut registry = create_registry(&problem, Registry::new(&problem.fleet, environment.random.clone()));
     let state = Default::default();
 
     let mut sequence_job_usage: HashMap<Job, usize> = Default::default();
@@ -131,7 +135,7 @@ pub fn create_insertion_context_from_solution(
         acc
     });
 
-    let mut registry = solution.0.registry.deep_copy();
+    let mut registry = create_registry(&problem, solution.0.registry.deep_copy());
     let mut routes: Vec<RouteContext> = Vec::new();
     let state = Default::default();
 
@@ -165,9 +169,76 @@ pub fn create_insertion_context_from_solution(
     insertion_ctx
 }
 
+/// Merges routes of two solutions of the same problem into one insertion context, e.g. to warm start
+/// the search from solutions of separately solved regions. Jobs assigned in both solutions (matched
+/// by job id) are conflicting: they are removed from routes of both solutions and become unassigned.
+/// Returns an error if the same actor is used by routes of both solutions (see `Solution::merge`).
+pub fn merge_solutions(
+    problem: Arc<Problem>,
+    a: Solution,
+    b: Solution,
+    environment: Arc<Environment>,
+) -> GenericResult<InsertionContext> {
+    let get_assigned_ids = |solution: &Solution| {
+        solution
+            .routes
+            .iter()
+            .flat_map(|route| route.tour.jobs())
+            .filter_map(|job| job.dimens().get_job_id().cloned())
+            .collect::<HashSet<_>>()
+    };
+    let (a_ids, b_ids) = (get_assigned_ids(&a), get_assigned_ids(&b));
+    let conflicting_ids = a_ids.intersection(&b_ids).cloned().collect::<HashSet<_>>();
+    let assigned_ids = a_ids.union(&b_ids).cloned().collect::<HashSet<_>>();
+
+    let mut conflicting = Vec::new();
+    let a = remove_merge_conflicts(a, &conflicting_ids, &assigned_ids, &|_| false, &mut conflicting);
+    let a_unassigned = a.unassigned.iter().map(|(job, _)| job.clone()).collect::<HashSet<_>>();
+    let a_unassigned_ids = a_unassigned.iter().filter_map(|job| job.dimens().get_job_id()).collect::<HashSet<_>>();
+    let is_unassigned = |job: &Job| {
+        a_unassigned.contains(job) || job.dimens().get_job_id().is_some_and(|id| a_unassigned_ids.contains(id))
+    };
+    let b = remove_merge_conflicts(b, &conflicting_ids, &assigned_ids, &is_unassigned, &mut conflicting);
+
+    let solution = a.merge(b)?;
+    let mut insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+    // NOTE conflicting jobs are kept as unassigned, so they are reinserted on the next recreate
+    let conflicting = conflicting.into_iter().collect::<HashSet<_>>();
+    insertion_ctx.solution.required.retain(|job| !conflicting.contains(job));
+    insertion_ctx.solution.unassigned.extend(conflicting.into_iter().map(|job| (job, UnassignmentInfo::Unknown)));
+
+    Ok(insertion_ctx)
+}
+
+/// Removes conflicting jobs from solution routes and unassigned jobs which are assigned in any of
+/// merged solutions or already unassigned in another one, so that solutions can be merged.
+fn remove_merge_conflicts(
+    mut solution: Solution,
+    conflicting_ids: &HashSet<String>,
+    assigned_ids: &HashSet<String>,
+    is_unassigned: &dyn Fn(&Job) -> bool,
+    conflicting: &mut Vec<Job>,
+) -> Solution {
+    let has_id_in = |job: &Job, ids: &HashSet<String>| job.dimens().get_job_id().is_some_and(|id| ids.contains(id));
+
+    solution.routes.iter_mut().for_each(|route| {
+        let jobs = route.tour.jobs().filter(|job| has_id_in(job, conflicting_ids)).cloned().collect::<Vec<_>>();
+
+        jobs.into_iter().for_each(|job| {
+            route.tour.remove(&job);
+            conflicting.push(job);
+        });
+    });
+
+    solution.unassigned.retain(|(job, _)| !has_id_in(job, assigned_ids) && !is_unassigned(job));
+
+    solution
+}
+
 /// Creates an empty insertion context.
 pub fn create_empty_insertion_context(problem: Arc<Problem>, environment: Arc<Environment>) -> InsertionContext {
-    let registry = Registry::new(problem.fleet.as_ref(), environment.random.clone());
+    let registry = create_registry(&problem, Registry::new(problem.fleet.as_ref(), environment.random.clone()));
     InsertionContext {
         problem: problem.clone(),
         solution: SolutionContext {
@@ -192,3 +263,10 @@ fn update_insertion_context(insertion_ctx: &mut InsertionContext) {
         .unassigned
         .extend(insertion_ctx.solution.required.drain(0..).map(|job| (job, UnassignmentInfo::Unknown)));
 }
+
+fn create_registry(problem: &Problem, registry: Registry) -> Registry {
+    match problem.extras.get_actor_weights() {
+        Some(weight_fn) => registry.with_weights(weight_fn.as_ref().clone()),
+        None => registry,
+    }
+}
@@ -11,6 +11,7 @@ use lazy_static::lazy_static;
 use rosomaxa::prelude::*;
 use std::borrow::Borrow;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::ops::{Add, ControlFlow, Index, Sub};
 use std::sync::Arc;
@@ -65,6 +66,37 @@ impl Debug for InsertionSuccess {
     }
 }
 
+custom_solution_state!(InsertionLog typeof Vec<InsertionRecord>);
+
+/// Keeps information about a single job insertion applied to the solution. Insertions are recorded
+/// into solution's [InsertionLogSolutionState] only when it is initialized (e.g. with an empty log)
+/// before construction. When jobs are removed from the solution (e.g. by ruin), the log is rebuilt
+/// from the current tours on the next insertion run, so it always reconstructs the current solution.
+pub struct InsertionRecord {
+    /// Inserted job.
+    pub job: Job,
+
+    /// Actor of the route where the job was inserted.
+    pub actor: Arc<Actor>,
+
+    /// Inserted activities with their positions in the tour (excluding tour start).
+    pub activities: Vec<(Activity, usize)>,
+}
+
+impl Clone for InsertionRecord {
+    fn clone(&self) -> Self {
+        Self {
+            job: self.job.clone(),
+            actor: self.actor.clone(),
+            activities: self.activities.iter().map(|(activity, index)| (activity.deep_copy(), *index)).collect(),
+        }
+    }
+}
+
+// NOTE constraint codes encountered on each attempted route are recorded as detailed unassignment reasons
+// of jobs which failed to be inserted only when this flag is set before construction.
+custom_solution_state!(DetailedInsertionFailures typeof bool);
+
 /// Specifies insertion failure.
 #[derive(Debug)]
 pub struct InsertionFailure {
@@ -285,9 +317,16 @@ impl InsertionHeuristic {
                     apply_insertion_success(&mut insertion_ctx, success);
                 }
                 InsertionResult::Failure(failure) => {
+                    let details = collect_failure_details(
+                        &insertion_ctx,
+                        failure.job.as_ref(),
+                        routes.as_slice(),
+                        leg_selection,
+                        result_selector,
+                    );
                     // NOTE copy data to make borrow checker happy
                     let (route_indices, jobs) = copy_selection_data(&insertion_ctx, routes.as_slice(), jobs.as_slice());
-                    apply_insertion_failure(&mut insertion_ctx, failure, &route_indices, &jobs);
+                    apply_insertion_failure(&mut insertion_ctx, failure, details, &route_indices, &jobs);
                 }
             }
         }
@@ -362,6 +401,8 @@ impl TryFrom<InsertionResult> for InsertionSuccess {
 }
 
 pub(crate) fn prepare_insertion_ctx(insertion_ctx: &mut InsertionContext) {
+    sync_insertion_log(insertion_ctx);
+
     insertion_ctx.solution.required.extend(insertion_ctx.solution.unassigned.keys().cloned());
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
 }
@@ -372,7 +413,38 @@ pub(crate) fn finalize_insertion_ctx(insertion_ctx: &mut InsertionContext) {
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
 }
 
+/// Replays given insertions in their order: the same insertion order applied to the same initial
+/// solution reconstructs the same solution.
+pub fn replay_insertions(mut insertion_ctx: InsertionContext, records: &[InsertionRecord]) -> InsertionContext {
+    prepare_insertion_ctx(&mut insertion_ctx);
+
+    records.iter().cloned().for_each(|record| {
+        let success = InsertionSuccess {
+            cost: InsertionCost::default(),
+            job: record.job,
+            activities: record.activities,
+            actor: record.actor,
+        };
+
+        apply_insertion_success(&mut insertion_ctx, success);
+    });
+
+    finalize_insertion_ctx(&mut insertion_ctx);
+
+    insertion_ctx
+}
+
 pub(crate) fn apply_insertion_success(insertion_ctx: &mut InsertionContext, success: InsertionSuccess) {
+    if let Some(log) =
+        insertion_ctx.solution.state.get_value_mut::<InsertionLogSolutionStateKey, Vec<InsertionRecord>>()
+    {
+        log.push(InsertionRecord {
+            job: success.job.clone(),
+            actor: success.actor.clone(),
+            activities: success.activities.iter().map(|(activity, index)| (activity.deep_copy(), *index)).collect(),
+        });
+    }
+
     let route_index = if let Some(new_route_ctx) = insertion_ctx.solution.registry.get_route(&success.actor) {
         insertion_ctx.solution.routes.push(new_route_ctx);
         insertion_ctx.solution.routes.len() - 1
@@ -397,9 +469,67 @@ pub(crate) fn apply_insertion_success(insertion_ctx: &mut InsertionContext, succ
     insertion_ctx.problem.goal.accept_insertion(&mut insertion_ctx.solution, route_index, &job);
 }
 
+/// Rebuilds insertion log from the current tours if it does not match them anymore (e.g. some jobs
+/// were removed after they were recorded).
+fn sync_insertion_log(insertion_ctx: &mut InsertionContext) {
+    let Some(log) = insertion_ctx.solution.state.get_insertion_log() else { return };
+    let routes = &insertion_ctx.solution.routes;
+
+    let total_jobs = routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>();
+    let is_synced = log.len() == total_jobs
+        && log.iter().all(|record| {
+            routes.iter().any(|route_ctx| {
+                route_ctx.route().actor == record.actor && route_ctx.route().tour.contains(&record.job)
+            })
+        });
+
+    if is_synced {
+        return;
+    }
+
+    let log = routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let route = route_ctx.route();
+            let activities = route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.retrieve_job().map(|job| (activity, job)))
+                .collect::<Vec<_>>();
+
+            // NOTE jobs are recorded in order of their first activity, so activity positions are relative
+            // to the tour which contains only activities of already recorded jobs
+            let ranks = activities.iter().fold(HashMap::<Job, usize>::new(), |mut ranks, (_, job)| {
+                let rank = ranks.len();
+                ranks.entry(job.clone()).or_insert(rank);
+                ranks
+            });
+            let mut jobs = ranks.iter().map(|(job, rank)| (job.clone(), *rank)).collect::<Vec<_>>();
+            jobs.sort_by_key(|(_, rank)| *rank);
+
+            jobs.into_iter()
+                .map(|(job, rank)| {
+                    let records = activities
+                        .iter()
+                        .filter(|(_, other)| ranks[other] <= rank)
+                        .enumerate()
+                        .filter(|(_, (_, other))| *other == job)
+                        .map(|(index, (activity, _))| (activity.deep_copy(), index))
+                        .collect();
+
+                    InsertionRecord { job, actor: route.actor.clone(), activities: records }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    insertion_ctx.solution.state.set_insertion_log(log);
+}
+
 fn apply_insertion_failure(
     insertion_ctx: &mut InsertionContext,
     failure: InsertionFailure,
+    details: Vec<(Arc<Actor>, ViolationCode)>,
     route_indices: &[usize],
     jobs: &[Job],
 ) {
@@ -422,7 +552,13 @@ fn apply_insertion_failure(
     let no_routes_available = failure.job.is_none();
 
     if let Some(job) = failure.job {
-        insertion_ctx.solution.unassigned.insert(job.clone(), UnassignmentInfo::Simple(failure.constraint));
+        let info = if details.is_empty() {
+            UnassignmentInfo::Simple(failure.constraint)
+        } else {
+            UnassignmentInfo::Detailed(details)
+        };
+
+        insertion_ctx.solution.unassigned.insert(job.clone(), info);
         insertion_ctx.solution.required.retain(|j| *j != job);
     }
 
@@ -433,6 +569,58 @@ fn apply_insertion_failure(
     }
 }
 
+/// Evaluates failed job on each attempted route separately to get constraint codes encountered there.
+/// Codes recorded previously are kept for unmodified routes as they are not evaluated again.
+/// Returns an empty collection when detailed insertion failures are not requested.
+fn collect_failure_details(
+    insertion_ctx: &InsertionContext,
+    job: Option<&Job>,
+    routes: &[&RouteContext],
+    leg_selection: &LegSelection,
+    result_selector: &(dyn ResultSelector),
+) -> Vec<(Arc<Actor>, ViolationCode)> {
+    let job = match (insertion_ctx.solution.state.get_detailed_insertion_failures(), job) {
+        (Some(true), Some(job)) => job,
+        _ => return vec![],
+    };
+
+    let recorded = match insertion_ctx.solution.unassigned.get(job) {
+        Some(UnassignmentInfo::Detailed(details)) => details.as_slice(),
+        _ => &[],
+    };
+
+    let eval_ctx = EvaluationContext { goal: &insertion_ctx.problem.goal, job, leg_selection, result_selector };
+
+    routes
+        .iter()
+        .filter_map(|&route_ctx| {
+            let actor = &route_ctx.route().actor;
+
+            // NOTE unmodified route is not evaluated again for the job with known code
+            if !route_ctx.is_stale() {
+                if let Some((_, code)) = recorded.iter().find(|(other, _)| other == actor) {
+                    return Some((actor.clone(), *code));
+                }
+            }
+
+            let result = eval_job_insertion_in_route(
+                insertion_ctx,
+                &eval_ctx,
+                route_ctx,
+                InsertionPosition::Any,
+                InsertionResult::make_failure(),
+            );
+
+            match result {
+                InsertionResult::Failure(failure) if !failure.constraint.is_unknown() => {
+                    Some((actor.clone(), failure.constraint))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
 fn finalize_unassigned(insertion_ctx: &mut InsertionContext, code: UnassignmentInfo) {
     let unassigned = &insertion_ctx.solution.unassigned;
     insertion_ctx.solution.required.retain(|job| !unassigned.contains_key(job));
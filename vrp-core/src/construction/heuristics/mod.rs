@@ -10,6 +10,7 @@ mod evaluators;
 pub use self::evaluators::*;
 
 mod factories;
+pub use self::factories::merge_solutions;
 
 mod insertions;
 pub use self::insertions::*;
@@ -17,5 +18,8 @@ pub use self::insertions::*;
 mod metrics;
 pub use self::metrics::*;
 
+mod savings;
+pub use self::savings::compute_savings;
+
 mod selectors;
 pub use self::selectors::*;
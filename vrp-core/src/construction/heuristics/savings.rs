@@ -0,0 +1,40 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/savings_test.rs"]
+mod savings_test;
+
+use crate::models::common::{Location, Profile};
+use crate::models::problem::{get_job_locations, Job, TransportCost};
+use rosomaxa::prelude::Float;
+
+/// Computes Clarke-Wright savings for each pair of jobs: `d(depot, i) + d(depot, j) - d(i, j)`, where
+/// `d` is the time-independent distance for the given profile. A job is represented by its first
+/// known location, jobs without location are ignored. Returns pairs sorted by savings descending.
+pub fn compute_savings(
+    jobs: &[Job],
+    depot: Location,
+    transport: &(dyn TransportCost),
+    profile: &Profile,
+) -> Vec<(Job, Job, Float)> {
+    let jobs = jobs
+        .iter()
+        .filter_map(|job| get_job_locations(job).flatten().next().map(|location| (job, location)))
+        .collect::<Vec<_>>();
+
+    let mut savings = jobs
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, &(job_i, loc_i))| {
+            jobs.iter().skip(idx + 1).map(move |&(job_j, loc_j)| {
+                let saving = transport.distance_approx(profile, depot, loc_i)
+                    + transport.distance_approx(profile, depot, loc_j)
+                    - transport.distance_approx(profile, loc_i, loc_j);
+
+                (job_i.clone(), job_j.clone(), saving)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    savings.sort_by(|(_, _, a), (_, _, b)| b.total_cmp(a));
+
+    savings
+}
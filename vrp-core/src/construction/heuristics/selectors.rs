@@ -184,6 +184,67 @@ impl ResultSelector for BestResultSelector {
     }
 }
 
+/// Specifies how to break ties between insertion alternatives with equal cost.
+#[derive(Clone)]
+pub enum TieBreakPolicy {
+    /// Prefers the alternative evaluated first, e.g. the earliest position in the route.
+    EarliestPosition,
+    /// Prefers the alternative evaluated last, e.g. the latest position in the route.
+    LatestPosition,
+    /// Picks one of the alternatives randomly. Use a repeatable random to get reproducible results.
+    Random(Arc<dyn Random>),
+}
+
+/// Selects best result and breaks ties between alternatives with equal cost using given policy.
+pub struct TieBreakResultSelector {
+    policy: TieBreakPolicy,
+}
+
+impl TieBreakResultSelector {
+    /// Creates a new instance of `TieBreakResultSelector`.
+    pub fn new(policy: TieBreakPolicy) -> Self {
+        Self { policy }
+    }
+
+    fn prefer_earliest(&self) -> bool {
+        match &self.policy {
+            TieBreakPolicy::EarliestPosition => true,
+            TieBreakPolicy::LatestPosition => false,
+            TieBreakPolicy::Random(random) => random.is_head_not_tails(),
+        }
+    }
+}
+
+impl ResultSelector for TieBreakResultSelector {
+    fn select_insertion(&self, _: &InsertionContext, left: InsertionResult, right: InsertionResult) -> InsertionResult {
+        match (&left, &right) {
+            // NOTE left is the best known result, right is the one evaluated after it
+            (InsertionResult::Success(lhs), InsertionResult::Success(rhs)) if lhs.cost == rhs.cost => {
+                if self.prefer_earliest() {
+                    left
+                } else {
+                    right
+                }
+            }
+            _ => InsertionResult::choose_best_result(left, right),
+        }
+    }
+
+    fn select_cost<'a>(
+        &self,
+        left: &'a InsertionCost,
+        right: &'a InsertionCost,
+    ) -> Either<&'a InsertionCost, &'a InsertionCost> {
+        // NOTE left is a newly evaluated cost, right is the best known one
+        match left.cmp(right) {
+            Ordering::Less => Either::Left(left),
+            Ordering::Greater => Either::Right(right),
+            Ordering::Equal if self.prefer_earliest() => Either::Right(right),
+            Ordering::Equal => Either::Left(left),
+        }
+    }
+}
+
 /// Selects results with noise.
 pub struct NoiseResultSelector {
     noise: Noise,
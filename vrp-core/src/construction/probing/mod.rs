@@ -1,4 +1,8 @@
-//! This module responsible for functionality needed to restore feasible solution from infeasible one.
+//! This module responsible for functionality needed to restore feasible solution from infeasible one
+//! and to probe feasibility of candidate routes.
 
 mod repair_solution;
 pub use self::repair_solution::*;
+
+mod route_evaluation;
+pub use self::route_evaluation::*;
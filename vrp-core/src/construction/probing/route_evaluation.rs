@@ -0,0 +1,88 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/probing/route_evaluation_test.rs"]
+mod route_evaluation_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::{Job, VehicleIdDimension};
+use crate::models::{Problem, ViolationCode};
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+/// Specifies a result of successful route evaluation.
+pub struct RouteEval {
+    /// A total cost of the route.
+    pub cost: Cost,
+    /// An evaluated route.
+    pub route_ctx: RouteContext,
+}
+
+/// Specifies a violation found during route evaluation.
+#[derive(Clone, Debug)]
+pub struct RouteViolation {
+    /// A job which cannot be served at its position in the sequence.
+    /// None if violation is not related to a specific job, e.g. vehicle is not found.
+    pub job: Option<Job>,
+    /// A violation code.
+    pub code: ViolationCode,
+}
+
+/// Evaluates feasibility of the route defined by the sequence of jobs served by the vehicle with given id.
+/// Jobs are appended one by one to the end of the tour using the full constraint pipeline of the problem.
+/// Infeasible jobs are skipped, so all violations are collected.
+/// Returns the route with its cost if all jobs can be served in the given order.
+pub fn evaluate_route(problem: Arc<Problem>, vehicle_id: &str, jobs: &[Job]) -> Result<RouteEval, Vec<RouteViolation>> {
+    let mut insertion_ctx = InsertionContext::new_empty(problem, Arc::new(Environment::default()));
+
+    let actor = insertion_ctx
+        .problem
+        .fleet
+        .actors
+        .iter()
+        .find(|actor| actor.vehicle.dimens.get_vehicle_id().map_or(false, |id| id == vehicle_id))
+        .cloned();
+
+    if let Some(route_ctx) = actor.and_then(|actor| insertion_ctx.solution.registry.get_route(&actor)) {
+        insertion_ctx.solution.routes.push(route_ctx);
+    } else {
+        return Err(vec![RouteViolation { job: None, code: ViolationCode::unknown() }]);
+    }
+
+    let goal = insertion_ctx.problem.goal.clone();
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    let violations = jobs.iter().fold(Vec::new(), |mut violations, job| {
+        let route_ctx = insertion_ctx.solution.routes.first().expect("route should be present");
+        let eval_ctx =
+            EvaluationContext { goal: &goal, job, leg_selection: &leg_selection, result_selector: &result_selector };
+
+        let result = if let Some(violation) =
+            goal.evaluate(&MoveContext::route(&insertion_ctx.solution, route_ctx, job))
+        {
+            InsertionResult::make_failure_with_code(violation.code, true, Some(job.clone()))
+        } else {
+            eval_job_constraint_in_route(&eval_ctx, route_ctx, InsertionPosition::Last, InsertionCost::default(), None)
+        };
+
+        match result {
+            InsertionResult::Success(success) => apply_insertion_success(&mut insertion_ctx, success),
+            InsertionResult::Failure(failure) => {
+                violations.push(RouteViolation { job: Some(job.clone()), code: failure.constraint })
+            }
+        }
+
+        violations
+    });
+
+    if !violations.is_empty() {
+        return Err(violations);
+    }
+
+    goal.accept_solution_state(&mut insertion_ctx.solution);
+
+    let cost = insertion_ctx.get_total_cost().unwrap_or_default();
+    let route_ctx = insertion_ctx.solution.routes.swap_remove(0);
+
+    Ok(RouteEval { cost, route_ctx })
+}
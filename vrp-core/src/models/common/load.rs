@@ -23,6 +23,9 @@ pub trait Load: Add + Sub + PartialOrd + Copy + Default + Debug + Send + Sync {
 
     /// Returns ratio.
     fn ratio(&self, other: &Self) -> Float;
+
+    /// Returns load scaled by the given ratio, rounded down to not exceed the exact value.
+    fn scale_down(&self, ratio: Float) -> Self;
 }
 
 /// Specifies constraints on Load operations.
@@ -162,6 +165,10 @@ impl Load for SingleDimLoad {
     fn ratio(&self, other: &Self) -> Float {
         self.value as Float / other.value as Float
     }
+
+    fn scale_down(&self, ratio: Float) -> Self {
+        Self::new((self.value as Float * ratio).floor() as i32)
+    }
 }
 
 impl Add for SingleDimLoad {
@@ -271,6 +278,13 @@ impl Load for MultiDimLoad {
     fn ratio(&self, other: &Self) -> Float {
         self.load.iter().zip(other.load.iter()).fold(0., |acc, (a, b)| (*a as Float / *b as Float).max(acc))
     }
+
+    fn scale_down(&self, ratio: Float) -> Self {
+        let mut dimens = *self;
+        dimens.load.iter_mut().for_each(|item| *item = (*item as Float * ratio).floor() as i32);
+
+        dimens
+    }
 }
 
 impl LoadOps for MultiDimLoad {}
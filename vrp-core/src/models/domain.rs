@@ -1,10 +1,15 @@
+#[cfg(test)]
+#[path = "../../tests/unit/models/domain_test.rs"]
+mod domain_test;
+
 use crate::construction::heuristics::UnassignmentInfo;
-use crate::models::common::{Cost, Location};
+use crate::models::common::{Cost, Duration, Location};
 use crate::models::problem::*;
 use crate::models::solution::{Registry, Route};
 use crate::models::*;
-use rosomaxa::evolution::TelemetryMetrics;
+use rosomaxa::evolution::{SolveSummary, TelemetryMetrics};
 use rosomaxa::prelude::*;
+use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
@@ -60,6 +65,13 @@ pub struct Solution {
 
     /// An optional telemetry metrics if available.
     pub telemetry: Option<TelemetryMetrics>,
+
+    /// An optional summary of the search (e.g. termination reason) if the solution is produced by evolution.
+    pub summary: Option<SolveSummary>,
+
+    /// Specifies whether the search was interrupted by the execution quota (e.g. time budget)
+    /// while some jobs are still unassigned.
+    pub is_partial: bool,
 }
 
 /// An enumeration which specifies how jobs should be ordered in tour.
@@ -254,4 +266,277 @@ impl Solution {
     pub fn get_locations(&self) -> impl Iterator<Item = impl Iterator<Item = Location> + '_> + '_ {
         self.routes.iter().map(|route| route.tour.all_activities().map(|activity| activity.place.location))
     }
+
+    /// Merges two solutions, typically obtained from solving sub-problems, into one by combining
+    /// their routes and unassigned jobs. Registry of the first solution is used, the actors of the
+    /// merged routes are marked as used there.
+    ///
+    /// Returns an error if the same actor or job is present in both solutions. Cost is reset and
+    /// telemetry is not preserved as they are no longer consistent with the merged solution: the
+    /// caller has to re-evaluate the merged solution, e.g. by creating insertion context from it.
+    pub fn merge(self, other: Solution) -> GenericResult<Solution> {
+        let mut registry = self.registry;
+        let mut routes = self.routes;
+        let mut unassigned = self.unassigned;
+        let is_partial = self.is_partial || other.is_partial;
+
+        let get_job_ids = |routes: &[Route], unassigned: &[(Job, UnassignmentInfo)]| {
+            routes
+                .iter()
+                .flat_map(|route| route.tour.jobs().cloned())
+                .chain(unassigned.iter().map(|(job, _)| job.clone()))
+                .map(|job| (job.dimens().get_job_id().cloned(), job))
+                .collect::<Vec<_>>()
+        };
+
+        let actors = routes.iter().map(|route| route.actor.clone()).collect::<HashSet<_>>();
+        let vehicle_keys = routes
+            .iter()
+            .filter_map(|route| {
+                route.actor.vehicle.dimens.get_vehicle_id().map(|id| (id.clone(), route.actor.detail.clone()))
+            })
+            .collect::<HashSet<_>>();
+
+        if let Some(route) = other.routes.iter().find(|route| {
+            actors.contains(&route.actor)
+                || route
+                    .actor
+                    .vehicle
+                    .dimens
+                    .get_vehicle_id()
+                    .map_or(false, |id| vehicle_keys.contains(&(id.clone(), route.actor.detail.clone())))
+        }) {
+            return Err(format!("cannot merge solutions: actor {:?} is used in both of them", route.actor).into());
+        }
+
+        let (job_ids, jobs): (HashSet<_>, HashSet<_>) = get_job_ids(&routes, &unassigned).into_iter().unzip();
+        if let Some((_, job)) = get_job_ids(&other.routes, &other.unassigned)
+            .into_iter()
+            .find(|(job_id, job)| jobs.contains(job) || job_id.as_ref().map_or(false, |_| job_ids.contains(job_id)))
+        {
+            return Err(format!("cannot merge solutions: job {job:?} is present in both of them").into());
+        }
+
+        other.routes.iter().for_each(|route| {
+            registry.use_actor(&route.actor);
+        });
+
+        routes.extend(other.routes);
+        unassigned.extend(other.unassigned);
+
+        Ok(Solution { cost: Cost::default(), registry, routes, unassigned, telemetry: None, summary: None, is_partial })
+    }
+}
+
+/// Calculates a similarity between two solutions of the same problem as a Jaccard index over
+/// job-actor assignment pairs: `1` means that all jobs are served by the same actors in both
+/// solutions, `0` means that no job is served by the same actor. Order of jobs inside the tour
+/// is not considered.
+pub fn solution_similarity(a: &Solution, b: &Solution) -> Float {
+    let get_assignments = |solution: &Solution| {
+        solution
+            .routes
+            .iter()
+            .flat_map(|route| route.tour.jobs().map(|job| (job.clone(), route.actor.clone())))
+            .collect::<HashSet<_>>()
+    };
+
+    let (a, b) = (get_assignments(a), get_assignments(b));
+    let union = a.union(&b).count();
+
+    if union == 0 {
+        1.
+    } else {
+        a.intersection(&b).count() as Float / union as Float
+    }
+}
+
+/// Calculates a workload fairness index of the solution as a normalized Gini coefficient over total
+/// working time of each route's driver: from the tour start departure till the tour end. `0` means that
+/// all drivers have equal working time, `1` means that all work is done by one driver.
+pub fn workload_fairness(solution: &Solution) -> Float {
+    let workloads = solution
+        .routes
+        .iter()
+        .filter_map(|route| {
+            let start = route.tour.start()?;
+            let end = route.tour.end()?;
+            let end_time = if route.actor.detail.end.is_some() { end.schedule.arrival } else { end.schedule.departure };
+
+            Some((end_time - start.schedule.departure).max(0.))
+        })
+        .collect::<Vec<_>>();
+
+    let size = workloads.len() as Float;
+    let total = workloads.iter().sum::<Float>();
+
+    if workloads.len() < 2 || total <= 0. {
+        return 0.;
+    }
+
+    let differences =
+        workloads.iter().flat_map(|left| workloads.iter().map(move |right| (left - right).abs())).sum::<Float>();
+
+    // NOTE max value of Gini coefficient for the sample is (n - 1) / n, so it is normalized to [0, 1]
+    (differences / (2. * size * total)) * size / (size - 1.)
+}
+
+/// Computes a quick lower bound of the total distance objective of the problem: each job has
+/// to be reached from some other job or vehicle start location, so the distance from the nearest
+/// such location is summed over all jobs. The bound is advisory, assumes that all jobs are
+/// assigned and is not tight.
+pub fn compute_objective_lower_bound(problem: &Problem) -> Float {
+    let singles = problem
+        .jobs
+        .all()
+        .iter()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single.clone()],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    let start_locations = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.details.iter())
+        .filter_map(|detail| detail.start.as_ref().map(|start| (None, start.location)));
+
+    let sources = singles
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, single)| single.places.iter().filter_map(move |place| place.location.map(|l| (Some(idx), l))))
+        .chain(start_locations)
+        .collect::<Vec<_>>();
+
+    let compare = |a: &Float, b: &Float| a.total_cmp(b);
+
+    singles
+        .iter()
+        .enumerate()
+        .map(|(idx, single)| {
+            single
+                .places
+                .iter()
+                .map(|place| {
+                    place.location.map_or(0., |to| {
+                        sources
+                            .iter()
+                            .filter(|(owner, _)| *owner != Some(idx))
+                            .flat_map(|&(_, from)| {
+                                problem
+                                    .fleet
+                                    .profiles
+                                    .iter()
+                                    .map(move |profile| problem.transport.distance_approx(profile, from, to))
+                            })
+                            .min_by(compare)
+                            .unwrap_or(0.)
+                    })
+                })
+                .min_by(compare)
+                .unwrap_or(0.)
+        })
+        .sum()
+}
+
+/// Computes a lower bound of each route duration for benchmarking purposes: a weight of minimum
+/// spanning tree over route locations (using the shortest travel duration in either direction) plus
+/// total service time of its job activities. As any path visiting all route locations is a spanning
+/// tree, the bound never exceeds the actual route duration (waiting time is ignored). Bounds are
+/// returned in the same order as solution routes.
+pub fn compute_route_duration_lower_bounds(problem: &Problem, solution: &Solution) -> Vec<Duration> {
+    solution
+        .routes
+        .iter()
+        .map(|route| {
+            let profile = &route.actor.vehicle.profile;
+            let locations = route.tour.all_activities().map(|activity| activity.place.location).collect::<Vec<_>>();
+            let service = route
+                .tour
+                .all_activities()
+                .filter(|activity| activity.job.is_some())
+                .map(|activity| activity.place.duration)
+                .sum::<Duration>();
+
+            let get_duration = |from: Location, to: Location| {
+                problem
+                    .transport
+                    .duration_approx(profile, from, to)
+                    .min(problem.transport.duration_approx(profile, to, from))
+            };
+
+            // NOTE use Prim's algorithm as amount of route locations is small
+            let mut in_tree = vec![false; locations.len()];
+            let mut distances = vec![Float::MAX; locations.len()];
+            let mut total = Duration::default();
+
+            if let Some(first) = distances.first_mut() {
+                *first = 0.;
+            }
+
+            for _ in 0..locations.len() {
+                let next = (0..locations.len())
+                    .filter(|&idx| !in_tree[idx])
+                    .min_by(|&a, &b| distances[a].total_cmp(&distances[b]));
+
+                if let Some(next) = next {
+                    in_tree[next] = true;
+                    total += distances[next];
+
+                    (0..locations.len()).filter(|&idx| !in_tree[idx]).for_each(|idx| {
+                        distances[idx] = distances[idx].min(get_duration(locations[next], locations[idx]));
+                    });
+                }
+            }
+
+            total + service
+        })
+        .collect()
+}
+
+/// Estimates solution robustness against travel time uncertainty: each travel duration is perturbed
+/// using given noise and routes are replayed from their actual start departure. Returns amount of
+/// routes which violate any activity time window, averaged over given amount of samples. The lower
+/// value is, the more robust solution is.
+pub fn compute_time_robustness(problem: &Problem, solution: &Solution, noise: &Noise, samples: usize) -> Float {
+    if samples == 0 {
+        return 0.;
+    }
+
+    let is_route_feasible = |route: &Route| {
+        route.tour.start().map_or(true, |start| {
+            route
+                .tour
+                .all_activities()
+                .skip(1)
+                .try_fold((start.place.location, start.schedule.departure), |(location, departure), activity| {
+                    let duration = problem.transport.duration(
+                        route,
+                        location,
+                        activity.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                    // NOTE noise is not applied to zero travel as there is nothing to perturb
+                    let duration = if duration > 0. { noise.generate(duration).max(0.) } else { duration };
+                    let arrival = departure + duration;
+
+                    if arrival > activity.place.time.end {
+                        None
+                    } else {
+                        Some((
+                            activity.place.location,
+                            arrival.max(activity.place.time.start) + activity.place.duration,
+                        ))
+                    }
+                })
+                .is_some()
+        })
+    };
+
+    let infeasible =
+        (0..samples).map(|_| solution.routes.iter().filter(|route| !is_route_feasible(route)).count()).sum::<usize>();
+
+    infeasible as Float / samples as Float
 }
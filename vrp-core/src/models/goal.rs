@@ -10,7 +10,7 @@ use rand::prelude::SliceRandom;
 use rosomaxa::population::Shuffled;
 use rosomaxa::prelude::*;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::ControlFlow;
 use std::sync::Arc;
@@ -34,6 +34,8 @@ pub struct GoalContext {
     alternative_goals: Vec<(Goal, Float)>,
     constraints: Vec<Arc<dyn FeatureConstraint>>,
     states: Vec<Arc<dyn FeatureState>>,
+    objectives: Vec<(String, Arc<dyn FeatureObjective>)>,
+    features: Vec<(String, Vec<FeatureKind>)>,
 }
 
 impl GoalContext {
@@ -49,6 +51,27 @@ impl GoalContext {
     pub fn constraints(&self) -> impl Iterator<Item = Arc<dyn FeatureConstraint>> + '_ {
         self.constraints.iter().cloned()
     }
+
+    /// Returns names of features used to build the goal context with kinds of functionality
+    /// they contribute, in order they were added.
+    pub fn features(&self) -> Vec<(String, Vec<FeatureKind>)> {
+        self.features.clone()
+    }
+
+    /// Returns amount of internal feature constraints.
+    pub fn constraints_len(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Returns names of features which contribute a constraint, in order they were added.
+    /// NOTE: names are taken from features used to build the goal context, so they are not affected
+    /// by constraints replacement via [GoalContext::with_constraints].
+    pub fn constraint_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.features
+            .iter()
+            .filter(|(_, kinds)| kinds.contains(&FeatureKind::Constraint))
+            .map(|(name, _)| name.as_str())
+    }
 }
 
 impl Debug for GoalContext {
@@ -63,35 +86,68 @@ impl Debug for GoalContext {
 }
 
 /// Provides a customizable way to build goal context.
+///
+/// Feature constraints are evaluated in the order features are added: the first hard constraint
+/// violation short-circuits evaluation, so constraints of later features are not evaluated at all.
+/// Putting cheap and restrictive constraints (e.g. skills) before expensive ones (e.g. capacity)
+/// can improve performance.
 pub struct GoalContextBuilder {
     main_goal: Option<Goal>,
+    is_simple_goal: bool,
     alternative_goals: Vec<(Goal, Float)>,
     features: Vec<Feature>,
+    objective_names: Vec<String>,
 }
 
 impl GoalContextBuilder {
     /// Creates a `GoalContextBuilder` with the given list of features.
     pub fn with_features(features: &[Feature]) -> GenericResult<Self> {
         let features = features.to_vec();
-        let ids_all = features.iter().map(|feature| feature.name.as_str()).collect::<Vec<_>>();
-        let ids_unique = ids_all.iter().collect::<HashSet<_>>();
+        check_unique_names(&features)?;
 
-        if ids_unique.len() != ids_all.len() {
+        let goal = Goal::simple(&features)?;
+        let objective_names = features.iter().filter(|f| f.objective.is_some()).map(|f| f.name.clone()).collect();
+
+        Ok(Self {
+            main_goal: Some(goal),
+            is_simple_goal: true,
+            alternative_goals: Vec::default(),
+            features,
+            objective_names,
+        })
+    }
+
+    /// Inserts a feature at the given position, so its constraint is evaluated before constraints
+    /// of features which follow it. The position doesn't affect objectives order: if the main goal is
+    /// not set explicitly, feature's objective is added as the last one.
+    pub fn add_feature_at(mut self, index: usize, feature: Feature) -> GenericResult<Self> {
+        if index > self.features.len() {
             return Err(format!(
-                "some of the features are defined more than once, check ids list: {}",
-                ids_all.join(",")
+                "cannot insert feature '{}' at {index}: there are only {} features",
+                feature.name,
+                self.features.len()
             )
             .into());
         }
 
-        let goal = Goal::simple(&features)?;
+        if feature.objective.is_some() {
+            self.objective_names.push(feature.name.clone());
+        }
+
+        self.features.insert(index, feature);
+        check_unique_names(&self.features)?;
 
-        Ok(Self { main_goal: Some(goal), alternative_goals: Vec::default(), features })
+        if self.is_simple_goal {
+            self.main_goal = Some(Goal::subset_of(&self.features, &self.objective_names)?);
+        }
+
+        Ok(self)
     }
 
     /// Sets a main goal of optimization.
     pub fn set_main_goal(mut self, goal: Goal) -> Self {
         self.main_goal = Some(goal);
+        self.is_simple_goal = false;
         self
     }
 
@@ -107,11 +163,30 @@ impl GoalContextBuilder {
         let alternative_goals = self.alternative_goals;
         let states = self.features.iter().filter_map(|feature| feature.state.clone()).collect();
         let constraints = self.features.iter().filter_map(|feature| feature.constraint.clone()).collect();
+        let objectives = self
+            .features
+            .iter()
+            .filter_map(|feature| feature.objective.clone().map(|objective| (feature.name.clone(), objective)))
+            .collect();
+        let features = self.features.iter().map(|feature| (feature.name.clone(), feature.kinds())).collect();
 
-        Ok(GoalContext { goal, alternative_goals, constraints, states })
+        Ok(GoalContext { goal, alternative_goals, constraints, states, objectives, features })
     }
 }
 
+fn check_unique_names(features: &[Feature]) -> GenericResult<()> {
+    let ids_all = features.iter().map(|feature| feature.name.as_str()).collect::<Vec<_>>();
+    let ids_unique = ids_all.iter().collect::<HashSet<_>>();
+
+    if ids_unique.len() != ids_all.len() {
+        return Err(
+            format!("some of the features are defined more than once, check ids list: {}", ids_all.join(",")).into()
+        );
+    }
+
+    Ok(())
+}
+
 type TotalOrderFn =
     Arc<dyn Fn(&[Arc<dyn FeatureObjective>], &InsertionContext, &InsertionContext) -> Ordering + Send + Sync>;
 type CostEstimateFn = Arc<dyn Fn(&[Arc<dyn FeatureObjective>], &MoveContext<'_>) -> Cost + Send + Sync>;
@@ -148,19 +223,35 @@ impl Goal {
         builder.build()
     }
 
+    /// Creates a goal using feature names with relative tolerances from the given list. Objectives are
+    /// defined in lexicographical order, but objective is treated as tied when fitness values of two
+    /// solutions are within its tolerance, so the next objective decides.
+    pub fn subset_of_with_tolerance<S: AsRef<str>>(features: &[Feature], names: &[(S, Float)]) -> GenericResult<Self> {
+        let mut builder = GoalBuilder::default();
+
+        for (name, tolerance) in names {
+            if *tolerance < 0. {
+                return Err(format!("tolerance of '{}' objective should not be negative", name.as_ref()).into());
+            }
+
+            let objective = Self::get_objective(features, name.as_ref())?;
+            builder = builder.add_single_with_tolerance(objective, *tolerance);
+        }
+
+        builder.build()
+    }
+
     fn add_with_name(builder: GoalBuilder, features: &[Feature], name: &str) -> GenericResult<GoalBuilder> {
+        Ok(builder.add_single(Self::get_objective(features, name)?))
+    }
+
+    fn get_objective(features: &[Feature], name: &str) -> GenericResult<Arc<dyn FeatureObjective>> {
         let feature = features
             .iter()
             .find(|f| f.name == name)
             .ok_or_else(|| GenericError::from(format!("cannot find a feature with given name: '{name}'")))?;
 
-        let objective = feature
-            .objective
-            .clone()
-            .take()
-            .ok_or_else(|| GenericError::from(format!("feature '{name}' has no objective")))?;
-
-        Ok(builder.add_single(objective))
+        feature.objective.clone().ok_or_else(|| GenericError::from(format!("feature '{name}' has no objective")))
     }
 }
 
@@ -198,16 +289,27 @@ pub struct GoalBuilder {
 
 impl GoalBuilder {
     /// Add a layer which consists of one objective function with a given feature name.
-    pub fn add_single(mut self, objective: Arc<dyn FeatureObjective>) -> Self {
+    pub fn add_single(self, objective: Arc<dyn FeatureObjective>) -> Self {
+        self.add_single_with_tolerance(objective, 0.)
+    }
+
+    /// Add a layer which consists of one objective function with a given feature name and a relative
+    /// tolerance: two solutions are treated as tied on this layer if their fitness values differ by no
+    /// more than `tolerance` fraction of the larger absolute value, so the comparison falls through to
+    /// the next layer. This helps to avoid getting stuck on optimizing the first objective only.
+    pub fn add_single_with_tolerance(mut self, objective: Arc<dyn FeatureObjective>, tolerance: Float) -> Self {
         // NOTE: indices are controlled internally
         self.layers.push((
-            Arc::new(|objectives, a, b| {
+            Arc::new(move |objectives, a, b| {
                 let fitness_a = objectives[0].fitness(a);
                 let fitness_b = objectives[0].fitness(b);
 
                 // NOTE total_cmp distinguishes between positive zero and negative zero while
                 // logically they are the same in this context
-                if fitness_a == 0. && fitness_b == 0. {
+                let is_zero = fitness_a == 0. && fitness_b == 0.;
+                let is_tied = (fitness_a - fitness_b).abs() <= tolerance * fitness_a.abs().max(fitness_b.abs());
+
+                if is_zero || is_tied {
                     Ordering::Equal
                 } else {
                     fitness_a.total_cmp(&fitness_b)
@@ -279,6 +381,31 @@ pub struct Feature {
     pub state: Option<Arc<dyn FeatureState>>,
 }
 
+impl Feature {
+    /// Returns kinds of functionality the feature contributes.
+    pub fn kinds(&self) -> Vec<FeatureKind> {
+        [
+            (self.constraint.is_some(), FeatureKind::Constraint),
+            (self.objective.is_some(), FeatureKind::Objective),
+            (self.state.is_some(), FeatureKind::State),
+        ]
+        .into_iter()
+        .filter_map(|(is_present, kind)| is_present.then_some(kind))
+        .collect()
+    }
+}
+
+/// Specifies a kind of functionality contributed by a [Feature].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FeatureKind {
+    /// A hard constraint, see [FeatureConstraint].
+    Constraint,
+    /// An objective, see [FeatureObjective].
+    Objective,
+    /// A state change handler, see [FeatureState].
+    State,
+}
+
 /// Specifies a result of hard route constraint check.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ConstraintViolation {
@@ -514,6 +641,16 @@ impl GoalContext {
         merge_with_constraints(&self.constraints, source, candidate)
     }
 
+    /// Checks whether two jobs can theoretically be served by the same vehicle. Only merge logic of
+    /// constraints is used (e.g. skills, groups, compatibility), so no insertion is evaluated.
+    /// As merge is not symmetric, each constraint is checked in both directions independently and
+    /// it accepts jobs if any direction succeeds, so the result does not depend on the order of jobs.
+    pub fn can_coexist(&self, a: &Job, b: &Job) -> bool {
+        self.constraints.iter().all(|constraint| {
+            constraint.merge(a.clone(), b.clone()).is_ok() || constraint.merge(b.clone(), a.clone()).is_ok()
+        })
+    }
+
     /// Evaluates feasibility of the refinement move.
     pub fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         evaluate_with_constraints(&self.constraints, move_ctx)
@@ -524,6 +661,20 @@ impl GoalContext {
         self.goal.estimate(move_ctx)
     }
 
+    /// Evaluates the refinement move without modifying the solution and returns a cost contribution
+    /// of each feature objective by feature name. Returns constraint violation if the move is not feasible.
+    /// NOTE: all feature objectives are estimated independently of how they are combined in the goal.
+    pub fn evaluate_insertion_detailed(
+        &self,
+        move_ctx: &MoveContext<'_>,
+    ) -> Result<HashMap<String, Cost>, ConstraintViolation> {
+        if let Some(violation) = self.evaluate(move_ctx) {
+            return Err(violation);
+        }
+
+        Ok(self.objectives.iter().map(|(name, objective)| (name.clone(), objective.estimate(move_ctx))).collect())
+    }
+
     /// Calculates solution's fitness.
     pub fn fitness<'a>(&'a self, solution: &'a InsertionContext) -> impl Iterator<Item = Float> + 'a {
         self.goal.fitness(solution)
@@ -9,6 +9,8 @@ use rosomaxa::utils::CollectGroupBy;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+custom_dimension!(VehicleServiceTimeMultiplier typeof Float);
+
 /// Specifies a travel time type.
 #[derive(Copy, Clone)]
 pub enum TravelTime {
@@ -25,7 +27,7 @@ pub trait ActivityCost: Send + Sync {
         let actor = route.actor.as_ref();
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0. };
-        let service = activity.place.duration;
+        let service = get_service_duration(route, activity);
 
         waiting * (actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time)
             + service * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
@@ -43,15 +45,28 @@ pub trait ActivityCost: Send + Sync {
 pub struct SimpleActivityCost {}
 
 impl ActivityCost for SimpleActivityCost {
-    fn estimate_departure(&self, _: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
-        arrival.max(activity.place.time.start) + activity.place.duration
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        arrival.max(activity.place.time.start) + get_service_duration(route, activity)
     }
 
-    fn estimate_arrival(&self, _: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        activity.place.time.end.min(departure - activity.place.duration)
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        activity.place.time.end.min(departure - get_service_duration(route, activity))
     }
 }
 
+/// Returns activity's service duration adjusted by the route vehicle's service time multiplier
+/// (see `VehicleServiceTimeMultiplierDimension`). Activities without a job are not affected.
+pub fn get_service_duration(route: &Route, activity: &Activity) -> Duration {
+    let multiplier = activity
+        .job
+        .as_ref()
+        .and_then(|_| route.actor.vehicle.dimens.get_vehicle_service_time_multiplier())
+        .copied()
+        .unwrap_or(1.);
+
+    activity.place.duration * multiplier
+}
+
 /// Provides the way to get routing information for specific locations and actor.
 pub trait TransportCost: Send + Sync {
     /// Returns time-dependent transport cost between two locations for given actor.
@@ -71,6 +86,18 @@ pub trait TransportCost: Send + Sync {
     /// Returns time-independent travel distance between locations specific for given profile.
     fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance;
 
+    /// Returns travel duration between locations specific for given profile and departure time.
+    /// Default implementation ignores departure and returns time-independent duration.
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, _departure: Timestamp) -> Duration {
+        self.duration_approx(profile, from, to)
+    }
+
+    /// Returns travel distance between locations specific for given profile and departure time.
+    /// Default implementation ignores departure and returns time-independent distance.
+    fn distance_at(&self, profile: &Profile, from: Location, to: Location, _departure: Timestamp) -> Distance {
+        self.distance_approx(profile, from, to)
+    }
+
     /// Returns time-dependent travel duration between locations specific for given actor.
     fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration;
 
@@ -195,6 +222,272 @@ pub fn create_matrix_transport_cost_with_fallback<T: TransportFallback + 'static
     })
 }
 
+/// Creates time aware routing costs based on matrix data passed which bucket travel times to
+/// the given granularity before matrix lookup, trading precision for speed.
+pub fn create_matrix_transport_cost_with_discretization(
+    costs: Vec<MatrixData>,
+    granularity: Duration,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    create_discretized_transport_cost(create_matrix_transport_cost(costs)?, granularity)
+}
+
+/// Wraps given transport costs to bucket travel times to the given granularity before passing
+/// them to the inner implementation.
+pub fn create_discretized_transport_cost(
+    inner: Arc<dyn TransportCost>,
+    granularity: Duration,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    if granularity <= 0. {
+        return Err("discretization granularity should be positive".into());
+    }
+
+    Ok(Arc::new(DiscretizedTransportCost { inner, granularity }))
+}
+
+/// A transport cost which discretizes travel times.
+struct DiscretizedTransportCost {
+    inner: Arc<dyn TransportCost>,
+    granularity: Duration,
+}
+
+impl DiscretizedTransportCost {
+    fn bucket(&self, timestamp: Timestamp) -> Timestamp {
+        (timestamp / self.granularity).floor() * self.granularity
+    }
+
+    fn discretize(&self, travel_time: TravelTime) -> TravelTime {
+        match travel_time {
+            TravelTime::Arrival(arrival) => TravelTime::Arrival(self.bucket(arrival)),
+            TravelTime::Departure(departure) => TravelTime::Departure(self.bucket(departure)),
+        }
+    }
+}
+
+impl TransportCost for DiscretizedTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.inner.duration_at(profile, from, to, self.bucket(departure))
+    }
+
+    fn distance_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.inner.distance_at(profile, from, to, self.bucket(departure))
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.inner.duration(route, from, to, self.discretize(travel_time))
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.inner.distance(route, from, to, self.discretize(travel_time))
+    }
+}
+
+/// Specifies an extra duration and distance added to the travel over a specific edge.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EdgePenalty {
+    /// An extra duration, e.g. a turn or traffic delay.
+    pub duration: Duration,
+    /// An extra distance, e.g. a detour.
+    pub distance: Distance,
+}
+
+/// A transport cost which adds user defined penalties to the edges of the base transport cost.
+/// Edges without penalty are passed through unchanged.
+pub struct PenalizedTransportCost {
+    base: Arc<dyn TransportCost>,
+    penalties: HashMap<(Location, Location), EdgePenalty>,
+}
+
+impl PenalizedTransportCost {
+    /// Creates a new instance of `PenalizedTransportCost`.
+    pub fn new(base: Arc<dyn TransportCost>, penalties: HashMap<(Location, Location), EdgePenalty>) -> Self {
+        Self { base, penalties }
+    }
+
+    fn get_penalty(&self, from: Location, to: Location) -> EdgePenalty {
+        self.penalties.get(&(from, to)).copied().unwrap_or_default()
+    }
+}
+
+impl TransportCost for PenalizedTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.base.duration_approx(profile, from, to) + self.get_penalty(from, to).duration
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.base.distance_approx(profile, from, to) + self.get_penalty(from, to).distance
+    }
+
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.base.duration_at(profile, from, to, departure) + self.get_penalty(from, to).duration
+    }
+
+    fn distance_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.base.distance_at(profile, from, to, departure) + self.get_penalty(from, to).distance
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.base.duration(route, from, to, travel_time) + self.get_penalty(from, to).duration
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.base.distance(route, from, to, travel_time) + self.get_penalty(from, to).distance
+    }
+}
+
+/// A function which checks whether activity is a reload.
+pub type ReloadActivityFn = Arc<dyn Fn(&Activity) -> bool + Send + Sync>;
+
+/// A transport cost which switches the routing profile of the route after a reload activity, e.g.
+/// an empty vehicle can use a faster profile than a loaded one. Profiles are switched using mapping
+/// from vehicle's profile index to the profile used after reload.
+/// NOTE: legs after reload use time-independent routing information of the switched profile.
+pub struct ReloadProfileTransportCost {
+    base: Arc<dyn TransportCost>,
+    profiles: HashMap<usize, Profile>,
+    is_reload_fn: ReloadActivityFn,
+}
+
+impl ReloadProfileTransportCost {
+    /// Creates a new instance of `ReloadProfileTransportCost`.
+    pub fn new(
+        base: Arc<dyn TransportCost>,
+        profiles: HashMap<usize, Profile>,
+        is_reload_fn: ReloadActivityFn,
+    ) -> Self {
+        Self { base, profiles, is_reload_fn }
+    }
+
+    /// Returns a profile to be used after reload if the leg starts after any reload in the route.
+    fn get_reload_profile(&self, route: &Route, travel_time: TravelTime) -> Option<&Profile> {
+        let profile = self.profiles.get(&route.actor.vehicle.profile.index)?;
+
+        // NOTE activities are ordered by their schedule, so only activities before the leg are checked
+        let mut activities_before = route.tour.all_activities().take_while(|activity| match travel_time {
+            TravelTime::Departure(departure) => activity.schedule.departure <= departure,
+            TravelTime::Arrival(arrival) => activity.schedule.arrival < arrival,
+        });
+        let is_after_reload = activities_before.any(|activity| (self.is_reload_fn)(activity));
+
+        if is_after_reload {
+            Some(profile)
+        } else {
+            None
+        }
+    }
+}
+
+impl TransportCost for ReloadProfileTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.base.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.base.distance_approx(profile, from, to)
+    }
+
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.base.duration_at(profile, from, to, departure)
+    }
+
+    fn distance_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.base.distance_at(profile, from, to, departure)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        match self.get_reload_profile(route, travel_time) {
+            Some(profile) => self.base.duration_approx(profile, from, to),
+            None => self.base.duration(route, from, to, travel_time),
+        }
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        match self.get_reload_profile(route, travel_time) {
+            Some(profile) => self.base.distance_approx(profile, from, to),
+            None => self.base.distance(route, from, to, travel_time),
+        }
+    }
+}
+
+/// A transport cost which blends routing information of two profiles, e.g. to model a vehicle
+/// traversing mixed terrain. Blending is configured using mapping from vehicle's profile index
+/// to a secondary profile and a weight `w` of the vehicle's profile, so the result is calculated
+/// as `w * primary + (1 - w) * secondary`.
+pub struct BlendedProfileTransportCost {
+    base: Arc<dyn TransportCost>,
+    blends: HashMap<usize, (Profile, Float)>,
+}
+
+impl BlendedProfileTransportCost {
+    /// Creates a new instance of `BlendedProfileTransportCost`.
+    pub fn new(base: Arc<dyn TransportCost>, blends: HashMap<usize, (Profile, Float)>) -> GenericResult<Self> {
+        if blends.values().any(|(_, weight)| !(0. ..=1.).contains(weight)) {
+            return Err("blend weight should be in [0, 1] range".into());
+        }
+
+        Ok(Self { base, blends })
+    }
+
+    fn blend(&self, profile: &Profile, primary: Float, secondary_fn: impl FnOnce(&Profile) -> Float) -> Float {
+        match self.blends.get(&profile.index) {
+            Some((secondary, weight)) => weight * primary + (1. - weight) * secondary_fn(secondary),
+            None => primary,
+        }
+    }
+}
+
+impl TransportCost for BlendedProfileTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.blend(profile, self.base.duration_approx(profile, from, to), |other| {
+            self.base.duration_approx(other, from, to)
+        })
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.blend(profile, self.base.distance_approx(profile, from, to), |other| {
+            self.base.distance_approx(other, from, to)
+        })
+    }
+
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.blend(profile, self.base.duration_at(profile, from, to, departure), |other| {
+            self.base.duration_at(other, from, to, departure)
+        })
+    }
+
+    fn distance_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.blend(profile, self.base.distance_at(profile, from, to, departure), |other| {
+            self.base.distance_at(other, from, to, departure)
+        })
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.blend(&route.actor.vehicle.profile, self.base.duration(route, from, to, travel_time), |other| {
+            self.base.duration_at(other, from, to, get_timestamp(travel_time))
+        })
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.blend(&route.actor.vehicle.profile, self.base.distance(route, from, to, travel_time), |other| {
+            self.base.distance_at(other, from, to, get_timestamp(travel_time))
+        })
+    }
+}
+
+fn get_timestamp(travel_time: TravelTime) -> Timestamp {
+    match travel_time {
+        TravelTime::Arrival(arrival) => arrival,
+        TravelTime::Departure(departure) => departure,
+    }
+}
+
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost<T: TransportFallback> {
     durations: Vec<Vec<Duration>>,
@@ -370,6 +663,14 @@ impl<T: TransportFallback> TransportCost for TimeAwareMatrixTransportCost<T> {
         self.interpolate_distance(profile, from, to, TravelTime::Departure(0.))
     }
 
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.interpolate_duration(profile, from, to, TravelTime::Departure(departure))
+    }
+
+    fn distance_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.interpolate_distance(profile, from, to, TravelTime::Departure(departure))
+    }
+
     fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
         self.interpolate_duration(&route.actor.vehicle.profile, from, to, travel_time)
     }
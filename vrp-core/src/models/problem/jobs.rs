@@ -2,7 +2,9 @@
 #[path = "../../../tests/unit/models/problem/jobs_test.rs"]
 mod jobs_test;
 
+use crate::algorithms::geometry::Point;
 use crate::construction::clustering::dbscan::create_job_clusters;
+use crate::construction::features::CoordIndexFn;
 use crate::models::common::*;
 use crate::models::problem::{Costs, Fleet, TransportCost};
 use crate::utils::{short_type_name, Either};
@@ -218,6 +220,10 @@ struct JobLink;
 /// Floating type wit less precision, but lower impact on memory footprint.
 type LowPrecisionCost = f32;
 type JobIndex = HashMap<Job, (Vec<(Job, LowPrecisionCost)>, LowPrecisionCost)>;
+/// Maps a location to indices of jobs which have it.
+type LocationIndex = HashMap<Location, Vec<usize>>;
+/// Specifies a grid cell as a pair of column and row indices.
+type GridCell = (i64, i64);
 
 // TODO: we don't know actual departure and zero-cost when we create job index.
 const DEFAULT_COST: LowPrecisionCost = 0.;
@@ -235,6 +241,8 @@ pub struct Jobs {
     jobs: Vec<Job>,
     index: HashMap<usize, JobIndex>,
     clusters: Vec<HashSet<Job>>,
+    locations: LocationIndex,
+    grid: Option<GridIndex>,
 }
 
 impl Jobs {
@@ -249,7 +257,18 @@ impl Jobs {
         let clusters =
             create_job_clusters(&jobs, fleet, Some(3), None, |profile, job| neighbors(&index, profile, job))?;
 
-        Ok(Jobs { jobs, index, clusters })
+        let locations = create_location_index(&jobs);
+
+        Ok(Jobs { jobs, index, clusters, locations, grid: None })
+    }
+
+    /// Builds a grid based spatial index used by [`Jobs::within_radius`] to check only locations
+    /// from cells near the center. Coordinates are expected to be in the same units as transport
+    /// distance and their euclidean distance should not exceed the transport one, otherwise
+    /// some jobs within radius can be missed.
+    pub fn with_coord_index(mut self, coord_index: CoordIndexFn) -> Self {
+        self.grid = Some(GridIndex::new(&self.locations, coord_index));
+        self
     }
 
     /// Returns all jobs in the original order as a slice.
@@ -268,6 +287,35 @@ impl Jobs {
         &self.clusters
     }
 
+    /// Returns jobs which have at least one location within given radius (distance) from center location.
+    /// Jobs are bucketed by their locations, so distance is checked once per distinct location.
+    /// When grid index is available (see [`Jobs::with_coord_index`]), only locations from nearby
+    /// cells are checked. Returned jobs preserve their original order.
+    pub fn within_radius(
+        &self,
+        profile: &Profile,
+        center: Location,
+        radius: Distance,
+        transport: &(dyn TransportCost),
+    ) -> Vec<&Job> {
+        let candidates: Box<dyn Iterator<Item = Location> + '_> =
+            match self.grid.as_ref().and_then(|grid| grid.candidates(center, radius)) {
+                Some(candidates) => candidates,
+                None => Box::new(self.locations.keys().copied()),
+            };
+
+        let mut job_indices = candidates
+            .filter(|&location| transport.distance_approx(profile, center, location) <= radius)
+            .filter_map(|location| self.locations.get(&location))
+            .flat_map(|job_indices| job_indices.iter().copied())
+            .collect::<Vec<_>>();
+
+        job_indices.sort_unstable();
+        job_indices.dedup();
+
+        job_indices.into_iter().map(|idx| &self.jobs[idx]).collect()
+    }
+
     /// Returns job rank as relative cost from any vehicle's start position.
     /// Returns `None` if a job is not found in index.
     pub fn rank(&self, profile: &Profile, job: &Job) -> Option<Cost> {
@@ -325,6 +373,86 @@ pub fn get_job_locations(job: &Job) -> impl Iterator<Item = Option<Location>> +
     }
 }
 
+/// Creates location index which buckets jobs by their locations.
+fn create_location_index(jobs: &[Job]) -> LocationIndex {
+    jobs.iter().enumerate().fold(HashMap::new(), |mut acc, (idx, job)| {
+        get_job_locations(job).flatten().for_each(|location| {
+            acc.entry(location).or_insert_with(Vec::new).push(idx);
+        });
+        acc
+    })
+}
+
+/// A grid based spatial index which buckets job locations by coordinate cells.
+struct GridIndex {
+    coord_index: CoordIndexFn,
+    cell_size: Float,
+    cells: HashMap<GridCell, Vec<(Location, Point)>>,
+    /// Locations without coordinates: they are always checked.
+    unplaced: Vec<Location>,
+}
+
+impl GridIndex {
+    fn new(locations: &LocationIndex, coord_index: CoordIndexFn) -> Self {
+        let (placed, unplaced): (Vec<_>, Vec<_>) =
+            locations.keys().map(|&location| (location, coord_index(location))).partition(|(_, point)| point.is_some());
+        let placed =
+            placed.into_iter().filter_map(|(location, point)| point.map(|point| (location, point))).collect::<Vec<_>>();
+        let unplaced = unplaced.into_iter().map(|(location, _)| location).collect();
+
+        // NOTE aim for about one location per cell
+        let (min, max) = placed.iter().fold(
+            (Point::new(Float::MAX, Float::MAX), Point::new(Float::MIN, Float::MIN)),
+            |(min, max), (_, point)| {
+                (Point::new(min.x.min(point.x), min.y.min(point.y)), Point::new(max.x.max(point.x), max.y.max(point.y)))
+            },
+        );
+        let extent = (max.x - min.x).max(max.y - min.y);
+        let cell_size = if extent > 0. { extent / (placed.len() as Float).sqrt().ceil() } else { 1. };
+
+        let cells = placed.into_iter().fold(HashMap::<_, Vec<_>>::new(), |mut acc, (location, point)| {
+            acc.entry(get_grid_cell(&point, cell_size)).or_default().push((location, point));
+            acc
+        });
+
+        Self { coord_index, cell_size, cells, unplaced }
+    }
+
+    /// Returns locations which can be within radius from the center or `None` if center has no coordinate.
+    fn candidates(&self, center: Location, radius: Distance) -> Option<Box<dyn Iterator<Item = Location> + '_>> {
+        let center = (self.coord_index)(center)?;
+
+        let (min_col, min_row) = get_grid_cell(&Point::new(center.x - radius, center.y - radius), self.cell_size);
+        let (max_col, max_row) = get_grid_cell(&Point::new(center.x + radius, center.y + radius), self.cell_size);
+        let is_in_range =
+            move |&(col, row): &GridCell| (min_col..=max_col).contains(&col) && (min_row..=max_row).contains(&row);
+
+        // NOTE visit only existing cells when the query area covers more cells than the grid has
+        let area = (max_col - min_col + 1).saturating_mul(max_row - min_row + 1);
+        let cells: Box<dyn Iterator<Item = &Vec<(Location, Point)>> + '_> = if area > self.cells.len() as i64 {
+            Box::new(self.cells.iter().filter(move |(cell, _)| is_in_range(cell)).map(|(_, locations)| locations))
+        } else {
+            Box::new(
+                (min_col..=max_col)
+                    .flat_map(move |col| (min_row..=max_row).map(move |row| (col, row)))
+                    .filter_map(|cell| self.cells.get(&cell)),
+            )
+        };
+
+        Some(Box::new(
+            cells
+                .flat_map(|locations| locations.iter())
+                .filter(move |(_, point)| point.distance_to_point(&center) <= radius)
+                .map(|(location, _)| *location)
+                .chain(self.unplaced.iter().copied()),
+        ))
+    }
+}
+
+fn get_grid_cell(point: &Point, cell_size: Float) -> GridCell {
+    ((point.x / cell_size).floor() as i64, (point.y / cell_size).floor() as i64)
+}
+
 /// Creates job index.
 fn create_index(
     fleet: &Fleet,
@@ -0,0 +1,30 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/compactness_test.rs"]
+mod compactness_test;
+
+use crate::algorithms::geometry::{get_convex_hull, get_perimeter, Point};
+use crate::models::common::Location;
+use crate::models::solution::Route;
+use rosomaxa::prelude::Float;
+
+/// A compactness value returned for routes which have less than three distinct stop coordinates.
+pub const UNDEFINED_ROUTE_COMPACTNESS: Float = -1.;
+
+/// Computes route compactness as a ratio of the convex hull perimeter of its stops to the actual
+/// route length in (0, 1] range: the higher the value, the more compact the route is. A route which
+/// goes around its stops has compactness close to one, a zig-zag route has compactness close to zero.
+/// `coord_index` maps a location to its coordinate, locations without coordinate are ignored.
+/// Returns `UNDEFINED_ROUTE_COMPACTNESS` if route has less than three distinct stop coordinates.
+pub fn route_compactness(route: &Route, coord_index: &dyn Fn(Location) -> Option<Point>) -> Float {
+    let points =
+        route.tour.all_activities().filter_map(|activity| coord_index(activity.place.location)).collect::<Vec<_>>();
+
+    let hull = get_convex_hull(points.as_slice());
+    if hull.len() < 3 {
+        return UNDEFINED_ROUTE_COMPACTNESS;
+    }
+
+    let route_length = points.windows(2).map(|pair| pair[0].distance_to_point(&pair[1])).sum::<Float>();
+
+    (get_perimeter(hull.as_slice()) / route_length).min(1.)
+}
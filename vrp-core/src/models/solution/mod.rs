@@ -1,10 +1,13 @@
 //! Solution domain models.
 
+mod compactness;
+pub use self::compactness::{route_compactness, UNDEFINED_ROUTE_COMPACTNESS};
+
 mod route;
 pub use self::route::{Activity, Commute, CommuteInfo, Place, Route};
 
 mod registry;
-pub use self::registry::Registry;
+pub use self::registry::{ActorWeightFn, ActorWeightsExtraProperty, Registry};
 
 mod tour;
 pub use self::tour::{Leg, Tour};
@@ -3,16 +3,23 @@
 mod actor_test;
 
 use crate::models::problem::{Actor, Fleet};
+use crate::models::Extras;
 use rosomaxa::prelude::Random;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+/// Specifies a function which returns a selection weight of the actor.
+pub type ActorWeightFn = Arc<dyn Fn(&Actor) -> usize + Send + Sync>;
+
+custom_extra_property!(ActorWeights typeof ActorWeightFn);
+
 /// Specifies an entity responsible for providing actors and keeping track of their usage.
 pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
     random: Arc<dyn Random>,
+    weight_fn: Option<ActorWeightFn>,
 }
 
 impl Registry {
@@ -24,7 +31,15 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random, weight_fn: None }
+    }
+
+    /// Sets a weight function used to prefer some actors proportionally to their weight
+    /// when next available actor is selected. Without it, actors are selected uniformly.
+    /// Insertion contexts apply it automatically when problem extras have [ActorWeightsExtraProperty] set.
+    pub fn with_weights(mut self, weight_fn: ActorWeightFn) -> Self {
+        self.weight_fn = Some(weight_fn);
+        self
     }
 
     /// Removes an actor from the list of available actors.
@@ -53,7 +68,14 @@ impl Registry {
     pub fn next(&'_ self) -> impl Iterator<Item = Arc<Actor>> + '_ {
         self.available.iter().flat_map(move |(_, set)| {
             // NOTE pick a random actor from set of available actors.
-            let skip_amount = if set.len() < 2 { 0 } else { self.random.uniform_int(0, set.len() as i32 - 1) as usize };
+            let skip_amount = match (set.len(), self.weight_fn.as_ref()) {
+                (0..=1, _) => 0,
+                (_, Some(weight_fn)) => {
+                    let weights = set.iter().map(|actor| weight_fn(actor.as_ref())).collect::<Vec<_>>();
+                    self.random.weighted(weights.as_slice())
+                }
+                (len, None) => self.random.uniform_int(0, len as i32 - 1) as usize,
+            };
             set.iter().skip(skip_amount).take(1).cloned()
         })
     }
@@ -65,6 +87,7 @@ impl Registry {
             index: self.index.clone(),
             all: self.all.clone(),
             random: self.random.clone(),
+            weight_fn: self.weight_fn.clone(),
         }
     }
 
@@ -87,6 +110,7 @@ impl Registry {
                 .collect(),
             all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
             random: self.random.clone(),
+            weight_fn: self.weight_fn.clone(),
         }
     }
 }
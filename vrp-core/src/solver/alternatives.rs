@@ -0,0 +1,45 @@
+//! This module contains logic to select alternative near-optimal solutions.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/alternatives_test.rs"]
+mod alternatives_test;
+
+use crate::models::{solution_similarity, Solution};
+use rosomaxa::prelude::Float;
+
+/// Specifies how alternative solutions are selected from the final population.
+#[derive(Clone, Debug)]
+pub struct AlternativeSolutionsConfig {
+    /// Max amount of returned solutions, including the best one.
+    pub max_solutions: usize,
+    /// Max relative cost gap to the best solution, e.g. `0.05` keeps solutions which are
+    /// at most 5% more expensive than the best one.
+    pub max_cost_gap: Float,
+    /// Max allowed similarity between any two returned solutions, see [`solution_similarity`].
+    /// Candidates which are too similar to already selected solutions are skipped as near-duplicates.
+    pub max_similarity: Float,
+}
+
+impl Default for AlternativeSolutionsConfig {
+    fn default() -> Self {
+        Self { max_solutions: 3, max_cost_gap: 0.05, max_similarity: 0.9 }
+    }
+}
+
+/// Selects alternative solutions: the best solution is always selected, other candidates are added
+/// greedily in the given order if they are within the cost gap and not too similar to any of already
+/// selected solutions. Solutions are expected to be sorted using goal's total order, the best first.
+pub fn select_alternative_solutions(solutions: Vec<Solution>, config: &AlternativeSolutionsConfig) -> Vec<Solution> {
+    let max_cost = solutions.first().map_or(0., |best| best.cost + best.cost.abs() * config.max_cost_gap);
+
+    solutions.into_iter().filter(|solution| solution.cost <= max_cost).fold(Vec::default(), |mut selected, solution| {
+        let is_diverse =
+            selected.iter().all(|other: &Solution| solution_similarity(other, &solution) <= config.max_similarity);
+
+        if selected.len() < config.max_solutions && is_diverse {
+            selected.push(solution);
+        }
+
+        selected
+    })
+}
@@ -31,6 +31,8 @@ pub type GreedyPopulation = Greedy<GoalContext, InsertionContext>;
 pub type ElitismPopulation = Elitism<GoalContext, InsertionContext>;
 /// A type for rosomaxa population.
 pub type RosomaxaPopulation = Rosomaxa<GoalContext, InsertionContext>;
+/// A type for simulated annealing population.
+pub type AnnealingPopulation = Annealing<GoalContext, InsertionContext>;
 
 /// A type alias for domain specific termination type.
 pub type DynTermination = dyn Termination<Context = RefinementContext, Objective = GoalContext> + Send + Sync;
@@ -62,12 +64,13 @@ pub struct VrpConfigBuilder {
     environment: Option<Arc<Environment>>,
     heuristic: Option<TargetHeuristic>,
     telemetry_mode: Option<TelemetryMode>,
+    seed: Option<u64>,
 }
 
 impl VrpConfigBuilder {
     /// Creates a new instance of `VrpConfigBuilder`.
     pub fn new(problem: Arc<Problem>) -> Self {
-        Self { problem, environment: None, heuristic: None, telemetry_mode: None }
+        Self { problem, environment: None, heuristic: None, telemetry_mode: None, seed: None }
     }
 
     /// Sets [Environment] instance to be used.
@@ -82,6 +85,14 @@ impl VrpConfigBuilder {
         self
     }
 
+    /// Sets a seed of random generator used by the solver to get reproducible results. It overrides
+    /// random generator of [Environment]. Please note that reproducibility also requires single threaded
+    /// mode and termination criteria which don't depend on time.
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     /// Sets [TargetHeuristic] to be used.
     /// By default, it is used what is returned by [get_default_heuristic].
     pub fn set_heuristic(mut self, heuristic: TargetHeuristic) -> Self {
@@ -93,6 +104,13 @@ impl VrpConfigBuilder {
     pub fn prebuild(self) -> GenericResult<ProblemConfigBuilder> {
         let problem = self.problem;
         let environment = self.environment.unwrap_or_else(|| Arc::new(Environment::default()));
+        let environment = match self.seed {
+            Some(seed) => Arc::new(Environment {
+                random: Arc::new(DefaultRandom::new_with_seed(seed)),
+                ..environment.as_ref().clone()
+            }),
+            None => environment,
+        };
         let telemetry_mode =
             self.telemetry_mode.unwrap_or_else(|| get_default_telemetry_mode(environment.logger.clone()));
 
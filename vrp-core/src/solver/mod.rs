@@ -73,9 +73,13 @@
 
 extern crate rand;
 
+#[cfg(test)]
+#[path = "../../tests/unit/solver/solver_test.rs"]
+mod solver_test;
+
 use crate::construction::heuristics::InsertionContext;
 use crate::models::{GoalContext, Problem, Solution};
-use crate::solver::search::Recreate;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
 use rosomaxa::evolution::*;
 use rosomaxa::prelude::*;
 use rosomaxa::{get_default_population, TelemetryHeuristicContext};
@@ -83,14 +87,20 @@ use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+pub use self::alternatives::*;
 pub use self::heuristic::*;
+pub use self::relaxation::*;
+pub use self::single_vehicle::*;
 use rosomaxa::population::Rosomaxa;
 use rosomaxa::utils::Timer;
 
 pub mod processing;
 pub mod search;
 
+mod alternatives;
 mod heuristic;
+mod relaxation;
+mod single_vehicle;
 
 /// A type which encapsulates information needed to perform solution refinement process.
 pub struct RefinementContext {
@@ -230,6 +240,20 @@ impl Solver {
         Self { problem, config }
     }
 
+    /// Enables a fast path for problems with a single vehicle: the first initial solution is built
+    /// directly by `SingleVehicleInitialOperator`, so the metaheuristic starts from it. If the fast
+    /// path cannot assign all jobs, the initial solution is built by cheapest insertion instead.
+    pub fn with_single_vehicle_fast_path(mut self, is_enabled: bool) -> Self {
+        if is_enabled && is_single_vehicle_problem(self.problem.as_ref()) {
+            let random = self.config.context.environment.random.clone();
+            let fallback = RecreateInitialOperator::new(Arc::new(RecreateWithCheapest::new(random)));
+
+            self.config.initial.operators.insert(0, (Box::new(SingleVehicleInitialOperator::new(fallback)), 1));
+        }
+
+        self
+    }
+
     /// Solves a Vehicle Routing Problem and returns a feasible solution in case of success
     /// or error description if solution cannot be found.
     pub fn solve(self) -> GenericResult<Solution> {
@@ -239,14 +263,46 @@ impl Solver {
             self.problem.fleet.actors.len()
         ));
 
-        let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+        let quota = self.config.context.environment.quota.clone();
+        let EvolutionOutput { mut solutions, metrics, summary } = EvolutionSimulator::new(self.config)?.run()?;
 
         // NOTE select the first best individual from population
         let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
             .ok_or_else(|| "cannot find any solution".to_string())?;
 
-        let solution = (insertion_ctx, metrics).into();
+        let mut solution: Solution = (insertion_ctx, metrics).into();
+        solution.summary = Some(summary);
+        solution.is_partial =
+            !solution.unassigned.is_empty() && quota.as_ref().map_or(false, |quota| quota.is_reached());
 
         Ok(solution)
     }
+
+    /// Solves a Vehicle Routing Problem and returns a few distinct near-optimal solutions selected from
+    /// the final population, the best one goes first. Alternatives are taken only from solutions returned
+    /// by evolution, so the config should be built with result size bigger than one.
+    pub fn solve_alternatives(self, config: &AlternativeSolutionsConfig) -> GenericResult<Vec<Solution>> {
+        let quota = self.config.context.environment.quota.clone();
+        let EvolutionOutput { mut solutions, mut metrics, summary } = EvolutionSimulator::new(self.config)?.run()?;
+
+        if solutions.is_empty() {
+            return Err("cannot find any solution".into());
+        }
+
+        solutions.sort_by(|a, b| self.problem.goal.total_order(a, b));
+
+        let solutions = solutions
+            .into_iter()
+            .map(|insertion_ctx| {
+                let mut solution: Solution = (insertion_ctx, metrics.take()).into();
+                solution.summary = Some(summary.clone());
+                solution.is_partial =
+                    !solution.unassigned.is_empty() && quota.as_ref().map_or(false, |quota| quota.is_reached());
+
+                solution
+            })
+            .collect();
+
+        Ok(select_alternative_solutions(solutions, config))
+    }
 }
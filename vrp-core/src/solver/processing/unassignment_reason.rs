@@ -54,7 +54,18 @@ impl HeuristicSolutionProcessing for UnassignmentReason {
                 })
                 .collect::<Vec<_>>();
 
-            let code = if details.is_empty() { code } else { UnassignmentInfo::Detailed(details) };
+            let code = match code {
+                // NOTE keep details recorded during construction for routes which were not evaluated here
+                UnassignmentInfo::Detailed(recorded) => {
+                    let recorded = recorded
+                        .into_iter()
+                        .filter(|(actor, _)| details.iter().all(|(other, _)| other != actor))
+                        .collect::<Vec<_>>();
+                    UnassignmentInfo::Detailed(details.into_iter().chain(recorded).collect())
+                }
+                code if details.is_empty() => code,
+                _ => UnassignmentInfo::Detailed(details),
+            };
 
             (job, code)
         });
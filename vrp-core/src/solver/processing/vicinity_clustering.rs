@@ -4,9 +4,7 @@ mod vicinity_clustering_test;
 
 use super::*;
 use crate::construction::clustering::vicinity::*;
-use crate::models::common::Schedule;
 use crate::models::problem::Jobs;
-use crate::models::solution::{Activity, Place};
 use crate::models::{Extras, GoalContext, Problem};
 use crate::solver::RefinementContext;
 use std::collections::HashSet;
@@ -105,41 +103,7 @@ impl HeuristicSolutionProcessing for VicinityClustering {
 
             clusters.into_iter().rev().for_each(|(activity_idx, cluster)| {
                 let cluster_activity = route_ctx.route().tour.get(activity_idx).unwrap();
-                let cluster_time = cluster_activity.place.time.clone();
-                let cluster_arrival = cluster_activity.schedule.arrival;
-                let last_job = cluster.last().unwrap().job.clone();
-
-                let (_, activities) =
-                    cluster.into_iter().fold((cluster_arrival, Vec::new()), |(arrival, mut activities), info| {
-                        // NOTE assumption: no waiting time possible in between of clustered jobs
-                        let job = info.job.to_single().clone();
-                        let place_idx = 0;
-                        let place = &job.places[place_idx];
-
-                        let backward = match config.visiting {
-                            VisitPolicy::Return => info.commute.backward.duration,
-                            VisitPolicy::ClosedContinuation if info.job == last_job => info.commute.backward.duration,
-                            _ => 0.,
-                        };
-
-                        let service_time = info.service_time;
-                        let service_start = (arrival + info.commute.forward.duration).max(cluster_time.start);
-                        let departure = service_start + service_time + backward;
-
-                        activities.push(Activity {
-                            place: Place {
-                                idx: place_idx,
-                                location: place.location.unwrap(),
-                                duration: info.service_time,
-                                time: cluster_time.clone(),
-                            },
-                            schedule: Schedule::new(arrival, departure),
-                            job: Some(job),
-                            commute: Some(info.commute),
-                        });
-
-                        (departure, activities)
-                    });
+                let activities = unfold_cluster_activity(config.as_ref(), cluster_activity, cluster.as_slice());
 
                 route_ctx.route_mut().tour.remove_activity_at(activity_idx);
                 activities.into_iter().enumerate().for_each(|(seq_idx, activity)| {
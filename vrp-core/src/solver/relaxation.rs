@@ -0,0 +1,50 @@
+//! This module contains logic to find a least infeasible solution by relaxing constraints.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/relaxation_test.rs"]
+mod relaxation_test;
+
+use crate::models::{Feature, GoalContext, GoalContextBuilder, Solution};
+use rosomaxa::prelude::*;
+
+/// A solution found with some of the constraints relaxed.
+pub struct RelaxedSolution {
+    /// A found solution.
+    pub solution: Solution,
+    /// Names of features which constraints were relaxed, in the order of relaxation.
+    pub relaxed: Vec<String>,
+}
+
+/// Solves the problem relaxing constraints in the given priority order until all jobs are assigned.
+/// Relaxation removes a hard constraint of the feature with the given name, its objective and state
+/// are kept. Relaxations are cumulative: once relaxed, the constraint stays relaxed.
+/// If all jobs cannot be assigned even with all constraints from the list relaxed, the last found
+/// solution is returned.
+///
+/// `solve_fn` is called with the goal built from the (relaxed) features and has to return a solution
+/// of the problem defined with this goal.
+pub fn solve_with_relaxation<F>(features: &[Feature], order: &[String], solve_fn: F) -> GenericResult<RelaxedSolution>
+where
+    F: Fn(GoalContext) -> GenericResult<Solution>,
+{
+    if let Some(name) = order.iter().find(|name| !features.iter().any(|feature| feature.name == **name)) {
+        return Err(format!("cannot relax unknown feature: '{name}'").into());
+    }
+
+    let mut features = features.to_vec();
+    let mut relaxed = Vec::default();
+    let mut solution = solve_fn(GoalContextBuilder::with_features(&features)?.build()?)?;
+
+    for name in order {
+        if solution.unassigned.is_empty() {
+            break;
+        }
+
+        features.iter_mut().filter(|feature| feature.name == *name).for_each(|feature| feature.constraint = None);
+        relaxed.push(name.clone());
+
+        solution = solve_fn(GoalContextBuilder::with_features(&features)?.build()?)?;
+    }
+
+    Ok(RelaxedSolution { solution, relaxed })
+}
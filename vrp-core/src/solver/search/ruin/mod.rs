@@ -30,8 +30,11 @@ pub use self::route_removal::*;
 mod random_job_removal;
 pub use self::random_job_removal::RandomJobRemoval;
 
+mod vicinity_cluster_removal;
+pub use self::vicinity_cluster_removal::VicinityClusterRemoval;
+
 mod worst_jobs_removal;
-pub use self::worst_jobs_removal::WorstJobRemoval;
+pub use self::worst_jobs_removal::{get_job_marginal_costs, WorstJobRemoval};
 
 /// A type which specifies a group of multiple ruin strategies with their probability.
 pub type RuinGroup = (Vec<(Arc<dyn Ruin>, Float)>, usize);
@@ -0,0 +1,67 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/ruin/vicinity_cluster_removal_test.rs"]
+mod vicinity_cluster_removal_test;
+
+use super::*;
+use crate::construction::clustering::vicinity::ClusterInfoDimension;
+use crate::construction::heuristics::InsertionContext;
+use crate::models::problem::{Job, JobIdDimension};
+use crate::solver::search::{get_route_jobs, JobRemovalTracker, TabuList};
+use crate::solver::RefinementContext;
+
+/// A ruin strategy which removes jobs created by vicinity clustering and puts their original
+/// member jobs back to required list, so they can be reinserted individually.
+pub struct VicinityClusterRemoval {
+    limits: RemovalLimits,
+}
+
+impl VicinityClusterRemoval {
+    /// Creates a new instance of `VicinityClusterRemoval`.
+    pub fn new(limits: RemovalLimits) -> Self {
+        Self { limits }
+    }
+}
+
+impl Ruin for VicinityClusterRemoval {
+    fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut clusters = get_route_jobs(&insertion_ctx.solution)
+            .into_iter()
+            .filter(|(job, _)| job.dimens().get_cluster_info().map_or(false, |info| !info.is_empty()))
+            .collect::<Vec<_>>();
+
+        if clusters.is_empty() {
+            return insertion_ctx;
+        }
+
+        // NOTE sort to keep shuffle results reproducible as route jobs are collected into hash map
+        clusters.sort_by(|(a, _), (b, _)| a.dimens().get_job_id().cmp(&b.dimens().get_job_id()));
+        clusters.shuffle(&mut insertion_ctx.environment.random.get_rng());
+
+        let mut tracker = JobRemovalTracker::new(&self.limits, insertion_ctx.environment.random.as_ref());
+        let mut tabu_list = TabuList::from(&insertion_ctx);
+
+        clusters.into_iter().for_each(|(job, route_idx)| {
+            if tracker.is_limit() {
+                return;
+            }
+
+            if tracker.try_remove_job(&mut insertion_ctx.solution, route_idx, &job) {
+                tabu_list.add_actor(insertion_ctx.solution.routes[route_idx].route().actor.clone());
+                split_cluster(&mut insertion_ctx, &job);
+            }
+        });
+
+        tabu_list.inject(&mut insertion_ctx);
+
+        insertion_ctx
+    }
+}
+
+fn split_cluster(insertion_ctx: &mut InsertionContext, cluster: &Job) {
+    let members = cluster.dimens().get_cluster_info().map(|info| info.iter().map(|info| info.job.clone()));
+    let members = if let Some(members) = members { members } else { return };
+
+    let required = &mut insertion_ctx.solution.required;
+    required.retain(|job| job != cluster);
+    required.extend(members);
+}
@@ -82,32 +82,50 @@ impl Ruin for WorstJobRemoval {
     }
 }
 
+/// Returns a marginal contribution of each assigned job to the solution's routing cost: the cost
+/// which is saved when the job is forced to be unassigned while schedule of the rest activities is kept.
+/// Jobs are sorted by their contribution in descending order.
+pub fn get_job_marginal_costs(insertion_ctx: &InsertionContext) -> Vec<(Job, Cost)> {
+    let mut marginals = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| get_route_cost_savings(insertion_ctx, route_ctx.route()))
+        .collect::<Vec<_>>();
+    marginals.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    marginals
+}
+
 fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(Profile, Vec<(Job, Cost)>)> {
     parallel_collect(&insertion_ctx.solution.routes, |route_ctx| {
-        let route = route_ctx.route();
-        let mut savings: Vec<(Job, Cost)> = route
-            .tour
-            .all_activities()
-            .as_slice()
-            .windows(3)
-            .fold(HashMap::<Job, Cost>::default(), |mut acc, iter| match iter {
-                [start, eval, end] => {
-                    let savings = get_cost_savings(route, start, eval, end, &insertion_ctx.problem.transport);
-                    let job = eval.retrieve_job().unwrap_or_else(|| panic!("Unexpected activity without job"));
-                    *acc.entry(job).or_insert(0.) += savings;
-
-                    acc
-                }
-                _ => panic!("Unexpected activity window"),
-            })
-            .drain()
-            .collect();
+        let mut savings = get_route_cost_savings(insertion_ctx, route_ctx.route());
         savings.sort_by(|(_, a), (_, b)| b.total_cmp(a));
 
         (route_ctx.route().actor.vehicle.profile.clone(), savings)
     })
 }
 
+fn get_route_cost_savings(insertion_ctx: &InsertionContext, route: &Route) -> Vec<(Job, Cost)> {
+    route
+        .tour
+        .all_activities()
+        .as_slice()
+        .windows(3)
+        .fold(HashMap::<Job, Cost>::default(), |mut acc, iter| match iter {
+            [start, eval, end] => {
+                let savings = get_cost_savings(route, start, eval, end, &insertion_ctx.problem.transport);
+                let job = eval.retrieve_job().unwrap_or_else(|| panic!("Unexpected activity without job"));
+                *acc.entry(job).or_insert(0.) += savings;
+
+                acc
+            }
+            _ => panic!("Unexpected activity window"),
+        })
+        .drain()
+        .collect()
+}
+
 fn get_cost_savings(
     route: &Route,
     start: &Activity,
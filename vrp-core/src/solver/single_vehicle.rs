@@ -0,0 +1,136 @@
+//! This module contains a fast path for problems with a single vehicle.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/single_vehicle_test.rs"]
+mod single_vehicle_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::models::{GoalContext, Problem};
+use crate::solver::{RecreateInitialOperator, RefinementContext};
+use rosomaxa::evolution::InitialOperator;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Specifies max amount of improvement passes done on the constructed route.
+const MAX_IMPROVEMENT_PASSES: usize = 64;
+
+/// Checks whether the problem can be handled by the single vehicle fast path: it has exactly
+/// one actor, only single jobs and no locks.
+pub fn is_single_vehicle_problem(problem: &Problem) -> bool {
+    problem.fleet.actors.len() == 1
+        && problem.locks.is_empty()
+        && problem.jobs.all().iter().all(|job| job.as_single().is_some())
+}
+
+/// An initial operator which builds a solution of a single vehicle problem directly (see
+/// `try_solve_single_vehicle`) and falls back to the given operator if it cannot assign all jobs.
+pub struct SingleVehicleInitialOperator {
+    fallback: RecreateInitialOperator,
+}
+
+impl SingleVehicleInitialOperator {
+    /// Creates a new instance of `SingleVehicleInitialOperator`.
+    pub fn new(fallback: RecreateInitialOperator) -> Self {
+        Self { fallback }
+    }
+}
+
+impl InitialOperator for SingleVehicleInitialOperator {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn create(&self, heuristic_ctx: &Self::Context) -> Self::Solution {
+        try_solve_single_vehicle(heuristic_ctx.problem.clone(), heuristic_ctx.environment.clone())
+            .unwrap_or_else(|| self.fallback.create(heuristic_ctx))
+    }
+}
+
+/// Tries to solve a single vehicle problem directly: the route is built using cheapest insertion
+/// and then improved by relocating jobs to their best positions until no improvement is found.
+/// Returns `None` if the problem is not a single vehicle one or not all jobs can be assigned.
+pub fn try_solve_single_vehicle(problem: Arc<Problem>, environment: Arc<Environment>) -> Option<InsertionContext> {
+    if !is_single_vehicle_problem(problem.as_ref()) {
+        return None;
+    }
+
+    let insertion_ctx = InsertionHeuristic::default().process(
+        InsertionContext::new(problem, environment),
+        &AllJobSelector::default(),
+        &AllRouteSelector::default(),
+        &LegSelection::Exhaustive,
+        &BestResultSelector::default(),
+    );
+
+    if !insertion_ctx.solution.unassigned.is_empty() || !insertion_ctx.solution.required.is_empty() {
+        return None;
+    }
+
+    let mut insertion_ctx = insertion_ctx;
+    for _ in 0..MAX_IMPROVEMENT_PASSES {
+        match try_improve_route(&insertion_ctx) {
+            Some(improved_ctx) => insertion_ctx = improved_ctx,
+            None => break,
+        }
+    }
+
+    Some(insertion_ctx)
+}
+
+/// Returns the first solution which is better than the given one after relocating a single job.
+fn try_improve_route(insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+    let route_ctx = insertion_ctx.solution.routes.first()?;
+
+    route_ctx
+        .route()
+        .tour
+        .jobs()
+        .filter(|job| !insertion_ctx.solution.locked.contains(*job))
+        .cloned()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .filter_map(|job| relocate_job(insertion_ctx, &job))
+        .find(|new_insertion_ctx| {
+            insertion_ctx.problem.goal.total_order(new_insertion_ctx, insertion_ctx) == Ordering::Less
+        })
+}
+
+/// Removes the job from the route and inserts it back at its best position.
+fn relocate_job(insertion_ctx: &InsertionContext, job: &Job) -> Option<InsertionContext> {
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+    let route_ctx = new_insertion_ctx.solution.routes.first_mut()?;
+
+    if !route_ctx.route_mut().tour.remove(job) {
+        return None;
+    }
+    new_insertion_ctx.problem.goal.accept_route_state(route_ctx);
+
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        goal: &insertion_ctx.problem.goal,
+        job,
+        leg_selection: &leg_selection,
+        result_selector: &result_selector,
+    };
+
+    let insertion = eval_job_insertion_in_route(
+        &new_insertion_ctx,
+        &eval_ctx,
+        new_insertion_ctx.solution.routes.first()?,
+        InsertionPosition::Any,
+        InsertionResult::make_failure(),
+    );
+
+    match insertion {
+        InsertionResult::Success(success) => {
+            new_insertion_ctx.solution.required.push(job.clone());
+            apply_insertion_success(&mut new_insertion_ctx, success);
+            finalize_insertion_ctx(&mut new_insertion_ctx);
+            Some(new_insertion_ctx)
+        }
+        InsertionResult::Failure(_) => None,
+    }
+}
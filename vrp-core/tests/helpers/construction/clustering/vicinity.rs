@@ -7,7 +7,7 @@ use crate::models::problem::{Job, JobIdDimension};
 use crate::models::*;
 use rosomaxa::prelude::Float;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 pub type JobPlaces = Vec<(Option<Location>, Duration, Vec<(Float, Float)>)>;
@@ -102,10 +102,12 @@ pub fn create_cluster_config() -> ClusterConfig {
 
     ClusterConfig {
         profile: Profile::new(0, None),
+        speed_factors: HashMap::default(),
         threshold: ThresholdPolicy {
             moving_duration: 10.,
             moving_distance: 10.,
             min_shared_time: None,
+            min_shared_time_ratio: None,
             smallest_time_window: None,
             max_jobs_per_cluster: None,
         },
@@ -123,6 +125,10 @@ pub fn create_cluster_config() -> ClusterConfig {
                     &right.job,
                 )
             }),
+            cluster_score_fn: None,
         },
+        similarity_fn: None,
+        unite_skills: false,
+        demand_fit_fn: None,
     }
 }
@@ -1,4 +1,8 @@
-use crate::models::common::{Demand, MultiDimLoad, SingleDimLoad};
+use crate::construction::heuristics::RouteContext;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::common::{Cost, Demand, Location, MultiDimLoad, SingleDimLoad};
+use crate::models::Feature;
 
 pub fn create_simple_demand(size: i32) -> Demand<SingleDimLoad> {
     if size > 0 {
@@ -39,3 +43,22 @@ pub fn single_demand_as_multi(pickup: (i32, i32), delivery: (i32, i32)) -> Deman
 
     Demand { pickup: (make(pickup.0), make(pickup.1)), delivery: (make(delivery.0), make(delivery.1)) }
 }
+
+/// Creates a route context with a default vehicle and job activities at given locations.
+pub fn create_route_ctx_with_locations(locations: &[Location]) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(locations.iter().map(|&location| ActivityBuilder::with_location(location).build()))
+                .build(),
+        )
+        .build()
+}
+
+/// Returns feature's objective fitness of a solution with a single route visiting given locations.
+pub fn get_route_fitness_with_locations(feature: &Feature, locations: &[Location]) -> Cost {
+    let insertion_ctx =
+        TestInsertionContextBuilder::default().with_routes(vec![create_route_ctx_with_locations(locations)]).build();
+
+    feature.objective.as_ref().unwrap().fitness(&insertion_ctx)
+}
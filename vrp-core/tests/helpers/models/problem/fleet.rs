@@ -152,6 +152,11 @@ impl FleetBuilder {
         self
     }
 
+    pub fn add_vehicles_with_ids(&mut self, ids: &[&str]) -> &mut FleetBuilder {
+        self.vehicles.extend(ids.iter().map(|id| test_vehicle_with_id(id)));
+        self
+    }
+
     pub fn with_group_key_fn(&mut self, group_key_fn: GroupKeyFn) -> &mut FleetBuilder {
         self.group_key_fn = Some(group_key_fn);
         self
@@ -168,8 +168,15 @@ pub fn generate_matrix_routes(
         extras: Arc::new(extras),
     };
 
-    let solution =
-        Solution { cost: Cost::default(), registry, routes, unassigned: Default::default(), telemetry: None };
+    let solution = Solution {
+        cost: Cost::default(),
+        registry,
+        routes,
+        unassigned: Default::default(),
+        telemetry: None,
+        summary: None,
+        is_partial: false,
+    };
 
     (problem, solution)
 }
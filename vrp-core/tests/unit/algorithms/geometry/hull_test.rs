@@ -0,0 +1,43 @@
+use super::*;
+
+fn to_points(data: &[(Float, Float)]) -> Vec<Point> {
+    data.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+parameterized_test! {can_get_convex_hull, (points, expected), {
+    can_get_convex_hull_impl(to_points(points), to_points(expected));
+}}
+
+can_get_convex_hull! {
+    case01_square_with_inner: (&[(0., 0.), (1., 1.), (2., 0.), (2., 2.), (0., 2.)], &[(0., 0.), (2., 0.), (2., 2.), (0., 2.)]),
+    case02_duplicates: (&[(0., 0.), (0., 0.), (1., 0.), (0., 1.)], &[(0., 0.), (1., 0.), (0., 1.)]),
+    case03_collinear: (&[(0., 0.), (1., 0.), (2., 0.)], &[(0., 0.), (2., 0.)]),
+    case04_single: (&[(1., 1.)], &[(1., 1.)]),
+}
+
+fn can_get_convex_hull_impl(points: Vec<Point>, expected: Vec<Point>) {
+    let result = get_convex_hull(points.as_slice());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_get_perimeter() {
+    let points = to_points(&[(0., 0.), (3., 0.), (3., 4.)]);
+
+    assert_eq!(get_perimeter(points.as_slice()), 12.);
+}
+
+parameterized_test! {can_check_point_inside_polygon, (point, polygon, expected), {
+    let result = is_inside_polygon(&Point::new(point.0, point.1), to_points(polygon).as_slice());
+
+    assert_eq!(result, expected);
+}}
+
+can_check_point_inside_polygon! {
+    case01_inside_square: ((1., 1.), &[(0., 0.), (2., 0.), (2., 2.), (0., 2.)], true),
+    case02_outside_square: ((3., 1.), &[(0., 0.), (2., 0.), (2., 2.), (0., 2.)], false),
+    case03_inside_concave: ((0.5, 1.), &[(0., 0.), (2., 0.), (2., 2.), (1., 1.), (0., 2.)], true),
+    case04_in_concave_notch: ((1., 1.5), &[(0., 0.), (2., 0.), (2., 2.), (1., 1.), (0., 2.)], false),
+    case05_degenerate: ((0., 0.), &[(0., 0.), (1., 1.)], false),
+}
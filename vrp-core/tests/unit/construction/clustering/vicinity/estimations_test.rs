@@ -1,7 +1,10 @@
 use super::*;
+use crate::construction::features::JobSkills;
 use crate::helpers::construction::clustering::vicinity::*;
-use crate::helpers::models::problem::{get_job_id, TestPlace, TestSingleBuilder, TestTransportCost};
-use crate::models::problem::JobIdDimension;
+use crate::helpers::models::problem::{fake_routing, get_job_id, TestPlace, TestSingleBuilder, TestTransportCost};
+use crate::models::problem::{JobIdDimension, TravelTime};
+use crate::models::solution::Route;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 fn get_check_insertion_fn(disallow_insertion_list: Vec<&str>) -> Arc<CheckInsertionFn> {
     let disallow_insertion_list = disallow_insertion_list.into_iter().map(|id| id.to_string()).collect::<HashSet<_>>();
@@ -64,11 +67,172 @@ fn get_location(job: &Job) -> Location {
     job.to_single().places.first().unwrap().location.unwrap()
 }
 
+parameterized_test! {can_use_custom_similarity_fn, (outer_times, inner_times, expected), {
+    can_use_custom_similarity_fn_impl(outer_times, inner_times, expected);
+}}
+
+can_use_custom_similarity_fn! {
+    case_01_overlap_far_away: ((0., 10.), (2., 12.), 1),
+    case_02_small_overlap_far_away: ((0., 10.), (8., 18.), 0),
+    case_03_no_overlap_far_away: ((0., 10.), (20., 30.), 0),
+}
+
+fn can_use_custom_similarity_fn_impl(outer_times: (Float, Float), inner_times: (Float, Float), expected: usize) {
+    let outer = create_single_job("job1", vec![(Some(1), 2., vec![outer_times])]);
+    let inner = create_single_job("job2", vec![(Some(100), 3., vec![inner_times])]);
+    let transport = TestTransportCost::default();
+    let similarity_fn: SimilarityFn = Arc::new(|ctx: &SimilarityContext| ctx.shared_time >= 5.);
+    let config = ClusterConfig { similarity_fn: Some(similarity_fn), ..create_cluster_config() };
+
+    let dissimilarities = get_dissimilarities(&outer, &inner, &transport, &config)
+        .into_iter()
+        .filter(|(reachable, ..)| *reachable)
+        .collect::<Vec<_>>();
+
+    assert_eq!(dissimilarities.len(), expected);
+}
+
+#[test]
+fn can_use_thresholds_without_custom_similarity_fn() {
+    let outer = create_single_job("job1", vec![(Some(1), 2., vec![(0., 10.)])]);
+    let inner = create_single_job("job2", vec![(Some(100), 3., vec![(2., 12.)])]);
+    let transport = TestTransportCost::default();
+    let config = create_cluster_config();
+
+    let dissimilarities = get_dissimilarities(&outer, &inner, &transport, &config);
+
+    assert_eq!(dissimilarities.len(), 1);
+    assert!(!dissimilarities[0].0);
+}
+
+parameterized_test! {can_apply_profile_speed_factor, (profile_idx, expected), {
+    can_apply_profile_speed_factor_impl(profile_idx, expected);
+}}
+
+can_apply_profile_speed_factor! {
+    case_01_truck_default_factor: (0, true),
+    case_02_bike_slow_factor: (1, false),
+}
+
+fn can_apply_profile_speed_factor_impl(profile_idx: usize, expected: bool) {
+    let outer = create_single_job("job1", vec![(Some(1), 2., vec![(0., 10.)])]);
+    let inner = create_single_job("job2", vec![(Some(7), 3., vec![(2., 12.)])]);
+    let transport = TestTransportCost::default();
+    let config = ClusterConfig {
+        profile: Profile::new(profile_idx, None),
+        speed_factors: HashMap::from([(1, 0.5)]),
+        ..create_cluster_config()
+    };
+
+    let dissimilarities = get_dissimilarities(&outer, &inner, &transport, &config);
+
+    assert_eq!(dissimilarities.len(), 1);
+    assert_eq!(dissimilarities[0].0, expected);
+    assert_eq!(dissimilarities[0].2.commute.forward.distance, 6.);
+    assert_eq!(dissimilarities[0].2.commute.forward.duration, if expected { 6. } else { 12. });
+}
+
+struct RushHourTransportCost {
+    threshold: Timestamp,
+}
+
+impl TransportCost for RushHourTransportCost {
+    fn duration_approx(&self, _: &Profile, from: Location, to: Location) -> Duration {
+        fake_routing(from, to)
+    }
+
+    fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        fake_routing(from, to)
+    }
+
+    fn duration_at(&self, profile: &Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        let duration = self.duration_approx(profile, from, to);
+
+        if departure >= self.threshold {
+            duration * 2.
+        } else {
+            duration
+        }
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let departure = match travel_time {
+            TravelTime::Arrival(arrival) => arrival,
+            TravelTime::Departure(departure) => departure,
+        };
+
+        self.duration_at(&route.actor.vehicle.profile, from, to, departure)
+    }
+
+    fn distance(&self, _: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        fake_routing(from, to)
+    }
+}
+
+parameterized_test! {can_use_departure_from_time_windows, (outer_times, inner_times, expected), {
+    can_use_departure_from_time_windows_impl(outer_times, inner_times, expected);
+}}
+
+can_use_departure_from_time_windows! {
+    case_01_before_rush_hour: (vec![(0., 20.)], vec![(10., 30.)], Some((6., true))),
+    case_02_in_rush_hour: (vec![(100., 120.)], vec![(110., 130.)], Some((12., false))),
+    case_03_largest_shared_window: (vec![(0., 5.), (100., 120.)], vec![(0., 3.), (100., 120.)], Some((12., false))),
+    case_04_unbounded: (vec![(0., Float::MAX)], vec![(0., Float::MAX)], Some((6., true))),
+}
+
+fn can_use_departure_from_time_windows_impl(
+    outer_times: Vec<(Float, Float)>,
+    inner_times: Vec<(Float, Float)>,
+    expected: Option<(Duration, bool)>,
+) {
+    let outer = create_single_job("job1", vec![(Some(1), 2., outer_times)]);
+    let inner = create_single_job("job2", vec![(Some(7), 3., inner_times)]);
+    let transport = RushHourTransportCost { threshold: 50. };
+    let config = create_cluster_config();
+
+    let dissimilarities = get_dissimilarities(&outer, &inner, &transport, &config);
+
+    let result = dissimilarities.first().map(|(reachable, _, info)| (info.commute.forward.duration, *reachable));
+    assert_eq!(dissimilarities.len(), 1);
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_use_min_shared_time_ratio, (min_shared_time, min_shared_time_ratio, expected), {
+    can_use_min_shared_time_ratio_impl(min_shared_time, min_shared_time_ratio, expected);
+}}
+
+can_use_min_shared_time_ratio! {
+    case_01_ratio_of_shorter_service: (None, Some(0.5), 1),
+    case_02_ratio_above_shared_time: (None, Some(1.5), 0),
+    case_03_absolute_mis_sized: (Some(5.), None, 0),
+    case_04_absolute_has_priority: (Some(5.), Some(0.5), 0),
+    case_05_no_threshold: (None, None, 1),
+}
+
+fn can_use_min_shared_time_ratio_impl(
+    min_shared_time: Option<Duration>,
+    min_shared_time_ratio: Option<Float>,
+    expected: usize,
+) {
+    // NOTE shared time is 3, the shorter service time is 2
+    let outer = create_single_job("job1", vec![(Some(1), 2., vec![(0., 10.)])]);
+    let inner = create_single_job("job2", vec![(Some(2), 4., vec![(7., 20.)])]);
+    let transport = TestTransportCost::default();
+    let mut config = create_cluster_config();
+    config.threshold.min_shared_time = min_shared_time;
+    config.threshold.min_shared_time_ratio = min_shared_time_ratio;
+
+    let dissimilarities = get_dissimilarities(&outer, &inner, &transport, &config);
+
+    assert_eq!(dissimilarities.len(), expected);
+}
+
 parameterized_test! {can_get_dissimilarities, (places_outer, places_inner, threshold, serving, expected), {
     let threshold = ThresholdPolicy {
         moving_duration: threshold.0,
         moving_distance: threshold.1,
         min_shared_time: threshold.2,
+        min_shared_time_ratio: None,
         smallest_time_window: None,
         max_jobs_per_cluster: None,
     };
@@ -114,6 +278,12 @@ can_get_dissimilarities! {
         (5., 5., None), ServingPolicy::Fixed  { value: 20., parking: 0. },
         vec![(0, 0, 20., (1, 1., 1.), (1, 1., 1.))]
     ),
+    case_05_dynamic_serving_policy: (
+        vec![(Some(1), 2., vec![(0., 10.)])],
+        vec![(Some(2), 3., vec![(5., 15.)])],
+        (5., 5., None), ServingPolicy::Dynamic { service_time_fn: Arc::new(|d| d * 0.5 + 10.), parking: 0. },
+        vec![(0, 0, 11.5, (1, 1., 1.), (1, 1., 1.))]
+    ),
 
     case_06_threshold: (
         vec![(Some(1), 2., vec![(0., 10.)])],
@@ -194,6 +364,7 @@ fn can_get_dissimilarities_impl(
     assert_eq!(dissimilarities.len(), expected.len());
     dissimilarities.into_iter().zip(expected).for_each(|(result, expected)| {
         assert_eq!(result.1, expected.0);
+        assert_eq!(result.2.service_time, expected.1.service_time);
         compare_visit_info(&result.2, &expected.1);
     });
 }
@@ -435,6 +606,7 @@ pub fn can_get_clusters_impl(
         moving_duration,
         moving_distance: 10.0,
         min_shared_time: None,
+        min_shared_time_ratio: None,
         smallest_time_window: None,
         max_jobs_per_cluster,
     };
@@ -471,3 +643,293 @@ pub fn can_get_clusters_impl(
         },
     );
 }
+
+#[test]
+fn can_get_same_jobs_dissimilarities_as_sequential_estimation() {
+    let jobs_places = (0..20).map(|idx| vec![(Some(idx % 7), 2., vec![(0., 100.)])]).collect();
+    let transport = TestTransportCost::default();
+    let config = create_cluster_config();
+    let jobs = create_jobs(jobs_places);
+
+    let result = get_jobs_dissimilarities(jobs.as_slice(), &transport, &config);
+
+    assert_eq!(result.len(), jobs.len());
+    jobs.iter().for_each(|outer| {
+        let result = result.get(outer).expect("no dissimilarities for job");
+        let expected = jobs
+            .iter()
+            .filter(|inner| outer != *inner)
+            .map(|inner| (inner, get_dissimilarities(outer, inner, &transport, &config)))
+            .filter(|(_, dissimilarities)| !dissimilarities.is_empty())
+            .collect::<Vec<_>>();
+
+        assert_eq!(result.len(), expected.len());
+        expected.into_iter().for_each(|(inner, expected)| {
+            let result = result.get(inner).expect("no dissimilarity for inner job");
+            assert_eq!(result.len(), expected.len());
+            result.iter().zip(expected.iter()).for_each(|(result, expected)| {
+                assert_eq!((result.0, result.1), (expected.0, expected.1));
+                assert_eq!(result.2.service_time, expected.2.service_time);
+                compare_visit_info(&result.2, &expected.2);
+            });
+        });
+    });
+}
+
+#[test]
+fn can_update_jobs_dissimilarities_with_new_job() {
+    let jobs_places = (0..10).map(|idx| vec![(Some(idx % 7), 2., vec![(0., 100.)])]).collect();
+    let transport = TestTransportCost::default();
+    let config = create_cluster_config();
+    let jobs = create_jobs(jobs_places);
+    let (new_job, old_jobs) = jobs.split_last().unwrap();
+    let expected = get_jobs_dissimilarities(jobs.as_slice(), &transport, &config);
+
+    let mut result = get_jobs_dissimilarities(old_jobs, &transport, &config);
+    update_dissimilarities(&mut result, new_job, &transport, &config);
+
+    assert_eq!(result.len(), expected.len());
+    expected.iter().for_each(|(outer, expected)| {
+        let result = result.get(outer).expect("no dissimilarities for job");
+        assert_eq!(result.len(), expected.len());
+        expected.iter().for_each(|(inner, expected)| {
+            let result = result.get(inner).expect("no dissimilarity for inner job");
+            assert_eq!(result.len(), expected.len());
+            result.iter().zip(expected.iter()).for_each(|(result, expected)| {
+                assert_eq!((result.0, result.1), (expected.0, expected.1));
+                assert_eq!(result.2.service_time, expected.2.service_time);
+                compare_visit_info(&result.2, &expected.2);
+            });
+        });
+    });
+}
+
+#[test]
+fn can_get_same_clusters_regardless_of_estimates_order() {
+    let threshold = ThresholdPolicy {
+        moving_duration: 2.5,
+        moving_distance: 10.0,
+        min_shared_time: None,
+        min_shared_time_ratio: None,
+        smallest_time_window: None,
+        max_jobs_per_cluster: None,
+    };
+    // NOTE groups of jobs with the same layout produce clusters which are equal by global ordering
+    let jobs_places =
+        (0..4).flat_map(|group| (0..3).map(move |idx| vec![(Some(group * 50 + idx), 2., vec![(0., 100.)])])).collect();
+    let transport = TestTransportCost::default();
+    let default_config = create_cluster_config();
+    let building = BuilderPolicy {
+        // NOTE global ordering without tie-breaking
+        ordering_global_fn: Arc::new(|(_, left), (_, right)| left.len().cmp(&right.len())),
+        ..default_config.building.clone()
+    };
+    let config = ClusterConfig { threshold, building, ..default_config };
+    let constraint = create_goal_context_with_vicinity(vec![]);
+    let check_insertion = get_check_insertion_fn(vec![]);
+    let jobs = create_jobs(jobs_places);
+    let estimates = get_jobs_dissimilarities(jobs.as_slice(), &transport, &config);
+    let get_cluster_ids = |estimates: HashMap<Job, DissimilarityIndex>| {
+        get_clusters(&constraint, estimates, &config, check_insertion.as_ref())
+            .into_iter()
+            .map(|(center, clustered)| {
+                (get_job_id(&center).clone(), clustered.iter().map(|job| get_job_id(job).clone()).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let expected = get_cluster_ids(estimates.clone());
+
+    assert_eq!(expected.len(), 4);
+    (0..10).for_each(|_| {
+        // NOTE re-collect estimates to get a map with different iteration order
+        let estimates = estimates.clone().into_iter().collect::<HashMap<_, _>>();
+        assert_eq!(get_cluster_ids(estimates), expected);
+    });
+}
+
+#[test]
+fn can_limit_cluster_size_with_many_reachable_jobs() {
+    let max_jobs = 3;
+    let threshold = ThresholdPolicy {
+        moving_duration: 100.,
+        moving_distance: 100.,
+        min_shared_time: None,
+        min_shared_time_ratio: None,
+        smallest_time_window: None,
+        max_jobs_per_cluster: Some(max_jobs),
+    };
+    let jobs_places = (0..12).map(|idx| vec![(Some(idx), 2., vec![(0., 1000.)])]).collect();
+    let transport = TestTransportCost::default();
+    let config = ClusterConfig { threshold, ..create_cluster_config() };
+    let constraint = create_goal_context_with_vicinity(vec![]);
+    let check_insertion = get_check_insertion_fn(vec![]);
+    let jobs = create_jobs(jobs_places);
+    let estimates = get_jobs_dissimilarities(jobs.as_slice(), &transport, &config);
+
+    let result = get_clusters(&constraint, estimates, &config, check_insertion.as_ref());
+
+    assert!(!result.is_empty());
+    assert!(result.iter().all(|(_, clustered)| clustered.len() <= max_jobs));
+}
+
+parameterized_test! {can_add_job_with_skills, (cluster_skills, candidate_skills, unite_skills, expected), {
+    can_add_job_with_skills_impl(cluster_skills, candidate_skills, unite_skills, expected);
+}}
+
+can_add_job_with_skills! {
+    case_01_disjoint_not_united: (Some(vec!["a"]), Some(vec!["b"]), false, Some(vec!["a"])),
+    case_02_disjoint_united: (Some(vec!["a"]), Some(vec!["b"]), true, None),
+    case_03_shared_united: (Some(vec!["a", "b"]), Some(vec!["b", "c"]), true, Some(vec!["a", "b", "c"])),
+    case_04_only_candidate_united: (None, Some(vec!["b"]), true, Some(vec!["b"])),
+    case_05_no_skills_united: (None, None, true, Some(vec![])),
+}
+
+fn can_add_job_with_skills_impl(
+    cluster_skills: Option<Vec<&str>>,
+    candidate_skills: Option<Vec<&str>>,
+    unite_skills: bool,
+    expected: Option<Vec<&str>>,
+) {
+    let create_job = |job_id: &str, location: Location, skills: Option<Vec<&str>>| {
+        let mut builder = TestSingleBuilder::default();
+        builder.id(job_id).places(vec![(Some(location), 2., vec![(0., 100.)])]);
+        if let Some(skills) = skills {
+            builder.dimens_mut().set_job_skills(JobSkills::new(
+                Some(skills.into_iter().map(|skill| skill.to_string()).collect()),
+                None,
+                None,
+            ));
+        }
+        builder.build_as_job_ref()
+    };
+    let config = ClusterConfig { visiting: VisitPolicy::ClosedContinuation, unite_skills, ..create_cluster_config() };
+    let cluster = create_job("cluster", 1, cluster_skills);
+    let candidate = create_job("job1", 5, candidate_skills);
+    let constraint = create_goal_context_with_vicinity(vec![]);
+    let check_insertion = get_check_insertion_fn(vec![]);
+    let transport = TestTransportCost::default();
+    let dissimilarity_info = get_dissimilarities(&cluster, &candidate, &transport, &config);
+
+    let result = try_add_job(
+        &constraint,
+        0,
+        &cluster,
+        (&candidate, &dissimilarity_info),
+        &config,
+        |info: &ClusterInfo| info.commute.clone(),
+        check_insertion.as_ref(),
+    );
+
+    let result = result.map(|(merged, _)| {
+        let mut skills = merged
+            .dimens()
+            .get_job_skills()
+            .and_then(|skills| skills.all_of.as_ref())
+            .map(|skills| skills.iter().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        skills.sort();
+        skills
+    });
+    assert_eq!(result, expected.map(|skills| skills.into_iter().map(|skill| skill.to_string()).collect()));
+}
+
+parameterized_test! {can_build_job_cluster_with_score_fn, (use_score_fn, expected), {
+    can_build_job_cluster_with_score_fn_impl(use_score_fn, expected);
+}}
+
+can_build_job_cluster_with_score_fn! {
+    case_01_count: (false, vec![0, 1, 2]),
+    case_02_score: (true, vec![0, 3]),
+}
+
+fn can_build_job_cluster_with_score_fn_impl(use_score_fn: bool, expected: Vec<usize>) {
+    // NOTE assume that travel saved by clustering grows with distance to the clustered job
+    let score_fn: ClusterScoreFn = Arc::new(|cluster: &Job| {
+        cluster.dimens().get_cluster_info().map_or(0., |infos| infos.iter().map(|info| info.commute.duration()).sum())
+    });
+    let cluster_score_fn = if use_score_fn { Some(score_fn) } else { None };
+    let config = create_cluster_config();
+    let config = ClusterConfig { building: BuilderPolicy { cluster_score_fn, ..config.building }, ..config };
+    let transport = TestTransportCost::default();
+    let constraint = create_goal_context_with_vicinity(vec![]);
+    let check_insertion = get_check_insertion_fn(vec![]);
+    let jobs = create_jobs(vec![
+        vec![(Some(0), 2., vec![(0., 100.)]), (Some(40), 2., vec![(200., 300.)])],
+        vec![(Some(1), 2., vec![(0., 100.)])],
+        vec![(Some(2), 2., vec![(0., 100.)])],
+        vec![(Some(48), 2., vec![(200., 300.)])],
+    ]);
+    let estimates = get_jobs_dissimilarities(jobs.as_slice(), &transport, &config);
+
+    let result = build_job_cluster(
+        &constraint,
+        jobs.first().unwrap(),
+        &estimates,
+        &HashSet::default(),
+        &config,
+        check_insertion.as_ref(),
+    )
+    .expect("no cluster built");
+
+    let result = result.dimens().get_cluster_info().unwrap().iter().map(|info| info.job.clone()).collect::<Vec<_>>();
+    let expected = expected.into_iter().map(|idx| jobs.get(idx).unwrap().clone()).collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_skip_candidates_exceeding_max_demand() {
+    let max_demand = 5;
+    let get_demand =
+        |job: &Job| job.dimens().get_job_demand::<SingleDimLoad>().map_or(0, |demand| demand.delivery.0.value);
+    let transport = TestTransportCost::default();
+    let constraint = create_goal_context_with_vicinity(vec![]);
+    let jobs = (0..6)
+        .map(|idx| {
+            TestSingleBuilder::default()
+                .id(format!("job{}", idx + 1).as_str())
+                .places(vec![(Some(idx), 2., vec![(0., 100.)])])
+                .demand(Demand::delivery(2))
+                .build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+    let build_cluster = |demand_fit_fn: Option<DemandFitFn>| {
+        let config = ClusterConfig { demand_fit_fn, ..create_cluster_config() };
+        let estimates = get_jobs_dissimilarities(jobs.as_slice(), &transport, &config);
+        let counter = Arc::new(AtomicUsize::new(0));
+        let check_insertion: Arc<CheckInsertionFn> = Arc::new({
+            let counter = counter.clone();
+            move |job: &Job| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                let clustered = job.dimens().get_clustered_jobs().map_or(0, |jobs| jobs.iter().map(get_demand).sum());
+
+                if get_demand(job) + clustered > max_demand {
+                    Err(ViolationCode::unknown())
+                } else {
+                    Ok(())
+                }
+            }
+        });
+
+        let cluster = build_job_cluster(
+            &constraint,
+            jobs.first().unwrap(),
+            &estimates,
+            &HashSet::default(),
+            &config,
+            check_insertion.as_ref(),
+        )
+        .expect("no cluster built");
+        let cluster =
+            cluster.dimens().get_cluster_info().unwrap().iter().map(|info| info.job.clone()).collect::<Vec<_>>();
+
+        (cluster, counter.load(Ordering::Relaxed))
+    };
+
+    let (original_cluster, original_calls) = build_cluster(None);
+    let (cluster, calls) = build_cluster(Some(create_demand_fit_fn(SingleDimLoad::new(max_demand))));
+
+    assert_eq!(cluster, original_cluster);
+    assert_eq!(cluster.len(), 2);
+    assert!(calls < original_calls);
+}
@@ -3,6 +3,7 @@ use crate::helpers::construction::clustering::vicinity::*;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::domain::*;
 use crate::helpers::models::problem::*;
+use crate::models::solution::Registry;
 
 #[test]
 fn can_get_check_insertion() {
@@ -44,3 +45,79 @@ pub fn can_create_job_clusters() {
     let clustered = &cluster.1;
     assert_eq!(clustered.len(), 2);
 }
+
+#[test]
+fn can_get_unassigned_job_groups() {
+    let jobs = vec![(1, 0), (2, 2), (3, 4), (4, 100), (5, 103)]
+        .into_iter()
+        .map(|(idx, location)| {
+            TestSingleBuilder::default().id(format!("job{idx}").as_str()).location(Some(location)).build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+    let problem =
+        ProblemBuilder::default().with_jobs(jobs.clone()).with_goal(create_goal_context_with_vicinity(vec![])).build();
+    let solution = Solution {
+        cost: 0.,
+        registry: Registry::new(&problem.fleet, test_random()),
+        routes: vec![],
+        unassigned: jobs.iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)).collect(),
+        telemetry: None,
+        summary: None,
+        is_partial: false,
+    };
+    let coord_index = |location: Location| Some(Point::new(location as Float, 0.));
+
+    let groups = get_unassigned_job_groups(&problem, &solution, &create_cluster_config(), &coord_index);
+
+    let groups = groups
+        .iter()
+        .map(|group| {
+            let mut ids = group.jobs.iter().map(|job| get_job_id(job).as_str()).collect::<Vec<_>>();
+            ids.sort();
+            (ids, group.centroid.as_ref().map(|point| point.x))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(groups, vec![(vec!["job1", "job2", "job3"], Some(2.)), (vec!["job4", "job5"], Some(101.5))]);
+}
+
+#[test]
+fn can_dissolve_cluster_into_original_jobs() {
+    let jobs = vec![("job1", 1, 5., (0., 100.)), ("job2", 3, 7., (10., 90.))]
+        .into_iter()
+        .map(|(id, location, duration, (start, end))| {
+            TestSingleBuilder::default()
+                .id(id)
+                .location(Some(location))
+                .duration(duration)
+                .times(vec![TimeWindow::new(start, end)])
+                .build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+    let problem =
+        ProblemBuilder::default().with_jobs(jobs.clone()).with_goal(create_goal_context_with_vicinity(vec![])).build();
+    let clusters = create_job_clusters(Arc::new(problem), Arc::new(Environment::default()), &create_cluster_config());
+    assert_eq!(clusters.len(), 1);
+    let (cluster, _) = clusters.first().unwrap();
+    assert_ne!(cluster.to_single().places[0].duration, 5.);
+
+    let mut dissolved = dissolve_cluster(cluster);
+
+    dissolved.sort_by(|a, b| get_job_id(a).cmp(get_job_id(b)));
+    assert_eq!(dissolved, jobs);
+    dissolved.iter().zip(jobs.iter()).for_each(|(result, expected)| {
+        let (result, expected) = (&result.to_single().places[0], &expected.to_single().places[0]);
+        assert_eq!(result.location, expected.location);
+        assert_eq!(result.duration, expected.duration);
+        let get_times = |place: &crate::models::problem::Place| {
+            place.times.iter().filter_map(|t| t.as_time_window()).collect::<Vec<_>>()
+        };
+        assert_eq!(get_times(result), get_times(expected));
+    });
+}
+
+#[test]
+fn can_dissolve_non_cluster_job_into_itself() {
+    let job = TestSingleBuilder::default().id("job1").build_as_job_ref();
+
+    assert_eq!(dissolve_cluster(&job), vec![job]);
+}
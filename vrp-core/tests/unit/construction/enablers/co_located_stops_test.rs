@@ -0,0 +1,75 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, TimeWindow};
+use crate::models::problem::{JobIdDimension, SimpleActivityCost};
+
+fn create_stop(id: &str, location: Location, duration: Duration) -> Activity {
+    ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 1000.), duration)
+        .job(Some(TestSingleBuilder::default().id(id).location(Some(location)).build_shared()))
+        .build()
+}
+
+fn create_activity_cost(is_combined: bool) -> Arc<dyn ActivityCost> {
+    let inner: Arc<dyn ActivityCost> = Arc::new(SimpleActivityCost::default());
+    if !is_combined {
+        return inner;
+    }
+
+    let has_id = |expected: &'static str| -> StopSingleFn {
+        Arc::new(move |single: &Single| single.dimens.get_job_id().map_or(false, |id| id == expected))
+    };
+
+    Arc::new(CoLocatedStopsActivityCost::new(inner, has_id("break"), has_id("refuel")))
+}
+
+/// Returns total stop time: from arrival at the first stop till departure from the last one.
+fn get_stop_time(stops: Vec<(&str, Location, Duration)>, is_combined: bool) -> Duration {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activity(create_stop("job1", 5, 1.))
+                .add_activities(stops.into_iter().map(|(id, location, duration)| create_stop(id, location, duration)))
+                .add_activity(create_stop("job2", 15, 1.))
+                .build(),
+        )
+        .build();
+
+    update_route_schedule(
+        &mut route_ctx,
+        create_activity_cost(is_combined).as_ref(),
+        TestTransportCost::new_shared().as_ref(),
+    );
+
+    let tour = &route_ctx.route().tour;
+    let (first, last) = (tour.get(2).unwrap(), tour.get(3).unwrap());
+
+    last.schedule.departure - first.schedule.arrival
+}
+
+parameterized_test! {can_count_co_located_stop_time_once, (stops, is_combined, expected), {
+    let result = get_stop_time(stops, is_combined);
+
+    assert_eq!(result, expected);
+}}
+
+can_count_co_located_stop_time_once! {
+    case01_refuel_then_break: (vec![("refuel", 10, 30.), ("break", 10, 20.)], true, 30.),
+    case02_break_then_refuel: (vec![("break", 10, 20.), ("refuel", 10, 30.)], true, 30.),
+    case03_not_combined: (vec![("refuel", 10, 30.), ("break", 10, 20.)], false, 50.),
+    case04_different_locations: (vec![("refuel", 10, 30.), ("break", 11, 20.)], true, 51.),
+    case05_same_kind: (vec![("break", 10, 30.), ("break", 10, 20.)], true, 50.),
+    case06_not_stops: (vec![("job3", 10, 30.), ("break", 10, 20.)], true, 50.),
+}
+
+#[test]
+fn can_reduce_route_duration_with_co_located_stops() {
+    let stops = || vec![("refuel", 10, 30.), ("break", 10, 20.)];
+
+    let combined = get_stop_time(stops(), true);
+    let separate = get_stop_time(stops(), false);
+
+    assert!(combined < separate);
+    assert_eq!(separate - combined, 20.);
+}
@@ -0,0 +1,146 @@
+use super::*;
+use crate::construction::enablers::{DynamicActivityCost, DynamicTransportCost, ReservedTimesIndex};
+use crate::construction::features::TransportFeatureBuilder;
+use crate::construction::heuristics::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::{Feature, ViolationCode};
+use std::sync::Arc;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+type ActivityData = (Location, (Timestamp, Timestamp), Duration);
+
+// NOTE two days horizon with rests in [70, 100) and [170, 200)
+fn create_horizon() -> DayHorizon {
+    DayHorizon::new(100., 70., 30.).unwrap()
+}
+
+fn create_feature_and_route(activities: Vec<ActivityData>) -> (Feature, RouteContext) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![TestVehicleBuilder::default().id("v1").build()])
+        .build();
+    let actor = fleet.actors.first().unwrap().clone();
+    let reserved_times_idx: ReservedTimesIndex =
+        vec![(actor, create_horizon().get_reserved_times(&TimeWindow::new(0., 300.)))].into_iter().collect();
+
+    let activities = activities.into_iter().map(|(loc, (start, end), dur)| {
+        ActivityBuilder::with_location_tw_and_duration(loc, TimeWindow::new(start, end), dur).build()
+    });
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    let feature = TransportFeatureBuilder::new("minimize_costs")
+        .set_violation_code(VIOLATION_CODE)
+        .set_transport_cost(Arc::new(
+            DynamicTransportCost::new(reserved_times_idx.clone(), Arc::new(TestTransportCost::default())).unwrap(),
+        ))
+        .set_activity_cost(Arc::new(DynamicActivityCost::new(reserved_times_idx).unwrap()))
+        .build_minimize_cost()
+        .unwrap();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    (feature, route_ctx)
+}
+
+fn get_schedules(route_ctx: &RouteContext) -> Vec<(Timestamp, Timestamp)> {
+    route_ctx.route().tour.all_activities().map(|a| (a.schedule.arrival, a.schedule.departure)).collect()
+}
+
+parameterized_test! {can_get_reserved_times, (time, expected), {
+    can_get_reserved_times_impl(time, expected);
+}}
+
+can_get_reserved_times! {
+    case01_single_day: ((0., 60.), vec![]),
+    case02_two_days: ((0., 300.), vec![70., 170., 270.]),
+    case03_starts_within_rest: ((90., 150.), vec![70.]),
+    case04_ends_at_rest_start: ((0., 70.), vec![]),
+}
+
+fn can_get_reserved_times_impl(time: (Timestamp, Timestamp), expected: Vec<Timestamp>) {
+    let result = create_horizon().get_reserved_times(&TimeWindow::new(time.0, time.1));
+
+    let result = result
+        .into_iter()
+        .map(|span| match span.time {
+            TimeSpan::Window(tw) => {
+                assert_eq!(tw.start, tw.end);
+                assert_eq!(span.duration, 30.);
+                tw.start
+            }
+            TimeSpan::Offset(_) => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_rest_intersection, (interval, expected), {
+    can_detect_rest_intersection_impl(interval, expected);
+}}
+
+can_detect_rest_intersection! {
+    case01_before_rest: ((60., 69.), false),
+    case02_till_rest_start: ((50., 70.), false),
+    case03_crosses_rest_start: ((69., 71.), true),
+    case04_before_midnight: ((90., 95.), true),
+    case05_after_rest: ((100., 150.), false),
+    case06_second_day_rest: ((195., 215.), true),
+    case07_previous_day_rest: ((-25., -15.), true),
+}
+
+fn can_detect_rest_intersection_impl(interval: (Timestamp, Timestamp), expected: bool) {
+    assert_eq!(create_horizon().intersects_rest(interval.0, interval.1), expected);
+}
+
+parameterized_test! {can_evaluate_activity, (target, activities, expected_schedules), {
+    can_evaluate_activity_impl(target, activities, expected_schedules);
+}}
+
+can_evaluate_activity! {
+    case01_early_job_rest_on_return: ((60, (0., 100.), 5.), vec![],
+        vec![(0., 0.), (60., 65.), (155., 155.)]),
+
+    case02_late_job_pushed_to_day_two: ((80, (0., 130.), 5.), vec![],
+        vec![(0., 0.), (110., 115.), (225., 225.)]),
+
+    case03_late_job_cannot_be_served_on_day_one: ((80, (0., 100.), 5.), vec![],
+        vec![]),
+
+    case04_straddles_midnight_service_interrupted: ((68, (60., 130.), 5.), vec![],
+        vec![(0., 0.), (68., 103.), (201., 201.)]),
+
+    case05_rest_violates_tail: ((50, (0., 100.), 30.), vec![(10, (0., 200.), 5.), (20, (0., 60.), 5.)],
+        vec![]),
+}
+
+fn can_evaluate_activity_impl(
+    target: ActivityData,
+    activities: Vec<ActivityData>,
+    expected_schedules: Vec<(Timestamp, Timestamp)>,
+) {
+    let (feature, mut route_ctx) = create_feature_and_route(activities);
+    let (feature_constraint, feature_state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    let (loc, (start, end), dur) = target;
+    let prev = route_ctx.route().tour.get(0).unwrap();
+    let target = ActivityBuilder::with_location_tw_and_duration(loc, TimeWindow::new(start, end), dur).build();
+    let next = route_ctx.route().tour.get(1);
+    let activity_ctx = ActivityContext { index: 0, prev, target: &target, next };
+
+    let is_violation = feature_constraint.evaluate(&MoveContext::activity(&route_ctx, &activity_ctx)).is_some();
+
+    assert_eq!(is_violation, expected_schedules.is_empty());
+    if !is_violation {
+        route_ctx.route_mut().tour.insert_at(target, 1);
+        feature_state.accept_route_state(&mut route_ctx);
+        assert_eq!(get_schedules(&route_ctx), expected_schedules)
+    }
+}
+
+#[test]
+fn can_reject_invalid_horizon() {
+    assert!(DayHorizon::new(0., 0., 10.).is_err());
+    assert!(DayHorizon::new(100., 100., 10.).is_err());
+    assert!(DayHorizon::new(100., 80., 100.).is_err());
+}
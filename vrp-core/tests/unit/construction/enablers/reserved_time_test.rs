@@ -141,7 +141,7 @@ can_update_state_for_reserved_time! {
 
     case04_two_inside_service: ((0, 0, 0., 100.), (35., 40.),
               vec![(10, (0., 20.), 10.), (20, (0., 50.), 10.)],
-              vec![Some(0.), Some(15.), Some(50.), None],
+              vec![Some(0.), Some(20.), Some(50.), None],
               vec![(0., 0.), (10., 20.), (30., 45.), (65., 65.)]),
 }
 
@@ -286,3 +286,35 @@ fn can_avoid_reserved_time_when_driving_impl(
 
     assert_eq!(get_schedules(&route_ctx), expected_schedules)
 }
+
+parameterized_test! {can_estimate_arrival, (reserved_time, activity, departure, expected), {
+    can_estimate_arrival_impl(reserved_time, activity, departure, expected);
+}}
+
+can_estimate_arrival! {
+    case01_no_reserved_time: ((10., 10.), ((0., 100.), 10.), 60., 50.),
+    case02_reserved_time_inside_service: ((45., 10.), ((0., 100.), 10.), 60., 40.),
+    case03_reserved_time_limited_by_time_window: ((45., 10.), ((45., 100.), 10.), 60., 45.),
+    case04_reserved_time_inside_waiting_till_departure: ((150., 10.), ((0., 100.), 10.), 200., 100.),
+}
+
+fn can_estimate_arrival_impl(
+    reserved_time: (Timestamp, Duration),
+    activity: ((Timestamp, Timestamp), Duration),
+    departure: Timestamp,
+    expected: Timestamp,
+) {
+    let reserved_time = ReservedTimeSpan {
+        time: TimeSpan::Window(TimeWindow::new(reserved_time.0, reserved_time.0)),
+        duration: reserved_time.1,
+    };
+    let (_, _, route_ctx) = create_feature_and_route((0, 0, 0., 1000.), vec![], reserved_time.clone());
+    let reserved_times_idx = vec![(route_ctx.route().actor.clone(), vec![reserved_time])].into_iter().collect();
+    let activity_cost = DynamicActivityCost::new(reserved_times_idx).unwrap();
+    let ((start, end), duration) = activity;
+    let activity = ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(start, end), duration).build();
+
+    let result = activity_cost.estimate_arrival(route_ctx.route(), &activity, departure);
+
+    assert_eq!(result, expected);
+}
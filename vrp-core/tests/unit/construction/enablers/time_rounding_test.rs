@@ -0,0 +1,94 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::construction::heuristics::RouteContext;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::{SimpleActivityCost, SimpleTransportCost};
+use rosomaxa::prelude::Float;
+
+fn create_transport() -> Arc<dyn TransportCost> {
+    let size = 4;
+    let durations = (0..size * size)
+        .map(|idx| {
+            let (from, to) = ((idx / size) as Float, (idx % size) as Float);
+            (from - to).abs() * 1.37
+        })
+        .collect::<Vec<_>>();
+
+    Arc::new(SimpleTransportCost::new(durations.clone(), durations).unwrap())
+}
+
+fn create_route_ctx() -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities((1..4).map(|location| {
+                    ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 1000.), 0.6).build()
+                }))
+                .build(),
+        )
+        .build()
+}
+
+fn get_schedules(granularity: Option<Duration>) -> Vec<(Timestamp, Timestamp)> {
+    let (transport, activity): (Arc<dyn TransportCost>, Arc<dyn ActivityCost>) = match granularity {
+        Some(granularity) => (
+            Arc::new(TimeRoundingTransportCost::new(create_transport(), granularity)),
+            Arc::new(TimeRoundingActivityCost::new(Arc::new(SimpleActivityCost::default()), granularity)),
+        ),
+        None => (create_transport(), Arc::new(SimpleActivityCost::default())),
+    };
+    let mut route_ctx = create_route_ctx();
+
+    update_route_schedule(&mut route_ctx, activity.as_ref(), transport.as_ref());
+
+    route_ctx.route().tour.all_activities().skip(1).map(|a| (a.schedule.arrival, a.schedule.departure)).collect()
+}
+
+parameterized_test! {can_round_schedule_times_to_granularity, granularity, {
+    can_round_schedule_times_to_granularity_impl(granularity);
+}}
+
+can_round_schedule_times_to_granularity! {
+    case01_one: 1.,
+    case02_half: 0.5,
+    case03_ten: 10.,
+}
+
+fn can_round_schedule_times_to_granularity_impl(granularity: Duration) {
+    let schedules = get_schedules(Some(granularity));
+
+    assert_eq!(schedules.len(), 4);
+    schedules.iter().for_each(|&(arrival, departure)| {
+        assert_eq!(arrival, (arrival / granularity).round() * granularity);
+        assert_eq!(departure, (departure / granularity).round() * granularity);
+        assert!(arrival <= departure);
+    });
+}
+
+#[test]
+fn can_produce_identical_rounded_times_between_runs() {
+    let first = get_schedules(Some(1.));
+    let second = get_schedules(Some(1.));
+
+    assert_eq!(first, second);
+    assert_eq!(first, vec![(2., 3.), (5., 6.), (8., 9.), (14., 14.)]);
+}
+
+#[test]
+fn can_round_times_up() {
+    let rounded = get_schedules(Some(1.));
+    let original = get_schedules(None);
+
+    rounded.iter().zip(original.iter()).for_each(|(rounded, original)| {
+        assert!(rounded.0 >= original.0);
+        assert!(rounded.1 >= original.1);
+    });
+}
+
+#[test]
+fn can_keep_original_times_without_rounding() {
+    let schedules = get_schedules(None);
+
+    assert!(schedules.iter().any(|&(arrival, _)| arrival.fract() != 0.));
+}
@@ -13,6 +13,10 @@ struct JobTypeDimenKey;
 struct VehicleIdDimenKey;
 
 fn create_break_feature() -> Feature {
+    create_break_feature_with_interval(None)
+}
+
+fn create_break_feature_with_interval(min_interval: Option<Duration>) -> Feature {
     fn is_break_job(single: &Single) -> bool {
         single.dimens.get_value::<JobTypeDimenKey, String>().map_or(false, |job_type| job_type == "break")
     }
@@ -28,6 +32,7 @@ fn create_break_feature() -> Feature {
 
             job_vehicle_id.zip(vehicle_id).map_or(false, |(a, b)| a == b)
         })
+        .set_min_interval(move |_| min_interval)
         .build()
         .unwrap()
 }
@@ -108,3 +113,62 @@ fn can_skip_merge_breaks_impl(source: Job, candidate: Job, expected: Result<(),
 
     assert_eq!(result, expected);
 }
+
+fn create_activity(location: Location, single: Arc<Single>, schedule: (Timestamp, Timestamp)) -> Activity {
+    ActivityBuilder::with_location(location).job(Some(single)).schedule(Schedule::new(schedule.0, schedule.1)).build()
+}
+
+fn create_route_with_breaks(breaks: Vec<(Timestamp, Timestamp)>) -> RouteContext {
+    let activities = vec![(1, create_single("job1", 1), (10., 20.))]
+        .into_iter()
+        .chain(breaks.into_iter().map(|schedule| (2, create_break("v1", Some(2)), schedule)))
+        .chain(vec![(3, create_single("job2", 3), (100., 110.)), (4, create_single("job3", 4), (200., 210.))])
+        .map(|(location, single, schedule)| create_activity(location, single, schedule));
+
+    RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build()
+}
+
+parameterized_test! {can_evaluate_break_min_interval, (index, min_interval, expected), {
+    can_evaluate_break_min_interval_impl(index, min_interval, expected);
+}}
+
+can_evaluate_break_min_interval! {
+    case01_back_to_back_after: (2, Some(50.), Some(VIOLATION_CODE)),
+    case02_back_to_back_before: (1, Some(50.), Some(VIOLATION_CODE)),
+    case03_far_enough: (4, Some(50.), None),
+    case04_no_interval: (2, None, None),
+}
+
+fn can_evaluate_break_min_interval_impl(index: usize, min_interval: Option<Duration>, expected: Option<ViolationCode>) {
+    let route_ctx = create_route_with_breaks(vec![(20., 30.)]);
+    let target = create_activity(2, create_break("v1", Some(2)), (0., 0.));
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let feature = create_break_feature_with_interval(min_interval);
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_remove_break_scheduled_too_close_to_previous() {
+    let mut solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_route_with_breaks(vec![(20., 30.), (35., 45.)])])
+        .build()
+        .solution;
+    let feature = create_break_feature_with_interval(Some(50.));
+
+    feature.state.unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+    let tour = &solution_ctx.routes.first().unwrap().route().tour;
+    assert_eq!(tour.job_count(), 4);
+    assert_eq!(tour.get(2).unwrap().schedule, Schedule::new(20., 30.));
+}
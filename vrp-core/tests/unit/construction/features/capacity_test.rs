@@ -194,3 +194,107 @@ fn can_merge_jobs_with_demand_impl(
         (Err(ViolationCode(result)), Err(expected)) => assert_eq!(result, expected),
     }
 }
+
+parameterized_test! {can_evaluate_demand_with_time_dependent_capacity, (departure, expected), {
+    can_evaluate_demand_with_time_dependent_capacity_impl(departure, expected);
+}}
+
+can_evaluate_demand_with_time_dependent_capacity! {
+    case01_morning: (8. * 3600., None),
+    case02_afternoon: (14. * 3600., create_constraint_violation(true)),
+}
+
+fn can_evaluate_demand_with_time_dependent_capacity_impl(departure: Float, expected: Option<ConstraintViolation>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+    let mut route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure;
+    let job = TestSingleBuilder::default().demand(create_simple_demand(8)).build_as_job_ref();
+    let feature = CapacityFeatureBuilder::<SingleDimLoad>::new("capacity")
+        .set_violation_code(VIOLATION_CODE)
+        .set_time_dependent_capacity(Arc::new(|_: &Actor, departure: Timestamp| {
+            // NOTE afternoon weight limit reduces effective capacity
+            if departure >= 12. * 3600. {
+                Some(SingleDimLoad::new(5))
+            } else {
+                None
+            }
+        }))
+        .build()
+        .unwrap();
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &job));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_demand_with_max_load_ratio, (max_load_ratio, size, expected), {
+    can_evaluate_demand_with_max_load_ratio_impl(max_load_ratio, size, expected);
+}}
+
+can_evaluate_demand_with_max_load_ratio! {
+    case01_exceeds_headroom: (Some(0.8), 4, create_constraint_violation(false)),
+    case02_within_headroom: (Some(0.8), 3, None),
+    case03_no_headroom: (None, 4, None),
+    case04_full_capacity: (Some(1.), 5, None),
+}
+
+fn can_evaluate_demand_with_max_load_ratio_impl(
+    max_load_ratio: Option<Float>,
+    size: i32,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(create_activity_with_simple_demand(5))
+                .build(),
+        )
+        .build();
+    let builder = CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").set_violation_code(VIOLATION_CODE);
+    let feature = match max_load_ratio {
+        Some(ratio) => builder.set_max_load_ratio(ratio),
+        None => builder,
+    }
+    .build()
+    .unwrap();
+    feature.state.unwrap().accept_route_state(&mut route_ctx);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &create_activity_with_simple_demand(size),
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_job_exceeding_max_load_ratio_on_route() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let job = TestSingleBuilder::default().demand(create_simple_demand(9)).build_as_job_ref();
+    let feature = CapacityFeatureBuilder::<SingleDimLoad>::new("capacity")
+        .set_violation_code(VIOLATION_CODE)
+        .set_max_load_ratio(0.8)
+        .build()
+        .unwrap();
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &job));
+
+    assert_eq!(result, create_constraint_violation(true));
+}
+
+#[test]
+fn can_reject_invalid_max_load_ratio() {
+    let result = CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").set_max_load_ratio(1.5).build();
+
+    assert!(result.is_err());
+}
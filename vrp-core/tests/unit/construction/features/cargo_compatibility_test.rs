@@ -0,0 +1,96 @@
+use super::*;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::*;
+use crate::models::problem::JobIdDimension;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_pdp_job(id: &str, class: &str, locations: (Location, Location)) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id(id.to_string());
+    dimens.set_job_cargo_class(class.to_string());
+
+    Multi::new_shared(
+        vec![
+            TestSingleBuilder::default().location(Some(locations.0)).build_shared(),
+            TestSingleBuilder::default().location(Some(locations.1)).build_shared(),
+        ],
+        dimens,
+    )
+}
+
+fn create_activity(multi: &Arc<Multi>, sub_job_idx: usize) -> Activity {
+    let single = multi.jobs[sub_job_idx].clone();
+    ActivityBuilder::with_location(single.places[0].location.unwrap()).job(Some(single)).build()
+}
+
+fn create_feature() -> Feature {
+    create_cargo_compatibility_feature(
+        "cargo_compatibility",
+        VIOLATION_CODE,
+        &[("food".to_string(), "chemicals".to_string())],
+    )
+    .unwrap()
+}
+
+fn evaluate(
+    route: Vec<(&Arc<Multi>, usize)>,
+    target: (&Arc<Multi>, usize),
+    index: usize,
+) -> Option<ConstraintViolation> {
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(route.into_iter().map(|(multi, idx)| create_activity(multi, idx)))
+                .build(),
+        )
+        .build();
+    let tour = &route_ctx.route().tour;
+    let target = create_activity(target.0, target.1);
+    let activity_ctx =
+        ActivityContext { index, prev: tour.get(index).unwrap(), target: &target, next: tour.get(index + 1) };
+
+    create_feature().constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_evaluate_pickup_insertion, (other_class, index, expected), {
+    let onboard = create_pdp_job("job1", "food", (1, 2));
+    let job = create_pdp_job("job2", other_class, (3, 4));
+
+    let result = evaluate(vec![(&onboard, 0), (&onboard, 1)], (&job, 0), index);
+
+    assert_eq!(result, expected);
+}}
+
+can_evaluate_pickup_insertion! {
+    case01_before_pickup: ("chemicals", 0, None),
+    case02_while_onboard: ("chemicals", 1, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_after_delivery: ("chemicals", 2, None),
+    case04_compatible_while_onboard: ("food", 1, None),
+    case05_unknown_while_onboard: ("other", 1, None),
+}
+
+parameterized_test! {can_evaluate_delivery_insertion, (index, expected), {
+    let onboard = create_pdp_job("job1", "food", (1, 2));
+    let job = create_pdp_job("job2", "chemicals", (3, 4));
+
+    let result = evaluate(vec![(&job, 0), (&onboard, 0), (&onboard, 1)], (&job, 1), index);
+
+    assert_eq!(result, expected);
+}}
+
+can_evaluate_delivery_insertion! {
+    case01_before_other_pickup: (1, None),
+    case02_while_other_onboard: (2, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_after_other_delivery: (3, ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+#[test]
+fn can_merge_only_compatible_cargo() {
+    let constraint = create_feature().constraint.unwrap();
+    let create_job = |class: &str| Job::Multi(create_pdp_job("job", class, (1, 2)));
+
+    assert!(constraint.merge(create_job("food"), create_job("food")).is_ok());
+    assert!(constraint.merge(create_job("food"), create_job("other")).is_ok());
+    assert_eq!(constraint.merge(create_job("food"), create_job("chemicals")).map(|_| ()), Err(VIOLATION_CODE));
+}
@@ -0,0 +1,157 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestActivityCost, TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, TimeWindow};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    create_completion_deadline_feature(
+        "completion_deadline",
+        2.,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_activity(deadline: Option<Timestamp>, duration: Duration, schedule: (Timestamp, Timestamp)) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(deadline) = deadline {
+        builder.dimens_mut().set_job_completion_deadline(deadline);
+    }
+
+    ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(0., 100.), duration)
+        .job(Some(builder.build_shared()))
+        .schedule(Schedule::new(schedule.0, schedule.1))
+        .build()
+}
+
+#[test]
+fn can_penalize_long_service_job_completed_after_deadline() {
+    let activity = create_activity(Some(60.), 50., (20., 70.));
+    assert!(activity.place.time.contains(activity.schedule.arrival));
+    let insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![RouteContextBuilder::default()
+            .with_route(RouteBuilder::with_default_vehicle().add_activity(activity).build())
+            .build()])
+        .build();
+
+    let fitness = create_feature().objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 20.);
+}
+
+parameterized_test! {can_estimate_completion_deadline_penalty, (deadline, duration, prev_departure, expected), {
+    can_estimate_completion_deadline_penalty_impl(deadline, duration, prev_departure, expected);
+}}
+
+can_estimate_completion_deadline_penalty! {
+    case_01_long_service_late: (Some(60.), 50., 10., 20.),
+    case_02_short_service: (Some(60.), 10., 10., 0.),
+    case_03_long_service_in_time: (Some(60.), 50., 0., 0.),
+    case_04_no_deadline: (None, 50., 10., 0.),
+}
+
+fn can_estimate_completion_deadline_penalty_impl(
+    deadline: Option<Timestamp>,
+    duration: Duration,
+    prev_departure: Timestamp,
+    expected: Cost,
+) {
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+    let prev = ActivityBuilder::with_location(0).schedule(Schedule::new(prev_departure, prev_departure)).build();
+    let target = create_activity(deadline, duration, (0., 0.));
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    let result = create_feature().objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_negative_cost() {
+    let result = create_completion_deadline_feature(
+        "completion_deadline",
+        -1.,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    );
+
+    assert!(result.is_err());
+}
+
+fn create_hard_feature() -> Feature {
+    create_hard_completion_deadline_feature(
+        "hard_completion_deadline",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_job_activity(
+    location: Location,
+    duration: Duration,
+    deadline: Option<Timestamp>,
+    schedule: Schedule,
+) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(deadline) = deadline {
+        builder.dimens_mut().set_job_completion_deadline(deadline);
+    }
+
+    ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 100.), duration)
+        .job(Some(builder.build_shared()))
+        .schedule(schedule)
+        .build()
+}
+
+/// Creates a route: start (0) -> 10 [10, 15] -> 20 [25, 30], deadline 40 -> end (0).
+fn create_hard_deadline_route_ctx() -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activity(create_job_activity(10, 5., None, Schedule::new(10., 15.)))
+                .add_activity(create_job_activity(20, 5., Some(40.), Schedule::new(25., 30.)))
+                .build(),
+        )
+        .build();
+    create_hard_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_evaluate_hard_completion_deadline, (index, location, duration, deadline, expected), {
+    can_evaluate_hard_completion_deadline_impl(index, location, duration, deadline, expected);
+}}
+
+can_evaluate_hard_completion_deadline! {
+    case_01_early_placement_in_time: (0, 12, 5., Some(20.), None),
+    case_02_late_placement_after_deadline: (1, 12, 5., Some(20.), Some(VIOLATION_CODE)),
+    case_03_last_placement_after_deadline: (2, 12, 5., Some(20.), Some(VIOLATION_CODE)),
+    case_04_shifts_next_job_after_deadline: (0, 30, 10., None, Some(VIOLATION_CODE)),
+    case_05_shifts_job_with_deadline_too_much: (1, 30, 10., None, Some(VIOLATION_CODE)),
+    case_06_placed_after_job_with_deadline: (2, 30, 10., None, None),
+    case_07_shifts_next_job_in_time: (0, 5, 0., None, None),
+}
+
+fn can_evaluate_hard_completion_deadline_impl(
+    index: usize,
+    location: Location,
+    duration: Duration,
+    deadline: Option<Timestamp>,
+    expected: Option<ViolationCode>,
+) {
+    let route_ctx = create_hard_deadline_route_ctx();
+    let target = create_job_activity(location, duration, deadline, Schedule::new(0., 0.));
+    let tour = &route_ctx.route().tour;
+    let activity_ctx =
+        ActivityContext { index, prev: tour.get(index).unwrap(), target: &target, next: tour.get(index + 1) };
+
+    let result = create_hard_feature().constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: false }));
+}
@@ -0,0 +1,71 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder, TestVehicleBuilder};
+use crate::helpers::models::solution::*;
+use crate::models::problem::Fleet;
+
+fn create_fleet(schedule: Option<Vec<(Timestamp, Cost)>>) -> Fleet {
+    let create_vehicle = |id: &str| {
+        let mut builder = TestVehicleBuilder::default();
+        if let Some(schedule) = schedule.clone() {
+            builder.dimens_mut().set_vehicle_dispatch_costs(schedule);
+        }
+        builder.id(id).build()
+    };
+
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![create_vehicle("v1"), create_vehicle("v2")])
+        .build()
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, departure: Timestamp, with_job: bool) -> RouteContext {
+    let mut builder = RouteBuilder::default();
+    builder.with_vehicle(fleet, vehicle_id);
+    if with_job {
+        builder.add_activity(ActivityBuilder::with_location(10).build());
+    }
+    let mut route_ctx = RouteContextBuilder::default().with_route(builder.build()).build();
+    route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure;
+
+    route_ctx
+}
+
+parameterized_test! {can_get_dispatch_cost_by_departure_time, (schedule, departure, expected), {
+    let fleet = create_fleet(schedule);
+    let insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_route_ctx(&fleet, "v1", departure, true)])
+        .build();
+
+    let result = create_dispatch_cost_feature("dispatch_cost").unwrap().objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}}
+
+can_get_dispatch_cost_by_departure_time! {
+    case01_day: (Some(vec![(0., 10.), (100., 50.)]), 0., 10.),
+    case02_day_later: (Some(vec![(0., 10.), (100., 50.)]), 50., 10.),
+    case03_night_start: (Some(vec![(0., 10.), (100., 50.)]), 100., 50.),
+    case04_night: (Some(vec![(0., 10.), (100., 50.)]), 200., 50.),
+    case05_before_schedule: (Some(vec![(10., 10.), (100., 50.)]), 0., 0.),
+    case06_no_schedule: (None, 100., 0.),
+}
+
+#[test]
+fn can_prefer_vehicle_with_earlier_dispatch() {
+    let fleet = create_fleet(Some(vec![(0., 10.), (100., 50.)]));
+    let early_route = create_route_ctx(&fleet, "v1", 0., false);
+    let late_route = create_route_ctx(&fleet, "v2", 120., false);
+    let used_route = create_route_ctx(&fleet, "v2", 120., true);
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let job = TestSingleBuilder::default().build_as_job_ref();
+    let objective = create_dispatch_cost_feature("dispatch_cost").unwrap().objective.unwrap();
+
+    let early = objective.estimate(&MoveContext::route(&solution_ctx, &early_route, &job));
+    let late = objective.estimate(&MoveContext::route(&solution_ctx, &late_route, &job));
+    let used = objective.estimate(&MoveContext::route(&solution_ctx, &used_route, &job));
+
+    assert_eq!(early, 10.);
+    assert_eq!(late, 50.);
+    assert_eq!(used, 0.);
+}
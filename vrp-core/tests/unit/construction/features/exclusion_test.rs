@@ -0,0 +1,81 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    create_exclusion_feature("exclusion", VIOLATION_CODE).unwrap()
+}
+
+fn create_test_single(id: &str, exclusions: Vec<&str>) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.id(id);
+
+    if !exclusions.is_empty() {
+        builder.dimens_mut().set_job_exclusions(exclusions.into_iter().map(|id| id.to_string()).collect());
+    }
+
+    builder.build_shared()
+}
+
+fn create_test_route_ctx(jobs: Vec<(&str, Vec<&str>)>) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(jobs.into_iter().map(|(id, exclusions)| {
+                    ActivityBuilder::with_location(1).job(Some(create_test_single(id, exclusions))).build()
+                }))
+                .build(),
+        )
+        .build()
+}
+
+parameterized_test! {can_use_exclusion, (route_jobs, job, expected), {
+    can_use_exclusion_impl(route_jobs, job, expected);
+}}
+
+can_use_exclusion! {
+    case_01_job_excludes_route_job: (vec![("job1", vec![])], ("job2", vec!["job1"]), Some(())),
+    case_02_route_job_excludes_job: (vec![("job1", vec!["job2"])], ("job2", vec![]), Some(())),
+    case_03_mutually_exclusive: (vec![("job1", vec!["job2"])], ("job2", vec!["job1"]), Some(())),
+    case_04_unrelated: (vec![("job1", vec!["job3"])], ("job2", vec!["job4"]), None),
+    case_05_no_exclusions: (vec![("job1", vec![])], ("job2", vec![]), None),
+    case_06_empty_route: (vec![], ("job2", vec!["job1"]), None),
+}
+
+fn can_use_exclusion_impl(route_jobs: Vec<(&str, Vec<&str>)>, job: (&str, Vec<&str>), expected: Option<()>) {
+    let solution_ctx =
+        TestInsertionContextBuilder::default().with_routes(vec![create_test_route_ctx(route_jobs)]).build().solution;
+    let job = Job::Single(create_test_single(job.0, job.1));
+
+    let result = create_feature()
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job))
+        .map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case_01_unrelated: (("job1", vec!["job3"]), ("job2", vec![]), Ok("job1")),
+    case_02_source_excludes: (("job1", vec!["job2"]), ("job2", vec![]), Err(VIOLATION_CODE)),
+    case_03_candidate_has_exclusions: (("job1", vec![]), ("job2", vec!["job3"]), Err(VIOLATION_CODE)),
+    case_04_no_exclusions: (("job1", vec![]), ("job2", vec![]), Ok("job1")),
+}
+
+fn can_merge_jobs_impl(source: (&str, Vec<&str>), candidate: (&str, Vec<&str>), expected: Result<&str, ViolationCode>) {
+    let source = Job::Single(create_test_single(source.0, source.1));
+    let candidate = Job::Single(create_test_single(candidate.0, candidate.1));
+    let constraint = create_feature().constraint.unwrap();
+
+    let result = constraint.merge(source, candidate).map(|job| job.dimens().get_job_id().cloned().unwrap());
+
+    assert_eq!(result, expected.map(|id| id.to_string()));
+}
@@ -42,3 +42,97 @@ fn can_properly_estimate_solutions_impl(left: &[Float], right: &[Float], expecte
 
     assert_eq!(left.total_cmp(&right), expected);
 }
+
+mod vehicle_types {
+    use super::*;
+    use crate::helpers::models::domain::ProblemBuilder;
+    use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder, TestVehicleBuilder};
+    use crate::models::problem::Fleet;
+
+    custom_dimension!(TestVehicleType typeof String);
+
+    fn create_test_fleet() -> Fleet {
+        let create_vehicle = |id: &str, vehicle_type: &str| {
+            let mut builder = TestVehicleBuilder::default();
+            builder.id(id);
+            builder.dimens_mut().set_test_vehicle_type(vehicle_type.to_string());
+            builder.build()
+        };
+
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![create_vehicle("v1", "a"), create_vehicle("v2", "a"), create_vehicle("v3", "b")])
+            .build()
+    }
+
+    fn create_test_feature() -> Feature {
+        create_minimize_vehicle_types_feature(
+            "min_vehicle_types",
+            Arc::new(|actor| actor.vehicle.dimens.get_test_vehicle_type().cloned()),
+        )
+        .unwrap()
+    }
+
+    fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, has_job: bool) -> RouteContext {
+        let mut builder = RouteBuilder::default();
+        builder.with_vehicle(fleet, vehicle_id);
+        if has_job {
+            builder.add_activity(ActivityBuilder::with_location(1).build());
+        }
+
+        RouteContextBuilder::default().with_route(builder.build()).build()
+    }
+
+    fn create_insertion_ctx(fleet: Fleet, routes: &[(&str, bool)]) -> InsertionContext {
+        let routes =
+            routes.iter().map(|(vehicle_id, has_job)| create_route_ctx(&fleet, vehicle_id, *has_job)).collect();
+
+        TestInsertionContextBuilder::default()
+            .with_problem(ProblemBuilder::default().with_fleet(fleet).build())
+            .with_routes(routes)
+            .build()
+    }
+
+    parameterized_test! {can_count_distinct_vehicle_types, (routes, expected), {
+        can_count_distinct_vehicle_types_impl(routes, expected);
+    }}
+
+    can_count_distinct_vehicle_types! {
+        case_01_same_type: (&[("v1", true), ("v2", true)], 1.),
+        case_02_different_types: (&[("v1", true), ("v3", true)], 2.),
+        case_03_empty_route: (&[("v1", true), ("v3", false)], 1.),
+        case_04_no_routes: (&[], 0.),
+    }
+
+    fn can_count_distinct_vehicle_types_impl(routes: &[(&str, bool)], expected: Cost) {
+        let insertion_ctx = create_insertion_ctx(create_test_fleet(), routes);
+
+        let fitness = create_test_feature().objective.unwrap().fitness(&insertion_ctx);
+
+        assert_eq!(fitness, expected);
+    }
+
+    parameterized_test! {can_estimate_new_vehicle_type, (vehicle_id, expected), {
+        can_estimate_new_vehicle_type_impl(vehicle_id, expected);
+    }}
+
+    can_estimate_new_vehicle_type! {
+        case_01_used_type: ("v2", 0.),
+        case_02_new_type: ("v3", 1.),
+    }
+
+    fn can_estimate_new_vehicle_type_impl(vehicle_id: &str, expected: Cost) {
+        let fleet = create_test_fleet();
+        let route_ctx = create_route_ctx(&fleet, vehicle_id, false);
+        let insertion_ctx = create_insertion_ctx(fleet, &[("v1", true)]);
+        let job = TestSingleBuilder::default().build_as_job_ref();
+
+        let estimate = create_test_feature().objective.unwrap().estimate(&MoveContext::route(
+            &insertion_ctx.solution,
+            &route_ctx,
+            &job,
+        ));
+
+        assert_eq!(estimate, expected);
+    }
+}
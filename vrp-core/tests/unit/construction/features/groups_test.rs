@@ -1,7 +1,7 @@
 use super::*;
 use crate::construction::enablers::create_typed_actor_groups;
 use crate::helpers::models::domain::{test_random, TestGoalContextBuilder};
-use crate::helpers::models::problem::{test_driver, test_vehicle_with_id, FleetBuilder, TestSingleBuilder};
+use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder};
 use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder, RouteStateBuilder};
 use crate::models::problem::Actor;
 use crate::models::problem::{Fleet, Single};
@@ -22,8 +22,7 @@ fn get_total_jobs(routes: &[(&str, Vec<Option<&str>>)]) -> usize {
 fn create_test_fleet() -> Fleet {
     FleetBuilder::default()
         .add_driver(test_driver())
-        .add_vehicle(test_vehicle_with_id("v1"))
-        .add_vehicle(test_vehicle_with_id("v2"))
+        .add_vehicles_with_ids(&["v1", "v2"])
         .with_group_key_fn(Box::new(|actors| {
             Box::new(create_typed_actor_groups(actors, |a| a.vehicle.dimens.get_vehicle_id().cloned().unwrap()))
         }))
@@ -162,6 +161,10 @@ parameterized_test! {can_evaluate_job, (routes, route_idx, job_group, expected),
 can_evaluate_job! {
     case_01: (vec![("v1", vec![]), ("v2", vec![Some("g1")])], 0, Some("g1"), Some(VIOLATION_CODE)),
     case_02: (vec![("v1", vec![]), ("v2", vec![])], 0, Some("g1"), None),
+    case_03: (vec![("v1", vec![Some("g1")]), ("v2", vec![])], 0, Some("g1"), None),
+    case_04: (vec![("v1", vec![Some("g1")]), ("v2", vec![])], 1, Some("g1"), Some(VIOLATION_CODE)),
+    case_05: (vec![("v1", vec![]), ("v2", vec![Some("g2")])], 0, Some("g1"), None),
+    case_06: (vec![("v1", vec![]), ("v2", vec![Some("g1")])], 0, None, None),
 }
 
 fn can_evaluate_job_impl(
@@ -175,7 +178,7 @@ fn can_evaluate_job_impl(
     let solution_ctx = create_test_solution_context(total_jobs, &fleet, routes);
     let route_ctx = solution_ctx.routes.get(route_idx).unwrap();
     let job = Job::Single(create_test_single(job_group));
-    let constraint = create_test_group_feature(total_jobs).constraint.unwrap();
+    let constraint = create_test_group_feature(solution_ctx.get_jobs_amount()).constraint.unwrap();
 
     let result = constraint.evaluate(&MoveContext::route(&solution_ctx, route_ctx, &job));
 
@@ -0,0 +1,163 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder, TestVehicleBuilder};
+use crate::helpers::models::solution::{ActivityBuilder, RouteContextBuilder};
+use crate::models::solution::{Activity, Route, Tour};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_job(is_home_return: bool) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    if is_home_return {
+        builder.dimens_mut().set_job_home_return(true);
+    }
+
+    builder.build_as_job_ref()
+}
+
+fn create_test_fleet() -> Fleet {
+    let vehicles = (0..3)
+        .map(|period| {
+            let mut builder = TestVehicleBuilder::default();
+            builder.dimens_mut().set_vehicle_period(period);
+            builder.id("v1").build()
+        })
+        .collect();
+
+    FleetBuilder::default().add_driver(test_driver()).add_vehicles(vehicles).build()
+}
+
+/// Creates a route for a given period: None means no jobs, otherwise whether it has a home return job.
+fn create_route_ctx(fleet: &Fleet, period: usize, home_return: Option<bool>) -> RouteContext {
+    let actor = fleet.actors.iter().find(|actor| actor.vehicle.dimens.get_vehicle_period() == Some(&period)).unwrap();
+    let mut route = Route { actor: actor.clone(), tour: Tour::new(actor) };
+
+    if let Some(is_home_return) = home_return {
+        let single = create_job(is_home_return).to_single().clone();
+        route.tour.insert_last(ActivityBuilder::with_location(1).job(Some(single)).build());
+    }
+
+    let mut route_ctx = RouteContextBuilder::default().with_route(route).build();
+    create_feature(1).state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_feature(max_away_periods: usize) -> Feature {
+    create_home_return_feature("home_return", max_away_periods, VIOLATION_CODE).unwrap()
+}
+
+fn create_activity(is_home_return: bool) -> Activity {
+    ActivityBuilder::with_location(1).job(Some(create_job(is_home_return).to_single().clone())).build()
+}
+
+parameterized_test! {can_limit_away_periods, (max_away_periods, routes, period, is_home_return, expected), {
+    can_limit_away_periods_impl(max_away_periods, routes, period, is_home_return, expected);
+}}
+
+can_limit_away_periods! {
+    case01_two_periods_away: (1, vec![(0, false)], 1, false, ConstraintViolation::fail(VIOLATION_CODE)),
+    case02_two_periods_away_backward: (1, vec![(1, false)], 0, false, ConstraintViolation::fail(VIOLATION_CODE)),
+    case03_home_return_before: (1, vec![(0, true)], 1, false, None),
+    case04_home_return_job: (1, vec![(0, false)], 1, true, None),
+    case05_single_period: (1, vec![], 1, false, None),
+    case06_unused_period_between: (1, vec![(0, false)], 2, false, None),
+    case07_within_limit: (2, vec![(0, false)], 1, false, None),
+    case08_exceeds_limit_around: (2, vec![(0, false), (2, false)], 1, false, ConstraintViolation::fail(VIOLATION_CODE)),
+}
+
+fn can_limit_away_periods_impl(
+    max_away_periods: usize,
+    routes: Vec<(usize, bool)>,
+    period: usize,
+    is_home_return: bool,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_test_fleet();
+    let routes = routes
+        .into_iter()
+        .map(|(period, is_home_return)| create_route_ctx(&fleet, period, Some(is_home_return)))
+        .collect();
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+    let route_ctx = create_route_ctx(&fleet, period, None);
+    let constraint = create_feature(max_away_periods).constraint.unwrap();
+
+    let result =
+        constraint.evaluate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &create_job(is_home_return)));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_accept_job_in_already_used_route() {
+    let fleet = create_test_fleet();
+    let insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_route_ctx(&fleet, 0, Some(false)), create_route_ctx(&fleet, 1, Some(false))])
+        .build();
+    let route_ctx = insertion_ctx.solution.routes.get(1).unwrap();
+    let constraint = create_feature(1).constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::route(&insertion_ctx.solution, route_ctx, &create_job(false)));
+
+    assert_eq!(result, None);
+}
+
+parameterized_test! {can_keep_home_return_as_last_job, (prev, target, next, expected), {
+    can_keep_home_return_as_last_job_impl(prev, target, next, expected);
+}}
+
+can_keep_home_return_as_last_job! {
+    case01_regular_jobs: (false, false, Some(false), None),
+    case02_home_return_last: (false, true, None, None),
+    case03_home_return_before_job: (false, true, Some(false), ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_job_after_home_return: (true, false, None, ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_keep_home_return_as_last_job_impl(
+    prev: bool,
+    target: bool,
+    next: Option<bool>,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_test_fleet();
+    let route_ctx = create_route_ctx(&fleet, 0, None);
+    let (prev, target, next) = (create_activity(prev), create_activity(target), next.map(create_activity));
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: next.as_ref() };
+
+    let result = create_feature(1).constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_mark_route_away_when_home_return_is_removed() {
+    let fleet = create_test_fleet();
+    let mut route_ctx = create_route_ctx(&fleet, 0, Some(true));
+    assert_eq!(route_ctx.state().get_away_from_home(), Some(&false));
+    let job = route_ctx.route().tour.jobs().next().cloned().unwrap();
+    route_ctx.route_mut().tour.insert_last(create_activity(false));
+    route_ctx.route_mut().tour.remove(&job);
+
+    create_feature(1).state.unwrap().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state().get_away_from_home(), Some(&true));
+}
+
+#[test]
+fn can_reject_zero_max_away_periods() {
+    assert!(create_home_return_feature("home_return", 0, VIOLATION_CODE).is_err());
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    let constraint = create_feature(1).constraint.unwrap();
+
+    let result = constraint.merge(create_job(source), create_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}}
+
+can_merge_jobs! {
+    case01_regular_jobs: (false, false, Ok(())),
+    case02_home_return_source: (true, false, Err(VIOLATION_CODE)),
+    case03_home_return_candidate: (false, true, Err(VIOLATION_CODE)),
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::*;
+
+const DISCOUNT: Cost = 10.;
+
+fn create_job(bundle: Option<&str>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(bundle) = bundle {
+        builder.dimens_mut().set_job_bundle(bundle.to_string());
+    }
+
+    builder.build_as_job_ref()
+}
+
+fn create_route_ctx(jobs: &[Job]) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(
+                    jobs.iter().map(|job| ActivityBuilder::with_location(1).job(Some(job.to_single().clone())).build()),
+                )
+                .build(),
+        )
+        .build();
+    create_job_bundle_feature("bundles", DISCOUNT).unwrap().state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn get_fitness(routes: Vec<Vec<Job>>) -> Cost {
+    let insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(routes.iter().map(|jobs| create_route_ctx(jobs.as_slice())).collect())
+        .build();
+
+    create_job_bundle_feature("bundles", DISCOUNT).unwrap().objective.unwrap().fitness(&insertion_ctx)
+}
+
+#[test]
+fn can_give_discount_when_bundle_is_served_together() {
+    let (job1, job2, job3) = (create_job(Some("b1")), create_job(Some("b1")), create_job(None));
+
+    let together = get_fitness(vec![vec![job1.clone(), job2.clone(), job3.clone()], vec![]]);
+    let separate = get_fitness(vec![vec![job1, job3], vec![job2]]);
+
+    assert_eq!(together, -DISCOUNT);
+    assert_eq!(separate, 0.);
+    assert!(together < separate);
+}
+
+#[test]
+fn can_calculate_fitness_for_many_bundles() {
+    let jobs = vec![
+        create_job(Some("b1")),
+        create_job(Some("b1")),
+        create_job(Some("b1")),
+        create_job(Some("b2")),
+        create_job(Some("b2")),
+        create_job(Some("b3")),
+    ];
+
+    assert_eq!(get_fitness(vec![jobs]), -3. * DISCOUNT);
+}
+
+parameterized_test! {can_estimate_bundle_discount, (route_bundles, job_bundle, expected), {
+    can_estimate_bundle_discount_impl(route_bundles, job_bundle, expected);
+}}
+
+can_estimate_bundle_discount! {
+    case_01: (vec![Some("b1")], Some("b1"), -DISCOUNT),
+    case_02: (vec![Some("b1"), Some("b1")], Some("b1"), -DISCOUNT),
+    case_03: (vec![Some("b2")], Some("b1"), 0.),
+    case_04: (vec![None], Some("b1"), 0.),
+    case_05: (vec![Some("b1")], None, 0.),
+    case_06: (vec![], Some("b1"), 0.),
+}
+
+fn can_estimate_bundle_discount_impl(route_bundles: Vec<Option<&str>>, job_bundle: Option<&str>, expected: Cost) {
+    let jobs = route_bundles.into_iter().map(create_job).collect::<Vec<_>>();
+    let route_ctx = create_route_ctx(jobs.as_slice());
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let objective = create_job_bundle_feature("bundles", DISCOUNT).unwrap().objective.unwrap();
+
+    let result = objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &create_job(job_bundle)));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_negative_discount() {
+    assert!(create_job_bundle_feature("bundles", -1.).is_err());
+}
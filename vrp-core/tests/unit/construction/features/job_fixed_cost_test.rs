@@ -0,0 +1,66 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_job(id: &str, fixed_cost: Option<Cost>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    builder.id(id);
+    if let Some(fixed_cost) = fixed_cost {
+        builder.dimens_mut().set_job_fixed_cost(fixed_cost);
+    }
+
+    builder.build_as_job_ref()
+}
+
+parameterized_test! {can_estimate_job_fixed_cost, (fixed_cost, expected), {
+    can_estimate_job_fixed_cost_impl(fixed_cost, expected);
+}}
+
+can_estimate_job_fixed_cost! {
+    case_01: (Some(10.), 10.),
+    case_02: (Some(0.), 0.),
+    case_03: (None, 0.),
+}
+
+fn can_estimate_job_fixed_cost_impl(fixed_cost: Option<Cost>, expected: Cost) {
+    let objective = create_job_fixed_cost_feature("fixed_cost", VIOLATION_CODE).unwrap().objective.unwrap();
+    let route_ctx = RouteContextBuilder::default().build();
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+    let result = objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &create_job("job", fixed_cost)));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_fitness_only_for_assigned_jobs() {
+    let objective = create_job_fixed_cost_feature("fixed_cost", VIOLATION_CODE).unwrap().objective.unwrap();
+    let assigned = create_job("job1", Some(7.));
+    let unassigned = create_job("job2", Some(100.));
+    let mut insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::with_default_vehicle()
+                    .add_activity(ActivityBuilder::with_location(1).job(Some(assigned.to_single().clone())).build())
+                    .build(),
+            )
+            .build()])
+        .build();
+    insertion_ctx.solution.unassigned.insert(unassigned, UnassignmentInfo::Unknown);
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 7.);
+}
+
+#[test]
+fn can_sum_fixed_costs_on_merge() {
+    let constraint = create_job_fixed_cost_feature("fixed_cost", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let merged = constraint.merge(create_job("source", Some(3.)), create_job("candidate", Some(4.))).unwrap();
+
+    assert_eq!(merged.dimens().get_job_fixed_cost().copied(), Some(7.));
+}
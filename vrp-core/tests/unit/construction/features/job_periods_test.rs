@@ -0,0 +1,65 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder, TestVehicleBuilder};
+use crate::helpers::models::solution::{RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_job(period: Option<usize>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(period) = period {
+        builder.dimens_mut().set_job_period(period);
+    }
+
+    builder.build_as_job_ref()
+}
+
+parameterized_test! {can_pin_job_to_period, (job_period, vehicle_period, expected), {
+    can_pin_job_to_period_impl(job_period, vehicle_period, expected);
+}}
+
+can_pin_job_to_period! {
+    case01_same_period: (Some(1), Some(1), None),
+    case02_day1_job_on_day2_route: (Some(1), Some(2), ConstraintViolation::fail(VIOLATION_CODE)),
+    case03_vehicle_without_period: (Some(1), None, ConstraintViolation::fail(VIOLATION_CODE)),
+    case04_job_without_period: (None, Some(2), None),
+    case05_no_periods: (None, None, None),
+}
+
+fn can_pin_job_to_period_impl(
+    job_period: Option<usize>,
+    vehicle_period: Option<usize>,
+    expected: Option<ConstraintViolation>,
+) {
+    let mut vehicle_builder = TestVehicleBuilder::default();
+    if let Some(period) = vehicle_period {
+        vehicle_builder.dimens_mut().set_vehicle_period(period);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle_builder.id("v1").build()).build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let constraint = create_job_period_feature("period", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::route(
+        &TestInsertionContextBuilder::default().build().solution,
+        &route_ctx,
+        &create_job(job_period),
+    ));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs_with_periods, (source, candidate, expected), {
+    let constraint = create_job_period_feature("period", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(create_job(source), create_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}}
+
+can_merge_jobs_with_periods! {
+    case01: (Some(1), Some(1), Ok(())),
+    case02: (Some(1), Some(2), Err(VIOLATION_CODE)),
+    case03: (Some(1), None, Err(VIOLATION_CODE)),
+    case04: (None, None, Ok(())),
+}
@@ -0,0 +1,149 @@
+use super::*;
+use crate::helpers::models::domain::{test_random, TestGoalContextBuilder};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::problem::Fleet;
+use crate::models::solution::{Activity, Registry};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_test_feature() -> Feature {
+    create_location_exclusion_feature(
+        "location_exclusion",
+        [5].into_iter().collect(),
+        TestTransportCost::new_shared(),
+        VIOLATION_CODE,
+    )
+    .unwrap()
+}
+
+fn create_test_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicles_with_ids(&["v1", "v2"]).build()
+}
+
+fn create_job_activity(location: Location, schedule: Schedule) -> Activity {
+    ActivityBuilder::with_location_tw_and_duration(location, DEFAULT_ACTIVITY_TIME_WINDOW, 10.)
+        .schedule(schedule)
+        .job(Some(TestSingleBuilder::default().location(Some(location)).duration(10.).build_shared()))
+        .build()
+}
+
+/// Creates solution with a job served on the first route (v1) at exclusive location 5 within [10, 20].
+fn create_test_solution_context(fleet: &Fleet) -> SolutionContext {
+    let other = create_job_activity(5, Schedule::new(10., 20.));
+
+    SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        routes: vec![
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(fleet, "v1").add_activity(other).build())
+                .build(),
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(fleet, "v2").build())
+                .build(),
+        ],
+        registry: RegistryContext::new(&TestGoalContextBuilder::default().build(), Registry::new(fleet, test_random())),
+        state: Default::default(),
+    }
+}
+
+parameterized_test! {can_evaluate_activity_at_exclusive_location, (route_idx, prev_departure, location, expected), {
+    can_evaluate_activity_at_exclusive_location_impl(route_idx, prev_departure, location, expected);
+}}
+
+can_evaluate_activity_at_exclusive_location! {
+    case_01_overlapping_start: (1, 5., 5, Some(VIOLATION_CODE)),
+    case_02_overlapping_end: (1, 15., 5, Some(VIOLATION_CODE)),
+    case_03_right_before: (1, 0., 5, None),
+    case_04_right_after: (1, 20., 5, None),
+    case_05_not_exclusive_location: (1, 5., 6, None),
+    case_06_same_route: (0, 5., 5, None),
+}
+
+fn can_evaluate_activity_at_exclusive_location_impl(
+    route_idx: usize,
+    prev_departure: Float,
+    location: Location,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = create_test_fleet();
+    let feature = create_test_feature();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.get(route_idx).unwrap();
+    let prev = ActivityBuilder::with_location(location).schedule(Schedule::new(0., prev_departure)).build();
+    let target = create_job_activity(location, Schedule::new(0., 0.));
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: false }));
+}
+
+#[test]
+fn can_keep_only_other_route_services_in_route_state() {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+
+    create_test_feature().state.unwrap().accept_solution_state(&mut solution_ctx);
+
+    let own = solution_ctx.routes[0].state().get_exclusive_location_services().unwrap();
+    let other = solution_ctx.routes[1].state().get_exclusive_location_services().unwrap();
+    assert!(own.is_empty());
+    assert_eq!(other.get(&5), Some(&vec![TimeWindow::new(10., 20.)]));
+}
+
+parameterized_test! {can_evaluate_insertion_before_exclusive_activity, (location, expected), {
+    can_evaluate_insertion_before_exclusive_activity_impl(location, expected);
+}}
+
+can_evaluate_insertion_before_exclusive_activity! {
+    case_01_keeps_exclusive_service: (3, None),
+    case_02_shifts_exclusive_service_into_overlap: (20, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_insertion_before_exclusive_activity_impl(location: Location, expected: Option<ViolationCode>) {
+    let fleet = create_test_fleet();
+    let feature = create_test_feature();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+    solution_ctx.routes[0].route_mut().tour.insert_last(create_job_activity(5, Schedule::new(40., 50.)));
+    solution_ctx.routes[1].route_mut().tour.insert_last(create_job_activity(5, Schedule::new(20., 30.)));
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.get(1).unwrap();
+    let tour = &route_ctx.route().tour;
+    let target = create_job_activity(location, Schedule::new(0., 0.));
+    let activity_ctx = ActivityContext { index: 0, prev: tour.get(0).unwrap(), target: &target, next: tour.get(1) };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: false }));
+}
+
+#[test]
+fn can_update_services_on_any_insertion() {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+    let state = create_test_feature().state.unwrap();
+    state.accept_solution_state(&mut solution_ctx);
+    solution_ctx.routes[0].route_mut().tour.get_mut(1).unwrap().schedule = Schedule::new(30., 40.);
+
+    state.accept_insertion(&mut solution_ctx, 0, &Job::Single(TestSingleBuilder::default().build_shared()));
+
+    let other = solution_ctx.routes[1].state().get_exclusive_location_services().unwrap();
+    assert_eq!(other.get(&5), Some(&vec![TimeWindow::new(30., 40.)]));
+}
+
+#[test]
+fn can_reject_empty_exclusive_locations() {
+    let result = create_location_exclusion_feature(
+        "location_exclusion",
+        HashSet::new(),
+        TestTransportCost::new_shared(),
+        VIOLATION_CODE,
+    );
+
+    assert!(result.is_err());
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
 use crate::helpers::models::solution::RouteContextBuilder;
 
 #[test]
@@ -12,3 +13,37 @@ fn can_properly_estimate_empty_solution() {
     assert_eq!(objective.fitness(&empty), 0.);
     assert_eq!(objective.fitness(&non_empty), 0.);
 }
+
+fn create_job_with_age(age: Option<Float>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(age) = age {
+        builder.dimens_mut().set_job_age(age);
+    }
+
+    builder.build_as_job_ref()
+}
+
+#[test]
+fn can_penalize_older_unassigned_job_more() {
+    let objective =
+        MinimizeUnassignedBuilder::new("minimize_unassigned").set_age_factor(0.5).build().unwrap().objective.unwrap();
+    let get_fitness = |age: Option<Float>| {
+        let mut insertion_ctx =
+            TestInsertionContextBuilder::default().with_routes(vec![RouteContextBuilder::default().build()]).build();
+        insertion_ctx.solution.unassigned.insert(create_job_with_age(age), UnassignmentInfo::Unknown);
+
+        objective.fitness(&insertion_ctx)
+    };
+
+    let newer = get_fitness(Some(1.));
+    let older = get_fitness(Some(4.));
+
+    assert_eq!(get_fitness(None), 1.);
+    assert_eq!(newer, 1.5);
+    assert_eq!(older, 3.);
+}
+
+#[test]
+fn can_reject_negative_age_factor() {
+    assert!(MinimizeUnassignedBuilder::new("minimize_unassigned").set_age_factor(-1.).build().is_err());
+}
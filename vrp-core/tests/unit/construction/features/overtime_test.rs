@@ -0,0 +1,104 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{VehicleDetail, VehiclePlace};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_transport_feature() -> Feature {
+    TransportFeatureBuilder::new("transport")
+        .set_violation_code(VIOLATION_CODE)
+        .set_transport_cost(TestTransportCost::new_shared())
+        .set_activity_cost(TestActivityCost::new_shared())
+        .build_minimize_cost()
+        .unwrap()
+}
+
+fn create_overtime_feature_for_test() -> Feature {
+    create_overtime_feature("overtime", TestTransportCost::new_shared(), TestActivityCost::new_shared()).unwrap()
+}
+
+fn create_route_ctx(overtime: Option<VehicleOvertime>, locations: Vec<Location>) -> RouteContext {
+    let mut vehicle_builder = TestVehicleBuilder::default();
+    if let Some(overtime) = overtime {
+        vehicle_builder.dimens_mut().set_vehicle_overtime(overtime);
+    }
+    let place = |time: TimeInterval| Some(VehiclePlace { location: 0, time });
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![vehicle_builder
+            .id("v1")
+            .details(vec![VehicleDetail {
+                start: place(TimeInterval { earliest: Some(0.), latest: None }),
+                end: place(TimeInterval { earliest: None, latest: Some(100.) }),
+            }])
+            .build()])
+        .build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activities(locations.into_iter().map(|location| ActivityBuilder::with_location(location).build()))
+                .build(),
+        )
+        .build();
+    create_transport_feature().state.unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_overtime() -> Option<VehicleOvertime> {
+    Some(VehicleOvertime { max_duration: 20., cost_per_time: 2. })
+}
+
+parameterized_test! {can_estimate_overtime_insertion, (overtime, location, expected_penalty, expected_violation), {
+    can_estimate_overtime_insertion_impl(overtime, location, expected_penalty, expected_violation);
+}}
+
+can_estimate_overtime_insertion! {
+    case01_no_overtime: (create_overtime(), 30, 0., None),
+    case02_within_regular_shift: (create_overtime(), 35, 0., None),
+    case03_modest_overtime: (create_overtime(), 40, 10., None),
+    case04_near_max_overtime: (create_overtime(), 45, 30., None),
+    case05_excessive_overtime: (create_overtime(), 50, 50., ConstraintViolation::skip(VIOLATION_CODE)),
+    case06_no_allowance: (None, 40, 0., None),
+}
+
+fn can_estimate_overtime_insertion_impl(
+    overtime: Option<VehicleOvertime>,
+    location: Location,
+    expected_penalty: Cost,
+    expected_violation: Option<ConstraintViolation>,
+) {
+    let route_ctx = create_route_ctx(overtime, vec![30]);
+    let target = ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 1000.), 5.).build();
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+    let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+
+    let penalty = create_overtime_feature_for_test().objective.unwrap().estimate(&move_ctx);
+    let violation = create_transport_feature().constraint.unwrap().evaluate(&move_ctx);
+
+    assert_eq!(penalty, expected_penalty);
+    assert_eq!(violation, expected_violation);
+}
+
+parameterized_test! {can_calculate_overtime_fitness, (overtime, locations, expected), {
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![create_route_ctx(overtime, locations)]).build();
+
+    let fitness = create_overtime_feature_for_test().objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}}
+
+can_calculate_overtime_fitness! {
+    case01_no_jobs: (create_overtime(), vec![], 0.),
+    case02_regular_shift: (create_overtime(), vec![40], 0.),
+    case03_overtime: (create_overtime(), vec![45], 20.),
+    case04_no_allowance: (None, vec![45], 0.),
+}
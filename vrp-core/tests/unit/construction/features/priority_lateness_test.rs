@@ -0,0 +1,62 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, TimeWindow};
+
+fn create_feature() -> Feature {
+    create_priority_lateness_feature("priority_lateness", TestTransportCost::new_shared()).unwrap()
+}
+
+fn create_activity(priority: Option<Float>, schedule: (Timestamp, Timestamp)) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(priority) = priority {
+        builder.dimens_mut().set_job_priority(priority);
+    }
+
+    ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(0., 100.), 5.)
+        .job(Some(builder.build_shared()))
+        .schedule(Schedule::new(schedule.0, schedule.1))
+        .build()
+}
+
+fn get_fitness(activities: Vec<Activity>) -> Cost {
+    let insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![RouteContextBuilder::default()
+            .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+            .build()])
+        .build();
+
+    create_feature().objective.unwrap().fitness(&insertion_ctx)
+}
+
+#[test]
+fn can_prefer_serving_high_priority_job_earlier_in_its_window() {
+    let high_first = get_fitness(vec![create_activity(Some(2.), (10., 15.)), create_activity(Some(1.), (15., 20.))]);
+    let low_first = get_fitness(vec![create_activity(Some(1.), (10., 15.)), create_activity(Some(2.), (15., 20.))]);
+
+    assert_eq!(high_first, 35.);
+    assert_eq!(low_first, 40.);
+}
+
+parameterized_test! {can_estimate_priority_lateness, (priority, prev_departure, expected), {
+    can_estimate_priority_lateness_impl(priority, prev_departure, expected);
+}}
+
+can_estimate_priority_lateness! {
+    case_01: (Some(2.), 0., 20.),
+    case_02: (Some(2.), 5., 30.),
+    case_03: (Some(1.), 5., 15.),
+    case_04: (None, 5., 0.),
+}
+
+fn can_estimate_priority_lateness_impl(priority: Option<Float>, prev_departure: Timestamp, expected: Cost) {
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+    let prev = ActivityBuilder::with_location(0).schedule(Schedule::new(prev_departure, prev_departure)).build();
+    let target = create_activity(priority, (0., 0.));
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    let result = create_feature().objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,120 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, SimpleActivityCost, SimpleTransportCost};
+
+/// Location `1` is inside the region, location `2` has the same distance from depot, but is outside.
+fn get_points() -> Vec<Point> {
+    vec![Point::new(0., 0.), Point::new(10., 5.), Point::new(10., -5.)]
+}
+
+fn create_transport() -> Arc<dyn TransportCost> {
+    let points = get_points();
+    let distances =
+        points.iter().flat_map(|from| points.iter().map(move |to| from.distance_to_point(to))).collect::<Vec<_>>();
+
+    Arc::new(SimpleTransportCost::new(distances.clone(), distances).unwrap())
+}
+
+fn create_coord_index() -> CoordIndexFn {
+    let points = get_points();
+    Arc::new(move |location| points.get(location).cloned())
+}
+
+fn create_fleet(multiplier: Option<Float>) -> Fleet {
+    let mut builder = TestVehicleBuilder::default();
+    if let Some(multiplier) = multiplier {
+        let polygon = vec![Point::new(8., 2.), Point::new(12., 2.), Point::new(12., 8.), Point::new(8., 8.)];
+        builder.dimens_mut().set_vehicle_toll_regions(vec![TollRegion { polygon, multiplier }]);
+    }
+
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.id("v1").build()).build()
+}
+
+fn create_route_ctx(fleet: &Fleet, location: Location) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(fleet, "v1")
+                .add_activity(ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::max(), 0.).build())
+                .build(),
+        )
+        .build();
+    update_route_schedule(&mut route_ctx, &SimpleActivityCost::default(), create_transport().as_ref());
+
+    route_ctx
+}
+
+fn get_total_cost(fleet: &Fleet, location: Location) -> Cost {
+    let transport = create_transport();
+    let route_ctx = create_route_ctx(fleet, location);
+    let route = route_ctx.route();
+    let transport_cost = route
+        .tour
+        .all_activities()
+        .zip(route.tour.all_activities().skip(1))
+        .map(|(from, to)| {
+            transport.cost(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            )
+        })
+        .sum::<Cost>();
+
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let feature = create_region_toll_feature("toll", create_coord_index(), transport).unwrap();
+
+    transport_cost + feature.objective.unwrap().fitness(&insertion_ctx)
+}
+
+parameterized_test! {can_calculate_region_toll, (multiplier, location, expected), {
+    let fleet = create_fleet(multiplier);
+    let route_ctx = create_route_ctx(&fleet, location);
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let feature = create_region_toll_feature("toll", create_coord_index(), create_transport()).unwrap();
+
+    let result = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert!((result - expected).abs() < 1E-6, "expected {expected}, got {result}");
+}}
+
+can_calculate_region_toll! {
+    // NOTE leg cost is 2 * (distance + duration) with default driver and vehicle costs, both are sqrt(125)
+    case01_entering_region: (Some(1.5), 1, 2. * 125_f64.sqrt()),
+    case02_avoiding_region: (Some(1.5), 2, 0.),
+    case03_no_regions: (None, 1, 0.),
+    case04_no_surcharge: (Some(1.), 1, 0.),
+}
+
+#[test]
+fn can_make_route_entering_tolled_region_more_expensive() {
+    let fleet = create_fleet(Some(2.));
+
+    let tolled = get_total_cost(&fleet, 1);
+    let avoiding = get_total_cost(&fleet, 2);
+
+    assert!(tolled > avoiding, "tolled: {tolled}, avoiding: {avoiding}");
+}
+
+#[test]
+fn can_estimate_insertion_into_tolled_region() {
+    let fleet = create_fleet(Some(2.));
+    let route_ctx = create_route_ctx(&fleet, 2);
+    let objective =
+        create_region_toll_feature("toll", create_coord_index(), create_transport()).unwrap().objective.unwrap();
+    let estimate = |location| {
+        let route = route_ctx.route();
+        let target = ActivityBuilder::with_location(location).build();
+        let activity_ctx =
+            ActivityContext { index: 0, prev: route.tour.get(0).unwrap(), target: &target, next: route.tour.get(1) };
+
+        objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx))
+    };
+
+    assert!(estimate(1) > 0.);
+    assert_eq!(estimate(2), 0.);
+}
@@ -0,0 +1,81 @@
+use super::*;
+use crate::algorithms::geometry::Point;
+use crate::helpers::construction::features::*;
+use crate::helpers::models::solution::ActivityBuilder;
+
+const PENALTY: Cost = 10.;
+
+/// Depot is at location 0, other locations are placed around it.
+fn create_coord_index() -> CoordIndexFn {
+    let coordinates = [(0., 0.), (1., 0.), (0., 1.), (-1., 0.), (0., -1.), (1., 1.), (1., -1.)];
+    Arc::new(move |location| coordinates.get(location).map(|&(x, y)| Point::new(x, y)))
+}
+
+fn create_feature(preferred: Option<RotationDirection>) -> Feature {
+    create_rotation_direction_feature("rotation_direction", create_coord_index(), preferred, PENALTY).unwrap()
+}
+
+#[test]
+fn can_penalize_back_and_forth_route_more_than_rotating_one_with_equal_distance() {
+    let feature = create_feature(None);
+
+    let rotating = get_route_fitness_with_locations(&feature, &[1, 2, 3]);
+    let back_and_forth = get_route_fitness_with_locations(&feature, &[2, 1, 2]);
+
+    assert_eq!(rotating, 0.);
+    assert_eq!(back_and_forth, PENALTY);
+}
+
+parameterized_test! {can_use_preferred_direction, (preferred, locations, expected), {
+    can_use_preferred_direction_impl(preferred, locations, expected);
+}}
+
+can_use_preferred_direction! {
+    case01_counterclockwise_preferred: (Some(RotationDirection::CounterClockwise), vec![1, 2, 3], 0.),
+    case02_clockwise_preferred: (Some(RotationDirection::Clockwise), vec![1, 2, 3], PENALTY),
+    case03_clockwise_preferred_and_used: (Some(RotationDirection::Clockwise), vec![3, 2, 1], 0.),
+    case04_no_preference: (None, vec![3, 2, 1], 0.),
+}
+
+fn can_use_preferred_direction_impl(preferred: Option<RotationDirection>, locations: Vec<Location>, expected: Cost) {
+    let feature = create_feature(preferred);
+
+    let fitness = get_route_fitness_with_locations(&feature, locations.as_slice());
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (target_location, expected), {
+    can_estimate_activity_insertion_impl(target_location, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_keeps_rotation: (5, 0.),
+    case02_reverses_rotation: (6, PENALTY),
+    case03_no_coordinates: (7, 0.),
+}
+
+fn can_estimate_activity_insertion_impl(target_location: Location, expected: Cost) {
+    let feature = create_feature(None);
+    let mut route_ctx = create_route_ctx_with_locations(&[1, 2]);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let prev = route_ctx.route().tour.get(1).unwrap();
+    let next = route_ctx.route().tour.get(2);
+    let target = ActivityBuilder::with_location(target_location).build();
+    let activity_ctx = ActivityContext { index: 1, prev, target: &target, next };
+
+    let estimate = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(estimate, expected);
+}
+
+#[test]
+fn can_cache_route_penalty_in_route_state() {
+    let feature = create_feature(None);
+    let mut route_ctx = create_route_ctx_with_locations(&[2, 1, 2]);
+
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state().get_rotation_penalty(), Some(&PENALTY));
+    assert_eq!(route_ctx.state().get_rotation_bearings().map(|bearings| bearings.len()), Some(5));
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature(max_diameter: Distance) -> Feature {
+    create_route_diameter_feature("route_diameter", VIOLATION_CODE, max_diameter, TestTransportCost::new_shared())
+        .unwrap()
+}
+
+fn create_test_route_ctx(locations: Vec<Location>) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(locations.into_iter().map(|location| {
+                    ActivityBuilder::with_location(location)
+                        .job(Some(TestSingleBuilder::default().location(Some(location)).build_shared()))
+                        .build()
+                }))
+                .build(),
+        )
+        .build()
+}
+
+parameterized_test! {can_limit_route_diameter, (route_locations, job_location, max_diameter, expected), {
+    can_limit_route_diameter_impl(route_locations, job_location, max_diameter, expected);
+}}
+
+can_limit_route_diameter! {
+    case_01_within_diameter: (vec![10, 15], 12, 10., None),
+    case_02_on_diameter_limit: (vec![10, 15], 20, 10., None),
+    case_03_far_flung_job: (vec![10, 15], 30, 10., Some(VIOLATION_CODE)),
+    case_04_far_flung_from_one_side: (vec![10, 15], 1, 10., Some(VIOLATION_CODE)),
+    case_05_empty_route: (vec![], 100, 10., None),
+}
+
+fn can_limit_route_diameter_impl(
+    route_locations: Vec<Location>,
+    job_location: Location,
+    max_diameter: Distance,
+    expected: Option<ViolationCode>,
+) {
+    let feature = create_feature(max_diameter);
+    let mut route_ctx = create_test_route_ctx(route_locations);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+    let job = TestSingleBuilder::default().location(Some(job_location)).build_as_job_ref();
+
+    let result =
+        feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: true }));
+}
+
+#[test]
+fn can_update_route_diameter_incrementally_on_insertion() {
+    let feature = create_feature(100.);
+    let state = feature.state.as_ref().unwrap();
+    let mut route_ctx = create_test_route_ctx(vec![10, 15]);
+    state.accept_route_state(&mut route_ctx);
+    assert_eq!(route_ctx.state().get_route_diameter().copied(), Some(5.));
+
+    let job = TestSingleBuilder::default().location(Some(30)).build_shared();
+    route_ctx.route_mut().tour.insert_last(ActivityBuilder::with_location(30).job(Some(job.clone())).build());
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+    state.accept_insertion(&mut solution_ctx, 0, &Job::Single(job));
+
+    assert_eq!(solution_ctx.routes[0].state().get_route_diameter().copied(), Some(20.));
+}
+
+#[test]
+fn can_recalculate_route_diameter_after_job_removal() {
+    let feature = create_feature(10.);
+    let state = feature.state.as_ref().unwrap();
+    let mut route_ctx = create_test_route_ctx(vec![10, 15, 30]);
+    state.accept_route_state(&mut route_ctx);
+    let far_job = Job::Single(route_ctx.route().tour.get(3).and_then(|activity| activity.job.clone()).unwrap());
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+    solution_ctx.routes[0].route_mut().tour.remove(&far_job);
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].state().get_route_diameter().copied(), Some(5.));
+    let job = TestSingleBuilder::default().location(Some(20)).build_as_job_ref();
+    let result =
+        feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job));
+    assert_eq!(result, None);
+}
+
+#[test]
+fn can_reject_negative_diameter() {
+    let result = create_route_diameter_feature("route_diameter", VIOLATION_CODE, -1., TestTransportCost::new_shared());
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::construction::features::*;
+use crate::helpers::models::solution::ActivityBuilder;
+
+const MAX_ANGLE: Float = 90.;
+
+fn create_coord_index(coordinates: Vec<(Float, Float)>) -> CoordIndexFn {
+    Arc::new(move |location| coordinates.get(location).map(|&(x, y)| Point::new(x, y)))
+}
+
+fn create_feature(coordinates: Vec<(Float, Float)>) -> Feature {
+    create_sharp_turns_feature("sharp_turns", create_coord_index(coordinates), MAX_ANGLE, 1.).unwrap()
+}
+
+#[test]
+fn can_penalize_sharp_backtrack_more_than_smooth_route() {
+    let feature = create_feature(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (2., 0.)]);
+
+    let smooth = get_route_fitness_with_locations(&feature, &[1, 2, 3]);
+    let backtrack = get_route_fitness_with_locations(&feature, &[1, 4, 1]);
+
+    assert!(smooth.abs() < 1E-6);
+    assert!((backtrack - 90.).abs() < 1E-6);
+}
+
+#[test]
+fn can_ignore_locations_without_coordinates() {
+    let feature = create_feature(vec![(0., 0.), (1., 0.)]);
+
+    let fitness = get_route_fitness_with_locations(&feature, &[1, 7, 1]);
+
+    assert_eq!(fitness, 0.);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (target_location, expected), {
+    can_estimate_activity_insertion_impl(target_location, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_smoother_turn: (2, -45.),
+    case02_same_turn: (4, 0.),
+    case03_no_coordinates: (7, -90.),
+}
+
+fn can_estimate_activity_insertion_impl(target_location: Location, expected: Cost) {
+    let feature = create_feature(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (2., 0.)]);
+    let route_ctx = create_route_ctx_with_locations(&[1]);
+    let target = ActivityBuilder::with_location(target_location).build();
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let result = feature.objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert!((result - expected).abs() < 1E-6);
+}
@@ -0,0 +1,111 @@
+use super::*;
+use crate::construction::features::{JobSkills, JobSkillsDimension};
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature(budget: Distance) -> Feature {
+    let budgets = vec![("s1".to_string(), budget)].into_iter().collect();
+    create_skill_distance_budget_feature("skill_distance", VIOLATION_CODE, budgets, TestTransportCost::new_shared())
+        .unwrap()
+}
+
+fn create_single(location: Location, skill: Option<&str>) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location));
+    if let Some(skill) = skill {
+        builder.dimens_mut().set_job_skills(JobSkills::new(Some(vec![skill.to_string()]), None, None));
+    }
+
+    builder.build_shared()
+}
+
+fn create_insertion_ctx(feature: &Feature, locations: Vec<(Location, Option<&str>)>) -> InsertionContext {
+    let activities = locations
+        .into_iter()
+        .map(|(location, skill)| {
+            ActivityBuilder::with_location(location).job(Some(create_single(location, skill))).build()
+        })
+        .collect::<Vec<_>>();
+    let mut insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![RouteContextBuilder::default()
+            .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+            .build()])
+        .build();
+
+    let state = feature.state.as_ref().unwrap();
+    let job = insertion_ctx.solution.routes[0].route().tour.jobs().next().cloned().unwrap();
+    state.accept_insertion(&mut insertion_ctx.solution, 0, &job);
+
+    insertion_ctx
+}
+
+parameterized_test! {can_block_job_when_skill_budget_exhausted, (budget, skill, expected), {
+    let feature = create_feature(budget);
+    let insertion_ctx = create_insertion_ctx(&feature, vec![(10, Some("s1")), (20, Some("s1"))]);
+    let job = Job::Single(create_single(30, skill));
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(
+        &insertion_ctx.solution,
+        &insertion_ctx.solution.routes[0],
+        &job,
+    ));
+
+    assert_eq!(result, expected);
+}}
+
+can_block_job_when_skill_budget_exhausted! {
+    case01_exhausted: (20., Some("s1"), ConstraintViolation::fail(VIOLATION_CODE)),
+    case02_exceeded: (15., Some("s1"), ConstraintViolation::fail(VIOLATION_CODE)),
+    case03_available: (25., Some("s1"), None),
+    case04_other_skill: (20., Some("s2"), None),
+    case05_no_skill: (20., None, None),
+}
+
+parameterized_test! {can_evaluate_skill_distance_on_activity_insertion, (budget, location, skill, index, expected), {
+    let feature = create_feature(budget);
+    let insertion_ctx = create_insertion_ctx(&feature, vec![(10, Some("s1")), (20, None)]);
+    let route_ctx = &insertion_ctx.solution.routes[0];
+    let tour = &route_ctx.route().tour;
+    let target = ActivityBuilder::with_location(location).job(Some(create_single(location, skill))).build();
+    let activity_ctx =
+        ActivityContext { index, prev: tour.get(index).unwrap(), target: &target, next: tour.get(index + 1) };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}}
+
+can_evaluate_skill_distance_on_activity_insertion! {
+    case01_within_budget: (15., 12, Some("s1"), 1, None),
+    case02_exceeds_budget: (15., 18, Some("s1"), 1, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_no_skill_before_skill_job: (15., 5, None, 0, None),
+    case04_no_skill_detour_to_skill_job: (10., 25, None, 0, ConstraintViolation::skip(VIOLATION_CODE)),
+    case05_no_skill_after_skill_job: (10., 15, None, 1, None),
+}
+
+#[test]
+fn can_track_skill_distances_in_solution_state() {
+    let feature = create_feature(100.);
+
+    let insertion_ctx = create_insertion_ctx(&feature, vec![(10, Some("s1")), (20, None), (15, Some("s1"))]);
+
+    let distances = insertion_ctx.solution.state.get_skill_distances().unwrap();
+    assert_eq!(distances.get("s1").copied(), Some(15.));
+}
+
+#[test]
+fn can_update_skill_distances_after_job_removal() {
+    let feature = create_feature(100.);
+    let mut insertion_ctx = create_insertion_ctx(&feature, vec![(10, Some("s1")), (20, None), (15, Some("s1"))]);
+    let job =
+        insertion_ctx.solution.routes[0].route().tour.get(3).and_then(|activity| activity.retrieve_job()).unwrap();
+
+    insertion_ctx.solution.routes[0].route_mut().tour.remove(&job);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+
+    let distances = insertion_ctx.solution.state.get_skill_distances().unwrap();
+    assert_eq!(distances.get("s1").copied(), Some(10.));
+}
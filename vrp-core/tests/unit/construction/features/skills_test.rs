@@ -1,13 +1,20 @@
-use crate::construction::features::skills::create_skills_feature;
-use crate::construction::features::{JobSkills, JobSkillsDimension, VehicleSkillsDimension};
-use crate::construction::heuristics::MoveContext;
+use crate::construction::features::skills::{
+    create_max_route_skills_feature, create_skills_feature, create_skills_feature_with_merge_listener,
+    create_soft_skills_feature,
+};
+use crate::construction::features::{
+    JobPreferredSkillsDimension, JobSkillSet, JobSkills, JobSkillsDimension, VehicleSkillsDimension,
+};
+use crate::construction::heuristics::{MoveContext, SolutionContext};
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder, TestVehicleBuilder};
-use crate::helpers::models::solution::{RouteBuilder, RouteContextBuilder};
-use crate::models::problem::{Job, Vehicle};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::common::Cost;
+use crate::models::problem::{Job, JobIdDimension, Vehicle};
 use crate::models::{ConstraintViolation, ViolationCode};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
+use std::sync::{Arc, Mutex};
 
 const VIOLATION_CODE: ViolationCode = ViolationCode(1);
 
@@ -128,6 +135,51 @@ fn can_merge_skills_impl(source: Job, candidate: Job, expected: Result<(), Viola
     assert_eq!(result, expected);
 }
 
+parameterized_test! {can_notify_about_merge_failure, (source, candidate, expected), {
+    can_notify_about_merge_failure_impl(source, candidate, expected);
+}}
+
+can_notify_about_merge_failure! {
+    case_01_all_of: ((Some(vec!["skill1"]), None, None), (Some(vec!["skill2"]), None, None), Some(JobSkillSet::AllOf)),
+    case_02_one_of: ((None, Some(vec!["skill1"]), None), (None, Some(vec!["skill2"]), None), Some(JobSkillSet::OneOf)),
+    case_03_none_of: ((None, None, None), (None, None, Some(vec!["skill"])), Some(JobSkillSet::NoneOf)),
+    case_04_first_mismatch: ((None, None, None), (Some(vec!["skill"]), None, Some(vec!["skill"])), Some(JobSkillSet::AllOf)),
+    case_05_compatible: ((Some(vec!["skill"]), None, None), (Some(vec!["skill"]), None, None), None),
+}
+
+type SkillsData = (Option<Vec<&'static str>>, Option<Vec<&'static str>>, Option<Vec<&'static str>>);
+
+fn can_notify_about_merge_failure_impl(source: SkillsData, candidate: SkillsData, expected: Option<JobSkillSet>) {
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let constraint = create_skills_feature_with_merge_listener("skills", VIOLATION_CODE, {
+        let failures = failures.clone();
+        Arc::new(move |source: &Job, candidate: &Job, set: JobSkillSet| {
+            let get_id = |job: &Job| job.dimens().get_job_id().cloned().unwrap_or_default();
+            failures.lock().unwrap().push((get_id(source), get_id(candidate), set));
+        })
+    })
+    .unwrap()
+    .constraint
+    .unwrap();
+    let create_job = |id: &str, (all_of, one_of, none_of): SkillsData| {
+        let mut builder = TestSingleBuilder::default();
+        builder.id(id).dimens_mut().set_job_skills(JobSkills {
+            all_of: all_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+            one_of: one_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+            none_of: none_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+        });
+        builder.build_as_job_ref()
+    };
+
+    let result = constraint.merge(create_job("source", source), create_job("candidate", candidate)).map(|_| ());
+
+    assert_eq!(result, if expected.is_some() { Err(VIOLATION_CODE) } else { Ok(()) });
+    assert_eq!(
+        failures.lock().unwrap().clone(),
+        expected.map(|set| ("source".to_string(), "candidate".to_string(), set)).into_iter().collect::<Vec<_>>()
+    );
+}
+
 #[test]
 fn can_create_empty_skills_as_none() {
     let skills = JobSkills::new(Some(vec![]), Some(vec![]), Some(vec![]));
@@ -136,3 +188,191 @@ fn can_create_empty_skills_as_none() {
     assert!(skills.one_of.is_none());
     assert!(skills.none_of.is_none());
 }
+
+#[test]
+fn can_build_skills_with_contradiction() {
+    let result = JobSkills::builder().set_all_of(&["s1", "s2"]).set_none_of(&["s3", "s2"]).build();
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("skill 's2' cannot be defined in both all_of and none_of".to_string())
+    );
+}
+
+#[test]
+fn can_build_skills_with_deduplication() {
+    let skills = JobSkills::builder()
+        .set_all_of(&["s1", "s1", "s2"])
+        .set_one_of(&["s3", "s3"])
+        .set_none_of(&["s4", "s4"])
+        .build()
+        .expect("cannot build skills");
+
+    assert_eq!(skills.all_of, Some(HashSet::from_iter(["s1".to_string(), "s2".to_string()])));
+    assert_eq!(skills.one_of, Some(HashSet::from_iter(["s3".to_string()])));
+    assert_eq!(skills.none_of, Some(HashSet::from_iter(["s4".to_string()])));
+}
+
+#[test]
+fn can_build_empty_skills_as_none() {
+    let skills = JobSkills::builder().set_all_of(&[]).set_one_of(&[]).set_none_of(&[]).build().expect("cannot build");
+
+    assert!(skills.all_of.is_none());
+    assert!(skills.one_of.is_none());
+    assert!(skills.none_of.is_none());
+}
+
+parameterized_test! {can_limit_distinct_route_skills, (route_skills, job_skills, expected), {
+    can_limit_distinct_route_skills_impl(route_skills, job_skills, expected);
+}}
+
+can_limit_distinct_route_skills! {
+    case_01: (vec![vec!["s1"], vec!["s2"]], Some(vec!["s3"]), failure()),
+    case_02: (vec![vec!["s1"], vec!["s2"]], Some(vec!["s1"]), None),
+    case_03: (vec![vec!["s1"], vec!["s2"]], Some(vec!["s1", "s2"]), None),
+    case_04: (vec![vec!["s1"], vec!["s2"]], None, None),
+    case_05: (vec![vec!["s1"]], Some(vec!["s2"]), None),
+    case_06: (vec![vec!["s1"]], Some(vec!["s2", "s3"]), failure()),
+    case_07: (vec![], Some(vec!["s1", "s2", "s3"]), failure()),
+}
+
+fn can_limit_distinct_route_skills_impl(
+    route_skills: Vec<Vec<&str>>,
+    job_skills: Option<Vec<&str>>,
+    expected: Option<ConstraintViolation>,
+) {
+    let feature = create_max_route_skills_feature("max_skills", VIOLATION_CODE, 2).unwrap();
+    let activities = route_skills
+        .into_iter()
+        .map(|skills| {
+            let job = create_job_with_skills(Some(skills), None, None);
+            ActivityBuilder::with_location(1).job(Some(job.to_single().clone())).build()
+        })
+        .collect::<Vec<_>>();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    let actual = feature.constraint.unwrap().evaluate(&MoveContext::route(
+        &TestInsertionContextBuilder::default().build().solution,
+        &route_ctx,
+        &create_job_with_skills(job_skills, None, None),
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_update_distinct_route_skills_after_job_removal() {
+    let feature = create_max_route_skills_feature("max_skills", VIOLATION_CODE, 2).unwrap();
+    let state = feature.state.as_ref().unwrap();
+    let jobs =
+        [create_job_with_skills(Some(vec!["s1"]), None, None), create_job_with_skills(Some(vec!["s2"]), None, None)];
+    let activities =
+        jobs.iter().map(|job| ActivityBuilder::with_location(1).job(Some(job.to_single().clone())).build());
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build();
+    state.accept_route_state(&mut route_ctx);
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+    let job = create_job_with_skills(Some(vec!["s3"]), None, None);
+    let evaluate = |solution_ctx: &SolutionContext| {
+        feature.constraint.as_ref().unwrap().evaluate(&MoveContext::route(solution_ctx, &solution_ctx.routes[0], &job))
+    };
+    assert_eq!(evaluate(&solution_ctx), failure());
+
+    solution_ctx.routes[0].route_mut().tour.remove(&jobs[1]);
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(evaluate(&solution_ctx), None);
+}
+
+parameterized_test! {can_merge_with_distinct_route_skills_limit, (source, candidate, expected), {
+    let constraint = create_max_route_skills_feature("max_skills", VIOLATION_CODE, 2).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(create_job_with_skills(source, None, None), create_job_with_skills(candidate, None, None)).map(|_| ());
+
+    assert_eq!(result, expected);
+}}
+
+can_merge_with_distinct_route_skills_limit! {
+    case_01: (Some(vec!["s1"]), Some(vec!["s2"]), Ok(())),
+    case_02: (Some(vec!["s1", "s2"]), Some(vec!["s3"]), Err(VIOLATION_CODE)),
+    case_03: (Some(vec!["s1", "s2"]), Some(vec!["s2"]), Ok(())),
+    case_04: (None, Some(vec!["s1", "s2", "s3"]), Err(VIOLATION_CODE)),
+}
+
+fn create_job_with_preferred_skills(preferred: Vec<(&str, Cost)>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    builder.dimens_mut().set_job_preferred_skills(
+        preferred.into_iter().map(|(skill, penalty)| (skill.to_string(), penalty)).collect::<HashMap<_, _>>(),
+    );
+
+    builder.build_as_job_ref()
+}
+
+parameterized_test! {can_estimate_soft_skills, (preferred, vehicle_skills, expected), {
+    can_estimate_soft_skills_impl(preferred, vehicle_skills, expected);
+}}
+
+can_estimate_soft_skills! {
+    case01_no_preferred: (vec![], Some(vec!["s1"]), 0.),
+    case02_preferred_match: (vec![("s1", 10.)], Some(vec!["s1"]), 0.),
+    case03_preferred_mismatch: (vec![("s1", 10.)], Some(vec!["s2"]), 10.),
+    case04_no_vehicle_skills: (vec![("s1", 10.)], None, 10.),
+    case05_partial_match: (vec![("s1", 10.), ("s2", 5.), ("s3", 1.)], Some(vec!["s2"]), 11.),
+}
+
+fn can_estimate_soft_skills_impl(preferred: Vec<(&str, Cost)>, vehicle_skills: Option<Vec<&str>>, expected: Cost) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_vehicle_with_skills(vehicle_skills))
+        .build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let objective = create_soft_skills_feature("soft_skills").unwrap().objective.unwrap();
+
+    let actual = objective.estimate(&MoveContext::route(
+        &TestInsertionContextBuilder::default().build().solution,
+        &route_ctx,
+        &create_job_with_preferred_skills(preferred),
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_assign_job_to_non_preferred_vehicle_with_worse_score() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(TestVehicleBuilder::default().id("v1").build())
+        .add_vehicle({
+            let mut builder = TestVehicleBuilder::default();
+            builder.dimens_mut().set_vehicle_skills(HashSet::from_iter(["s1".to_string()]));
+            builder.id("v2").build()
+        })
+        .build();
+    let job = create_job_with_preferred_skills(vec![("s1", 100.)]);
+    let create_insertion_ctx = |vehicle_id: &str| {
+        TestInsertionContextBuilder::default()
+            .with_routes(vec![RouteContextBuilder::default()
+                .with_route(
+                    RouteBuilder::default()
+                        .with_vehicle(&fleet, vehicle_id)
+                        .add_activity(ActivityBuilder::with_location(1).job(Some(job.to_single().clone())).build())
+                        .build(),
+                )
+                .build()])
+            .build()
+    };
+    let feature = create_soft_skills_feature("soft_skills").unwrap();
+    let objective = feature.objective.unwrap();
+
+    let preferred = objective.fitness(&create_insertion_ctx("v2"));
+    let non_preferred = objective.fitness(&create_insertion_ctx("v1"));
+
+    assert!(feature.constraint.is_none());
+    assert_eq!(preferred, 0.);
+    assert_eq!(non_preferred, 100.);
+}
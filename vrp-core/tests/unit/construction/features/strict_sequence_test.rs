@@ -0,0 +1,64 @@
+use super::*;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_multi(is_strict: bool) -> Arc<Multi> {
+    let load = TestSingleBuilder::default().id("load").location(Some(1)).build_shared();
+    let unload = TestSingleBuilder::default().id("unload").location(Some(2)).build_shared();
+
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id("multi".to_string());
+    if is_strict {
+        dimens.set_strict_sequence(true);
+    }
+
+    Multi::new_shared(vec![load, unload], dimens)
+}
+
+parameterized_test! {can_keep_declared_order_of_multi_job, (is_strict, placed_idx, target_idx, insertion_idx, expected), {
+    can_keep_declared_order_of_multi_job_impl(is_strict, placed_idx, target_idx, insertion_idx, expected);
+}}
+
+can_keep_declared_order_of_multi_job! {
+    case01_load_before_unload: (true, 1, 0, 0, None),
+    case02_load_after_unload: (true, 1, 0, 1, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_unload_after_load: (true, 0, 1, 1, None),
+    case04_unload_before_load: (true, 0, 1, 0, ConstraintViolation::skip(VIOLATION_CODE)),
+    case05_not_strict: (false, 1, 0, 1, None),
+}
+
+fn can_keep_declared_order_of_multi_job_impl(
+    is_strict: bool,
+    placed_idx: usize,
+    target_idx: usize,
+    insertion_idx: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    let multi = create_multi(is_strict);
+    let placed = multi.jobs[placed_idx].clone();
+    let target = multi.jobs[target_idx].clone();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activity(
+                    ActivityBuilder::with_location(placed.places[0].location.unwrap()).job(Some(placed)).build(),
+                )
+                .build(),
+        )
+        .build();
+    let tour = &route_ctx.route().tour;
+    let target = ActivityBuilder::with_location(target.places[0].location.unwrap()).job(Some(target)).build();
+    let activity_ctx = ActivityContext {
+        index: insertion_idx,
+        prev: tour.get(insertion_idx).unwrap(),
+        target: &target,
+        next: tour.get(insertion_idx + 1),
+    };
+    let feature = create_strict_sequence_feature("strict_sequence", VIOLATION_CODE).unwrap();
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,178 @@
+use super::*;
+use crate::helpers::models::domain::{test_random, TestGoalContextBuilder};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::problem::{Fleet, Single};
+use crate::models::solution::{Activity, Registry};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_test_feature() -> Feature {
+    create_synchronization_feature("sync", TestTransportCost::new_shared(), VIOLATION_CODE).unwrap()
+}
+
+fn create_test_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicles_with_ids(&["v1", "v2"]).build()
+}
+
+fn create_sync_single(group: &str, location: Location) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location)).duration(10.);
+    builder.dimens_mut().set_job_sync_group(group.to_string());
+
+    builder.build_shared()
+}
+
+fn create_sync_activity(group: &str, location: Location, schedule: Schedule) -> Activity {
+    ActivityBuilder::with_location_tw_and_duration(location, DEFAULT_ACTIVITY_TIME_WINDOW, 10.)
+        .schedule(schedule)
+        .job(Some(create_sync_single(group, location)))
+        .build()
+}
+
+/// Creates solution with a partner job served on the first route (v1) at location 5 within [10, 20]
+/// and its sync job to be inserted.
+fn create_test_solution_context(fleet: &Fleet) -> SolutionContext {
+    let partner = create_sync_activity("s1", 5, Schedule::new(10., 20.));
+
+    SolutionContext {
+        required: vec![Job::Single(create_sync_single("s1", 5))],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        routes: vec![
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(fleet, "v1").add_activity(partner).build())
+                .build(),
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(fleet, "v2").build())
+                .build(),
+        ],
+        registry: RegistryContext::new(&TestGoalContextBuilder::default().build(), Registry::new(fleet, test_random())),
+        state: Default::default(),
+    }
+}
+
+parameterized_test! {can_evaluate_sync_activity, (prev_departure, location, expected), {
+    can_evaluate_sync_activity_impl(prev_departure, location, expected);
+}}
+
+can_evaluate_sync_activity! {
+    case_01_overlapping_start: (0., 5, None),
+    case_02_overlapping_end: (10., 5, None),
+    case_03_too_late: (100., 5, Some(VIOLATION_CODE)),
+    case_04_different_location: (0., 6, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_sync_activity_impl(prev_departure: Float, location: Location, expected: Option<ViolationCode>) {
+    let fleet = create_test_fleet();
+    let feature = create_test_feature();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.get(1).unwrap();
+    let prev = ActivityBuilder::with_location(0).schedule(Schedule::new(0., prev_departure)).build();
+    let target = create_sync_activity("s1", location, Schedule::new(0., 0.));
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: false }));
+}
+
+parameterized_test! {can_evaluate_sync_job_on_route, (route_idx, expected), {
+    can_evaluate_sync_job_on_route_impl(route_idx, expected);
+}}
+
+can_evaluate_sync_job_on_route! {
+    case_01_same_vehicle: (0, Some(VIOLATION_CODE)),
+    case_02_different_vehicle: (1, None),
+}
+
+fn can_evaluate_sync_job_on_route_impl(route_idx: usize, expected: Option<ViolationCode>) {
+    let fleet = create_test_fleet();
+    let solution_ctx = create_test_solution_context(&fleet);
+    let route_ctx = solution_ctx.routes.get(route_idx).unwrap();
+    let job = Job::Single(create_sync_single("s1", 5));
+
+    let result =
+        create_test_feature().constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, route_ctx, &job));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: true }));
+}
+
+#[test]
+fn can_keep_only_partner_services_in_route_state() {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+
+    create_test_feature().state.unwrap().accept_solution_state(&mut solution_ctx);
+
+    let own = solution_ctx.routes[0].state().get_sync_partner_services().unwrap();
+    let other = solution_ctx.routes[1].state().get_sync_partner_services().unwrap();
+    assert!(own.is_empty());
+    assert_eq!(other.get("s1"), Some(&(5, TimeWindow::new(10., 20.))));
+}
+
+parameterized_test! {can_evaluate_insertion_before_sync_activity, (location, expected), {
+    can_evaluate_insertion_before_sync_activity_impl(location, expected);
+}}
+
+can_evaluate_insertion_before_sync_activity! {
+    case_01_keeps_sync: (3, None),
+    case_02_shifts_sync_too_much: (10, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_insertion_before_sync_activity_impl(location: Location, expected: Option<ViolationCode>) {
+    let fleet = create_test_fleet();
+    let feature = create_test_feature();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+    solution_ctx.routes[1].route_mut().tour.insert_last(create_sync_activity("s1", 5, Schedule::new(5., 15.)));
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.get(1).unwrap();
+    let tour = &route_ctx.route().tour;
+    let target = ActivityBuilder::with_location_tw_and_duration(location, DEFAULT_ACTIVITY_TIME_WINDOW, 10.)
+        .job(Some(TestSingleBuilder::default().location(Some(location)).build_shared()))
+        .build();
+    let activity_ctx = ActivityContext { index: 0, prev: tour.get(0).unwrap(), target: &target, next: tour.get(1) };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: false }));
+}
+
+#[test]
+fn can_update_partner_services_on_any_insertion() {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+    let state = create_test_feature().state.unwrap();
+    state.accept_solution_state(&mut solution_ctx);
+    let partner = solution_ctx.routes[0].route_mut().tour.get_mut(1).unwrap();
+    partner.schedule = Schedule::new(30., 40.);
+
+    state.accept_insertion(&mut solution_ctx, 0, &Job::Single(TestSingleBuilder::default().build_shared()));
+
+    let other = solution_ctx.routes[1].state().get_sync_partner_services().unwrap();
+    assert_eq!(other.get("s1"), Some(&(5, TimeWindow::new(30., 40.))));
+}
+
+parameterized_test! {can_remove_incomplete_sync_pair, (is_partner_required, expected_removed), {
+    can_remove_incomplete_sync_pair_impl(is_partner_required, expected_removed);
+}}
+
+can_remove_incomplete_sync_pair! {
+    case_01_partner_missing: (false, true),
+    case_02_partner_required: (true, false),
+}
+
+fn can_remove_incomplete_sync_pair_impl(is_partner_required: bool, expected_removed: bool) {
+    let fleet = create_test_fleet();
+    let mut solution_ctx = create_test_solution_context(&fleet);
+    if !is_partner_required {
+        solution_ctx.required.clear();
+    }
+
+    create_test_feature().state.unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count() == 0, expected_removed);
+    assert_eq!(solution_ctx.unassigned.len(), if expected_removed { 1 } else { 0 });
+}
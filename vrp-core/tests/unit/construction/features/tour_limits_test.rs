@@ -167,3 +167,243 @@ mod traveling {
         assert_eq!(result, ConstraintViolation::skip(DURATION_CODE));
     }
 }
+
+mod soft_traveling {
+    use super::*;
+    use crate::construction::enablers::TotalDistanceTourState;
+    use crate::models::common::*;
+
+    const DISTANCE_CODE: ViolationCode = ViolationCode(2);
+    const DURATION_CODE: ViolationCode = ViolationCode(3);
+
+    fn create_test_data(limit: SoftTravelLimit<Distance>) -> (Feature, RouteContext) {
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+        let mut state = RouteState::default();
+        state.set_total_distance(50.);
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build())
+            .with_state(state)
+            .build();
+        let feature = create_soft_travel_limit_feature(
+            "soft_travel_limit",
+            TestTransportCost::new_shared(),
+            DISTANCE_CODE,
+            DURATION_CODE,
+            Arc::new(move |_| Some(limit)),
+            Arc::new(|_| None),
+        )
+        .unwrap();
+
+        (feature, route_ctx)
+    }
+
+    parameterized_test! {can_apply_tiered_travel_limits, (location, expected_violation, expected_cost), {
+        can_apply_tiered_travel_limits_impl(location, expected_violation, expected_cost);
+    }}
+
+    can_apply_tiered_travel_limits! {
+        case01_below_soft: (52, None, 0.),
+        case02_at_soft: (55, None, 0.),
+        case03_between_soft_and_hard: (60, None, 20.),
+        case04_close_to_hard: (74, None, 76.),
+        case05_above_hard: (76, ConstraintViolation::skip(DISTANCE_CODE), 84.),
+    }
+
+    fn can_apply_tiered_travel_limits_impl(
+        location: Location,
+        expected_violation: Option<ConstraintViolation>,
+        expected_cost: Cost,
+    ) {
+        let (feature, route_ctx) = create_test_data(SoftTravelLimit { soft: 60., hard: 100., penalty_slope: 2. });
+        let (prev, target, next) = (
+            ActivityBuilder::with_location(50).build(),
+            ActivityBuilder::with_location(location).build(),
+            ActivityBuilder::with_location(50).build(),
+        );
+        let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: Some(&next) };
+        let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+
+        let violation = feature.constraint.unwrap().evaluate(&move_ctx);
+        let cost = feature.objective.unwrap().estimate(&move_ctx);
+
+        assert_eq!(violation, expected_violation);
+        assert_eq!(cost, expected_cost);
+    }
+
+    #[test]
+    fn can_calculate_penalty() {
+        let limit = SoftTravelLimit { soft: 10., hard: 20., penalty_slope: 0.5 };
+
+        assert_eq!(limit.get_penalty(5.), 0.);
+        assert_eq!(limit.get_penalty(10.), 0.);
+        assert_eq!(limit.get_penalty(14.), 2.);
+        assert_eq!(limit.get_penalty(20.), 5.);
+    }
+}
+
+mod route_cost {
+    use super::*;
+    use crate::construction::enablers::{TotalDistanceTourState, TotalDurationTourState};
+
+    const VIOLATION_CODE: ViolationCode = ViolationCode(4);
+
+    parameterized_test! {can_limit_route_cost, (current, limit, expected), {
+        can_limit_route_cost_impl(current, limit, expected);
+    }}
+
+    can_limit_route_cost! {
+        case01_cheap_route: (50., Some(300.), None),
+        case02_near_cap_route: (60., Some(300.), ConstraintViolation::skip(VIOLATION_CODE)),
+        case03_exact_cap: (55., Some(300.), None),
+        case04_no_cap: (60., None, None),
+    }
+
+    fn can_limit_route_cost_impl(current: Float, limit: Option<Cost>, expected: Option<ConstraintViolation>) {
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+        let mut state = RouteState::default();
+        state.set_total_distance(current);
+        state.set_total_duration(current);
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build())
+            .with_state(state)
+            .build();
+        let feature = create_route_cost_limit_feature(
+            "route_cost_limit",
+            TestTransportCost::new_shared(),
+            VIOLATION_CODE,
+            Arc::new(move |_| limit),
+        )
+        .unwrap();
+
+        // NOTE: both vehicle and driver have per distance and per time costs equal to 1,
+        // so insertion adds 2 * (20 + 20) to the route cost
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &ActivityBuilder::with_location(50).build(),
+                target: &ActivityBuilder::with_location(60).build(),
+                next: Some(&ActivityBuilder::with_location(50).build()),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod min_jobs {
+    use super::*;
+    use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+
+    fn create_route_ctx(vehicle_id: &str, jobs: usize) -> RouteContext {
+        RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&test_fleet(), vehicle_id)
+                    .add_activities((0..jobs).map(|idx| ActivityBuilder::with_location(idx + 1).build()))
+                    .build(),
+            )
+            .build()
+    }
+
+    fn create_objective(min_jobs: Option<usize>) -> Arc<dyn FeatureObjective> {
+        create_min_jobs_limit_feature("min_jobs", Arc::new(move |_| min_jobs), 100.).unwrap().objective.unwrap()
+    }
+
+    parameterized_test! {can_penalize_tour_with_too_few_jobs, (jobs, min_jobs, expected), {
+        let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![create_route_ctx("v1", jobs)]).build();
+
+        let result = create_objective(min_jobs).fitness(&insertion_ctx);
+
+        assert_eq!(result, expected);
+    }}
+
+    can_penalize_tour_with_too_few_jobs! {
+        case01_one_job_rejected: (1, Some(2), 100.),
+        case02_empty_allowed: (0, Some(2), 0.),
+        case03_enough_jobs: (2, Some(2), 0.),
+        case04_more_jobs: (3, Some(2), 0.),
+        case05_two_missing: (1, Some(3), 200.),
+        case06_no_limit: (1, None, 0.),
+    }
+
+    parameterized_test! {can_estimate_job_insertion, (jobs, min_jobs, expected), {
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = create_route_ctx("v1", jobs);
+        let job = TestSingleBuilder::default().id("job").build_as_job_ref();
+
+        let result = create_objective(min_jobs).estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }}
+
+    can_estimate_job_insertion! {
+        case01_open_new_tour: (0, Some(2), 100.),
+        case02_complete_tour: (1, Some(2), -100.),
+        case03_already_complete: (2, Some(2), 0.),
+        case04_single_job_limit: (0, Some(1), 0.),
+        case05_no_limit: (0, None, 0.),
+    }
+
+    #[test]
+    fn can_reject_negative_penalty() {
+        let result = create_min_jobs_limit_feature("min_jobs", Arc::new(|_| Some(2)), -1.);
+
+        assert!(result.is_err());
+    }
+}
+
+mod tight_time_windows {
+    use super::*;
+    use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+
+    const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+    const MAX_WIDTH: Float = 10.;
+    const LIMIT: usize = 2;
+
+    fn create_job(is_tight: bool) -> Job {
+        let tw = if is_tight { TimeWindow::new(10., 15.) } else { TimeWindow::new(0., 100.) };
+
+        TestSingleBuilder::default().times(vec![tw]).build_as_job_ref()
+    }
+
+    parameterized_test! {can_limit_tight_time_window_jobs, (tight_jobs, wide_jobs, is_tight, expected), {
+        can_limit_tight_time_window_jobs_impl(tight_jobs, wide_jobs, is_tight, expected);
+    }}
+
+    can_limit_tight_time_window_jobs! {
+        case01_reject_third_tight: (2, 0, true, ConstraintViolation::fail(VIOLATION_CODE)),
+        case02_allow_second_tight: (1, 3, true, None),
+        case03_allow_wide_beyond_limit: (2, 3, false, None),
+        case04_allow_wide_on_empty: (0, 0, false, None),
+    }
+
+    fn can_limit_tight_time_window_jobs_impl(
+        tight_jobs: usize,
+        wide_jobs: usize,
+        is_tight: bool,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let activities = (0..tight_jobs)
+            .map(|_| true)
+            .chain((0..wide_jobs).map(|_| false))
+            .map(|is_tight| ActivityBuilder::default().job(create_job(is_tight).as_single().cloned()).build());
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&test_fleet(), "v1").add_activities(activities).build())
+            .build();
+        let constraint = create_tight_time_window_limit_feature(
+            "tight_time_window_limit",
+            VIOLATION_CODE,
+            MAX_WIDTH,
+            Arc::new(|_| Some(LIMIT)),
+        )
+        .unwrap()
+        .constraint
+        .unwrap();
+
+        let result = constraint.evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &create_job(is_tight)));
+
+        assert_eq!(result, expected);
+    }
+}
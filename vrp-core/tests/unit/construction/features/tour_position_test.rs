@@ -0,0 +1,74 @@
+use super::*;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_activity(location: Location, position: Option<JobTourPosition>) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(position) = position {
+        builder.dimens_mut().set_job_tour_position(position);
+    }
+
+    ActivityBuilder::with_location(location).job(Some(builder.build_shared())).build()
+}
+
+fn create_route_ctx(positions: Vec<Option<JobTourPosition>>) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(
+                    positions.into_iter().enumerate().map(|(idx, position)| create_activity(idx + 1, position)),
+                )
+                .build(),
+        )
+        .build()
+}
+
+parameterized_test! {can_evaluate_tour_position, (existing, target, index, expected), {
+    can_evaluate_tour_position_impl(existing, target, index, expected);
+}}
+
+can_evaluate_tour_position! {
+    case_01_start_first: (vec![None, None], Some(JobTourPosition::Start), 0, None),
+    case_02_start_middle: (vec![None, None], Some(JobTourPosition::Start), 1, Some(VIOLATION_CODE)),
+    case_03_start_last: (vec![None, None], Some(JobTourPosition::Start), 2, Some(VIOLATION_CODE)),
+    case_04_end_last: (vec![None, None], Some(JobTourPosition::End), 2, None),
+    case_05_end_first: (vec![None, None], Some(JobTourPosition::End), 0, Some(VIOLATION_CODE)),
+    case_06_any_before_start: (vec![Some(JobTourPosition::Start), None], None, 0, Some(VIOLATION_CODE)),
+    case_07_any_after_start: (vec![Some(JobTourPosition::Start), None], None, 1, None),
+    case_08_any_after_end: (vec![None, Some(JobTourPosition::End)], None, 2, Some(VIOLATION_CODE)),
+    case_09_any_before_end: (vec![None, Some(JobTourPosition::End)], None, 1, None),
+    case_10_start_after_start: (vec![Some(JobTourPosition::Start)], Some(JobTourPosition::Start), 1, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_tour_position_impl(
+    existing: Vec<Option<JobTourPosition>>,
+    target: Option<JobTourPosition>,
+    index: usize,
+    expected: Option<ViolationCode>,
+) {
+    let route_ctx = create_route_ctx(existing);
+    let target = create_activity(10, target);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let constraint = create_tour_position_feature("tour_position", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_reject_merge_of_job_with_tour_position() {
+    let constraint = create_tour_position_feature("tour_position", VIOLATION_CODE).unwrap().constraint.unwrap();
+    let flagged = create_activity(1, Some(JobTourPosition::Start)).job.map(Job::Single).unwrap();
+    let regular = create_activity(2, None).job.map(Job::Single).unwrap();
+
+    assert_eq!(constraint.merge(regular.clone(), flagged).map(|_| ()), Err(VIOLATION_CODE));
+    assert!(constraint.merge(regular.clone(), regular).is_ok());
+}
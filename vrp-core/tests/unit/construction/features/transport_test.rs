@@ -129,6 +129,104 @@ mod timing {
         assert_eq!(result, expected);
     }
 
+    parameterized_test! {can_detect_vehicle_unavailability, (unavailable, location, expected), {
+        can_detect_vehicle_unavailability_impl(unavailable, location, expected);
+    }}
+
+    can_detect_vehicle_unavailability! {
+        case01_no_block: (None, 15, None),
+        case02_before_block: (Some((20., 40.)), 5, None),
+        case03_arrival_in_block: (Some((20., 40.)), 30, ConstraintViolation::skip(VIOLATION_CODE)),
+        case04_return_in_block: (Some((20., 40.)), 15, ConstraintViolation::skip(VIOLATION_CODE)),
+        case05_after_block: (Some((100., 120.)), 10, None),
+        case06_service_in_block: (Some((12., 14.)), 10, ConstraintViolation::skip(VIOLATION_CODE)),
+    }
+
+    fn can_detect_vehicle_unavailability_impl(
+        unavailable: Option<(Timestamp, Timestamp)>,
+        location: Location,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let mut vehicle_builder = TestVehicleBuilder::default();
+        if let Some((start, end)) = unavailable {
+            vehicle_builder.dimens_mut().set_vehicle_unavailable_times(vec![TimeWindow::new(start, end)]);
+        }
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![vehicle_builder
+                .id("v1")
+                .details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))])
+                .build()])
+            .build();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build())
+            .build();
+        let feature = create_feature();
+        feature.state.unwrap().accept_route_state(&mut route_ctx);
+        let target = ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 1000.), 5.).build();
+        let activity_ctx = ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(1),
+        };
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_detect_vehicle_unavailability_of_shifted_activities, (unavailable, expected), {
+        can_detect_vehicle_unavailability_of_shifted_activities_impl(unavailable, expected);
+    }}
+
+    can_detect_vehicle_unavailability_of_shifted_activities! {
+        case01_no_block: (None, None),
+        case02_next_service_in_block: (Some((21., 23.)), ConstraintViolation::skip(VIOLATION_CODE)),
+        case03_return_in_block: (Some((30., 40.)), ConstraintViolation::skip(VIOLATION_CODE)),
+        case04_after_block: (Some((40., 50.)), None),
+    }
+
+    fn can_detect_vehicle_unavailability_of_shifted_activities_impl(
+        unavailable: Option<(Timestamp, Timestamp)>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let mut vehicle_builder = TestVehicleBuilder::default();
+        if let Some((start, end)) = unavailable {
+            vehicle_builder.dimens_mut().set_vehicle_unavailable_times(vec![TimeWindow::new(start, end)]);
+        }
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![vehicle_builder
+                .id("v1")
+                .details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))])
+                .build()])
+            .build();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&fleet, "v1")
+                    .add_activity(
+                        ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(0., 1000.), 5.).build(),
+                    )
+                    .build(),
+            )
+            .build();
+        let feature = create_feature();
+        feature.state.unwrap().accept_route_state(&mut route_ctx);
+        let target = ActivityBuilder::with_location_tw_and_duration(5, TimeWindow::new(0., 1000.), 10.).build();
+        let activity_ctx = ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(1),
+        };
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn can_update_activity_schedule() {
         let fleet = FleetBuilder::default()
@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::models::problem::{test_driver, test_vehicle_with_id, FleetBuilder, TestSingleBuilder};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+const DAY: TimeWindow = TimeWindow { start: 0., end: 100. };
+const NIGHT: TimeWindow = TimeWindow { start: 100., end: 200. };
+
+fn create_feature() -> Feature {
+    create_vehicle_time_windows_feature(
+        "vehicle_time_windows",
+        VIOLATION_CODE,
+        Arc::new(|actor| actor.vehicle.dimens.get_vehicle_id().cloned()),
+    )
+    .unwrap()
+}
+
+fn create_test_single() -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.times(vec![DAY, NIGHT]).dimens_mut().set_job_vehicle_time_windows(
+        vec![("loud".to_string(), vec![DAY]), ("quiet".to_string(), vec![DAY, NIGHT])].into_iter().collect(),
+    );
+
+    builder.build_shared()
+}
+
+parameterized_test! {can_use_vehicle_specific_time_windows, (vehicle_id, time, expected), {
+    can_use_vehicle_specific_time_windows_impl(vehicle_id, time, expected);
+}}
+
+can_use_vehicle_specific_time_windows! {
+    case01_loud_day: ("loud", DAY, None),
+    case02_loud_night: ("loud", NIGHT, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_quiet_day: ("quiet", DAY, None),
+    case04_quiet_night: ("quiet", NIGHT, None),
+    case05_unknown_night: ("other", NIGHT, None),
+}
+
+fn can_use_vehicle_specific_time_windows_impl(
+    vehicle_id: &str,
+    time: TimeWindow,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("loud"), test_vehicle_with_id("quiet"), test_vehicle_with_id("other")])
+        .build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).build())
+        .build();
+    let mut target = ActivityBuilder::default().job(Some(create_test_single())).build();
+    target.place.time = time;
+    let activity_ctx =
+        ActivityContext { index: 0, prev: route_ctx.route().tour.get(0).unwrap(), target: &target, next: None };
+
+    let result = create_feature().constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
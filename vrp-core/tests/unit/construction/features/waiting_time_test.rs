@@ -0,0 +1,72 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, TimeWindow};
+use crate::models::problem::SimpleActivityCost;
+
+fn create_feature() -> Feature {
+    create_min_waiting_feature("min_waiting", TestTransportCost::new_shared(), Arc::new(SimpleActivityCost::default()))
+        .unwrap()
+}
+
+fn create_activity(location: Location, time: (Timestamp, Timestamp), schedule: (Timestamp, Timestamp)) -> Activity {
+    ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(time.0, time.1), 1.)
+        .job(Some(TestSingleBuilder::default().location(Some(location)).build_shared()))
+        .schedule(Schedule::new(schedule.0, schedule.1))
+        .build()
+}
+
+parameterized_test! {can_calculate_total_waiting_time, (times, expected), {
+    can_calculate_total_waiting_time_impl(times, expected);
+}}
+
+can_calculate_total_waiting_time! {
+    case_01_wait_at_first: (vec![(5, (20., 100.)), (10, (0., 100.))], 15.),
+    case_02_wait_at_both: (vec![(5, (20., 100.)), (10, (40., 100.))], 29.),
+    case_03_open_ended: (vec![(5, (0., Float::MAX)), (10, (0., Float::MAX))], 0.),
+}
+
+fn can_calculate_total_waiting_time_impl(times: Vec<(Location, (Timestamp, Timestamp))>, expected: Cost) {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(times.into_iter().map(|(location, time)| create_activity(location, time, (0., 0.))))
+                .build(),
+        )
+        .build();
+    update_route_schedule(&mut route_ctx, &SimpleActivityCost::default(), &TestTransportCost::default());
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let result = create_feature().objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_waiting_time, (target_time, next, expected), {
+    can_estimate_waiting_time_impl(target_time, next, expected);
+}}
+
+can_estimate_waiting_time! {
+    case_01_no_next: ((20., 100.), None, 15.),
+    case_02_no_waiting: ((0., 100.), None, 0.),
+    case_03_reduces_next_waiting: ((20., 100.), Some((30., 100.)), -1.),
+    case_04_keeps_next_without_waiting: ((0., 100.), Some((0., 100.)), 0.),
+}
+
+fn can_estimate_waiting_time_impl(
+    target_time: (Timestamp, Timestamp),
+    next: Option<(Timestamp, Timestamp)>,
+    expected: Cost,
+) {
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+    let prev = ActivityBuilder::with_location(0).schedule(Schedule::new(0., 0.)).build();
+    let target = create_activity(5, target_time, (0., 0.));
+    let next = next.map(|time| create_activity(10, time, (10., 10.)));
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: next.as_ref() };
+
+    let result = create_feature().objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,100 @@
+use super::*;
+use crate::construction::features::JobSkills;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder, TestVehicleBuilder};
+use crate::helpers::models::solution::*;
+use std::collections::HashSet;
+
+fn create_skilled_job(skills: Option<Vec<&str>>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(skills) = skills {
+        builder.dimens_mut().set_job_skills(JobSkills::new(
+            Some(skills.iter().map(|s| s.to_string()).collect()),
+            None,
+            None,
+        ));
+    }
+
+    builder.build_as_job_ref()
+}
+
+fn create_skilled_vehicle(id: &str) -> Vehicle {
+    let mut builder = TestVehicleBuilder::default();
+    builder.dimens_mut().set_vehicle_skills(HashSet::from(["s1".to_string()]));
+
+    builder.id(id).build()
+}
+
+fn create_insertion_ctx(jobs_per_vehicle: Vec<usize>) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![create_skilled_vehicle("v1"), create_skilled_vehicle("v2")])
+        .build();
+    let routes = jobs_per_vehicle
+        .into_iter()
+        .zip(["v1", "v2"])
+        .map(|(job_count, vehicle_id)| {
+            let activities = (0..job_count).map(|idx| {
+                let job = create_skilled_job(Some(vec!["s1"]));
+                ActivityBuilder::with_location(idx + 1).job(Some(job.to_single().clone())).build()
+            });
+
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).add_activities(activities).build())
+                .build()
+        })
+        .collect();
+
+    TestInsertionContextBuilder::default().with_routes(routes).build()
+}
+
+#[test]
+fn can_prefer_sharing_skilled_jobs_between_vehicles() {
+    let objective = create_skill_balanced_feature("skill_balance").unwrap().objective.unwrap();
+
+    let shared = objective.fitness(&create_insertion_ctx(vec![2, 2]));
+    let concentrated = objective.fitness(&create_insertion_ctx(vec![4, 0]));
+
+    assert_eq!(shared, 0.);
+    assert!(concentrated > shared);
+}
+
+parameterized_test! {can_estimate_only_skilled_jobs, (skills, expected), {
+    can_estimate_only_skilled_jobs_impl(skills, expected);
+}}
+
+can_estimate_only_skilled_jobs! {
+    case_01: (Some(vec!["s1"]), 3.),
+    case_02: (None, 0.),
+}
+
+fn can_estimate_only_skilled_jobs_impl(skills: Option<Vec<&str>>, expected: Cost) {
+    let objective = create_skill_balanced_feature("skill_balance").unwrap().objective.unwrap();
+    let insertion_ctx = create_insertion_ctx(vec![3, 0]);
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+
+    let result =
+        objective.estimate(&MoveContext::route(&insertion_ctx.solution, route_ctx, &create_skilled_job(skills)));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_tour_size_balance, (jobs_per_vehicle, expected), {
+    can_estimate_tour_size_balance_impl(jobs_per_vehicle, expected);
+}}
+
+can_estimate_tour_size_balance! {
+    case01_balanced: (vec![3, 3], 0.),
+    case02_unbalanced: (vec![5, 1], 4.),
+    case03_empty_route: (vec![4, 0], 4.),
+    case04_all_empty: (vec![0, 0], 0.),
+    case05_no_routes: (vec![], 0.),
+}
+
+fn can_estimate_tour_size_balance_impl(jobs_per_vehicle: Vec<usize>, expected: Float) {
+    let objective = create_balance_tour_size_feature("tour_size_balance").unwrap().objective.unwrap();
+
+    let result = objective.fitness(&create_insertion_ctx(jobs_per_vehicle));
+
+    assert_eq!(result, expected);
+}
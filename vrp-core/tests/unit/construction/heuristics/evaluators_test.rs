@@ -368,3 +368,166 @@ mod multi {
         assert_activities(success, vec![(0, 5), (1, 10), (2, 15)]);
     }
 }
+
+mod tie_break {
+    use super::*;
+    use crate::helpers::utils::random::FakeRandom;
+
+    parameterized_test! {can_break_ties_between_equal_cost_positions, (policy, expected_index), {
+        can_break_ties_between_equal_cost_positions_impl(policy, expected_index);
+    }}
+
+    can_break_ties_between_equal_cost_positions! {
+        case01_earliest: (TieBreakPolicy::EarliestPosition, 0),
+        case02_latest: (TieBreakPolicy::LatestPosition, 1),
+        case03_random_head: (TieBreakPolicy::Random(Arc::new(FakeRandom::new(vec![1; 4], vec![]))), 0),
+        case04_random_tails: (TieBreakPolicy::Random(Arc::new(FakeRandom::new(vec![2; 4], vec![]))), 1),
+    }
+
+    fn can_break_ties_between_equal_cost_positions_impl(policy: TieBreakPolicy, expected_index: usize) {
+        let mut registry = create_test_registry();
+        let mut route_ctx = RouteContext::new(registry.next().next().unwrap());
+        registry.use_actor(&route_ctx.route().actor);
+        // NOTE inserting job at depot location before or after the activity costs the same
+        route_ctx.route_mut().tour.insert_at(create_activity_at(10), 1);
+        let insertion_ctx = TestInsertionContextBuilder::default()
+            .with_goal(TestGoalContextBuilder::with_transport_feature().build())
+            .with_registry(registry)
+            .with_routes(vec![route_ctx])
+            .build();
+        let job = TestSingleBuilder::default().location(Some(0)).build_as_job_ref();
+        let leg_selection = LegSelection::Exhaustive;
+        let result_selector = TieBreakResultSelector::new(policy);
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job: &job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        let result = eval_job_insertion_in_route(
+            &insertion_ctx,
+            &eval_ctx,
+            insertion_ctx.solution.routes.first().unwrap(),
+            InsertionPosition::Any,
+            InsertionResult::make_failure(),
+        );
+
+        let success: InsertionSuccess = result.try_into().ok().unwrap();
+        assert_eq!(success.activities.len(), 1);
+        assert_eq!(success.activities.first().unwrap().1, expected_index);
+    }
+}
+
+mod heatmap {
+    use super::*;
+
+    #[test]
+    fn can_map_insertion_costs_by_location() {
+        let mut registry = create_test_registry();
+        let mut route_ctx = RouteContext::new(registry.next().next().unwrap());
+        registry.use_actor(&route_ctx.route().actor);
+        route_ctx.route_mut().tour.insert_at(create_activity_at(5), 1);
+        route_ctx.route_mut().tour.insert_at(create_activity_at(10), 2);
+        let insertion_ctx = TestInsertionContextBuilder::default()
+            .with_goal(TestGoalContextBuilder::with_transport_feature().build())
+            .with_registry(registry)
+            .with_routes(vec![route_ctx])
+            .build();
+        let job = TestSingleBuilder::default().location(Some(7)).build_as_job_ref();
+        let leg_selection = LegSelection::Exhaustive;
+        let result_selector = BestResultSelector::default();
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job: &job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+        let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+
+        let costs = eval_job_insertion_costs_by_location(&insertion_ctx, &eval_ctx, route_ctx);
+
+        let locations = costs.iter().map(|(location, _)| *location).collect::<Vec<_>>();
+        assert_eq!(locations, vec![0, 5, 10]);
+        costs.iter().enumerate().for_each(|(idx, (_, cost))| {
+            let result = eval_job_insertion_in_route(
+                &insertion_ctx,
+                &eval_ctx,
+                route_ctx,
+                InsertionPosition::Concrete(idx),
+                InsertionResult::make_failure(),
+            );
+            assert_eq!(result.as_success().map(|success| &success.cost), Some(cost));
+        });
+    }
+}
+
+#[test]
+fn can_prefer_job_with_negative_cost_despite_longer_travel() {
+    use crate::construction::features::TransportFeatureBuilder;
+    use crate::models::problem::JobIdDimension;
+    use crate::models::{FeatureBuilder, FeatureObjective, GoalBuilder, GoalContextBuilder};
+    use crate::prelude::ViolationCode;
+
+    struct RevenueObjective;
+
+    impl FeatureObjective for RevenueObjective {
+        fn fitness(&self, _: &InsertionContext) -> Cost {
+            Cost::default()
+        }
+
+        fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+            match move_ctx {
+                MoveContext::Route { job, .. } if job.dimens().get_job_id().map(String::as_str) == Some("far") => {
+                    -1000.
+                }
+                _ => Cost::default(),
+            }
+        }
+    }
+
+    let features = vec![
+        TransportFeatureBuilder::new("transport")
+            .set_violation_code(ViolationCode(1))
+            .set_transport_cost(TestTransportCost::new_shared())
+            .set_activity_cost(TestActivityCost::new_shared())
+            .build_minimize_cost()
+            .unwrap(),
+        FeatureBuilder::default().with_name("revenue").with_objective(RevenueObjective).build().unwrap(),
+    ];
+    let objectives = features.iter().filter_map(|feature| feature.objective.clone()).collect::<Vec<_>>();
+    let goal = GoalBuilder::default()
+        .add_multi(
+            objectives.as_slice(),
+            |objectives, a, b| {
+                let fitness_a = objectives.iter().map(|objective| objective.fitness(a)).sum::<Cost>();
+                let fitness_b = objectives.iter().map(|objective| objective.fitness(b)).sum::<Cost>();
+                fitness_a.total_cmp(&fitness_b)
+            },
+            |objectives, move_ctx| objectives.iter().map(|objective| objective.estimate(move_ctx)).sum(),
+        )
+        .build()
+        .unwrap();
+    let goal = GoalContextBuilder::with_features(&features).unwrap().set_main_goal(goal).build().unwrap();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver_with_costs(empty_costs()))
+        .add_vehicle(TestVehicleBuilder::default().id("v1").build())
+        .build();
+    let route =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_goal(goal).with_routes(vec![route]).build();
+    let near = TestSingleBuilder::default().id("near").location(Some(1)).build_as_job_ref();
+    let far = TestSingleBuilder::default().id("far").location(Some(10)).build_as_job_ref();
+
+    let near_result = evaluate_job_insertion(&mut insertion_ctx, &near, InsertionPosition::Any);
+    let far_result = evaluate_job_insertion(&mut insertion_ctx, &far, InsertionPosition::Any);
+
+    let near_cost = near_result.as_success().map(|success| success.cost.iter().sum::<Cost>()).unwrap();
+    let far_cost = far_result.as_success().map(|success| success.cost.iter().sum::<Cost>()).unwrap();
+    assert!(far_cost < 0. && near_cost > 0.);
+    let best = InsertionResult::choose_best_result(near_result, far_result);
+    assert_eq!(
+        best.as_success().and_then(|success| success.job.dimens().get_job_id()).map(String::as_str),
+        Some("far")
+    );
+}
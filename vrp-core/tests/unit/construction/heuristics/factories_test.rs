@@ -0,0 +1,95 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::{get_vehicle_id, test_driver, FleetBuilder};
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use rosomaxa::prelude::DefaultRandom;
+
+fn split_solution(solution: &Solution, a_routes: &[usize], b_routes: &[usize]) -> (Solution, Solution) {
+    let create_solution = |indices: &[usize]| Solution {
+        cost: Cost::default(),
+        registry: solution.registry.deep_copy(),
+        routes: indices.iter().map(|idx| solution.routes[*idx].deep_copy()).collect(),
+        unassigned: vec![],
+        telemetry: None,
+        summary: None,
+        is_partial: false,
+    };
+
+    (create_solution(a_routes), create_solution(b_routes))
+}
+
+fn create_environment() -> Arc<Environment> {
+    create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![])))
+}
+
+#[test]
+fn can_merge_solutions_without_conflicts() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let (a, b) = split_solution(&solution, &[0], &[1]);
+
+    let insertion_ctx = merge_solutions(Arc::new(problem), a, b, create_environment()).unwrap();
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["c0", "c1"], vec!["c2", "c3"]]);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert!(insertion_ctx.solution.required.is_empty());
+}
+
+#[test]
+fn can_move_conflicting_jobs_to_unassigned() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let (a, mut b) = split_solution(&solution, &[0], &[1]);
+    let duplicate = a.routes[0].tour.get(1).unwrap().deep_copy();
+    b.routes[0].tour.insert_last(duplicate);
+
+    let insertion_ctx = merge_solutions(Arc::new(problem), a, b, create_environment()).unwrap();
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["c1"], vec!["c2", "c3"]]);
+    assert_eq!(get_customer_ids_from_unassigned(&insertion_ctx), vec!["c0"]);
+    assert!(insertion_ctx.solution.required.is_empty());
+}
+
+#[test]
+fn can_return_error_when_same_actor_is_used() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let (a, b) = split_solution(&solution, &[0], &[0, 1]);
+
+    let result = merge_solutions(Arc::new(problem), a, b, create_environment());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_apply_actor_weights_from_problem_extras() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicles_with_ids(&["v1", "v2"]).build();
+    let mut problem = ProblemBuilder::default().with_fleet(fleet).build();
+    let weight_fn: ActorWeightFn = Arc::new(|actor| if get_vehicle_id(&actor.vehicle) == "v1" { 100 } else { 1 });
+    let mut extras = problem.extras.as_ref().clone();
+    extras.set_actor_weights(Arc::new(weight_fn));
+    problem.extras = Arc::new(extras);
+    let environment = create_test_environment_with_random(Arc::new(DefaultRandom::new_repeatable()));
+
+    let insertion_ctx = create_insertion_context(Arc::new(problem), environment);
+
+    let high_count = (0..1000)
+        .flat_map(|_| insertion_ctx.solution.registry.resources().next().collect::<Vec<_>>())
+        .filter(|actor| get_vehicle_id(&actor.vehicle) == "v1")
+        .count();
+    assert!(high_count > 900, "unexpected amount of high weight actor selections: {high_count}");
+}
+
+#[test]
+fn can_keep_job_unassigned_in_both_solutions_once() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let (mut a, mut b) = split_solution(&solution, &[0], &[1]);
+    let job = b.routes[0].tour.get(1).and_then(|activity| activity.retrieve_job()).unwrap();
+    b.routes[0].tour.remove(&job);
+    a.unassigned.push((job.clone(), UnassignmentInfo::Unknown));
+    b.unassigned.push((job, UnassignmentInfo::Unknown));
+
+    let insertion_ctx = merge_solutions(Arc::new(problem), a, b, create_environment()).unwrap();
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["c0", "c1"], vec!["c3"]]);
+    assert_eq!(get_customer_ids_from_unassigned(&insertion_ctx), vec!["c2"]);
+}
@@ -138,3 +138,215 @@ mod debug {
         assert!(result.contains("vehicle: \"undef\""));
     }
 }
+
+mod replay {
+    use super::*;
+    use crate::helpers::solver::generate_matrix_routes_with_defaults;
+    use crate::models::problem::VehicleIdDimension;
+
+    fn get_routes(insertion_ctx: &InsertionContext) -> Vec<(String, Vec<String>)> {
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                let route = route_ctx.route();
+                let vehicle_id = route.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap();
+                let job_ids = route
+                    .tour
+                    .all_activities()
+                    .filter_map(|activity| activity.retrieve_job())
+                    .map(|job| job.dimens().get_job_id().cloned().unwrap())
+                    .collect();
+
+                (vehicle_id, job_ids)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn can_reconstruct_solution_by_replaying_insertion_order() {
+        let (problem, _) = generate_matrix_routes_with_defaults(4, 2, false);
+        let problem = Arc::new(problem);
+        let environment = Arc::new(Environment::default());
+        let mut insertion_ctx = InsertionContext::new(problem.clone(), environment.clone());
+        insertion_ctx.solution.state.set_insertion_log(Vec::default());
+
+        let original = InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        );
+        let records = original.solution.state.get_insertion_log().cloned().expect("insertion log is not recorded");
+        let replayed = replay_insertions(InsertionContext::new(problem, environment), records.as_slice());
+
+        assert_eq!(records.len(), 8);
+        assert!(records.iter().all(|record| !record.activities.is_empty()));
+        assert_eq!(get_routes(&replayed), get_routes(&original));
+        assert_eq!(replayed.solution.unassigned.len(), original.solution.unassigned.len());
+        assert_eq!(replayed.fitness().collect::<Vec<_>>(), original.fitness().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_reconstruct_solution_by_replaying_insertion_order_after_ruin() {
+        let (problem, _) = generate_matrix_routes_with_defaults(4, 2, false);
+        let problem = Arc::new(problem);
+        let environment = Arc::new(Environment::default());
+        let mut insertion_ctx = InsertionContext::new(problem.clone(), environment.clone());
+        insertion_ctx.solution.state.set_insertion_log(Vec::default());
+        let insert = |insertion_ctx: InsertionContext| {
+            InsertionHeuristic::default().process(
+                insertion_ctx,
+                &AllJobSelector::default(),
+                &AllRouteSelector::default(),
+                &LegSelection::Exhaustive,
+                &BestResultSelector::default(),
+            )
+        };
+        let mut insertion_ctx = insert(insertion_ctx);
+        let removed = insertion_ctx.solution.routes.iter_mut().fold(Vec::new(), |mut removed, route_ctx| {
+            let job = route_ctx.route().tour.jobs().next().cloned().unwrap();
+            route_ctx.route_mut().tour.remove(&job);
+            removed.push(job);
+            removed
+        });
+        insertion_ctx.solution.required.extend(removed);
+
+        let original = insert(insertion_ctx);
+        let records = original.solution.state.get_insertion_log().cloned().expect("insertion log is not recorded");
+        let replayed = replay_insertions(InsertionContext::new(problem, environment), records.as_slice());
+
+        assert_eq!(records.len(), 8);
+        assert_eq!(get_routes(&replayed), get_routes(&original));
+        assert_eq!(replayed.fitness().collect::<Vec<_>>(), original.fitness().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_skip_recording_insertions_when_log_is_not_initialized() {
+        let (problem, _) = generate_matrix_routes_with_defaults(2, 1, false);
+        let insertion_ctx = InsertionContext::new(Arc::new(problem), Arc::new(Environment::default()));
+
+        let result = InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        );
+
+        assert!(result.solution.state.get_insertion_log().is_none());
+    }
+}
+
+mod failures {
+    use super::*;
+    use crate::construction::features::{CapacityFeatureBuilder, TransportFeatureBuilder};
+    use crate::helpers::models::problem::TestTransportCost;
+    use crate::models::common::{Demand, SingleDimLoad};
+    use crate::models::problem::{SingleBuilder, VehicleBuilder, VehicleDetailBuilder, VehicleIdDimension};
+    use crate::models::{GoalContextBuilder, Problem, ProblemBuilder};
+
+    const CAPACITY_CONSTRAINT_CODE: ViolationCode = ViolationCode(4);
+
+    fn create_problem() -> GenericResult<Problem> {
+        let transport = TestTransportCost::new_shared();
+        let goal = GoalContextBuilder::with_features(&[
+            TransportFeatureBuilder::new("min-distance")
+                .set_transport_cost(transport.clone())
+                .set_time_constrained(false)
+                .build_minimize_distance()?,
+            CapacityFeatureBuilder::<SingleDimLoad>::new("capacity")
+                .set_violation_code(CAPACITY_CONSTRAINT_CODE)
+                .build()?,
+        ])?
+        .build()?;
+
+        let jobs = vec![("job1", 1), ("job2", 5)]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (id, demand))| {
+                SingleBuilder::default().id(id).demand(Demand::delivery(demand)).location(idx + 1)?.build_as_job()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let vehicles = vec!["v1", "v2"]
+            .into_iter()
+            .map(|id| {
+                VehicleBuilder::default()
+                    .id(id)
+                    .add_detail(VehicleDetailBuilder::default().set_start_location(0).set_end_location(0).build()?)
+                    .capacity(SingleDimLoad::new(3))
+                    .build()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ProblemBuilder::default()
+            .add_jobs(jobs.into_iter())
+            .add_vehicles(vehicles.into_iter())
+            .with_goal(goal)
+            .with_transport_cost(transport)
+            .build()
+    }
+
+    fn run_insertion(insertion_ctx: InsertionContext) -> InsertionContext {
+        InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        )
+    }
+
+    #[test]
+    fn can_record_constraint_codes_of_all_attempted_routes() {
+        let problem = Arc::new(create_problem().unwrap());
+        let mut insertion_ctx = InsertionContext::new(problem, Arc::new(Environment::default()));
+        insertion_ctx.solution.state.set_detailed_insertion_failures(true);
+
+        let result = run_insertion(insertion_ctx);
+
+        assert_eq!(result.solution.unassigned.len(), 1);
+        let (job, info) = result.solution.unassigned.iter().next().unwrap();
+        assert_eq!(job.dimens().get_job_id().unwrap(), "job2");
+        let mut details = match info {
+            UnassignmentInfo::Detailed(details) => details
+                .iter()
+                .map(|(actor, code)| (actor.vehicle.dimens.get_vehicle_id().cloned().unwrap(), *code))
+                .collect::<Vec<_>>(),
+            _ => unreachable!("unexpected unassignment info"),
+        };
+        details.sort_by(|(a, _), (b, _)| a.cmp(b));
+        assert_eq!(
+            details,
+            vec![("v1".to_string(), CAPACITY_CONSTRAINT_CODE), ("v2".to_string(), CAPACITY_CONSTRAINT_CODE)]
+        );
+    }
+
+    #[test]
+    fn can_keep_recorded_failure_details_when_job_fails_again() {
+        let problem = Arc::new(create_problem().unwrap());
+        let mut insertion_ctx = InsertionContext::new(problem, Arc::new(Environment::default()));
+        insertion_ctx.solution.state.set_detailed_insertion_failures(true);
+
+        let result = run_insertion(run_insertion(insertion_ctx));
+
+        assert_eq!(result.solution.unassigned.len(), 1);
+        let info = result.solution.unassigned.values().next().unwrap();
+        assert!(matches!(info, UnassignmentInfo::Detailed(details)
+            if details.len() == 2 && details.iter().all(|(_, code)| *code == CAPACITY_CONSTRAINT_CODE)));
+    }
+
+    #[test]
+    fn can_keep_simple_code_when_detailed_failures_are_not_requested() {
+        let problem = Arc::new(create_problem().unwrap());
+        let insertion_ctx = InsertionContext::new(problem, Arc::new(Environment::default()));
+
+        let result = run_insertion(insertion_ctx);
+
+        assert_eq!(result.solution.unassigned.len(), 1);
+        let info = result.solution.unassigned.values().next().unwrap();
+        assert!(matches!(info, UnassignmentInfo::Simple(code) if *code == CAPACITY_CONSTRAINT_CODE));
+    }
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::helpers::models::problem::{get_job_id, TestSingleBuilder};
+use crate::models::problem::SimpleTransportCost;
+
+fn create_transport() -> SimpleTransportCost {
+    #[rustfmt::skip]
+    let distances = vec![
+        0., 10., 12., 8.,
+        10., 0., 5., 15.,
+        12., 5., 0., 9.,
+        8., 15., 9., 0.,
+    ];
+
+    SimpleTransportCost::new(distances.clone(), distances).unwrap()
+}
+
+fn create_job(id: &str, location: Option<Location>) -> Job {
+    TestSingleBuilder::default().id(id).location(location).build_as_job_ref()
+}
+
+fn get_savings(jobs: &[Job]) -> Vec<(String, String, Float)> {
+    compute_savings(jobs, 0, &create_transport(), &Profile::default())
+        .into_iter()
+        .map(|(job_i, job_j, saving)| (get_job_id(&job_i).clone(), get_job_id(&job_j).clone(), saving))
+        .collect()
+}
+
+#[test]
+fn can_compute_savings_sorted_descending() {
+    let jobs = vec![create_job("job1", Some(1)), create_job("job2", Some(2)), create_job("job3", Some(3))];
+
+    let savings = get_savings(jobs.as_slice());
+
+    assert_eq!(
+        savings,
+        vec![
+            ("job1".to_string(), "job2".to_string(), 17.),
+            ("job2".to_string(), "job3".to_string(), 11.),
+            ("job1".to_string(), "job3".to_string(), 3.),
+        ]
+    );
+}
+
+#[test]
+fn can_match_savings_formula() {
+    let transport = create_transport();
+    let profile = Profile::default();
+    let jobs = (1..4).map(|location| create_job(format!("job{location}").as_str(), Some(location))).collect::<Vec<_>>();
+    let d = |from: Location, to: Location| transport.distance_approx(&profile, from, to);
+    let get_location = |job: &Job| get_job_locations(job).flatten().next().unwrap();
+
+    let savings = compute_savings(jobs.as_slice(), 0, &transport, &profile);
+
+    assert_eq!(savings.len(), 3);
+    savings.iter().for_each(|(job_i, job_j, saving)| {
+        let (i, j) = (get_location(job_i), get_location(job_j));
+        assert_eq!(*saving, d(0, i) + d(0, j) - d(i, j));
+    });
+}
+
+#[test]
+fn can_skip_jobs_without_location() {
+    let jobs = vec![create_job("job1", Some(1)), create_job("job2", None), create_job("job3", Some(3))];
+
+    let savings = get_savings(jobs.as_slice());
+
+    assert_eq!(savings, vec![("job1".to_string(), "job3".to_string(), 3.)]);
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_id, ProblemBuilder, TestGoalContextBuilder};
+use crate::helpers::models::problem::{test_fleet, TestSingleBuilder};
+use crate::models::common::{Location, TimeWindow, Timestamp};
+
+fn create_job(id: &str, location: Location, time: (Timestamp, Timestamp)) -> Job {
+    TestSingleBuilder::default()
+        .id(id)
+        .location(Some(location))
+        .times(vec![TimeWindow::new(time.0, time.1)])
+        .build_as_job_ref()
+}
+
+fn create_problem(jobs: Vec<Job>) -> Arc<Problem> {
+    Arc::new(
+        ProblemBuilder::default()
+            .with_fleet(test_fleet())
+            .with_goal(TestGoalContextBuilder::with_transport_feature().build())
+            .with_jobs(jobs)
+            .build(),
+    )
+}
+
+#[test]
+fn can_evaluate_feasible_route() {
+    let jobs = vec![create_job("job1", 5, (0., 1000.)), create_job("job2", 10, (0., 1000.))];
+    let problem = create_problem(jobs.clone());
+
+    let result = evaluate_route(problem, "v1", jobs.as_slice()).expect("route should be feasible");
+
+    assert_eq!(result.cost, 80.);
+    let job_ids = result
+        .route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .map(|job| get_customer_id(&job))
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids, vec!["job1", "job2"]);
+}
+
+#[test]
+fn can_return_all_violations_of_infeasible_route() {
+    let jobs = vec![
+        create_job("job1", 5, (0., 1000.)),
+        create_job("job2", 100, (0., 10.)),
+        create_job("job3", 10, (0., 1000.)),
+        create_job("job4", 200, (0., 10.)),
+    ];
+    let problem = create_problem(jobs.clone());
+
+    let violations = evaluate_route(problem, "v1", jobs.as_slice()).err().unwrap();
+
+    assert_eq!(
+        violations
+            .iter()
+            .map(|violation| (get_customer_id(violation.job.as_ref().unwrap()), violation.code))
+            .collect::<Vec<_>>(),
+        vec![("job2".to_string(), ViolationCode(1)), ("job4".to_string(), ViolationCode(1))]
+    );
+}
+
+#[test]
+fn can_return_violation_for_unknown_vehicle() {
+    let jobs = vec![create_job("job1", 5, (0., 1000.))];
+    let problem = create_problem(jobs.clone());
+
+    let violations = evaluate_route(problem, "unknown", jobs.as_slice()).err().unwrap();
+
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].job.is_none());
+    assert_eq!(violations[0].code, ViolationCode::unknown());
+}
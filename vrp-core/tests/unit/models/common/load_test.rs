@@ -45,6 +45,9 @@ mod single {
 
         assert!(from_value(10).can_fit(&from_value(5)));
         assert!(!from_value(5).can_fit(&from_value(10)));
+
+        assert_eq!(from_value(9).scale_down(0.8), from_value(7));
+        assert_eq!(from_value(10).scale_down(0.8), from_value(8));
     }
 
     #[test]
@@ -120,5 +123,7 @@ mod multi {
 
         assert!(!from_vec(vec![1, 0]).can_fit(&from_vec(vec![0, 1])));
         assert!(!from_vec(vec![3, 0, 2]).can_fit(&from_vec(vec![1, 1, 4])));
+
+        assert_eq!(from_vec(vec![9, 10]).scale_down(0.8), from_vec(vec![7, 8]));
     }
 }
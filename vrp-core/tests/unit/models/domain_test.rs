@@ -0,0 +1,339 @@
+use super::*;
+use crate::helpers::models::domain::test_random;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route(fleet: &Fleet, vehicle_id: &str, job_id: &str) -> Route {
+    RouteBuilder::default()
+        .with_vehicle(fleet, vehicle_id)
+        .add_activity(
+            ActivityBuilder::default().job(Some(TestSingleBuilder::default().id(job_id).build_shared())).build(),
+        )
+        .build()
+}
+
+fn create_solution(fleet: &Fleet, routes: Vec<Route>, unassigned: Vec<&str>, cost: Cost) -> Solution {
+    Solution {
+        cost,
+        registry: Registry::new(fleet, test_random()),
+        routes,
+        unassigned: unassigned
+            .into_iter()
+            .map(|job_id| (TestSingleBuilder::default().id(job_id).build_as_job_ref(), UnassignmentInfo::Unknown))
+            .collect(),
+        telemetry: None,
+        summary: None,
+        is_partial: false,
+    }
+}
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build()
+}
+
+#[test]
+fn can_merge_disjoint_solutions() {
+    let fleet = create_fleet();
+    let left = create_solution(&fleet, vec![create_route(&fleet, "v1", "job1")], vec![], 10.);
+    let right = create_solution(&fleet, vec![create_route(&fleet, "v2", "job2")], vec!["job3"], 5.);
+
+    let solution = left.merge(right).expect("cannot merge solutions");
+
+    assert_eq!(solution.cost, Cost::default());
+    assert_eq!(solution.routes.len(), 2);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert!(solution.telemetry.is_none());
+    let vehicle_ids =
+        solution.routes.iter().map(|route| get_vehicle_id(&route.actor.vehicle).as_str()).collect::<Vec<_>>();
+    assert_eq!(vehicle_ids, vec!["v1", "v2"]);
+}
+
+#[test]
+fn can_detect_job_id_collision() {
+    let fleet = create_fleet();
+    let left = create_solution(&fleet, vec![create_route(&fleet, "v1", "job1")], vec![], 10.);
+    let right = create_solution(&fleet, vec![create_route(&fleet, "v2", "job2")], vec!["job1"], 5.);
+
+    let result = left.merge(right);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_detect_actor_collision() {
+    let fleet = create_fleet();
+    let left = create_solution(&fleet, vec![create_route(&fleet, "v1", "job1")], vec![], 10.);
+    let right = create_solution(&fleet, vec![create_route(&fleet, "v1", "job2")], vec![], 5.);
+
+    let result = left.merge(right);
+
+    assert!(result.is_err());
+}
+
+mod similarity {
+    use super::*;
+
+    fn create_test_solution(fleet: &Fleet, routes: Vec<(&str, Vec<Job>)>) -> Solution {
+        let routes = routes
+            .into_iter()
+            .map(|(vehicle_id, jobs)| {
+                RouteBuilder::default()
+                    .with_vehicle(fleet, vehicle_id)
+                    .add_activities(
+                        jobs.iter().map(|job| ActivityBuilder::default().job(Some(job.to_single().clone())).build()),
+                    )
+                    .build()
+            })
+            .collect();
+
+        create_solution(fleet, routes, vec![], 0.)
+    }
+
+    fn create_test_jobs() -> Vec<Job> {
+        (1..=4).map(|idx| TestSingleBuilder::default().id(format!("job{idx}").as_str()).build_as_job_ref()).collect()
+    }
+
+    #[test]
+    fn can_get_similarity_of_identical_solutions() {
+        let fleet = create_fleet();
+        let jobs = create_test_jobs();
+        let a = create_test_solution(&fleet, vec![("v1", jobs[0..2].to_vec()), ("v2", jobs[2..4].to_vec())]);
+        let b = create_test_solution(&fleet, vec![("v1", jobs[0..2].to_vec()), ("v2", jobs[2..4].to_vec())]);
+
+        assert_eq!(solution_similarity(&a, &b), 1.);
+    }
+
+    #[test]
+    fn can_get_similarity_of_different_solutions() {
+        let fleet = create_fleet();
+        let jobs = create_test_jobs();
+        let a = create_test_solution(&fleet, vec![("v1", jobs[0..2].to_vec()), ("v2", jobs[2..4].to_vec())]);
+        let b = create_test_solution(&fleet, vec![("v2", jobs[0..2].to_vec()), ("v1", jobs[2..4].to_vec())]);
+
+        assert_eq!(solution_similarity(&a, &b), 0.);
+    }
+
+    #[test]
+    fn can_get_similarity_of_partially_overlapping_solutions() {
+        let fleet = create_fleet();
+        let jobs = create_test_jobs();
+        let a = create_test_solution(&fleet, vec![("v1", jobs[0..2].to_vec()), ("v2", jobs[2..4].to_vec())]);
+        let b = create_test_solution(&fleet, vec![("v1", jobs[0..3].to_vec()), ("v2", jobs[3..4].to_vec())]);
+
+        // 3 shared pairs out of 5 unique ones
+        assert_eq!(solution_similarity(&a, &b), 0.6);
+    }
+}
+
+mod lower_bound {
+    use super::*;
+    use crate::helpers::solver::generate_matrix_routes_with_defaults;
+
+    fn get_total_distance(problem: &Problem, solution: &Solution) -> Float {
+        solution
+            .routes
+            .iter()
+            .map(|route| {
+                let profile = &route.actor.vehicle.profile;
+                let locations = route.tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>();
+
+                locations
+                    .windows(2)
+                    .map(|pair| problem.transport.distance_approx(profile, pair[0], pair[1]))
+                    .sum::<Float>()
+            })
+            .sum()
+    }
+
+    parameterized_test! {can_compute_objective_lower_bound_not_exceeding_feasible_solution, (rows, cols, is_open_vrp), {
+        can_compute_objective_lower_bound_not_exceeding_feasible_solution_impl(rows, cols, is_open_vrp);
+    }}
+
+    can_compute_objective_lower_bound_not_exceeding_feasible_solution! {
+        case01_single_route: (4, 1, false),
+        case02_two_routes: (3, 2, false),
+        case03_open_routes: (3, 3, true),
+    }
+
+    fn can_compute_objective_lower_bound_not_exceeding_feasible_solution_impl(
+        rows: usize,
+        cols: usize,
+        is_open_vrp: bool,
+    ) {
+        let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, is_open_vrp);
+
+        let lower_bound = compute_objective_lower_bound(&problem);
+
+        assert!(lower_bound > 0.);
+        assert!(lower_bound <= get_total_distance(&problem, &solution));
+    }
+}
+
+mod fairness {
+    use super::*;
+    use crate::models::common::Schedule;
+
+    fn create_test_solution(fleet: &Fleet, workloads: Vec<(&str, Float)>) -> Solution {
+        let routes = workloads
+            .into_iter()
+            .map(|(vehicle_id, workload)| {
+                let create_activity = |time: Float| {
+                    ActivityBuilder::with_location(0).job(None).schedule(Schedule::new(time, time)).build()
+                };
+
+                RouteBuilder::default()
+                    .with_vehicle(fleet, vehicle_id)
+                    .with_start(create_activity(0.))
+                    .with_end(create_activity(workload))
+                    .build()
+            })
+            .collect();
+
+        create_solution(fleet, routes, vec![], 0.)
+    }
+
+    parameterized_test! {can_calculate_workload_fairness, (workloads, expected), {
+        let fleet = create_fleet();
+        let solution = create_test_solution(&fleet, workloads);
+
+        let fairness = workload_fairness(&solution);
+
+        assert!((fairness - expected).abs() < 1E-9, "expected {expected}, got {fairness}");
+    }}
+
+    can_calculate_workload_fairness! {
+        case01_equal: (vec![("v1", 100.), ("v2", 100.)], 0.),
+        case02_balanced: (vec![("v1", 98.), ("v2", 102.)], 0.02),
+        case03_skewed: (vec![("v1", 20.), ("v2", 180.)], 0.8),
+        case04_fully_skewed: (vec![("v1", 0.), ("v2", 200.)], 1.),
+        case05_single_route: (vec![("v1", 100.)], 0.),
+        case06_no_routes: (vec![], 0.),
+    }
+}
+
+mod route_duration {
+    use super::*;
+    use crate::construction::enablers::update_route_schedule;
+    use crate::construction::heuristics::RouteContext;
+    use crate::helpers::solver::generate_matrix_routes_with_defaults;
+
+    fn get_actual_durations(problem: &Problem, solution: &Solution) -> Vec<Duration> {
+        solution
+            .routes
+            .iter()
+            .map(|route| {
+                let mut route_ctx = RouteContext::new_with_state(route.deep_copy(), Default::default());
+                update_route_schedule(&mut route_ctx, problem.activity.as_ref(), problem.transport.as_ref());
+
+                let tour = &route_ctx.route().tour;
+                let (start, end) = (tour.start().unwrap(), tour.end().unwrap());
+                let end_time =
+                    if route.actor.detail.end.is_some() { end.schedule.arrival } else { end.schedule.departure };
+
+                end_time - start.schedule.departure
+            })
+            .collect()
+    }
+
+    parameterized_test! {can_compute_route_duration_lower_bounds_not_exceeding_actual, (rows, cols, is_open_vrp), {
+        can_compute_route_duration_lower_bounds_not_exceeding_actual_impl(rows, cols, is_open_vrp);
+    }}
+
+    can_compute_route_duration_lower_bounds_not_exceeding_actual! {
+        case01_single_route: (4, 1, false),
+        case02_two_routes: (3, 2, false),
+        case03_open_routes: (3, 3, true),
+    }
+
+    fn can_compute_route_duration_lower_bounds_not_exceeding_actual_impl(rows: usize, cols: usize, is_open_vrp: bool) {
+        let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, is_open_vrp);
+
+        let bounds = compute_route_duration_lower_bounds(&problem, &solution);
+        let actual = get_actual_durations(&problem, &solution);
+
+        assert_eq!(bounds.len(), cols);
+        bounds.iter().zip(actual.iter()).for_each(|(&bound, &actual)| {
+            assert!(bound > 0.);
+            assert!(bound <= actual + 1E-9, "bound {bound} exceeds actual {actual}");
+        });
+    }
+
+    #[test]
+    fn can_compute_route_duration_lower_bound_with_service_time() {
+        let (problem, mut solution) = generate_matrix_routes_with_defaults(3, 1, true);
+        let route = solution.routes.first_mut().unwrap();
+        route.tour.all_activities_mut().filter(|activity| activity.job.is_some()).for_each(|activity| {
+            activity.place.duration = 5.;
+        });
+
+        let bounds = compute_route_duration_lower_bounds(&problem, &solution);
+        let actual = get_actual_durations(&problem, &solution);
+
+        // NOTE open route on the line is a minimum spanning tree itself
+        assert_eq!(bounds, actual);
+        assert!(bounds[0] >= 15.);
+    }
+}
+
+mod robustness {
+    use super::*;
+    use crate::construction::enablers::update_route_schedule;
+    use crate::construction::heuristics::RouteContext;
+    use crate::helpers::solver::generate_matrix_routes_with_defaults;
+    use crate::models::common::TimeWindow;
+
+    fn create_solution_with_slack(slack: Duration) -> (Problem, Solution) {
+        let (problem, mut solution) = generate_matrix_routes_with_defaults(3, 2, false);
+
+        solution.routes = solution
+            .routes
+            .iter()
+            .map(|route| {
+                let mut route_ctx = RouteContext::new_with_state(route.deep_copy(), Default::default());
+                update_route_schedule(&mut route_ctx, problem.activity.as_ref(), problem.transport.as_ref());
+
+                let mut route = route_ctx.route().deep_copy();
+                route.tour.all_activities_mut().filter(|activity| activity.job.is_some()).for_each(|activity| {
+                    activity.place.time = TimeWindow::new(0., activity.schedule.arrival + slack);
+                });
+
+                route
+            })
+            .collect();
+
+        (problem, solution)
+    }
+
+    parameterized_test! {can_compute_time_robustness, (slack, ratio, expected), {
+        let (problem, solution) = create_solution_with_slack(slack);
+        let noise = Noise::from_samples(vec![ratio], 1., false).unwrap();
+
+        let result = compute_time_robustness(&problem, &solution, &noise, 5);
+
+        assert_eq!(result, expected);
+    }}
+
+    can_compute_time_robustness! {
+        case01_tight_no_noise: (0., 1., 0.),
+        case02_tight_with_noise: (0., 1.2, 2.),
+        case03_slack_with_noise: (100., 1.2, 0.),
+        case04_slack_faster_travel: (100., 0.8, 0.),
+    }
+
+    #[test]
+    fn can_detect_tight_solution_is_less_robust_than_slack_one() {
+        let random = Arc::new(DefaultRandom::new_repeatable());
+        let noise = Noise::new_with_addition(0.5, (0., 0.5), random);
+        let (tight_problem, tight_solution) = create_solution_with_slack(0.);
+        let (slack_problem, slack_solution) = create_solution_with_slack(100.);
+
+        let tight = compute_time_robustness(&tight_problem, &tight_solution, &noise, 10);
+        let slack = compute_time_robustness(&slack_problem, &slack_solution, &noise, 10);
+
+        assert!(tight > slack, "tight: {tight}, slack: {slack}");
+        assert_eq!(slack, 0.);
+    }
+}
@@ -2,8 +2,11 @@ use super::*;
 use crate::construction::features::*;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::domain::TestGoalContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
 use crate::helpers::models::solution::{test_actor, ActivityBuilder};
 use crate::models::common::SingleDimLoad;
+use crate::models::problem::JobIdDimension;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
 fn create_feature(name: &str, cost: Cost, violation: Option<ConstraintViolation>) -> Feature {
     struct TestFeatureObjective {
@@ -159,6 +162,46 @@ fn can_use_objective_estimate_impl(feature_map: &[&str], expected_cost: &[Cost])
     assert_eq!(result, InsertionCost::new(expected_cost));
 }
 
+#[test]
+fn can_evaluate_insertion_detailed() {
+    let route_ctx = RouteContext::new(test_actor());
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &ActivityBuilder::default().job(None).build(),
+        target: &ActivityBuilder::default().job(None).build(),
+        next: None,
+    };
+    let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+    let features = vec![create_feature("o_1", 3., None), create_feature("o_2", 5., None)];
+    let goal_ctx = TestGoalContextBuilder::empty().add_features(features).build();
+
+    let breakdown = goal_ctx.evaluate_insertion_detailed(&move_ctx).expect("no violation expected");
+
+    assert_eq!(breakdown.len(), 2);
+    assert_eq!(breakdown.get("o_1"), Some(&3.));
+    assert_eq!(breakdown.get("o_2"), Some(&5.));
+    assert_eq!(breakdown.values().sum::<Cost>(), goal_ctx.estimate(&move_ctx).iter().sum::<Cost>());
+}
+
+#[test]
+fn can_return_violation_from_insertion_detailed() {
+    let route_ctx = RouteContext::new(test_actor());
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &ActivityBuilder::default().job(None).build(),
+        target: &ActivityBuilder::default().job(None).build(),
+        next: None,
+    };
+    let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+    let features =
+        vec![create_feature("o_1", 3., None), create_feature("o_2", 5., ConstraintViolation::fail(ViolationCode(1)))];
+    let goal_ctx = TestGoalContextBuilder::empty().add_features(features).build();
+
+    let result = goal_ctx.evaluate_insertion_detailed(&move_ctx);
+
+    assert_eq!(result.err(), ConstraintViolation::fail(ViolationCode(1)));
+}
+
 parameterized_test! {can_use_objective_total_order, (left_fitness, right_fitness, expected), {
     can_use_objective_total_order_impl(left_fitness, right_fitness, expected);
 }}
@@ -211,3 +254,251 @@ fn can_detect_same_name_usage() {
         }
     }
 }
+
+#[test]
+fn can_short_circuit_constraints_with_feature_inserted_at_position() -> GenericResult<()> {
+    struct CountingConstraint {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl FeatureConstraint for CountingConstraint {
+        fn evaluate(&self, _: &MoveContext<'_>) -> Option<ConstraintViolation> {
+            self.calls.fetch_add(1, AtomicOrdering::Relaxed);
+            None
+        }
+
+        fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+            Ok(source)
+        }
+    }
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let expensive = FeatureBuilder::default()
+        .with_name("expensive")
+        .with_constraint(CountingConstraint { calls: calls.clone() })
+        .build()?;
+    let cheap = create_feature("cheap", 1., ConstraintViolation::fail(ViolationCode(1)));
+    let objective = create_objective_feature_with_dynamic_cost("objective", Arc::new(|_, _| 1.));
+    let goal_ctx = GoalContextBuilder::with_features(&[expensive, objective])?.add_feature_at(0, cheap)?.build()?;
+    let route_ctx = RouteContext::new(test_actor());
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &ActivityBuilder::default().job(None).build(),
+        target: &ActivityBuilder::default().job(None).build(),
+        next: None,
+    };
+
+    let result = goal_ctx.evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, ConstraintViolation::fail(ViolationCode(1)));
+    assert_eq!(calls.load(AtomicOrdering::Relaxed), 0);
+    assert_eq!(goal_ctx.constraint_names().collect::<Vec<_>>(), vec!["cheap", "expensive"]);
+    Ok(())
+}
+
+#[test]
+fn can_keep_objectives_order_with_feature_inserted_at_position() -> GenericResult<()> {
+    let create_objective =
+        |name: &str, value: Float| create_objective_feature_with_dynamic_cost(name, Arc::new(move |_, _| value));
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+
+    let goal_ctx = GoalContextBuilder::with_features(&[create_objective("o_1", 1.), create_objective("o_2", 2.)])?
+        .add_feature_at(0, create_objective("o_3", 3.))?
+        .add_feature_at(1, create_objective("o_4", 4.))?
+        .build()?;
+
+    assert_eq!(goal_ctx.fitness(&insertion_ctx).collect::<Vec<_>>(), vec![1., 2., 3., 4.]);
+    Ok(())
+}
+
+#[test]
+fn can_reject_feature_insertion_out_of_range() -> GenericResult<()> {
+    let builder = GoalContextBuilder::with_features(&[create_feature("o_1", 1., None)])?;
+
+    let result = builder.add_feature_at(2, create_feature("o_2", 1., None));
+
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn can_list_features_with_their_kinds() -> GenericResult<()> {
+    let features = vec![
+        create_feature("objective_1", 1., None),
+        create_compatibility_feature("compatibility", ViolationCode(1))?,
+        create_objective_feature_with_dynamic_cost("objective_2", Arc::new(|_, _| 1.)),
+    ];
+    let goal_ctx = GoalContextBuilder::with_features(&features)?.build()?;
+
+    let features = goal_ctx.features();
+
+    assert_eq!(
+        features,
+        vec![
+            ("objective_1".to_string(), vec![FeatureKind::Constraint, FeatureKind::Objective]),
+            ("compatibility".to_string(), vec![FeatureKind::Constraint, FeatureKind::State]),
+            ("objective_2".to_string(), vec![FeatureKind::Objective]),
+        ]
+    );
+    Ok(())
+}
+
+parameterized_test! {can_use_objective_total_order_with_tolerance, (left_fitness, right_fitness, tolerances, expected), {
+    can_use_objective_total_order_with_tolerance_impl(left_fitness, right_fitness, tolerances, expected);
+}}
+
+can_use_objective_total_order_with_tolerance! {
+    case01_primary_within_tolerance:  (vec![100., 5.], vec![101., 3.], (0.05, 0.), Ordering::Greater),
+    case02_primary_out_of_tolerance:  (vec![100., 5.], vec![110., 3.], (0.05, 0.), Ordering::Less),
+    case03_strict_lexicographic:      (vec![100., 5.], vec![101., 3.], (0., 0.), Ordering::Less),
+    case04_both_within_tolerance:     (vec![100., 5.], vec![101., 5.1], (0.05, 0.05), Ordering::Equal),
+}
+
+fn can_use_objective_total_order_with_tolerance_impl(
+    left_fitness: Vec<Float>,
+    right_fitness: Vec<Float>,
+    tolerances: (Float, Float),
+    expected: Ordering,
+) {
+    let fitness_fn = Arc::new(move |name: &str, insertion_ctx: &InsertionContext| {
+        let idx = name.parse::<usize>().unwrap();
+        insertion_ctx.solution.state.get_value::<(), Vec<Float>>().unwrap()[idx]
+    });
+    let create_insertion_ctx_with_fitness_state = |fitness: Vec<Float>| {
+        let mut insertion_ctx = TestInsertionContextBuilder::default().build();
+        insertion_ctx.solution.state.set_value::<(), _>(fitness);
+        insertion_ctx
+    };
+    let features = vec![
+        create_objective_feature_with_dynamic_cost("0", fitness_fn.clone()),
+        create_objective_feature_with_dynamic_cost("1", fitness_fn),
+    ];
+    let goal = Goal::subset_of_with_tolerance(&features, &[("0", tolerances.0), ("1", tolerances.1)]).unwrap();
+    let left = create_insertion_ctx_with_fitness_state(left_fitness);
+    let right = create_insertion_ctx_with_fitness_state(right_fitness);
+
+    assert_eq!(goal.total_order(&left, &right), expected);
+}
+
+#[test]
+fn can_reject_negative_tolerance() {
+    let features = vec![create_objective_feature_with_dynamic_cost("0", Arc::new(|_, _| 1.))];
+
+    assert!(Goal::subset_of_with_tolerance(&features, &[("0", -0.1)]).is_err());
+}
+
+#[test]
+fn can_introspect_constraints() -> GenericResult<()> {
+    let features = vec![
+        create_compatibility_feature("compatibility", ViolationCode(1))?,
+        create_objective_feature_with_dynamic_cost("objective", Arc::new(|_, _| 1.)),
+        create_feature("constrained_objective", 1., None),
+    ];
+    let goal_ctx = GoalContextBuilder::with_features(&features)?.build()?;
+
+    let names = goal_ctx.constraint_names().collect::<Vec<_>>();
+
+    assert_eq!(goal_ctx.constraints_len(), 2);
+    assert_eq!(names, vec!["compatibility", "constrained_objective"]);
+    Ok(())
+}
+
+parameterized_test! {can_check_jobs_coexistence, (a_skills, b_skills, a_group, b_group, expected), {
+    can_check_jobs_coexistence_impl(a_skills, b_skills, a_group, b_group, expected);
+}}
+
+can_check_jobs_coexistence! {
+    case_01_no_skills_no_groups: (None, None, None, None, true),
+    case_02_same_skills: (Some(vec!["s1"]), Some(vec!["s1"]), None, None, true),
+    case_03_one_with_skills: (Some(vec!["s1"]), None, None, None, true),
+    case_04_skills_subset: (Some(vec!["s1", "s2"]), Some(vec!["s2"]), None, None, true),
+    case_05_different_skills: (Some(vec!["s1"]), Some(vec!["s2"]), None, None, false),
+    case_06_same_group: (None, None, Some("g1"), Some("g1"), true),
+    case_07_different_groups: (None, None, Some("g1"), Some("g2"), false),
+    case_08_one_with_group: (None, None, Some("g1"), None, false),
+    case_09_same_group_different_skills: (Some(vec!["s1"]), Some(vec!["s2"]), Some("g1"), Some("g1"), false),
+}
+
+fn can_check_jobs_coexistence_impl(
+    a_skills: Option<Vec<&str>>,
+    b_skills: Option<Vec<&str>>,
+    a_group: Option<&str>,
+    b_group: Option<&str>,
+    expected: bool,
+) {
+    let create_job = |id: &str, skills: Option<Vec<&str>>, group: Option<&str>| {
+        let mut builder = TestSingleBuilder::default();
+        builder.id(id);
+        if let Some(skills) = skills {
+            let skills = skills.into_iter().map(|skill| skill.to_string()).collect();
+            builder.dimens_mut().set_job_skills(JobSkills::new(Some(skills), None, None));
+        }
+        if let Some(group) = group {
+            builder.dimens_mut().set_job_group(group.to_string());
+        }
+        builder.build_as_job_ref()
+    };
+    let features = vec![
+        create_skills_feature("skills", ViolationCode(1)).unwrap(),
+        create_group_feature("groups", 2, ViolationCode(2)).unwrap(),
+        create_objective_feature_with_dynamic_cost("objective", Arc::new(|_, _| 1.)),
+    ];
+    let goal_ctx = GoalContextBuilder::with_features(&features).unwrap().build().unwrap();
+    let (a, b) = (create_job("a", a_skills, a_group), create_job("b", b_skills, b_group));
+
+    assert_eq!(goal_ctx.can_coexist(&a, &b), expected);
+    assert_eq!(goal_ctx.can_coexist(&b, &a), expected);
+}
+
+parameterized_test! {can_check_jobs_coexistence_per_constraint, (accepted_sources, expected), {
+    can_check_jobs_coexistence_per_constraint_impl(accepted_sources, expected);
+}}
+
+can_check_jobs_coexistence_per_constraint! {
+    case_01_opposite_directions: (vec![vec!["a"], vec!["b"]], true),
+    case_02_same_direction: (vec![vec!["a"], vec!["a"]], true),
+    case_03_no_direction: (vec![vec!["a"], vec![]], false),
+}
+
+fn can_check_jobs_coexistence_per_constraint_impl(accepted_sources: Vec<Vec<&str>>, expected: bool) {
+    struct SourceConstraint {
+        accepted: Vec<String>,
+    }
+
+    impl FeatureConstraint for SourceConstraint {
+        fn evaluate(&self, _: &MoveContext<'_>) -> Option<ConstraintViolation> {
+            None
+        }
+
+        fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+            let source_id = source.dimens().get_job_id().cloned().unwrap_or_default();
+            if self.accepted.contains(&source_id) {
+                Ok(source)
+            } else {
+                Err(ViolationCode(1))
+            }
+        }
+    }
+
+    let mut features = accepted_sources
+        .into_iter()
+        .enumerate()
+        .map(|(idx, accepted)| {
+            let accepted = accepted.into_iter().map(|id| id.to_string()).collect();
+            FeatureBuilder::default()
+                .with_name(&format!("source_{idx}"))
+                .with_constraint(SourceConstraint { accepted })
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    features.push(create_objective_feature_with_dynamic_cost("objective", Arc::new(|_, _| 1.)));
+    let goal_ctx = GoalContextBuilder::with_features(&features).unwrap().build().unwrap();
+    let (a, b) = (
+        TestSingleBuilder::default().id("a").build_as_job_ref(),
+        TestSingleBuilder::default().id("b").build_as_job_ref(),
+    );
+
+    assert_eq!(goal_ctx.can_coexist(&a, &b), expected);
+    assert_eq!(goal_ctx.can_coexist(&b, &a), expected);
+}
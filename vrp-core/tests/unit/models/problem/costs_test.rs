@@ -114,6 +114,220 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+mod discretization {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct LookupCountingTransportCost {
+        lookups: Mutex<HashSet<u64>>,
+    }
+
+    impl TransportCost for LookupCountingTransportCost {
+        fn duration_approx(&self, _: &Profile, _: Location, _: Location) -> Duration {
+            1.
+        }
+
+        fn distance_approx(&self, _: &Profile, _: Location, _: Location) -> Distance {
+            1.
+        }
+
+        fn duration(&self, _: &Route, _: Location, _: Location, travel_time: TravelTime) -> Duration {
+            let timestamp = match travel_time {
+                TravelTime::Arrival(arrival) => arrival,
+                TravelTime::Departure(departure) => departure,
+            };
+            self.lookups.lock().unwrap().insert(timestamp.to_bits());
+
+            1.
+        }
+
+        fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+            self.duration(route, from, to, travel_time)
+        }
+    }
+
+    #[test]
+    fn can_compare_bucketed_and_exact_lookups() {
+        let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+        let p0 = route.actor.vehicle.profile.clone();
+        let create_matrices = || {
+            vec![
+                create_matrix_data(p0.clone(), Some(0.), (100., 2), (1., 2)),
+                create_matrix_data(p0.clone(), Some(10.), (200., 2), (1., 2)),
+            ]
+        };
+        let exact = create_matrix_transport_cost(create_matrices()).unwrap();
+        let bucketed = create_matrix_transport_cost_with_discretization(create_matrices(), 5.).unwrap();
+
+        for &(timestamp, exact_duration, bucketed_duration) in
+            &[(0., 100., 100.), (3., 130., 100.), (5., 150., 150.), (7., 170., 150.), (10., 200., 200.)]
+        {
+            let travel_time = TravelTime::Departure(timestamp);
+            assert_eq!(exact.duration(&route, 0, 1, travel_time), exact_duration);
+            assert_eq!(bucketed.duration(&route, 0, 1, travel_time), bucketed_duration);
+        }
+    }
+
+    #[test]
+    fn can_reduce_distinct_lookups() {
+        let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+        let exact = Arc::new(LookupCountingTransportCost::default());
+        let inner = Arc::new(LookupCountingTransportCost::default());
+        let bucketed = create_discretized_transport_cost(inner.clone(), 5.).unwrap();
+
+        (0..20).map(|timestamp| TravelTime::Departure(timestamp as Timestamp)).for_each(|travel_time| {
+            exact.duration(&route, 0, 1, travel_time);
+            bucketed.duration(&route, 0, 1, travel_time);
+        });
+
+        assert_eq!(exact.lookups.lock().unwrap().len(), 20);
+        assert_eq!(inner.lookups.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn can_reject_non_positive_granularity() {
+        let inner = Arc::new(LookupCountingTransportCost::default());
+
+        assert!(create_discretized_transport_cost(inner, 0.).is_err());
+    }
+}
+
+mod penalties {
+    use super::*;
+
+    fn create_base_transport() -> Arc<dyn TransportCost> {
+        Arc::new(SimpleTransportCost::new(vec![0., 10., 10., 0.], vec![0., 100., 100., 0.]).unwrap())
+    }
+
+    #[test]
+    fn can_add_penalty_to_penalized_edges() {
+        let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+        let profile = route.actor.vehicle.profile.clone();
+        let base = create_base_transport();
+        let penalties = vec![((0, 1), EdgePenalty { duration: 5., distance: 0. })].into_iter().collect();
+        let penalized = PenalizedTransportCost::new(base.clone(), penalties);
+        let travel_time = TravelTime::Departure(0.);
+
+        assert_eq!(base.duration_approx(&profile, 0, 1), 10.);
+        assert_eq!(penalized.duration_approx(&profile, 0, 1), 15.);
+        assert_eq!(penalized.duration(&route, 0, 1, travel_time), 15.);
+        assert_eq!(penalized.distance_approx(&profile, 0, 1), 100.);
+        assert_eq!(penalized.distance(&route, 0, 1, travel_time), 100.);
+    }
+
+    #[test]
+    fn can_add_distance_penalty() {
+        let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+        let penalties = vec![((1, 0), EdgePenalty { duration: 2., distance: 20. })].into_iter().collect();
+        let penalized = PenalizedTransportCost::new(create_base_transport(), penalties);
+        let travel_time = TravelTime::Departure(0.);
+
+        assert_eq!(penalized.duration(&route, 1, 0, travel_time), 12.);
+        assert_eq!(penalized.distance(&route, 1, 0, travel_time), 120.);
+    }
+
+    #[test]
+    fn can_skip_unknown_edges() {
+        let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+        let penalties = vec![((0, 1), EdgePenalty { duration: 5., distance: 50. })].into_iter().collect();
+        let penalized = PenalizedTransportCost::new(create_base_transport(), penalties);
+        let travel_time = TravelTime::Departure(0.);
+
+        assert_eq!(penalized.duration(&route, 1, 0, travel_time), 10.);
+        assert_eq!(penalized.distance(&route, 1, 0, travel_time), 100.);
+    }
+}
+
+mod reload_profiles {
+    use super::*;
+    use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder};
+
+    fn create_reload_transport(profiles: HashMap<usize, Profile>) -> ReloadProfileTransportCost {
+        let base = create_matrix_transport_cost(vec![
+            create_matrix_data(Profile::new(0, None), None, (10., 9), (100., 9)),
+            create_matrix_data(Profile::new(1, None), None, (5., 9), (50., 9)),
+        ])
+        .unwrap();
+
+        ReloadProfileTransportCost::new(base, profiles, Arc::new(|activity: &Activity| activity.place.location == 1))
+    }
+
+    fn create_route_with_reload() -> Route {
+        RouteBuilder::with_default_vehicle()
+            .add_activity(ActivityBuilder::with_location(1).schedule(Schedule::new(10., 15.)).build())
+            .add_activity(ActivityBuilder::with_location(2).schedule(Schedule::new(20., 20.)).build())
+            .build()
+    }
+
+    #[test]
+    fn can_switch_profile_after_reload() {
+        let route = create_route_with_reload();
+        let transport = create_reload_transport(vec![(0, Profile::new(1, None))].into_iter().collect());
+
+        assert_eq!(transport.duration(&route, 0, 1, TravelTime::Departure(0.)), 10.);
+        assert_eq!(transport.distance(&route, 0, 1, TravelTime::Departure(0.)), 100.);
+        assert_eq!(transport.duration(&route, 0, 1, TravelTime::Arrival(10.)), 10.);
+
+        assert_eq!(transport.duration(&route, 1, 2, TravelTime::Departure(15.)), 5.);
+        assert_eq!(transport.distance(&route, 1, 2, TravelTime::Departure(15.)), 50.);
+        assert_eq!(transport.duration(&route, 1, 2, TravelTime::Arrival(20.)), 5.);
+    }
+
+    #[test]
+    fn can_keep_profile_without_mapping() {
+        let route = create_route_with_reload();
+        let transport = create_reload_transport(HashMap::default());
+
+        assert_eq!(transport.duration(&route, 1, 2, TravelTime::Departure(15.)), 10.);
+        assert_eq!(transport.distance(&route, 1, 2, TravelTime::Departure(15.)), 100.);
+    }
+}
+
+mod blended_profiles {
+    use super::*;
+
+    fn create_blended_transport(weight: Float) -> GenericResult<BlendedProfileTransportCost> {
+        let base = create_matrix_transport_cost(vec![
+            create_matrix_data(Profile::new(0, None), None, (10., 4), (100., 4)),
+            create_matrix_data(Profile::new(1, None), None, (20., 4), (50., 4)),
+        ])
+        .unwrap();
+
+        BlendedProfileTransportCost::new(base, vec![(0, (Profile::new(1, None), weight))].into_iter().collect())
+    }
+
+    #[test]
+    fn can_blend_durations_between_profiles() {
+        let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+        let transport = create_blended_transport(0.25).unwrap();
+
+        let duration = transport.duration(&route, 0, 1, TravelTime::Departure(0.));
+        let distance = transport.distance(&route, 0, 1, TravelTime::Departure(0.));
+
+        assert!(duration > 10. && duration < 20.);
+        assert_eq!(duration, 17.5);
+        assert_eq!(distance, 62.5);
+        assert_eq!(transport.duration_approx(&Profile::new(0, None), 0, 1), 17.5);
+    }
+
+    #[test]
+    fn can_keep_profile_without_blend() {
+        let route = Route { actor: test_actor_with_profile(1), tour: Default::default() };
+        let transport = create_blended_transport(0.5).unwrap();
+
+        assert_eq!(transport.duration(&route, 0, 1, TravelTime::Departure(0.)), 20.);
+        assert_eq!(transport.distance(&route, 0, 1, TravelTime::Departure(0.)), 50.);
+    }
+
+    #[test]
+    fn can_reject_invalid_weight() {
+        assert!(create_blended_transport(1.5).is_err());
+        assert!(create_blended_transport(-0.1).is_err());
+    }
+}
+
 mod objective {
     use super::*;
     use crate::construction::heuristics::{InsertionContext, MoveContext};
@@ -264,3 +264,53 @@ fn can_handle_negative_distances_durations_impl(transport_costs: Arc<dyn Transpo
             .all(|(_, cost)| { (cost as LowPrecisionCost - UNREACHABLE_COST).abs() < f32::EPSILON }));
     }
 }
+
+parameterized_test! {can_get_jobs_within_radius, (radius, use_grid), {
+    can_get_jobs_within_radius_impl(radius, use_grid);
+}}
+
+can_get_jobs_within_radius! {
+    case01: (0., false),
+    case02: (2., false),
+    case03: (5., false),
+    case04: (100., false),
+    case05: (0., true),
+    case06: (2., true),
+    case07: (5., true),
+    case08: (100., true),
+}
+
+fn can_get_jobs_within_radius_impl(radius: Distance, use_grid: bool) {
+    let profile = Profile::default();
+    let center = 5;
+    let transport = create_only_distance_transport_cost();
+    let species = vec![
+        TestSingleBuilder::default().id("s0").location(Some(0)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s1").location(Some(3)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s2").location(Some(5)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s3").location(Some(5)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s4").location(Some(7)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s5").location(None).build_as_job_ref(),
+        TestSingleBuilder::default().id("s6").location(Some(42)).build_as_job_ref(),
+        Job::Multi(test_multi_job_with_locations(vec![vec![Some(1)], vec![Some(9)]])),
+    ];
+    let jobs = Jobs::new(&test_fleet(), species.clone(), transport.as_ref(), &test_logger()).unwrap();
+    // NOTE location 42 has no coordinate, so it is always checked using transport
+    let jobs = if use_grid {
+        jobs.with_coord_index(Arc::new(|location| (location != 42).then(|| Point::new(location as Float, 0.))))
+    } else {
+        jobs
+    };
+    let expected = species
+        .iter()
+        .filter(|job| {
+            get_job_locations(job)
+                .flatten()
+                .any(|location| transport.distance_approx(&profile, center, location) <= radius)
+        })
+        .collect::<Vec<_>>();
+
+    let result = jobs.within_radius(&profile, center, radius, transport.as_ref());
+
+    assert_eq!(result, expected);
+}
@@ -1,8 +1,11 @@
 use crate::helpers::models::domain::test_random;
-use crate::helpers::models::problem::{test_driver, test_vehicle_detail, FleetBuilder, TestVehicleBuilder};
+use crate::helpers::models::problem::{
+    get_vehicle_id, test_driver, test_vehicle_detail, FleetBuilder, TestVehicleBuilder,
+};
 use crate::models::common::TimeInterval;
 use crate::models::problem::{Actor, VehicleDetail, VehiclePlace};
 use crate::models::solution::Registry;
+use rosomaxa::prelude::{DefaultRandom, Float};
 use std::sync::Arc;
 
 parameterized_test! {can_provide_available_actors_from_registry, (count, expected), {
@@ -56,6 +59,38 @@ fn can_provide_next_actors_from_registry() {
     assert_eq!(actors.last().unwrap().detail.start.as_ref().map(|s| s.location), Some(1));
 }
 
+parameterized_test! {can_provide_next_actors_with_weights, (weights, expected_ratio), {
+    can_provide_next_actors_with_weights_impl(weights, expected_ratio);
+}}
+
+can_provide_next_actors_with_weights! {
+    case01_high_weight_preferred: (Some((10, 1)), (5., Float::MAX)),
+    case02_no_weights_uniform: (None, (0.5, 2.)),
+}
+
+fn can_provide_next_actors_with_weights_impl(weights: Option<(usize, usize)>, expected_ratio: (Float, Float)) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicles_with_ids(&["v1", "v2"]).build();
+    let registry = Registry::new(&fleet, Arc::new(DefaultRandom::new_repeatable()));
+    let registry = if let Some((high, low)) = weights {
+        registry.with_weights(Arc::new(move |actor| if get_vehicle_id(&actor.vehicle) == "v1" { high } else { low }))
+    } else {
+        registry
+    };
+
+    let (high_count, low_count) =
+        (0..1000).flat_map(|_| registry.next().collect::<Vec<_>>()).fold((0, 0), |(high, low), actor| {
+            if get_vehicle_id(&actor.vehicle) == "v1" {
+                (high + 1, low)
+            } else {
+                (high, low + 1)
+            }
+        });
+
+    let ratio = high_count as Float / low_count.max(1) as Float;
+    assert_eq!(high_count + low_count, 1000);
+    assert!(ratio > expected_ratio.0 && ratio < expected_ratio.1, "unexpected ratio: {ratio}");
+}
+
 fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
     vec![
         test_vehicle_detail(),
@@ -0,0 +1,42 @@
+use super::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder};
+
+fn create_route(locations: &[Location]) -> Route {
+    RouteBuilder::with_default_vehicle()
+        .add_activities(locations.iter().map(|&location| ActivityBuilder::with_location(location).build()))
+        .build()
+}
+
+fn get_compactness(locations: &[Location], coordinates: &[(Float, Float)]) -> Float {
+    let coord_index = |location: Location| coordinates.get(location).map(|&(x, y)| Point::new(x, y));
+
+    route_compactness(&create_route(locations), &coord_index)
+}
+
+#[test]
+fn can_get_high_compactness_for_clustered_route() {
+    let coordinates = [(0., 0.), (1., 0.), (1., 1.), (0., 1.)];
+
+    let compactness = get_compactness(&[1, 2, 3], &coordinates);
+
+    assert_eq!(compactness, 1.);
+}
+
+#[test]
+fn can_get_low_compactness_for_zig_zag_route() {
+    let coordinates = [(0., 0.), (4., 0.), (0., 1.), (4., 1.), (0., 2.), (4., 2.)];
+
+    let clustered = get_compactness(&[1, 3, 5, 4, 2], &coordinates);
+    let zig_zag = get_compactness(&[1, 2, 3, 4, 5], &coordinates);
+
+    assert!(zig_zag < 0.6);
+    assert!(zig_zag < clustered);
+}
+
+#[test]
+fn can_return_sentinel_for_route_with_less_than_three_stops() {
+    let coordinates = [(0., 0.), (1., 0.)];
+
+    assert_eq!(get_compactness(&[1, 1], &coordinates), UNDEFINED_ROUTE_COMPACTNESS);
+    assert_eq!(get_compactness(&[1, 5], &coordinates), UNDEFINED_ROUTE_COMPACTNESS);
+}
@@ -0,0 +1,118 @@
+use super::*;
+use crate::construction::features::{CapacityFeatureBuilder, MinimizeUnassignedBuilder, TransportFeatureBuilder};
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::models::domain::test_random;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder};
+use crate::models::common::{Demand, SingleDimLoad};
+use crate::models::problem::{Job, SingleBuilder, VehicleBuilder, VehicleDetailBuilder};
+use crate::models::solution::{Registry, Route};
+use crate::models::{GoalContextBuilder, Problem, ProblemBuilder};
+use crate::solver::{Solver, VrpConfigBuilder};
+use rosomaxa::prelude::{Environment, Float, GenericResult};
+use std::sync::Arc;
+
+fn create_problem() -> GenericResult<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let goal = GoalContextBuilder::with_features(&[
+        MinimizeUnassignedBuilder::new("min-unassigned").build()?,
+        TransportFeatureBuilder::new("min-distance")
+            .set_transport_cost(transport.clone())
+            .set_time_constrained(false)
+            .build_minimize_distance()?,
+        CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").build()?,
+    ])?
+    .build()?;
+
+    let jobs = (1..=6)
+        .map(|idx| {
+            SingleBuilder::default()
+                .id(format!("job{idx}").as_str())
+                .demand(Demand::delivery(1))
+                .location(idx)?
+                .build_as_job()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let vehicles = (1..=3)
+        .map(|idx| {
+            VehicleBuilder::default()
+                .id(format!("v{idx}").as_str())
+                .add_detail(VehicleDetailBuilder::default().set_start_location(0).set_end_location(0).build()?)
+                .capacity(SingleDimLoad::new(3))
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    ProblemBuilder::default()
+        .add_jobs(jobs.into_iter())
+        .add_vehicles(vehicles.into_iter())
+        .with_goal(goal)
+        .with_transport_cost(transport)
+        .build()
+}
+
+#[test]
+fn can_solve_with_distinct_alternatives_within_cost_gap() {
+    let config = AlternativeSolutionsConfig { max_solutions: 3, max_cost_gap: 0.5, max_similarity: 0.8 };
+    let problem = Arc::new(create_problem().unwrap());
+    let evolution_config = VrpConfigBuilder::new(problem.clone())
+        .set_environment(Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() }))
+        .prebuild()
+        .unwrap()
+        .with_max_generations(Some(20))
+        .with_result_size(20)
+        .build()
+        .unwrap();
+
+    let solutions = Solver::new(problem, evolution_config).solve_alternatives(&config).unwrap();
+
+    assert!(!solutions.is_empty() && solutions.len() <= config.max_solutions);
+    let best_cost = solutions[0].cost;
+    solutions.iter().for_each(|solution| {
+        assert!(solution.cost >= best_cost);
+        assert!(solution.cost <= best_cost * (1. + config.max_cost_gap));
+    });
+    solutions.iter().enumerate().for_each(|(idx, solution)| {
+        solutions.iter().skip(idx + 1).for_each(|other| {
+            assert!(solution_similarity(solution, other) <= config.max_similarity);
+        });
+    });
+}
+
+#[test]
+fn can_select_nothing_from_empty_solutions() {
+    let solutions = select_alternative_solutions(vec![], &AlternativeSolutionsConfig::default());
+
+    assert!(solutions.is_empty());
+}
+
+#[test]
+fn can_keep_goal_order_of_solutions() {
+    let fleet =
+        FleetBuilder::default().add_driver(test_driver()).add_vehicles(vec![test_vehicle_with_id("v1")]).build();
+    let create_solution = |routes: Vec<Route>, unassigned: Vec<Job>, cost: Float| Solution {
+        cost,
+        registry: Registry::new(&fleet, test_random()),
+        routes,
+        unassigned: unassigned.into_iter().map(|job| (job, UnassignmentInfo::Unknown)).collect(),
+        telemetry: None,
+        summary: None,
+        is_partial: false,
+    };
+    let job = TestSingleBuilder::default().id("job1").build_shared();
+    // NOTE the best solution by goal is more expensive than the one with unassigned job
+    let best = create_solution(
+        vec![RouteBuilder::default()
+            .with_vehicle(&fleet, "v1")
+            .add_activity(ActivityBuilder::default().job(Some(job.clone())).build())
+            .build()],
+        vec![],
+        10.,
+    );
+    let worse = create_solution(vec![], vec![Job::Single(job)], 5.);
+    let config = AlternativeSolutionsConfig { max_solutions: 2, max_cost_gap: 1., max_similarity: 1. };
+
+    let solutions = select_alternative_solutions(vec![best, worse], &config);
+
+    assert_eq!(solutions.iter().map(|solution| solution.cost).collect::<Vec<_>>(), vec![10., 5.]);
+}
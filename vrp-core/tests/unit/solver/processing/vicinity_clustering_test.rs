@@ -6,8 +6,9 @@ use crate::helpers::models::domain::*;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::Schedule;
 use crate::models::problem::{Job, JobIdDimension};
-use crate::models::solution::{Commute, CommuteInfo};
+use crate::models::solution::{Activity, Commute, CommuteInfo, Place};
 use crate::prelude::ViolationCode;
 
 fn create_test_jobs() -> Vec<Job> {
@@ -146,3 +147,46 @@ fn can_unwrap_clusters_in_unassigned_on_post_process() {
 
     assert_eq!(insertion_ctx.solution.unassigned.len(), 4);
 }
+
+#[test]
+fn can_report_served_by_for_clustered_jobs() {
+    let problem_jobs = vec![
+        TestSingleBuilder::default().id("job1").location(Some(1)).duration(2.).build_as_job_ref(),
+        TestSingleBuilder::default().id("job2").location(Some(2)).duration(2.).build_as_job_ref(),
+        TestSingleBuilder::default().id("job3_outlier").location(Some(20)).duration(2.).build_as_job_ref(),
+    ];
+    let config = create_cluster_config();
+    let (_, new_problem) = create_problems(config.clone(), problem_jobs);
+    let clustered_single = new_problem
+        .jobs
+        .all()
+        .iter()
+        .find(|job| job.dimens().get_cluster_info().is_some())
+        .expect("cluster should be created")
+        .to_single()
+        .clone();
+    let place = clustered_single.places.first().unwrap().clone();
+    let route = RouteBuilder::default()
+        .with_vehicle(new_problem.fleet.as_ref(), "v1")
+        .add_activity(Activity {
+            place: Place {
+                idx: 0,
+                location: place.location.unwrap(),
+                duration: place.duration,
+                time: place.times.first().unwrap().to_time_window(0.),
+            },
+            schedule: Schedule::new(3., 10.),
+            job: Some(clustered_single),
+            commute: Some(Commute::default()),
+        })
+        .build();
+
+    let served_by = get_cluster_served_by(&config, &route);
+
+    assert_eq!(served_by.len(), 2);
+    let mut job_ids = served_by.iter().map(|served_by| get_job_id(&served_by.job).as_str()).collect::<Vec<_>>();
+    job_ids.sort();
+    assert_eq!(job_ids, vec!["job1", "job2"]);
+    assert!(served_by.iter().all(|served_by| served_by.vehicle_id == "v1"));
+    assert_ne!(served_by[0].arrival, served_by[1].arrival);
+}
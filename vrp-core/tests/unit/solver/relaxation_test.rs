@@ -0,0 +1,84 @@
+use super::*;
+use crate::construction::features::{CapacityFeatureBuilder, MinimizeUnassignedBuilder, TransportFeatureBuilder};
+use crate::helpers::models::problem::TestTransportCost;
+use crate::models::common::{Demand, SingleDimLoad, TimeWindow};
+use crate::models::problem::{SingleBuilder, TransportCost, VehicleBuilder, VehicleDetailBuilder};
+use crate::models::ProblemBuilder;
+use crate::solver::{Solver, VrpConfigBuilder};
+use std::sync::Arc;
+
+fn create_features(transport: Arc<dyn TransportCost>) -> Vec<Feature> {
+    vec![
+        MinimizeUnassignedBuilder::new("min-unassigned").build().unwrap(),
+        TransportFeatureBuilder::new("transport").set_transport_cost(transport).build_minimize_distance().unwrap(),
+        CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").build().unwrap(),
+    ]
+}
+
+fn solve(goal: GoalContext, transport: Arc<dyn TransportCost>, capacity: i32) -> GenericResult<Solution> {
+    let jobs = vec![
+        SingleBuilder::default().id("job1").demand(Demand::delivery(1)).location(1)?.build_as_job()?,
+        SingleBuilder::default()
+            .id("job2")
+            .demand(Demand::delivery(1))
+            .location(100)?
+            .times(vec![TimeWindow::new(0., 5.)])?
+            .build_as_job()?,
+    ];
+    let vehicle = VehicleBuilder::default()
+        .id("v1")
+        .add_detail(VehicleDetailBuilder::default().set_start_location(0).set_end_location(0).build()?)
+        .capacity(SingleDimLoad::new(capacity))
+        .build()?;
+    let problem = Arc::new(
+        ProblemBuilder::default()
+            .add_jobs(jobs.into_iter())
+            .add_vehicles(vec![vehicle].into_iter())
+            .with_goal(goal)
+            .with_transport_cost(transport)
+            .build()?,
+    );
+    let config = VrpConfigBuilder::new(problem.clone())
+        .set_environment(Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() }))
+        .prebuild()?
+        .with_max_generations(Some(5))
+        .build()?;
+
+    Solver::new(problem, config).solve()
+}
+
+parameterized_test! {can_relax_constraints_in_order, (capacity, order, expected, is_feasible), {
+    can_relax_constraints_in_order_impl(capacity, order, expected, is_feasible);
+}}
+
+can_relax_constraints_in_order! {
+    case01_time_and_capacity: (1, vec!["transport", "capacity"], vec!["transport", "capacity"], true),
+    case02_time_only: (2, vec!["capacity", "transport"], vec!["capacity", "transport"], true),
+    case03_stop_when_feasible: (2, vec!["transport", "capacity"], vec!["transport"], true),
+    case04_not_enough_relaxations: (1, vec!["transport"], vec!["transport"], false),
+    case05_empty_order: (2, vec![], vec![], false),
+}
+
+fn can_relax_constraints_in_order_impl(capacity: i32, order: Vec<&str>, expected: Vec<&str>, is_feasible: bool) {
+    let transport = TestTransportCost::new_shared();
+    let features = create_features(transport.clone());
+    let order = order.into_iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
+    let result =
+        solve_with_relaxation(features.as_slice(), order.as_slice(), |goal| solve(goal, transport.clone(), capacity))
+            .expect("cannot solve problem");
+
+    assert_eq!(result.relaxed, expected);
+    assert_eq!(result.solution.unassigned.is_empty(), is_feasible);
+}
+
+#[test]
+fn can_return_error_for_unknown_feature() {
+    let transport = TestTransportCost::new_shared();
+    let features = create_features(transport.clone());
+
+    let result =
+        solve_with_relaxation(features.as_slice(), &["skills".to_string()], |goal| solve(goal, transport.clone(), 1));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::construction::clustering::vicinity::ClusterInfo;
+use crate::helpers::construction::clustering::dbscan::create_test_distances;
+use crate::helpers::models::domain::TestGoalContextBuilder;
+use crate::helpers::models::problem::{get_job_id, TestSingleBuilder};
+use crate::helpers::solver::*;
+use crate::models::solution::Commute;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+fn create_cluster_info(member_ids: &[&str]) -> Vec<ClusterInfo> {
+    member_ids
+        .iter()
+        .map(|id| ClusterInfo {
+            job: TestSingleBuilder::default().id(id).build_as_job_ref(),
+            service_time: 1.,
+            place_idx: 0,
+            commute: Commute::default(),
+        })
+        .collect()
+}
+
+fn create_insertion_ctx(clustered: &'static [(&'static str, &'static [&'static str])]) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(
+        8,
+        1,
+        false,
+        |_, _, _| TestGoalContextBuilder::with_transport_feature().build(),
+        |id, location| {
+            let mut builder = TestSingleBuilder::default();
+            builder.id(id).location(location);
+            if let Some((_, member_ids)) = clustered.iter().find(|(cluster_id, _)| *cluster_id == id) {
+                builder.dimens_mut().set_cluster_info(create_cluster_info(member_ids));
+            }
+
+            builder.build_shared()
+        },
+        |v| v,
+        |_| (vec![0.; 64], create_test_distances()),
+    );
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()))
+}
+
+fn get_required_ids(insertion_ctx: &InsertionContext) -> Vec<String> {
+    let mut ids = insertion_ctx.solution.required.iter().map(|job| get_job_id(job).clone()).collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+#[test]
+fn can_restore_cluster_members_to_required() {
+    let limits = RemovalLimits { removed_activities_range: 8..8, affected_routes_range: 8..8 };
+    let insertion_ctx = create_insertion_ctx(&[("c0", &["m1", "m2"]), ("c3", &["m3", "m4", "m5"])]);
+
+    let insertion_ctx = VicinityClusterRemoval::new(limits)
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_required_ids(&insertion_ctx), vec!["m1", "m2", "m3", "m4", "m5"]);
+    assert_eq!(insertion_ctx.solution.unassigned.len(), 0);
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(),
+        6
+    );
+}
+
+#[test]
+fn can_respect_removal_limits() {
+    let limits = RemovalLimits { removed_activities_range: 1..1, affected_routes_range: 8..8 };
+    let insertion_ctx = create_insertion_ctx(&[("c0", &["m1", "m2"]), ("c3", &["m3", "m4", "m5"])]);
+
+    let insertion_ctx = VicinityClusterRemoval::new(limits)
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    let required = get_required_ids(&insertion_ctx);
+    assert!(required == vec!["m1", "m2"] || required == vec!["m3", "m4", "m5"]);
+}
+
+#[test]
+fn can_skip_solution_without_clusters() {
+    let limits = RemovalLimits { removed_activities_range: 8..8, affected_routes_range: 8..8 };
+    let insertion_ctx = create_insertion_ctx(&[]);
+
+    let insertion_ctx = VicinityClusterRemoval::new(limits)
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(),
+        8
+    );
+}
@@ -1,10 +1,13 @@
-use super::{Ruin, WorstJobRemoval};
+use super::{get_job_marginal_costs, Ruin, WorstJobRemoval};
 use crate::construction::heuristics::InsertionContext;
 use crate::helpers::models::domain::get_sorted_customer_ids_from_jobs;
 use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
 use crate::helpers::utils::create_test_environment_with_random;
 use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::Cost;
+use crate::models::problem::TravelTime;
 use crate::solver::search::RemovalLimits;
+use rosomaxa::HeuristicSolution;
 use std::sync::Arc;
 
 parameterized_test! {can_ruin_solution_with_matrix_routes, (matrix, ints, expected_ids), {
@@ -32,3 +35,59 @@ fn can_ruin_solution_with_matrix_routes_impl(matrix: (usize, usize), ints: Vec<i
 
     assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_ids);
 }
+
+#[test]
+fn can_get_job_marginal_costs_matching_full_recompute() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![]))),
+    );
+    let original_cost = get_transport_cost(&insertion_ctx);
+
+    let marginals = get_job_marginal_costs(&insertion_ctx);
+
+    assert_eq!(marginals.len(), 10);
+    assert!(marginals.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    marginals.iter().for_each(|(job, marginal)| {
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+        let route_ctx = insertion_ctx
+            .solution
+            .routes
+            .iter_mut()
+            .find(|route_ctx| route_ctx.route().tour.contains(job))
+            .expect("cannot find route with job");
+        assert!(route_ctx.route_mut().tour.remove(job));
+        insertion_ctx.restore();
+
+        let new_cost = get_transport_cost(&insertion_ctx);
+        assert!(
+            (original_cost - new_cost - marginal).abs() < 1E-6,
+            "marginal: {marginal}, actual: {}",
+            original_cost - new_cost
+        );
+    });
+}
+
+fn get_transport_cost(insertion_ctx: &InsertionContext) -> Cost {
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let route = route_ctx.route();
+            route.tour.legs().filter_map(move |(activities, _)| match activities {
+                [from, to] => Some(transport.cost(
+                    route,
+                    from.place.location,
+                    to.place.location,
+                    TravelTime::Departure(from.schedule.departure),
+                )),
+                _ => None,
+            })
+        })
+        .sum()
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::models::common::Location;
+use crate::solver::search::RecreateWithCheapest;
+use crate::solver::{Solver, VrpConfigBuilder};
+
+fn get_route_distance(insertion_ctx: &InsertionContext) -> Location {
+    let locations =
+        insertion_ctx.solution.routes[0].route().tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>();
+
+    locations.windows(2).map(|pair| pair[0].abs_diff(pair[1])).sum()
+}
+
+#[test]
+fn can_solve_single_vehicle_problem_with_fast_path() {
+    let rows = 8;
+    let (problem, _) = generate_matrix_routes_with_defaults(rows, 1, false);
+
+    let insertion_ctx = try_solve_single_vehicle(Arc::new(problem), Arc::new(Environment::default()))
+        .expect("fast path should be applied");
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+    assert_eq!(insertion_ctx.solution.routes[0].route().tour.job_count(), rows);
+    assert_eq!(get_route_distance(&insertion_ctx), 2 * (rows - 1));
+}
+
+#[test]
+fn can_skip_fast_path_for_multiple_vehicles() {
+    let (problem, _) = generate_matrix_routes_with_defaults(4, 2, false);
+
+    assert!(!is_single_vehicle_problem(&problem));
+    assert!(try_solve_single_vehicle(Arc::new(problem), Arc::new(Environment::default())).is_none());
+}
+
+#[test]
+fn can_create_initial_solution_with_single_vehicle_operator() {
+    let rows = 8;
+    let (problem, _) = generate_matrix_routes_with_defaults(rows, 1, false);
+    let refinement_ctx = create_default_refinement_ctx(Arc::new(problem));
+    let operator = SingleVehicleInitialOperator::new(RecreateInitialOperator::new(Arc::new(
+        RecreateWithCheapest::new(refinement_ctx.environment.random.clone()),
+    )));
+
+    let insertion_ctx = operator.create(&refinement_ctx);
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(insertion_ctx.solution.routes[0].route().tour.job_count(), rows);
+    assert_eq!(get_route_distance(&insertion_ctx), 2 * (rows - 1));
+}
+
+#[test]
+fn can_solve_single_vehicle_problem_within_normal_pipeline() {
+    let (problem, _) = generate_matrix_routes_with_defaults(8, 1, false);
+    let problem = Arc::new(problem);
+    let config = VrpConfigBuilder::new(problem.clone())
+        .set_environment(Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() }))
+        .prebuild()
+        .unwrap()
+        .with_max_generations(Some(1))
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem, config).with_single_vehicle_fast_path(true).solve().unwrap();
+
+    assert!(solution.unassigned.is_empty());
+    assert!(solution.summary.is_some());
+    assert!(!solution.is_partial);
+}
@@ -0,0 +1,317 @@
+use super::*;
+use crate::construction::enablers::create_typed_actor_groups;
+use crate::construction::features::{
+    create_balance_tour_size_feature, CapacityFeatureBuilder, MinimizeUnassignedBuilder, TransportFeatureBuilder,
+};
+use crate::helpers::models::domain::get_customer_ids_from_routes_sorted;
+use crate::helpers::models::problem::TestTransportCost;
+use crate::models::common::{Demand, SingleDimLoad};
+use crate::models::problem::{
+    SingleBuilder, VehicleBuilder, VehicleDetailBuilder, VehicleIdDimension, VehicleServiceTimeMultiplierDimension,
+};
+use crate::models::{GoalContextBuilder, ProblemBuilder};
+use rosomaxa::termination::TerminationReason;
+use rosomaxa::utils::ThreadPool;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+fn create_problem() -> GenericResult<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let goal = GoalContextBuilder::with_features(&[
+        MinimizeUnassignedBuilder::new("min-unassigned").build()?,
+        TransportFeatureBuilder::new("min-distance")
+            .set_transport_cost(transport.clone())
+            .set_time_constrained(false)
+            .build_minimize_distance()?,
+        CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").build()?,
+    ])?
+    .build()?;
+
+    let jobs = (1..=4)
+        .map(|idx| {
+            SingleBuilder::default()
+                .id(format!("job{idx}").as_str())
+                .demand(Demand::delivery(1))
+                .location(idx)?
+                .build_as_job()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let vehicles = (1..=2)
+        .map(|idx| {
+            VehicleBuilder::default()
+                .id(format!("v{idx}").as_str())
+                .add_detail(VehicleDetailBuilder::default().set_start_location(0).set_end_location(0).build()?)
+                .capacity(SingleDimLoad::new(2))
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    ProblemBuilder::default()
+        .add_jobs(jobs.into_iter())
+        .add_vehicles(vehicles.into_iter())
+        .with_goal(goal)
+        .with_transport_cost(transport)
+        .build()
+}
+
+parameterized_test! {can_report_solve_summary, (max_generations, target_proximity, expected), {
+    can_report_solve_summary_impl(max_generations, target_proximity, expected);
+}}
+
+can_report_solve_summary! {
+    case01_budget: (5, None, TerminationReason::Budget),
+    case02_converged: (1000, Some((vec![0., 0., 0.], Float::MAX)), TerminationReason::Converged),
+}
+
+fn can_report_solve_summary_impl(
+    max_generations: usize,
+    target_proximity: Option<(Vec<Float>, Float)>,
+    expected: TerminationReason,
+) {
+    let problem = Arc::new(create_problem().unwrap());
+    let config = VrpConfigBuilder::new(problem.clone())
+        .set_environment(Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() }))
+        .prebuild()
+        .unwrap()
+        .with_max_generations(Some(max_generations))
+        .with_target_proximity(target_proximity)
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem, config).solve().unwrap();
+
+    let summary = solution.summary.expect("no solve summary");
+    assert_eq!(summary.termination, expected);
+    assert!(summary.generations <= max_generations);
+    assert!(!summary.fitness.is_empty());
+}
+
+struct CancellingPopulation {
+    inner: TargetPopulation,
+    cancel_after: usize,
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl HeuristicPopulation for CancellingPopulation {
+    type Objective = GoalContext;
+    type Individual = InsertionContext;
+
+    fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
+        self.inner.add_all(individuals)
+    }
+
+    fn add(&mut self, individual: Self::Individual) -> bool {
+        self.inner.add(individual)
+    }
+
+    fn on_generation(&mut self, statistics: &HeuristicStatistics) {
+        if statistics.generation >= self.cancel_after {
+            self.is_cancelled.store(true, Ordering::Relaxed);
+        }
+
+        self.inner.on_generation(statistics)
+    }
+
+    fn cmp(&self, a: &Self::Individual, b: &Self::Individual) -> std::cmp::Ordering {
+        self.inner.cmp(a, b)
+    }
+
+    fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        self.inner.select()
+    }
+
+    fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        self.inner.ranked()
+    }
+
+    fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Individual> + 'a> {
+        self.inner.all()
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn selection_phase(&self) -> SelectionPhase {
+        self.inner.selection_phase()
+    }
+}
+
+#[test]
+fn can_cancel_solve_using_cancellation_flag() {
+    let cancel_after = 5;
+    let problem = Arc::new(create_problem().unwrap());
+    let environment = Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() });
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+    let population = CancellingPopulation {
+        inner: Box::new(create_elitism_population(problem.goal.clone(), environment.clone())),
+        cancel_after,
+        is_cancelled: is_cancelled.clone(),
+    };
+    let config = VrpConfigBuilder::new(problem.clone())
+        .set_environment(environment.clone())
+        .prebuild()
+        .unwrap()
+        .with_context(RefinementContext::new(problem.clone(), Box::new(population), TelemetryMode::None, environment))
+        .with_max_generations(Some(100_000))
+        .with_cancellation(Some(is_cancelled.clone()))
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem, config).solve().unwrap();
+
+    assert!(is_cancelled.load(Ordering::Relaxed));
+    let summary = solution.summary.expect("no solve summary");
+    assert_eq!(summary.termination, TerminationReason::Cancelled);
+    assert_eq!(summary.generations, cancel_after);
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 2);
+}
+
+#[test]
+fn can_reproduce_solution_with_same_seed() {
+    let solve = || {
+        let problem = Arc::new(create_problem().unwrap());
+        let environment = Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() });
+        let config = VrpConfigBuilder::new(problem.clone())
+            .set_environment(environment.clone())
+            .set_seed(42)
+            .prebuild()
+            .unwrap()
+            .with_max_generations(Some(20))
+            .build()
+            .unwrap();
+        let solution = Solver::new(problem.clone(), config).solve().unwrap();
+        let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+        get_customer_ids_from_routes_sorted(&insertion_ctx)
+    };
+    // NOTE full reproducibility requires single threaded mode
+    let thread_pool = ThreadPool::new(1);
+
+    let first = thread_pool.execute(solve);
+    let second = thread_pool.execute(solve);
+
+    assert!(!first.is_empty());
+    assert_eq!(first, second);
+}
+
+#[test]
+fn can_prefer_vehicle_with_faster_service_time() {
+    let transport = TestTransportCost::new_shared();
+    let goal = GoalContextBuilder::with_features(&[
+        MinimizeUnassignedBuilder::new("min-unassigned").build().unwrap(),
+        TransportFeatureBuilder::new("min-duration")
+            .set_transport_cost(transport.clone())
+            .build_minimize_duration()
+            .unwrap(),
+    ])
+    .unwrap()
+    .build()
+    .unwrap();
+    let job = SingleBuilder::default().id("job1").location(5).unwrap().duration(10.).unwrap().build_as_job().unwrap();
+    // NOTE travel takes 10 in total, so the service time fits into the shift only on the fast vehicle
+    let vehicles = [("slow", 1.), ("fast", 0.5)].into_iter().map(|(id, multiplier)| {
+        VehicleBuilder::default()
+            .id(id)
+            .add_detail(
+                VehicleDetailBuilder::default()
+                    .set_start_location(0)
+                    .set_start_time(0.)
+                    .set_end_location(0)
+                    .set_end_time(18.)
+                    .build()
+                    .unwrap(),
+            )
+            .dimension(|dimens| {
+                dimens.set_vehicle_service_time_multiplier(multiplier);
+            })
+            .build()
+            .unwrap()
+    });
+    let problem = Arc::new(
+        ProblemBuilder::default()
+            .add_job(job)
+            .add_vehicles(vehicles)
+            // NOTE put vehicles into different groups, otherwise only one of them is offered for insertion
+            .with_vehicle_similarity(|actors| {
+                Box::new(create_typed_actor_groups(actors, |a| a.vehicle.dimens.get_vehicle_id().cloned().unwrap()))
+            })
+            .with_goal(goal)
+            .with_transport_cost(transport)
+            .build()
+            .unwrap(),
+    );
+    let config = VrpConfigBuilder::new(problem.clone())
+        .set_environment(Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() }))
+        .prebuild()
+        .unwrap()
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem, config).solve().unwrap();
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.routes[0].actor.vehicle.dimens.get_vehicle_id().map(String::as_str), Some("fast"));
+    assert_eq!(solution.routes[0].tour.get(1).map(|activity| activity.schedule.departure), Some(10.));
+}
+
+#[test]
+fn can_balance_tour_sizes_when_travel_costs_are_equal() {
+    let transport = TestTransportCost::new_shared();
+    let goal = GoalContextBuilder::with_features(&[
+        MinimizeUnassignedBuilder::new("min-unassigned").build().unwrap(),
+        CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").build().unwrap(),
+        create_balance_tour_size_feature("tour-size-balance").unwrap(),
+        TransportFeatureBuilder::new("min-distance")
+            .set_transport_cost(transport.clone())
+            .set_time_constrained(false)
+            .build_minimize_distance()
+            .unwrap(),
+    ])
+    .unwrap()
+    .build()
+    .unwrap();
+    // NOTE all jobs share the same location, so any split between two vehicles has the same travel cost
+    let jobs = (1..=6).map(|idx| {
+        SingleBuilder::default()
+            .id(format!("job{idx}").as_str())
+            .demand(Demand::delivery(1))
+            .location(1)
+            .unwrap()
+            .build_as_job()
+            .unwrap()
+    });
+    let vehicles = (1..=2).map(|idx| {
+        VehicleBuilder::default()
+            .id(format!("v{idx}").as_str())
+            .add_detail(VehicleDetailBuilder::default().set_start_location(0).set_end_location(0).build().unwrap())
+            .capacity(SingleDimLoad::new(5))
+            .build()
+            .unwrap()
+    });
+    let problem = Arc::new(
+        ProblemBuilder::default()
+            .add_jobs(jobs)
+            .add_vehicles(vehicles)
+            .with_goal(goal)
+            .with_transport_cost(transport)
+            .build()
+            .unwrap(),
+    );
+    let config = VrpConfigBuilder::new(problem.clone())
+        .set_environment(Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() }))
+        .prebuild()
+        .unwrap()
+        .with_max_generations(Some(100))
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem, config).solve().unwrap();
+
+    assert!(solution.unassigned.is_empty());
+    let mut sizes = solution.routes.iter().map(|route| route.tour.job_count()).collect::<Vec<_>>();
+    sizes.sort();
+    assert_eq!(sizes, vec![3, 3]);
+}
@@ -3,8 +3,8 @@
 mod assignment_test;
 
 use super::*;
-use crate::format::get_indices;
 use crate::format::solution::activity_matcher::*;
+use crate::format::{get_indices, JobIndex};
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
@@ -177,7 +177,16 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
                                             }
                                         }
                                         Ok(Some(JobInfo(_, _, place, time))) => {
-                                            is_valid_job_info(ctx, stop, activity, *idx, place, time)
+                                            let overlap = get_co_located_overlap(
+                                                ctx,
+                                                tour,
+                                                stop,
+                                                *idx,
+                                                &place,
+                                                job_index,
+                                                coord_index,
+                                            );
+                                            is_valid_job_info(ctx, stop, activity, *idx, place, time, overlap)
                                         }
                                         _ => false,
                                     }
@@ -213,6 +222,7 @@ fn is_valid_job_info(
     activity_idx: usize,
     place: Place,
     time: TimeWindow,
+    overlap: Float,
 ) -> bool {
     let not_equal = |left: Float, right: Float| left != right;
     let parking = ctx.clustering.as_ref().map(|config| config.serving.get_parking()).unwrap_or(0.);
@@ -223,14 +233,15 @@ fn is_valid_job_info(
     match (&ctx.clustering, &activity.commute, domain_commute) {
         (_, _, Err(_)) | (_, None, Ok(Some(_))) | (_, Some(_), Ok(None)) | (&None, &Some(_), Ok(Some(_))) => true,
         (_, None, Ok(None)) => {
-            let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
+            let expected_departure = time.start.max(place.time.start) + place.duration - overlap + extra_time;
             not_equal(time.end, expected_departure)
         }
         (Some(config), Some(commute), Ok(Some(d_commute))) => {
-            let (service_time, parking) = match config.serving {
-                ServingPolicy::Original { parking } => (place.duration, parking),
-                ServingPolicy::Multiplier { multiplier, parking } => (place.duration * multiplier, parking),
-                ServingPolicy::Fixed { value, parking } => (value, parking),
+            let (service_time, parking) = match &config.serving {
+                ServingPolicy::Original { parking } => (place.duration, *parking),
+                ServingPolicy::Multiplier { multiplier, parking } => (place.duration * multiplier, *parking),
+                ServingPolicy::Fixed { value, parking } => (*value, *parking),
+                ServingPolicy::Dynamic { service_time_fn, parking } => (service_time_fn(place.duration), *parking),
             };
 
             let a_commute = commute.to_domain(&ctx.coord_index);
@@ -255,6 +266,46 @@ fn is_valid_job_info(
     }
 }
 
+/// Returns service time which is shared with the previous activity when a break is combined with
+/// recharge at the same station.
+fn get_co_located_overlap(
+    ctx: &CheckerContext,
+    tour: &Tour,
+    stop: &PointStop,
+    activity_idx: usize,
+    place: &Place,
+    job_index: &JobIndex,
+    coord_index: &CoordIndex,
+) -> Float {
+    let is_combined = ctx
+        .get_vehicle_shift(tour)
+        .ok()
+        .and_then(|shift| shift.recharges.and_then(|recharges| recharges.combine_breaks))
+        .unwrap_or(false);
+
+    let (Some(prev), Some(activity)) =
+        (activity_idx.checked_sub(1).and_then(|idx| stop.activities.get(idx)), stop.activities.get(activity_idx))
+    else {
+        return 0.;
+    };
+
+    let is_co_located = matches!(
+        (prev.activity_type.as_str(), activity.activity_type.as_str()),
+        ("break", "recharge") | ("recharge", "break")
+    );
+
+    if !is_combined || !is_co_located {
+        return 0.;
+    }
+
+    match try_match_point_job(tour, stop, prev, job_index, coord_index) {
+        Ok(Some(JobInfo(_, _, prev_place, _))) if prev_place.location == place.location => {
+            prev_place.duration.min(place.duration)
+        }
+        _ => 0.,
+    }
+}
+
 fn check_groups(ctx: &CheckerContext) -> GenericResult<()> {
     let violations = ctx
         .solution
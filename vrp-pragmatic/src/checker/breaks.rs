@@ -91,32 +91,7 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
 
         let tour_tw = TimeWindow::new(departure, arrival);
 
-        let expected_break_count =
-            vehicle_shift.breaks.iter().flat_map(|breaks| breaks.iter()).fold(0, |acc, vehicle_break| {
-                let break_tw = get_break_time_window(tour, vehicle_break).expect("cannot get break time windows");
-
-                let should_assign = match vehicle_break {
-                    VehicleBreak::Optional { policy, .. } => {
-                        let policy =
-                            policy.as_ref().cloned().unwrap_or(VehicleOptionalBreakPolicy::SkipIfNoIntersection);
-
-                        match policy {
-                            VehicleOptionalBreakPolicy::SkipIfNoIntersection => break_tw.start < arrival,
-                            VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => arrival > break_tw.end,
-                        }
-                    }
-                    VehicleBreak::Required { .. } => {
-                        // NOTE: skip break if its end time is after tour end
-                        break_tw.intersects(&tour_tw) && break_tw.end < tour_tw.end
-                    }
-                };
-
-                if should_assign {
-                    acc + 1
-                } else {
-                    acc
-                }
-            });
+        let expected_break_count = get_expected_break_count(tour, &vehicle_shift, &tour_tw);
 
         let total_break_count = actual_break_count + get_break_violation_count(&context.solution, tour);
 
@@ -132,6 +107,85 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+/// Represents break compliance of a single tour.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreakCompliance {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Amount of breaks which have to be scheduled within the tour.
+    pub expected: usize,
+    /// Amount of breaks scheduled within the tour.
+    pub scheduled: usize,
+    /// Amount of scheduled breaks which are taken outside of their time windows.
+    pub out_of_window: usize,
+    /// Amount of breaks reported as break constraint violations.
+    pub violations: usize,
+    /// True if all expected breaks are scheduled within their time windows.
+    pub is_compliant: bool,
+}
+
+/// Creates a break compliance report for each tour in the solution. A break is considered as
+/// missing if it is expected to be taken within the tour, but it is not scheduled: such breaks
+/// are typically reported as break constraint violations.
+pub fn get_break_compliance_report(problem: &Problem, solution: &Solution) -> GenericResult<Vec<BreakCompliance>> {
+    solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let vehicle_shift = problem
+                .fleet
+                .vehicles
+                .iter()
+                .find(|vehicle| vehicle.vehicle_ids.contains(&tour.vehicle_id))
+                .and_then(|vehicle| vehicle.shifts.get(tour.shift_index))
+                .cloned()
+                .map(|shift| with_day_horizon_breaks(problem, shift))
+                .ok_or_else(|| format!("cannot find shift for vehicle '{}'", tour.vehicle_id))?;
+
+            let (departure, arrival) = match (tour.stops.first(), tour.stops.last()) {
+                (Some(first), Some(last)) => {
+                    (parse_time(&first.schedule().departure), parse_time(&last.schedule().arrival))
+                }
+                _ => return Err(format!("cannot get schedule for tour '{}'", tour.vehicle_id).into()),
+            };
+
+            let break_tws = vehicle_shift
+                .breaks
+                .iter()
+                .flat_map(|breaks| breaks.iter())
+                .map(|vehicle_break| get_break_time_window(tour, vehicle_break))
+                .collect::<GenericResult<Vec<_>>>()?;
+
+            let (scheduled, out_of_window) = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+                .filter(|(_, activity)| activity.activity_type == "break")
+                .fold((0, 0), |(scheduled, out_of_window), (stop, activity)| {
+                    let visit_time = get_time_window(stop, activity);
+                    let is_in_window = break_tws.iter().any(|break_tw| visit_time.intersects(break_tw));
+
+                    (scheduled + 1, if is_in_window { out_of_window } else { out_of_window + 1 })
+                });
+
+            let expected = get_expected_break_count(tour, &vehicle_shift, &TimeWindow::new(departure, arrival));
+            let violations = get_break_violation_count(solution, tour);
+
+            Ok(BreakCompliance {
+                vehicle_id: tour.vehicle_id.clone(),
+                shift_index: tour.shift_index,
+                expected,
+                scheduled,
+                out_of_window,
+                violations,
+                is_compliant: out_of_window == 0 && scheduled >= expected,
+            })
+        })
+        .collect()
+}
+
 /// Represents information about break and neighbour activity.
 type LegBreakInfo<'a> = (Option<Location>, (Option<&'a Activity>, &'a Activity), (&'a Activity, VehicleBreak));
 
@@ -197,6 +251,35 @@ pub(crate) fn get_break_time_window(tour: &Tour, vehicle_break: &VehicleBreak) -
     }
 }
 
+fn get_expected_break_count(tour: &Tour, vehicle_shift: &VehicleShift, tour_tw: &TimeWindow) -> usize {
+    let arrival = tour_tw.end;
+
+    vehicle_shift.breaks.iter().flat_map(|breaks| breaks.iter()).fold(0, |acc, vehicle_break| {
+        let break_tw = get_break_time_window(tour, vehicle_break).expect("cannot get break time windows");
+
+        let should_assign = match vehicle_break {
+            VehicleBreak::Optional { policy, .. } => {
+                let policy = policy.as_ref().cloned().unwrap_or(VehicleOptionalBreakPolicy::SkipIfNoIntersection);
+
+                match policy {
+                    VehicleOptionalBreakPolicy::SkipIfNoIntersection => break_tw.start < arrival,
+                    VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => arrival > break_tw.end,
+                }
+            }
+            VehicleBreak::Required { .. } => {
+                // NOTE: skip break if its end time is after tour end
+                break_tw.intersects(tour_tw) && break_tw.end < tour_tw.end
+            }
+        };
+
+        if should_assign {
+            acc + 1
+        } else {
+            acc
+        }
+    })
+}
+
 fn get_break_violation_count(solution: &Solution, tour: &Tour) -> usize {
     solution.violations.as_ref().map_or(0, |violations| {
         violations
@@ -19,25 +19,35 @@ fn check_shift_limits(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
 
-        if let Some(ref limits) = vehicle.limits {
-            if let Some(max_distance) = limits.max_distance {
-                if tour.statistic.distance as Float > max_distance {
-                    return Err(format!(
-                        "max distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
-                        max_distance, tour.statistic.distance, tour.vehicle_id, tour.shift_index
-                    ).into());
-                }
+        // NOTE shift limits take precedence over vehicle limits
+        let shift_limits = vehicle.shifts.get(tour.shift_index).and_then(|shift| shift.limits.as_ref());
+        let vehicle_limits = vehicle.limits.as_ref();
+
+        let max_distance = shift_limits
+            .and_then(|limits| limits.max_distance)
+            .or_else(|| vehicle_limits.and_then(|limits| limits.max_distance));
+        if let Some(max_distance) = max_distance {
+            if tour.statistic.distance as Float > max_distance {
+                return Err(format!(
+                    "max distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                    max_distance, tour.statistic.distance, tour.vehicle_id, tour.shift_index
+                ).into());
             }
+        }
 
-            if let Some(max_duration) = limits.max_duration {
-                if tour.statistic.duration as Float > max_duration {
-                    return Err(format!(
-                        "shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
-                        max_duration, tour.statistic.duration, tour.vehicle_id, tour.shift_index
-                    ).into());
-                }
+        let max_duration = shift_limits
+            .and_then(|limits| limits.max_duration)
+            .or_else(|| vehicle_limits.and_then(|limits| limits.max_duration));
+        if let Some(max_duration) = max_duration {
+            if tour.statistic.duration as Float > max_duration {
+                return Err(format!(
+                    "shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                    max_duration, tour.statistic.duration, tour.vehicle_id, tour.shift_index
+                ).into());
             }
+        }
 
+        if let Some(limits) = vehicle_limits {
             if let Some(tour_size_limit) = limits.tour_size {
                 let shift = context.get_vehicle_shift(tour)?;
 
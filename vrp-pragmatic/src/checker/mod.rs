@@ -8,12 +8,12 @@ mod checker_test;
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::format::{CoordIndex, Location};
-use crate::parse_time;
+use crate::{format_time, parse_time};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterConfig;
 use vrp_core::construction::clustering::vicinity::VisitPolicy;
-use vrp_core::models::common::{Duration, Profile, TimeWindow};
+use vrp_core::models::common::{Duration, Profile, TimeSpan, TimeWindow};
 use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo};
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::prelude::{GenericError, GenericResult};
@@ -159,6 +159,7 @@ impl CheckerContext {
                 shift_time.intersects(&tour_time)
             })
             .cloned()
+            .map(|shift| with_day_horizon_breaks(&self.problem, shift))
             .ok_or_else(|| format!("cannot find shift for tour with vehicle if: '{}'", tour.vehicle_id).into())
     }
 
@@ -348,8 +349,8 @@ impl CheckerContext {
     }
 
     fn get_location_index(&self, location: &Location) -> GenericResult<usize> {
-        self.coord_index
-            .get_by_loc(location)
+        location
+            .resolve_index(&self.coord_index)
             .ok_or_else(|| format!("cannot find coordinate in coord index: {location:?}").into())
     }
 
@@ -411,6 +412,30 @@ fn get_time_window(stop: &Stop, activity: &Activity) -> TimeWindow {
     TimeWindow::new(parse_time(start), parse_time(end))
 }
 
+/// Adds day horizon rests to the shift as required breaks as they are reported as breaks in the solution.
+fn with_day_horizon_breaks(problem: &Problem, mut shift: VehicleShift) -> VehicleShift {
+    let horizon = problem.fleet.day_horizon.as_ref().and_then(|horizon| horizon.to_core_horizon().ok());
+
+    if let Some((horizon, end)) = horizon.zip(shift.end.as_ref()) {
+        let shift_time = TimeWindow::new(parse_time(&shift.start.earliest), parse_time(&end.latest));
+        let rests =
+            horizon.get_reserved_times(&shift_time).into_iter().filter_map(|reserved_time| match reserved_time.time {
+                TimeSpan::Window(tw) => Some(VehicleBreak::Required {
+                    time: VehicleRequiredBreakTime::ExactTime {
+                        earliest: format_time(tw.start),
+                        latest: format_time(tw.end),
+                    },
+                    duration: reserved_time.duration,
+                }),
+                TimeSpan::Offset(_) => None,
+            });
+
+        shift.breaks = Some(shift.breaks.take().into_iter().flatten().chain(rests).collect());
+    }
+
+    shift
+}
+
 fn get_matrix_size(matrices: &[Matrix]) -> usize {
     (matrices.first().unwrap().travel_times.len() as Float).sqrt().round() as usize
 }
@@ -463,6 +488,7 @@ use crate::checker::limits::check_limits;
 
 mod breaks;
 use crate::checker::breaks::{check_breaks, get_break_time_window};
+pub use crate::checker::breaks::{get_break_compliance_report, BreakCompliance};
 
 mod relations;
 use crate::checker::relations::check_relations;
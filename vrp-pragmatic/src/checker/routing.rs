@@ -51,18 +51,28 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
                         let (distance, duration) = get_matrix_data(from, to)?;
                         (distance, duration, to.distance)
                     }
-                    (prev, Stop::Transit(transit)) => {
-                        let prev_departure = parse_time(&prev.schedule().departure);
-                        let next_arrival = parse_time(&transit.time.arrival);
-                        // NOTE an edge case: duration of break will be counted in transit stop
-                        let duration = if next_arrival == prev_departure {
-                            0.
-                        } else {
-                            parse_time(&transit.time.departure) - next_arrival
-                        };
-                        (0_i64, duration as i64, total_distance)
+                    (Stop::Point(prev), Stop::Transit(transit)) => {
+                        let next = tour
+                            .stops
+                            .get(leg_idx + 2)
+                            .and_then(|stop| stop.as_point())
+                            .ok_or_else(|| "transit stop should be followed by point stop".to_string())?;
+                        let (_, travel_duration) = get_matrix_data(prev, next)?;
+
+                        // NOTE transit stop splits traveling between two point stops
+                        let duration = get_transit_duration(prev, transit);
+                        if duration < 0 || duration > travel_duration {
+                            return Err(format!(
+                                "transit stop {} in the tour '{}' is not between its neighbours",
+                                leg_idx + 1,
+                                tour.vehicle_id
+                            )
+                            .into());
+                        }
+
+                        (0_i64, duration, total_distance)
                     }
-                    (Stop::Transit(_), Stop::Point(to)) => {
+                    (Stop::Transit(transit), Stop::Point(to)) => {
                         assert!(leg_idx > 0);
                         let from = tour
                             .stops
@@ -71,8 +81,9 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
                             .as_point()
                             .expect("two consistent transit stops are not supported");
                         let (distance, duration) = get_matrix_data(from, to)?;
-                        (distance, duration, to.distance)
+                        (distance, duration - get_transit_duration(from, transit), to.distance)
                     }
+                    (Stop::Transit(_), Stop::Transit(_)) => unreachable!("transit stop is followed by point stop"),
                 };
 
                 let arrival_time = arrival_time + duration;
@@ -98,6 +109,11 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
     check_solution_statistic(&context.solution)
 }
 
+/// Returns traveling duration from the point stop till transit stop.
+fn get_transit_duration(from: &PointStop, transit: &TransitStop) -> i64 {
+    (parse_time(&transit.time.arrival) - parse_time(&from.time.departure)) as i64
+}
+
 fn check_stop_statistic(
     arrival_time: i64,
     total_distance: i64,
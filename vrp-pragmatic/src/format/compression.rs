@@ -0,0 +1,30 @@
+#[cfg(test)]
+#[path = "../../tests/unit/format/compression_test.rs"]
+mod compression_test;
+
+use std::io::{BufReader, Error, Read};
+
+/// Gzip magic bytes which are used to detect compressed input.
+#[cfg(feature = "compression")]
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Returns a reader which transparently decompresses gzip input detected by its magic bytes.
+/// Any other input is read as is.
+#[cfg(feature = "compression")]
+pub(crate) fn create_decompressed_reader<'a, R: Read + 'a>(reader: BufReader<R>) -> Result<Box<dyn Read + 'a>, Error> {
+    use std::io::BufRead;
+
+    let mut reader = reader;
+
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        Ok(Box::new(BufReader::new(flate2::bufread::MultiGzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+/// Returns reader as is: decompression requires `compression` feature.
+#[cfg(not(feature = "compression"))]
+pub(crate) fn create_decompressed_reader<'a, R: Read + 'a>(reader: BufReader<R>) -> Result<Box<dyn Read + 'a>, Error> {
+    Ok(Box::new(reader))
+}
@@ -9,12 +9,16 @@ use crate::format::{CustomLocationType, Location};
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 
+/// A default max difference in lat/lng when two coordinates are considered the same by deduplication.
+pub const DEFAULT_DEDUP_EPSILON: f64 = 1E-7;
+
 /// A helper struct which keeps track of coordinate mapping.
 pub struct CoordIndex {
     direct_index: HashMap<Location, usize>,
     reverse_index: HashMap<usize, Location>,
     custom_locations: HashSet<Location>,
     max_matrix_index: usize,
+    dedup_epsilon: f64,
     flags: u8,
 }
 
@@ -26,6 +30,7 @@ impl CoordIndex {
             reverse_index: Default::default(),
             custom_locations: Default::default(),
             max_matrix_index: 0,
+            dedup_epsilon: DEFAULT_DEDUP_EPSILON,
             flags: 0,
         };
 
@@ -100,8 +105,11 @@ impl CoordIndex {
                     self.flags |= 0b0010;
                     *index
                 }
-                Location::Custom { r#type: CustomLocationType::Unknown } => {
+                Location::Custom { r#type } => {
                     self.flags |= 0b0100;
+                    if matches!(r#type, CustomLocationType::Depot) {
+                        self.flags |= 0b1000;
+                    }
                     // NOTE do not add custom location in the index yet
                     self.custom_locations.insert(location.clone());
                     return;
@@ -113,6 +121,68 @@ impl CoordIndex {
         }
     }
 
+    /// Sets max difference in lat/lng used by `add_deduplicated` to consider two coordinates the same.
+    pub fn with_dedup_epsilon(mut self, epsilon: f64) -> Self {
+        self.dedup_epsilon = epsilon;
+        self
+    }
+
+    /// Adds location to indices reusing the index of an already known coordinate which is within
+    /// the dedup epsilon on both lat and lng. Returns index of the location.
+    /// Custom locations get their index in the special range after matrix locations, so they are
+    /// expected to be added after all coordinates.
+    pub fn add_deduplicated(&mut self, location: &Location) -> usize {
+        if let Some(index) = self.get_by_loc(location) {
+            return index;
+        }
+
+        let value = match location {
+            Location::Coordinate { lat, lng } => {
+                let existing = self
+                    .direct_index
+                    .iter()
+                    .filter(|(other, _)| match other {
+                        Location::Coordinate { lat: o_lat, lng: o_lng } => {
+                            (lat - o_lat).abs() <= self.dedup_epsilon && (lng - o_lng).abs() <= self.dedup_epsilon
+                        }
+                        _ => false,
+                    })
+                    .map(|(_, &index)| index)
+                    .min();
+
+                if let Some(index) = existing {
+                    return index;
+                }
+
+                self.flags |= 0b0001;
+                let value = self.direct_index.keys().filter(|loc| !matches!(loc, Location::Custom { .. })).count();
+                self.max_matrix_index = self.max_matrix_index.max(value);
+
+                value
+            }
+            Location::Reference { index } => {
+                self.flags |= 0b0010;
+                self.max_matrix_index = self.max_matrix_index.max(*index);
+
+                *index
+            }
+            Location::Custom { r#type } => {
+                self.flags |= 0b0100;
+                if matches!(r#type, CustomLocationType::Depot) {
+                    self.flags |= 0b1000;
+                }
+                self.custom_locations.insert(location.clone());
+
+                (self.max_matrix_index + 1).pow(2) + self.custom_locations.len() - 1
+            }
+        };
+
+        self.direct_index.insert(location.clone(), value);
+        self.reverse_index.insert(value, location.clone());
+
+        value
+    }
+
     /// Gets index of location.
     pub fn get_by_loc(&self, location: &Location) -> Option<usize> {
         self.direct_index.get(location).cloned()
@@ -162,6 +232,19 @@ impl CoordIndex {
     pub fn has_custom(&self) -> bool {
         (self.flags & 0b0100) > 0
     }
+
+    /// Returns true if problem has custom locations of depot type.
+    pub fn has_custom_depot(&self) -> bool {
+        (self.flags & 0b1000) > 0
+    }
+}
+
+/// A precision (in degrees) used to compare and hash coordinates: lat/lng values are rounded to the
+/// nearest multiple of it, so coordinates which are rounded to the same values are considered equal.
+pub const COORDINATE_PRECISION: f64 = 1E-7;
+
+fn quantize(value: f64) -> i64 {
+    (value / COORDINATE_PRECISION).round() as i64
 }
 
 impl Eq for Location {}
@@ -170,13 +253,17 @@ impl PartialEq for Location {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Location::Coordinate { lat: l_lat, lng: l_lng }, Location::Coordinate { lat: r_lat, lng: r_lng }) => {
-                (l_lat - r_lat).abs() < f64::EPSILON && (l_lng - r_lng).abs() < f64::EPSILON
+                quantize(*l_lat) == quantize(*r_lat) && quantize(*l_lng) == quantize(*r_lng)
             }
             (Location::Reference { index: left }, Location::Reference { index: right }) => left == right,
             (
                 Location::Custom { r#type: CustomLocationType::Unknown },
                 Location::Custom { r#type: CustomLocationType::Unknown },
             ) => true,
+            (
+                Location::Custom { r#type: CustomLocationType::Depot },
+                Location::Custom { r#type: CustomLocationType::Depot },
+            ) => true,
             _ => false,
         }
     }
@@ -186,8 +273,8 @@ impl Hash for Location {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
             Location::Coordinate { lat, lng } => {
-                state.write_u64(lat.to_bits());
-                state.write_u64(lng.to_bits());
+                state.write_i64(quantize(*lat));
+                state.write_i64(quantize(*lng));
             }
             Location::Reference { index } => {
                 state.write_usize(*index);
@@ -195,6 +282,9 @@ impl Hash for Location {
             Location::Custom { r#type: CustomLocationType::Unknown } => {
                 state.write_usize(0);
             }
+            Location::Custom { r#type: CustomLocationType::Depot } => {
+                state.write_usize(1);
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Specifies different properties as extension points on Dimensions type.
 
+use crate::format::problem::VehicleConsumption;
 use vrp_core::construction::features::BreakPolicy;
 use vrp_core::custom_dimension;
 use vrp_core::models::common::Dimensions;
@@ -20,3 +21,5 @@ custom_dimension!(JobValue typeof Float);
 custom_dimension!(JobType typeof String);
 
 custom_dimension!(BreakPolicy typeof BreakPolicy);
+
+custom_dimension!(VehicleConsumption typeof VehicleConsumption);
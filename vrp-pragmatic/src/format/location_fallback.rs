@@ -1,11 +1,20 @@
+#[cfg(test)]
+#[path = "../../tests/unit/format/location_fallback_test.rs"]
+mod location_fallback_test;
+
 use crate::format::{CoordIndex, CustomLocationType, Location as ApiLocation};
 use std::sync::Arc;
 use vrp_core::models::common::{Distance, Duration, Location, Profile};
 use vrp_core::models::problem::TransportFallback;
 use vrp_core::prelude::Float;
 
-/// A transport fallback for only a custom unknown location type.
-/// Returns zero distance/duration for unknown type locations.
+/// A value used to mark unreachable location, similar to matrix error codes.
+const UNREACHABLE_VALUE: Float = -1.;
+
+/// A transport fallback for only custom location types.
+/// Returns zero distance/duration for unknown type locations. Depot type locations have zero
+/// distance/duration to any location referenced by matrix, but they are unreachable (negative value)
+/// from other custom locations.
 pub struct UnknownLocationFallback {
     coord_index: Arc<CoordIndex>,
 }
@@ -20,9 +29,16 @@ impl UnknownLocationFallback {
         let (from, to) = (self.coord_index.get_by_idx(from), self.coord_index.get_by_idx(to));
 
         match (from, to) {
-            (Some(ApiLocation::Custom { r#type: CustomLocationType::Unknown }), _)
-            | (_, Some(ApiLocation::Custom { r#type: CustomLocationType::Unknown })) => Float::default(),
-            _ => panic!("fallback is only for locations of custom unknown type"),
+            (
+                Some(ApiLocation::Custom { r#type: CustomLocationType::Depot }),
+                Some(ApiLocation::Custom { r#type: CustomLocationType::Depot }),
+            ) => Float::default(),
+            (Some(ApiLocation::Custom { r#type: CustomLocationType::Depot }), Some(ApiLocation::Custom { .. }))
+            | (Some(ApiLocation::Custom { .. }), Some(ApiLocation::Custom { r#type: CustomLocationType::Depot })) => {
+                UNREACHABLE_VALUE
+            }
+            (Some(ApiLocation::Custom { .. }), _) | (_, Some(ApiLocation::Custom { .. })) => Float::default(),
+            _ => panic!("fallback is only for locations of custom type"),
         }
     }
 }
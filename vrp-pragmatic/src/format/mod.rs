@@ -2,20 +2,27 @@
 //! format from json input and create and write pragmatic solution.
 //!
 
+#[cfg(test)]
+#[path = "../../tests/unit/format/format_test.rs"]
+mod format_test;
+
 extern crate serde_json;
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use vrp_core::construction::enablers::ReservedTimesIndex;
-use vrp_core::models::common::{Distance, Duration};
+use vrp_core::models::common::{Distance, Duration, Location as DomainLocation};
 use vrp_core::models::problem::{Job as CoreJob, Single, VehicleIdDimension};
 use vrp_core::models::solution::Route;
 use vrp_core::models::{Extras as CoreExtras, Problem as CoreProblem, ViolationCode};
 use vrp_core::prelude::{Float, GenericError};
 
+mod compression;
+pub(crate) use self::compression::create_decompressed_reader;
+
 mod coord_index;
-pub use self::coord_index::CoordIndex;
+pub use self::coord_index::{CoordIndex, COORDINATE_PRECISION, DEFAULT_DEDUP_EPSILON};
 
 mod dimensions;
 pub use self::dimensions::*;
@@ -26,8 +33,11 @@ pub use self::location_fallback::*;
 pub mod problem;
 pub mod solution;
 
-/// Represents a location type.
+/// Represents a location type. Locations can be used as hash map keys: coordinates are compared
+/// using fixed precision (see [`COORDINATE_PRECISION`]), references by index and custom
+/// locations by their type.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum Location {
     /// A location type represented by geocoordinate with latitude and longitude.
@@ -74,6 +84,16 @@ impl Location {
             _ => unreachable!("expect coordinate"),
         }
     }
+
+    /// Resolves matrix index of the location: returns the index itself for reference and looks up
+    /// coordinate or custom location in given coord index. Returns `None` for location which is not
+    /// present in the coord index, e.g. for custom location which has no index in the special range.
+    pub fn resolve_index(&self, coord_index: &CoordIndex) -> Option<usize> {
+        match self {
+            Self::Reference { index } => Some(*index),
+            Self::Coordinate { .. } | Self::Custom { .. } => coord_index.get_by_loc(self),
+        }
+    }
 }
 
 impl std::fmt::Display for Location {
@@ -84,6 +104,7 @@ impl std::fmt::Display for Location {
             Location::Custom { r#type } => {
                 let value = match r#type {
                     CustomLocationType::Unknown => "unknown",
+                    CustomLocationType::Depot => "depot",
                 };
                 write!(f, "custom={value}")
             }
@@ -93,14 +114,31 @@ impl std::fmt::Display for Location {
 
 /// A custom location type which has no reference to matrix.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CustomLocationType {
     /// Unknown location type which has a zero distance/duration to any other location.
     #[serde(rename(deserialize = "unknown", serialize = "unknown"))]
     Unknown,
+    /// Depot location type which has a zero distance/duration to any location referenced by matrix,
+    /// but cannot be reached from (or to) other custom locations.
+    #[serde(rename(deserialize = "depot", serialize = "depot"))]
+    Depot,
+}
+
+/// A severity of format error.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub enum FormatSeverity {
+    /// A hard error which makes problem definition invalid.
+    #[default]
+    #[serde(rename(serialize = "error"))]
+    Error,
+    /// A soft problem which does not prevent problem from being solved.
+    #[serde(rename(serialize = "warning"))]
+    Warning,
 }
 
 /// A format error.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 pub struct FormatError {
     /// An error code in registry.
     pub code: String,
@@ -110,17 +148,34 @@ pub struct FormatError {
     pub action: String,
     /// A details about exception.
     pub details: Option<String>,
+    /// A severity of the error.
+    pub severity: FormatSeverity,
 }
 
 impl FormatError {
     /// Creates a new instance of `FormatError` action without details.
     pub fn new(code: String, cause: String, action: String) -> Self {
-        Self { code, cause, action, details: None }
+        Self { code, cause, action, details: None, severity: FormatSeverity::Error }
     }
 
     /// Creates a new instance of `FormatError` action.
     pub fn new_with_details(code: String, cause: String, action: String, details: String) -> Self {
-        Self { code, cause, action, details: Some(details) }
+        Self { code, cause, action, details: Some(details), severity: FormatSeverity::Error }
+    }
+
+    /// Creates a new instance of `FormatError` with warning severity and without details.
+    pub fn new_warning(code: String, cause: String, action: String) -> Self {
+        Self { severity: FormatSeverity::Warning, ..Self::new(code, cause, action) }
+    }
+
+    /// Creates a new instance of `FormatError` with warning severity.
+    pub fn new_warning_with_details(code: String, cause: String, action: String, details: String) -> Self {
+        Self { severity: FormatSeverity::Warning, ..Self::new_with_details(code, cause, action, details) }
+    }
+
+    /// Returns true if error has warning severity.
+    pub fn is_warning(&self) -> bool {
+        self.severity == FormatSeverity::Warning
     }
 
     /// Serializes error into json string.
@@ -138,7 +193,7 @@ impl std::fmt::Display for FormatError {
 }
 
 /// Keeps track of multiple `FormatError`.
-#[derive(Debug)]
+#[derive(Debug, Eq, PartialEq)]
 pub struct MultiFormatError {
     /// Inner errors.
     pub errors: Vec<FormatError>,
@@ -149,6 +204,28 @@ impl MultiFormatError {
     pub fn to_json(&self) -> String {
         serde_json::to_string_pretty(&self.errors).unwrap()
     }
+
+    /// Collapses errors which share the same code and cause into one error keeping order of the
+    /// first occurrence. Their distinct details are merged into a newline-joined string.
+    pub fn deduplicated(self) -> Self {
+        let errors = self.errors.into_iter().fold(Vec::<FormatError>::new(), |mut errors, error| {
+            match errors.iter_mut().find(|other| other.code == error.code && other.cause == error.cause) {
+                Some(existing) => match (existing.details.as_mut(), error.details) {
+                    (Some(details), Some(other)) if !details.split('\n').any(|line| line == other) => {
+                        details.push('\n');
+                        details.push_str(other.as_str());
+                    }
+                    (None, Some(other)) => existing.details = Some(other),
+                    _ => {}
+                },
+                None => errors.push(error),
+            }
+
+            errors
+        });
+
+        Self { errors }
+    }
 }
 
 impl std::error::Error for MultiFormatError {}
@@ -199,15 +276,61 @@ const RECHARGE_CONSTRAINT_CODE: ViolationCode = ViolationCode(15);
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
 
-pub use self::properties::{CoordIndexExtraProperty, JobIndexExtraProperty};
+/// Separates an external job id from its suffix in job index keys, e.g. `cust-1#2`.
+pub const JOB_ID_SUFFIX_SEPARATOR: char = '#';
+
+/// Provides a way to access jobs kept in [`JobIndex`].
+pub trait JobIndexAccessor {
+    /// Returns all jobs which id is equal to the given prefix or starts with it followed by
+    /// [`JOB_ID_SUFFIX_SEPARATOR`]. Jobs are returned in the order of their ids.
+    fn get_jobs_by_prefix(&self, prefix: &str) -> Vec<CoreJob>;
+}
+
+impl JobIndexAccessor for JobIndex {
+    fn get_jobs_by_prefix(&self, prefix: &str) -> Vec<CoreJob> {
+        let mut jobs = self
+            .iter()
+            .filter(|(id, _)| {
+                id.strip_prefix(prefix)
+                    .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with(JOB_ID_SUFFIX_SEPARATOR))
+            })
+            .collect::<Vec<_>>();
+
+        jobs.sort_by_key(|(id, _)| *id);
+
+        jobs.into_iter().map(|(_, job)| job.clone()).collect()
+    }
+}
+
+/// Keeps travel time variances of routing matrices per routing profile index.
+pub struct TravelTimeVariances {
+    variances: HashMap<usize, Vec<Float>>,
+    size: usize,
+}
+
+impl TravelTimeVariances {
+    /// Creates a new instance of `TravelTimeVariances`.
+    pub fn new(variances: HashMap<usize, Vec<Float>>, size: usize) -> Self {
+        Self { variances, size }
+    }
+
+    /// Returns travel time variance between two locations for given profile index.
+    /// Unknown profiles or locations have zero variance.
+    pub fn get(&self, profile: usize, from: DomainLocation, to: DomainLocation) -> Float {
+        self.variances.get(&profile).and_then(|variances| variances.get(from * self.size + to)).copied().unwrap_or(0.)
+    }
+}
+
+pub use self::properties::{CoordIndexExtraProperty, JobIndexExtraProperty, TravelTimeVariancesExtraProperty};
 
 mod properties {
-    use crate::format::{CoordIndex, JobIndex};
+    use crate::format::{CoordIndex, JobIndex, TravelTimeVariances};
     use vrp_core::custom_extra_property;
     use vrp_core::models::Extras;
 
     custom_extra_property!(JobIndex typeof JobIndex);
     custom_extra_property!(CoordIndex typeof CoordIndex);
+    custom_extra_property!(TravelTimeVariances typeof TravelTimeVariances);
 }
 
 /// Get job and coord indices from extras
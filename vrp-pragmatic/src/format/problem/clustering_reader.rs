@@ -1,7 +1,7 @@
 use super::*;
 use crate::format::problem::fleet_reader::get_profile_index_map;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use vrp_core::construction::clustering::vicinity::*;
 use vrp_core::models::common::Profile;
 use vrp_core::models::problem::JobIdDimension;
@@ -12,10 +12,12 @@ pub(super) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<C
         match clustering {
             Clustering::Vicinity { profile, threshold, visiting, serving, filtering } => Ok(Some(ClusterConfig {
                 profile: get_profile(api_problem, profile)?,
+                speed_factors: HashMap::default(),
                 threshold: ThresholdPolicy {
                     moving_duration: threshold.distance,
                     moving_distance: threshold.duration,
                     min_shared_time: threshold.min_shared_time,
+                    min_shared_time_ratio: threshold.min_shared_time_ratio,
                     smallest_time_window: threshold.smallest_time_window,
                     max_jobs_per_cluster: threshold.max_jobs_per_cluster,
                 },
@@ -32,6 +34,9 @@ pub(super) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<C
                 },
                 filtering: get_filter_policy(api_problem, filtering.as_ref()),
                 building: get_builder_policy(),
+                similarity_fn: None,
+                unite_skills: false,
+                demand_fit_fn: None,
             })),
         }
     } else {
@@ -73,6 +78,7 @@ fn get_builder_policy() -> BuilderPolicy {
                 &right.job,
             )
         }),
+        cluster_score_fn: None,
     }
 }
 
@@ -3,7 +3,7 @@
 mod fleet_reader_test;
 
 use super::*;
-use crate::format::UnknownLocationFallback;
+use crate::format::{TravelTimeVariances, UnknownLocationFallback};
 use crate::get_unique_locations;
 use crate::utils::get_approx_transportation;
 use crate::Location as ApiLocation;
@@ -95,6 +95,36 @@ pub(super) fn create_transport_costs(
     }
 }
 
+/// Creates travel time variances from matrices which have them specified.
+pub(super) fn create_travel_time_variances(
+    api_problem: &ApiProblem,
+    matrices: &[Matrix],
+) -> Option<TravelTimeVariances> {
+    let matrix_profiles = get_profile_index_map(api_problem);
+    let size = matrices.first().map(|matrix| (matrix.travel_times.len() as Float).sqrt().round() as usize)?;
+
+    let variances = matrices
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, matrix)| {
+            let variances = matrix.travel_time_variances.as_ref()?;
+            let profile = matrix.profile.as_ref().and_then(|p| matrix_profiles.get(p)).cloned().unwrap_or(idx);
+
+            Some((profile, variances.iter().map(|&variance| variance as Float).collect::<Vec<_>>()))
+        })
+        // NOTE for time aware routing, variances of the first matrix are used
+        .fold(HashMap::new(), |mut acc, (profile, variances)| {
+            acc.entry(profile).or_insert(variances);
+            acc
+        });
+
+    if variances.is_empty() {
+        None
+    } else {
+        Some(TravelTimeVariances::new(variances, size))
+    }
+}
+
 pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> CoreFleet {
     let profile_indices = get_profile_index_map(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
@@ -115,14 +145,14 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
-                let location = coord_index.get_by_loc(&shift.start.location).unwrap();
+                let location = shift.start.location.resolve_index(coord_index).unwrap();
                 let earliest = parse_time(&shift.start.earliest);
                 let latest = shift.start.latest.as_ref().map(|time| parse_time(time));
                 (location, earliest, latest)
             };
 
             let end = shift.end.as_ref().map(|end| {
-                let location = coord_index.get_by_loc(&end.location).unwrap();
+                let location = end.location.resolve_index(coord_index).unwrap();
                 let time = parse_time(&end.latest);
                 (location, time)
             });
@@ -156,8 +186,14 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_vehicle_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
                 }
 
-                if let Some(skills) = vehicle.skills.as_ref() {
-                    dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
+                // NOTE fleet skills are shared by all vehicles, so they are merged with vehicle's own skills
+                if vehicle.skills.is_some() || api_problem.fleet.skills.is_some() {
+                    let skills = vehicle.skills.iter().chain(api_problem.fleet.skills.iter()).flatten();
+                    dimens.set_vehicle_skills(skills.cloned().collect::<HashSet<_>>());
+                }
+
+                if let Some(consumption) = vehicle.consumption.as_ref() {
+                    dimens.set_vehicle_consumption(consumption.clone());
                 }
 
                 vehicles.push(Arc::new(Vehicle {
@@ -222,6 +258,7 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
                 travel_times: approx_data[idx].0.clone(),
                 distances: approx_data[idx].1.clone(),
                 error_codes: None,
+                travel_time_variances: None,
             }
         })
         .collect()
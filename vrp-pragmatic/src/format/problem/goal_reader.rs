@@ -13,9 +13,10 @@ pub(super) fn create_goal_context(
     api_problem: &ApiProblem,
     blocks: &ProblemBlocks,
     props: &ProblemProperties,
+    registry: &ObjectiveRegistry,
 ) -> GenericResult<GoalContext> {
     // determine features from objective definition
-    let feature_layers = get_objective_feature_layers(api_problem, blocks, props)?;
+    let feature_layers = get_objective_feature_layers(api_problem, blocks, props, registry)?;
     let (mut features, goal_builder) = get_features_with_goal(&feature_layers)?;
 
     if props.has_unreachable_locations {
@@ -85,12 +86,13 @@ fn get_objective_feature_layers(
     api_problem: &ApiProblem,
     blocks: &ProblemBlocks,
     props: &ProblemProperties,
+    registry: &ObjectiveRegistry,
 ) -> GenericResult<Vec<FeatureLayer>> {
     let objectives = get_objectives(api_problem, props);
 
     objectives
         .iter()
-        .map(|objective| get_objective_feature_layer(objective, blocks, props))
+        .map(|objective| get_objective_feature_layer(objective, blocks, props, registry))
         .collect::<GenericResult<_>>()
 }
 
@@ -98,6 +100,7 @@ fn get_objective_feature_layer(
     objective: &Objective,
     blocks: &ProblemBlocks,
     props: &ProblemProperties,
+    registry: &ObjectiveRegistry,
 ) -> GenericResult<FeatureLayer> {
     let feature = match objective {
         Objective::MinimizeCost => TransportFeatureBuilder::new("min_cost")
@@ -117,6 +120,10 @@ fn get_objective_feature_layer(
             .build_minimize_duration(),
         Objective::MinimizeTours => create_minimize_tours_feature("min_tours"),
         Objective::MaximizeTours => create_maximize_tours_feature("max_tours"),
+        Objective::MinimizeVehicleTypes => create_minimize_vehicle_types_feature(
+            "min_vehicle_types",
+            Arc::new(|actor| actor.vehicle.dimens.get_vehicle_type().cloned()),
+        ),
         Objective::MaximizeValue { breaks } => create_maximize_total_job_value_feature(
             "max_value",
             JobReadValueFn::Left(Arc::new({
@@ -161,6 +168,9 @@ fn get_objective_feature_layer(
             .build(),
 
         Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
+        Objective::MinimizeWaitingTime => {
+            create_min_waiting_feature("min_waiting_time", blocks.transport.clone(), blocks.activity.clone())
+        }
         Objective::BalanceMaxLoad => {
             if props.has_multi_dimen_capacity {
                 create_max_load_balanced_feature::<MultiDimLoad>(
@@ -186,6 +196,7 @@ fn get_objective_feature_layer(
             }
         }
         Objective::BalanceActivities => create_activity_balanced_feature("activity_balance"),
+        Objective::BalanceTourSize => create_balance_tour_size_feature("tour_size_balance"),
         Objective::BalanceDistance => create_distance_balanced_feature("distance_balance"),
         Objective::BalanceDuration => create_duration_balanced_feature("duration_balance"),
         Objective::CompactTour { job_radius } => {
@@ -193,10 +204,11 @@ fn get_objective_feature_layer(
         }
         Objective::TourOrder => create_tour_order_soft_feature("tour_order", get_tour_order_fn()),
         Objective::FastService => get_fast_service_feature("fast_service", blocks),
+        Objective::Custom { name } => registry.create(name),
         Objective::MultiObjective { objectives, strategy: composition_type } => {
             let features = objectives
                 .iter()
-                .map(|o| get_objective_feature_layer(o, blocks, props))
+                .map(|o| get_objective_feature_layer(o, blocks, props, registry))
                 .map(|layer| match layer {
                     Ok(FeatureLayer::Single(feature)) => Ok(feature),
                     Ok(FeatureLayer::Multi { .. }) => {
@@ -387,26 +399,47 @@ fn get_tour_limit_feature(
     api_problem: &ApiProblem,
     transport: Arc<dyn TransportCost>,
 ) -> GenericResult<Feature> {
+    // NOTE shift limits take precedence over vehicle limits
     let (distances, durations) = api_problem
         .fleet
         .vehicles
         .iter()
-        .filter_map(|vehicle| vehicle.limits.as_ref().map(|limits| (vehicle, limits)))
-        .fold((HashMap::new(), HashMap::new()), |(mut distances, mut durations), (vehicle, limits)| {
-            limits.max_distance.iter().for_each(|max_distance| {
-                distances.insert(vehicle.type_id.clone(), *max_distance);
-            });
+        .flat_map(|vehicle| {
+            vehicle.shifts.iter().enumerate().map(move |(shift_idx, shift)| (vehicle, shift_idx, shift))
+        })
+        .fold((HashMap::new(), HashMap::new()), |(mut distances, mut durations), (vehicle, shift_idx, shift)| {
+            let key = (vehicle.type_id.clone(), shift_idx);
+            let vehicle_limits = vehicle.limits.as_ref();
+            let shift_limits = shift.limits.as_ref();
+
+            shift_limits
+                .and_then(|limits| limits.max_distance)
+                .or_else(|| vehicle_limits.and_then(|limits| limits.max_distance))
+                .iter()
+                .for_each(|max_distance| {
+                    distances.insert(key.clone(), *max_distance);
+                });
 
-            limits.max_duration.iter().for_each(|max_duration| {
-                durations.insert(vehicle.type_id.clone(), *max_duration);
-            });
+            shift_limits
+                .and_then(|limits| limits.max_duration)
+                .or_else(|| vehicle_limits.and_then(|limits| limits.max_duration))
+                .iter()
+                .for_each(|max_duration| {
+                    durations.insert(key.clone(), *max_duration);
+                });
 
             (distances, durations)
         });
 
-    let get_limit = |limit_map: HashMap<String, Float>| {
+    let get_limit = |limit_map: HashMap<(String, usize), Float>| {
         Arc::new(move |actor: &Actor| {
-            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limit_map.get(v_type)).cloned()
+            let dimens = &actor.vehicle.dimens;
+            dimens
+                .get_vehicle_type()
+                .cloned()
+                .zip(dimens.get_shift_index().copied())
+                .and_then(|key| limit_map.get(&key))
+                .cloned()
         })
     };
 
@@ -369,7 +369,7 @@ fn get_single(places: Vec<PlaceData>, coord_index: &CoordIndex) -> Single {
     let places = places
         .into_iter()
         .map(|(location, duration, times, _)| Place {
-            location: location.as_ref().and_then(|l| coord_index.get_by_loc(l)),
+            location: location.as_ref().and_then(|l| l.resolve_index(coord_index)),
             duration,
             times,
         })
@@ -2,6 +2,7 @@
 
 use super::*;
 use crate::parse_time;
+use serde::Deserialize;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::models::common::TimeWindow;
@@ -26,6 +27,17 @@ pub use self::fleet_reader::create_approx_matrices;
 mod goal_reader;
 mod job_reader;
 
+mod objective_registry;
+pub use self::objective_registry::{CustomObjectiveFn, ObjectiveRegistry};
+
+#[cfg(feature = "schema")]
+mod schema;
+#[cfg(feature = "schema")]
+pub use self::schema::{matrix_json_schema, problem_json_schema};
+
+mod problem_generator;
+pub use self::problem_generator::{generate_problem, ProblemGeneratorConfig};
+
 mod problem_reader;
 use self::problem_reader::{map_to_problem_with_approx, map_to_problem_with_matrices};
 
@@ -44,7 +56,7 @@ impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
             matrices.push(deserialize_matrix(matrix)?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, &ObjectiveRegistry::default())
     }
 }
 
@@ -52,7 +64,7 @@ impl<R: Read> PragmaticProblem for BufReader<R> {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
         let problem = deserialize_problem(self)?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, &ObjectiveRegistry::default())
     }
 }
 
@@ -65,7 +77,7 @@ impl PragmaticProblem for (String, Vec<String>) {
             matrices.push(deserialize_matrix(BufReader::new(matrix.as_bytes()))?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, &ObjectiveRegistry::default())
     }
 }
 
@@ -73,19 +85,19 @@ impl PragmaticProblem for String {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
         let problem = deserialize_problem(BufReader::new(self.as_bytes()))?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, &ObjectiveRegistry::default())
     }
 }
 
 impl PragmaticProblem for (ApiProblem, Vec<Matrix>) {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
-        map_to_problem_with_matrices(self.0, self.1)
+        map_to_problem_with_matrices(self.0, self.1, &ObjectiveRegistry::default())
     }
 }
 
 impl PragmaticProblem for ApiProblem {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
-        map_to_problem_with_approx(self)
+        map_to_problem_with_approx(self, &ObjectiveRegistry::default())
     }
 }
 
@@ -99,6 +111,48 @@ impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
     }
 }
 
+impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>, &ObjectiveRegistry) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        let (problem, matrices, registry) = self;
+
+        if let Some(matrices) = matrices {
+            map_to_problem_with_matrices(problem, matrices, registry)
+        } else {
+            map_to_problem_with_approx(problem, registry)
+        }
+    }
+}
+
+/// Reads problem defined in pragmatic format directly from the reader. The json document is
+/// deserialized while it is read from the stream, so the input is never buffered as a whole
+/// in memory. Approximated routing matrices are used when no `matrices` are specified.
+/// Gzip compressed input is decompressed transparently when `compression` feature is enabled.
+pub fn read_problem_from_reader<R: Read>(
+    reader: R,
+    matrices: Option<Vec<Matrix>>,
+) -> Result<CoreProblem, MultiFormatError> {
+    let reader = create_decompressed_reader(BufReader::new(reader)).map_err(|err| {
+        MultiFormatError::from(vec![FormatError::new(
+            "E0000".to_string(),
+            "cannot deserialize problem".to_string(),
+            format!("check input: '{err}'"),
+        )])
+    })?;
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+
+    let problem = ApiProblem::deserialize(&mut deserializer)
+        .and_then(|problem| deserializer.end().map(|_| problem))
+        .map_err(|err| {
+            MultiFormatError::from(vec![FormatError::new(
+                "E0000".to_string(),
+                "cannot deserialize problem".to_string(),
+                format!("check input json at line {}, column {}: '{err}'", err.line(), err.column()),
+            )])
+        })?;
+
+    (problem, matrices).read_pragmatic()
+}
+
 /// Keeps track of problem properties (e.g. features).
 struct ProblemProperties {
     has_multi_dimen_capacity: bool,
@@ -4,14 +4,15 @@ mod model_test;
 
 extern crate serde_json;
 
-use crate::format::{FormatError, Location, MultiFormatError};
+use crate::format::{create_decompressed_reader, FormatError, Location, MultiFormatError};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
-use vrp_core::prelude::Float;
+use vrp_core::prelude::{Float, GenericResult};
 // region Plan
 
 /// Relation type.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum RelationType {
     /// Relation type which locks jobs to specific vehicle in any order.
@@ -24,6 +25,7 @@ pub enum RelationType {
 
 /// Relation is the way to lock specific jobs to specific vehicles.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Relation {
     /// Relation type.
@@ -40,6 +42,7 @@ pub struct Relation {
 
 /// A job skills limitation for a vehicle.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct JobSkills {
     /// Vehicle should have all of these skills defined.
@@ -55,6 +58,7 @@ pub struct JobSkills {
 
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JobPlace {
     /// A job place location.
     pub location: Location,
@@ -71,6 +75,7 @@ pub struct JobPlace {
 
 /// Specifies a job task.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JobTask {
     /// A list of possible places where given task can be performed.
     pub places: Vec<JobPlace>,
@@ -87,6 +92,7 @@ pub struct JobTask {
 /// * all of them should be completed or none of them.
 /// * all pickups must be completed before any of deliveries.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Job {
     /// A job id.
     pub id: String,
@@ -128,6 +134,7 @@ pub struct Job {
 
 /// Specifies clustering algorithm.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum Clustering {
     /// Vicinity clustering.
@@ -149,6 +156,7 @@ pub enum Clustering {
 
 /// Defines a various thresholds to control cluster size.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct VicinityThresholdPolicy {
     /// Moving duration limit.
@@ -157,6 +165,9 @@ pub struct VicinityThresholdPolicy {
     pub distance: Float,
     /// Minimum shared time for jobs (non-inclusive).
     pub min_shared_time: Option<Float>,
+    /// Minimum shared time for jobs (non-inclusive) as a fraction of the shorter job's service time.
+    /// Used only when `minSharedTime` is not set.
+    pub min_shared_time_ratio: Option<Float>,
     /// The smallest time window of the cluster after service time shrinking.
     pub smallest_time_window: Option<Float>,
     /// The maximum amount of jobs per cluster.
@@ -165,6 +176,7 @@ pub struct VicinityThresholdPolicy {
 
 /// Specifies cluster visiting policy.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum VicinityVisitPolicy {
     /// It is required to return to the first job's location (cluster center) before visiting a next job.
@@ -176,6 +188,7 @@ pub enum VicinityVisitPolicy {
 
 /// Specifies service time policy.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum VicinityServingPolicy {
     /// Keep original service time.
@@ -204,6 +217,7 @@ pub enum VicinityServingPolicy {
 
 /// Specifies filtering policy for vicinity clustering.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct VicinityFilteringPolicy {
     /// Ids of the jobs which cannot be used within clustering.
@@ -214,6 +228,7 @@ pub struct VicinityFilteringPolicy {
 
 /// A plan specifies work which has to be done.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Plan {
     /// List of jobs.
     pub jobs: Vec<Job>,
@@ -233,6 +248,7 @@ pub struct Plan {
 
 /// Specifies vehicle costs.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VehicleCosts {
     /// Fixed is cost of vehicle usage per tour.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -247,6 +263,7 @@ pub struct VehicleCosts {
 
 /// Specifies vehicle shift start.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ShiftStart {
     /// Earliest possible departure date time in RFC3339 format.
     pub earliest: String,
@@ -263,6 +280,7 @@ pub struct ShiftStart {
 
 /// Specifies vehicle shift end.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ShiftEnd {
     /// Earliest possible arrival date time in RFC3339 format.
     /// At the moment, not supported, reserved for future.
@@ -278,6 +296,7 @@ pub struct ShiftEnd {
 
 /// Specifies vehicle shift.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VehicleShift {
     /// Vehicle shift start.
     pub start: ShiftStart,
@@ -298,10 +317,31 @@ pub struct VehicleShift {
     /// Vehicle recharge stations information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recharges: Option<VehicleRecharges>,
+
+    /// Shift specific travel limits which override vehicle limits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<VehicleShiftLimits>,
+}
+
+/// Specifies vehicle shift travel limits.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleShiftLimits {
+    /// Max traveling distance in the shift.
+    /// Vehicle limit is used when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<Float>,
+
+    /// Max duration of the shift.
+    /// Vehicle limit is used when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_duration: Option<Float>,
 }
 
 /// Specifies a place where vehicle can load or unload cargo.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct VehicleReload {
     /// A place location.
@@ -325,6 +365,7 @@ pub struct VehicleReload {
 
 /// Specifies vehicle recharge stations data.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct VehicleRecharges {
     /// Maximum traveled distance before recharge station has to be visited.
@@ -332,6 +373,11 @@ pub struct VehicleRecharges {
 
     /// Specifies list of recharge station. Each can be visited only once.
     pub stations: Vec<VehicleRechargeStation>,
+
+    /// Specifies whether a break scheduled directly before or after recharge at the same station
+    /// is done while recharging. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combine_breaks: Option<bool>,
 }
 
 /// Specifies type alias for vehicle recharge station.
@@ -339,6 +385,7 @@ pub type VehicleRechargeStation = JobPlace;
 
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct VehicleLimits {
     /// Max traveling distance per shift/tour.
@@ -360,6 +407,7 @@ pub struct VehicleLimits {
 
 /// Vehicle optional break time variant.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum VehicleOptionalBreakTime {
     /// Break time is defined by a time window with time specified in RFC3339 format.
@@ -370,6 +418,7 @@ pub enum VehicleOptionalBreakTime {
 
 /// Vehicle required break time variant.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum VehicleRequiredBreakTime {
     /// Break time is defined by exact time in RFC3339 format.
@@ -392,6 +441,7 @@ pub enum VehicleRequiredBreakTime {
 
 /// Vehicle break place.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VehicleOptionalBreakPlace {
     /// Break duration.
     pub duration: Float,
@@ -405,6 +455,7 @@ pub struct VehicleOptionalBreakPlace {
 
 /// Vehicle break policy.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub enum VehicleOptionalBreakPolicy {
     /// Allows to skip break if actual tour schedule doesn't intersect with vehicle time window.
@@ -415,6 +466,7 @@ pub enum VehicleOptionalBreakPolicy {
 
 /// Specifies a vehicle break.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum VehicleBreak {
     /// An optional break which is more flexible, but might be not assigned.
@@ -438,6 +490,7 @@ pub enum VehicleBreak {
 
 /// Specifies a vehicle type.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct VehicleType {
     /// Vehicle type id.
@@ -465,10 +518,28 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle energy (fuel) consumption used to estimate energy of each tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumption: Option<VehicleConsumption>,
+}
+
+/// Specifies vehicle energy (fuel) consumption.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleConsumption {
+    /// Energy consumed per distance unit.
+    pub distance: Float,
+
+    /// An extra energy consumed per distance unit for each unit of load (first capacity dimension).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<Float>,
 }
 
 /// Specifies a vehicle profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct VehicleProfile {
     /// Routing matrix profile name.
     pub matrix: String,
@@ -481,6 +552,7 @@ pub struct VehicleProfile {
 
 /// Specifies routing matrix profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MatrixProfile {
     /// Profile name.
     pub name: String,
@@ -493,6 +565,7 @@ pub struct MatrixProfile {
 
 /// Specifies vehicle resource type.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type")]
 pub enum VehicleResource {
     /// A shared reload resource.
@@ -507,6 +580,8 @@ pub enum VehicleResource {
 
 /// Specifies fleet.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
 pub struct Fleet {
     /// Vehicle types.
     pub vehicles: Vec<VehicleType>,
@@ -517,6 +592,31 @@ pub struct Fleet {
     /// Specifies vehicle resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<VehicleResource>>,
+
+    /// Specifies skills shared by all vehicles in addition to their own skills.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+
+    /// Specifies a multi-day planning horizon with a mandatory rest between days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_horizon: Option<DayHorizon>,
+}
+
+/// Specifies a multi-day planning horizon. Days are counted from the unix epoch, so with the default
+/// day length of 86400 seconds, a day starts at midnight UTC.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DayHorizon {
+    /// Day length in seconds. Default value is 86400.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_length: Option<Float>,
+
+    /// Rest start as an offset in seconds from the day start.
+    pub rest_start: Float,
+
+    /// Rest duration in seconds. A rest can span over the day boundary.
+    pub rest_duration: Float,
 }
 
 // endregion
@@ -525,6 +625,7 @@ pub struct Fleet {
 
 /// Specifies objective function types.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "kebab-case")]
 pub enum Objective {
     /// An objective to minimize total cost as a linear combination of total time and distance.
@@ -542,6 +643,9 @@ pub enum Objective {
     /// An objective to maximize total tour amount.
     MaximizeTours,
 
+    /// An objective to minimize amount of distinct vehicle types used in solution.
+    MinimizeVehicleTypes,
+
     /// An objective to maximize value of served jobs.
     MaximizeValue {
         /// Specifies a weight of skipped breaks.
@@ -560,12 +664,18 @@ pub enum Objective {
     /// An objective to minimize sum of arrival times from all routes.
     MinimizeArrivalTime,
 
+    /// An objective to minimize total waiting time before job time windows start.
+    MinimizeWaitingTime,
+
     /// An objective to balance max load across all tours.
     BalanceMaxLoad,
 
     /// An objective to balance activities across all tours.
     BalanceActivities,
 
+    /// An objective to balance tour sizes by minimizing variance of activity counts across all tours.
+    BalanceTourSize,
+
     /// An objective to balance distance across all tours.
     BalanceDistance,
 
@@ -584,6 +694,12 @@ pub enum Objective {
     /// An objective to prefer jobs to be served as soon as possible.
     FastService,
 
+    /// A custom objective registered by name in `ObjectiveRegistry`.
+    Custom {
+        /// A name of the registered objective.
+        name: String,
+    },
+
     /// A multi objective allows to define multiple competitive objectives at the same layer of hierarchy.
     MultiObjective {
         /// An objective composition type.
@@ -593,9 +709,31 @@ pub enum Objective {
     },
 }
 
+/// Specifies a direction of objective optimization.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ObjectiveDirection {
+    /// An objective value should be minimized.
+    Minimize,
+    /// An objective value should be maximized. Internally, it is handled by negation of fitness value.
+    Maximize,
+}
+
+impl Objective {
+    /// Returns a direction of the objective optimization. Returns `None` for multi objective as
+    /// it is a composition of other objectives.
+    pub fn direction(&self) -> Option<ObjectiveDirection> {
+        match self {
+            Objective::MaximizeTours | Objective::MaximizeValue { .. } => Some(ObjectiveDirection::Maximize),
+            Objective::MultiObjective { .. } => None,
+            _ => Some(ObjectiveDirection::Minimize),
+        }
+    }
+}
+
 /// An mupltiple objective strategy type specifies how competitive objective functions are compared
 /// among each other.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "name", rename_all = "kebab-case")]
 pub enum MultiStrategy {
     /// A sum type simply sums all objective values together.
@@ -614,6 +752,7 @@ pub enum MultiStrategy {
 
 /// A VRP problem definition.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Problem {
     /// Problem plan: customers to serve.
     pub plan: Plan,
@@ -627,7 +766,8 @@ pub struct Problem {
 }
 
 /// A routing matrix.
-#[derive(Clone, Deserialize, Debug, Serialize)]
+#[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Matrix {
     /// A name of profile.
@@ -646,10 +786,25 @@ pub struct Matrix {
     /// Error codes to mark unreachable locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// Travel time variances (in squared time units) to model stochastic travel times.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub travel_time_variances: Option<Vec<i64>>,
 }
 
 // endregion
 
+impl DayHorizon {
+    /// Creates a core day horizon model.
+    pub fn to_core_horizon(&self) -> GenericResult<vrp_core::construction::enablers::DayHorizon> {
+        vrp_core::construction::enablers::DayHorizon::new(
+            self.day_length.unwrap_or(86400.),
+            self.rest_start,
+            self.rest_duration,
+        )
+    }
+}
+
 impl Job {
     /// Returns iterator over all tasks.
     pub fn all_tasks_iter(&self) -> impl Iterator<Item = &JobTask> {
@@ -662,8 +817,17 @@ impl Job {
     }
 }
 
-/// Deserializes problem in json format from `BufReader`.
+/// Deserializes problem in json format from `BufReader`. Gzip compressed input is decompressed
+/// transparently when `compression` feature is enabled.
 pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, MultiFormatError> {
+    let reader = create_decompressed_reader(reader).map_err(|err| {
+        MultiFormatError::from(vec![FormatError::new(
+            "E0000".to_string(),
+            "cannot deserialize problem".to_string(),
+            format!("check input: '{err}'"),
+        )])
+    })?;
+
     serde_json::from_reader(reader).map_err(|err| {
         vec![FormatError::new(
             "E0000".to_string(),
@@ -674,8 +838,17 @@ pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Mul
     })
 }
 
-/// Deserializes routing matrix in json format from `BufReader`.
+/// Deserializes routing matrix in json format from `BufReader`. Gzip compressed input is decompressed
+/// transparently when `compression` feature is enabled.
 pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, MultiFormatError> {
+    let reader = create_decompressed_reader(reader).map_err(|err| {
+        MultiFormatError::from(vec![FormatError::new(
+            "E0001".to_string(),
+            "cannot deserialize matrix".to_string(),
+            format!("check input: '{err}'"),
+        )])
+    })?;
+
     serde_json::from_reader(reader).map_err(|err| {
         vec![FormatError::new(
             "E0001".to_string(),
@@ -702,3 +875,95 @@ pub fn deserialize_locations<R: Read>(reader: BufReader<R>) -> Result<Vec<Locati
 pub fn serialize_problem<W: Write>(problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, problem).map_err(Error::from)
 }
+
+/// Reads routing matrix from a compact binary format. All numbers use little-endian byte order:
+/// - `u32` length of profile name in bytes followed by utf-8 profile name (empty name means no profile)
+/// - `u32` row count `n`
+/// - `n * n` distances as `i64` followed by `n * n` travel times as `i64`
+///
+/// Timestamp and error codes are not supported by the format.
+pub fn read_matrix_from_bytes(bytes: &[u8]) -> Result<Matrix, FormatError> {
+    let mut reader = BinaryMatrixReader { bytes, offset: 0 };
+
+    let name_size = reader.read_u32()? as usize;
+    let profile = std::str::from_utf8(reader.take(name_size)?)
+        .map_err(|err| create_binary_matrix_error(format!("invalid profile name: {err}")))?;
+    let profile = if profile.is_empty() { None } else { Some(profile.to_string()) };
+
+    let rows = reader.read_u32()? as usize;
+    let size = rows.checked_mul(rows).ok_or_else(|| create_binary_matrix_error("too many rows".to_string()))?;
+    let distances = reader.read_i64s(size)?;
+    let travel_times = reader.read_i64s(size)?;
+
+    if reader.offset != bytes.len() {
+        return Err(create_binary_matrix_error(format!("unexpected {} trailing bytes", bytes.len() - reader.offset)));
+    }
+
+    Ok(Matrix { profile, timestamp: None, travel_times, distances, error_codes: None, travel_time_variances: None })
+}
+
+/// Writes routing matrix to a compact binary format, see `read_matrix_from_bytes` for details.
+pub fn write_matrix_to_bytes(matrix: &Matrix) -> Result<Vec<u8>, FormatError> {
+    let rows = (matrix.distances.len() as Float).sqrt().round() as usize;
+    if rows * rows != matrix.distances.len() || matrix.distances.len() != matrix.travel_times.len() {
+        return Err(FormatError::new(
+            "E0005".to_string(),
+            "cannot serialize matrix".to_string(),
+            "check that distances and travel times have the same square size".to_string(),
+        ));
+    }
+
+    let profile = matrix.profile.as_deref().unwrap_or_default().as_bytes();
+
+    let mut bytes = Vec::with_capacity(8 + profile.len() + 16 * matrix.distances.len());
+    bytes.extend_from_slice(&(profile.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(profile);
+    bytes.extend_from_slice(&(rows as u32).to_le_bytes());
+    matrix
+        .distances
+        .iter()
+        .chain(matrix.travel_times.iter())
+        .for_each(|value| bytes.extend_from_slice(&value.to_le_bytes()));
+
+    Ok(bytes)
+}
+
+struct BinaryMatrixReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> BinaryMatrixReader<'a> {
+    fn take(&mut self, size: usize) -> Result<&'a [u8], FormatError> {
+        let end =
+            self.offset.checked_add(size).filter(|&end| end <= self.bytes.len()).ok_or_else(|| {
+                create_binary_matrix_error(format!("unexpected end of input at offset {}", self.offset))
+            })?;
+
+        let slice = &self.bytes[self.offset..end];
+        self.offset = end;
+
+        Ok(slice)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, FormatError> {
+        self.take(4).map(|slice| u32::from_le_bytes(slice.try_into().expect("four bytes")))
+    }
+
+    fn read_i64s(&mut self, count: usize) -> Result<Vec<i64>, FormatError> {
+        let size = count.checked_mul(8).ok_or_else(|| create_binary_matrix_error("too many values".to_string()))?;
+
+        self.take(size).map(|slice| {
+            slice.chunks_exact(8).map(|chunk| i64::from_le_bytes(chunk.try_into().expect("eight bytes"))).collect()
+        })
+    }
+}
+
+fn create_binary_matrix_error(details: String) -> FormatError {
+    FormatError::new_with_details(
+        "E0001".to_string(),
+        "cannot deserialize matrix".to_string(),
+        "check binary matrix input".to_string(),
+        details,
+    )
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use vrp_core::models::Feature;
+use vrp_core::utils::GenericResult;
+
+/// Specifies a function which creates a feature with custom objective using given feature name.
+pub type CustomObjectiveFn = Arc<dyn Fn(&str) -> GenericResult<Feature> + Send + Sync>;
+
+/// Keeps custom objective implementations registered by their names, so they can be referenced
+/// from the problem's objective list using `custom` objective type.
+#[derive(Clone, Default)]
+pub struct ObjectiveRegistry {
+    objectives: HashMap<String, CustomObjectiveFn>,
+}
+
+impl ObjectiveRegistry {
+    /// Registers custom objective under the given name. Previously registered objective with
+    /// the same name is replaced.
+    pub fn register<F>(mut self, name: &str, objective_fn: F) -> Self
+    where
+        F: Fn(&str) -> GenericResult<Feature> + Send + Sync + 'static,
+    {
+        self.objectives.insert(name.to_string(), Arc::new(objective_fn));
+        self
+    }
+
+    /// Checks whether custom objective with the given name is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.objectives.contains_key(name)
+    }
+
+    /// Creates a feature for custom objective with the given name.
+    pub fn create(&self, name: &str) -> GenericResult<Feature> {
+        self.objectives
+            .get(name)
+            .ok_or_else(|| format!("unknown custom objective: '{name}'").into())
+            .and_then(|objective_fn| (objective_fn)(name))
+    }
+}
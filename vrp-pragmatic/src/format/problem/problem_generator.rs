@@ -0,0 +1,102 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/problem_generator_test.rs"]
+mod problem_generator_test;
+
+use super::*;
+use crate::format::Location;
+use crate::format_time;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Specifies parameters of a synthetic problem produced by `generate_problem`.
+#[derive(Clone, Debug)]
+pub struct ProblemGeneratorConfig {
+    /// Amount of delivery jobs.
+    pub job_count: usize,
+    /// Amount of vehicles in the fleet.
+    pub vehicle_count: usize,
+    /// Capacity of each vehicle.
+    pub vehicle_capacity: i32,
+    /// An area where jobs are placed as `(min_lat, min_lng)` and `(max_lat, max_lng)`.
+    /// Vehicles start and end their shifts at the center of the area.
+    pub area: ((f64, f64), (f64, f64)),
+    /// A shift duration (in seconds) starting at unix epoch.
+    pub horizon: Float,
+}
+
+impl Default for ProblemGeneratorConfig {
+    fn default() -> Self {
+        Self {
+            job_count: 50,
+            vehicle_count: 5,
+            vehicle_capacity: 20,
+            area: ((52.48, 13.30), (52.56, 13.46)),
+            horizon: 8. * 3600.,
+        }
+    }
+}
+
+/// Generates a randomized problem in pragmatic format. The same `seed` and `config` always
+/// produce the same problem, so it can be used to build reproducible test inputs.
+pub fn generate_problem(config: &ProblemGeneratorConfig, seed: u64) -> Problem {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let ((min_lat, min_lng), (max_lat, max_lng)) = config.area;
+
+    let jobs = (1..=config.job_count)
+        .map(|idx| {
+            let location = Location::new_coordinate(rng.gen_range(min_lat..=max_lat), rng.gen_range(min_lng..=max_lng));
+
+            Job {
+                id: format!("job{idx}"),
+                pickups: None,
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        location,
+                        duration: rng.gen_range(1..=10) as Float * 60.,
+                        times: None,
+                        tag: None,
+                    }],
+                    demand: Some(vec![rng.gen_range(1..=config.vehicle_capacity.max(1))]),
+                    order: None,
+                }]),
+                replacements: None,
+                services: None,
+                skills: None,
+                value: None,
+                group: None,
+                compatibility: None,
+            }
+        })
+        .collect();
+
+    let depot = Location::new_coordinate((min_lat + max_lat) / 2., (min_lng + max_lng) / 2.);
+
+    Problem {
+        plan: Plan { jobs, relations: None, clustering: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                type_id: "vehicle".to_string(),
+                vehicle_ids: (1..=config.vehicle_count).map(|idx| format!("vehicle_{idx}")).collect(),
+                profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+                costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: depot.clone() },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(config.horizon), location: depot }),
+                    breaks: None,
+                    reloads: None,
+                    recharges: None,
+                    limits: None,
+                }],
+                capacity: vec![config.vehicle_capacity],
+                skills: None,
+                limits: None,
+                consumption: None,
+            }],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            resources: None,
+            skills: None,
+            day_horizon: None,
+        },
+        objectives: None,
+    }
+}
@@ -3,34 +3,43 @@ use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
 use crate::format::problem::goal_reader::create_goal_context;
 use crate::format::problem::job_reader::{read_jobs_with_extra_locks, read_locks};
-use crate::format::{FormatError, JobIndex};
+use crate::format::{FormatError, JobIndex, TravelTimeVariancesExtraProperty};
 use crate::validation::ValidationContext;
 use crate::{parse_time, CoordIndex};
+use std::collections::HashSet;
 use vrp_core::construction::enablers::*;
 use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
 use vrp_core::models::Extras;
 use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
 
-pub(super) fn map_to_problem_with_approx(problem: ApiProblem) -> Result<CoreProblem, MultiFormatError> {
+pub(super) fn map_to_problem_with_approx(
+    problem: ApiProblem,
+    registry: &ObjectiveRegistry,
+) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
     let matrices = if coord_index.has_indices() { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, registry)
 }
 
 pub(super) fn map_to_problem_with_matrices(
     problem: ApiProblem,
     matrices: Vec<Matrix>,
+    registry: &ObjectiveRegistry,
 ) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, registry)
 }
 
 pub(super) fn map_to_problem(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
+    registry: &ObjectiveRegistry,
 ) -> Result<CoreProblem, MultiFormatError> {
-    ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
+    // NOTE warnings do not prevent problem from being solved, they are reported by validation only
+    let _warnings = ValidationContext::new(&api_problem, Some(&matrices), &coord_index)
+        .with_objective_registry(registry)
+        .validate()?;
 
     let mut extras = Extras::default();
 
@@ -39,14 +48,19 @@ pub(super) fn map_to_problem(
     let coord_index = extras.get_coord_index().expect("cannot get coord index");
     let mut job_index = JobIndex::default();
 
-    let props = get_problem_properties(&api_problem, &matrices);
+    let props = get_problem_properties(&api_problem, &matrices, coord_index.as_ref());
+
+    if let Some(variances) = create_travel_time_variances(&api_problem, &matrices) {
+        extras.set_travel_time_variances(Arc::new(variances));
+    }
+
     let mut blocks = get_problem_blocks(&api_problem, matrices, coord_index, &mut job_index, &props)?;
 
     let job_index = Arc::new(job_index);
     extras.set_job_index(job_index.clone());
     blocks.job_index = Some(job_index);
 
-    let goal = Arc::new(create_goal_context(&api_problem, &blocks, &props).map_err(to_multi_format_error)?);
+    let goal = Arc::new(create_goal_context(&api_problem, &blocks, &props, registry).map_err(to_multi_format_error)?);
 
     let ProblemBlocks { jobs, fleet, transport, activity, locks, reserved_times_index, .. } = blocks;
 
@@ -78,6 +92,9 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
         })
         .collect_group_by_key(|(type_id, shift_idx, _, _)| (type_id.clone(), *shift_idx));
 
+    // NOTE day horizon is checked by validation rules
+    let day_horizon = api_problem.fleet.day_horizon.as_ref().and_then(|horizon| horizon.to_core_horizon().ok());
+
     fleet
         .actors
         .iter()
@@ -102,6 +119,7 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
 
                     ReservedTimeSpan { time, duration }
                 })
+                .chain(day_horizon.iter().flat_map(|horizon| horizon.get_reserved_times(&actor.detail.time)))
                 .collect::<Vec<_>>();
 
             if times.is_empty() {
@@ -122,8 +140,13 @@ fn to_multi_format_error(error: GenericError) -> MultiFormatError {
     .into()
 }
 
-fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
-    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
+fn get_problem_properties(
+    api_problem: &ApiProblem,
+    matrices: &[Matrix],
+    coord_index: &CoordIndex,
+) -> ProblemProperties {
+    // NOTE custom depot location is unreachable from other custom locations
+    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some()) || coord_index.has_custom_depot();
     let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
         || api_problem
             .plan
@@ -154,11 +177,10 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
 
-    let has_tour_travel_limits = api_problem
-        .fleet
-        .vehicles
-        .iter()
-        .any(|v| v.limits.as_ref().map_or(false, |l| l.max_duration.or(l.max_distance).is_some()));
+    let has_tour_travel_limits = api_problem.fleet.vehicles.iter().any(|v| {
+        v.limits.as_ref().is_some_and(|l| l.max_duration.or(l.max_distance).is_some())
+            || v.shifts.iter().filter_map(|s| s.limits.as_ref()).any(|l| l.max_duration.or(l.max_distance).is_some())
+    });
 
     ProblemProperties {
         has_multi_dimen_capacity,
@@ -224,6 +246,8 @@ fn get_problem_blocks(
             })?
     };
 
+    let activity = get_activity_cost_with_co_located_stops(api_problem, activity);
+
     let (jobs, locks) = read_jobs_with_extra_locks(
         api_problem,
         problem_props,
@@ -245,3 +269,41 @@ fn get_problem_blocks(
         reserved_times_index,
     })
 }
+
+fn get_activity_cost_with_co_located_stops(
+    api_problem: &ApiProblem,
+    activity: Arc<dyn ActivityCost>,
+) -> Arc<dyn ActivityCost> {
+    let shifts = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle
+                .shifts
+                .iter()
+                .enumerate()
+                .filter(|(_, shift)| {
+                    shift.recharges.as_ref().and_then(|recharges| recharges.combine_breaks) == Some(true)
+                })
+                .flat_map(move |(shift_idx, _)| vehicle.vehicle_ids.iter().map(move |id| (id.clone(), shift_idx)))
+        })
+        .collect::<HashSet<_>>();
+
+    if shifts.is_empty() {
+        return activity;
+    }
+
+    let has_job_type = |single: &Single, expected: &str| single.dimens.get_job_type().is_some_and(|t| t == expected);
+    let is_break_fn: StopSingleFn = Arc::new(move |single| has_job_type(single, "break"));
+    let is_refuel_fn: StopSingleFn = Arc::new(move |single| {
+        has_job_type(single, "recharge")
+            && single
+                .dimens
+                .get_vehicle_id()
+                .zip(single.dimens.get_shift_index())
+                .is_some_and(|(vehicle_id, shift_idx)| shifts.contains(&(vehicle_id.clone(), *shift_idx)))
+    });
+
+    Arc::new(CoLocatedStopsActivityCost::new(activity, is_break_fn, is_refuel_fn))
+}
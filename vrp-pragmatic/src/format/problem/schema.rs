@@ -0,0 +1,31 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/schema_test.rs"]
+mod schema_test;
+
+use super::*;
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+use serde_json::Value;
+
+/// Returns a JSON schema of the pragmatic problem definition.
+pub fn problem_json_schema() -> Value {
+    to_json_schema(schema_for!(Problem))
+}
+
+/// Returns a JSON schema of the routing matrix definition.
+pub fn matrix_json_schema() -> Value {
+    to_json_schema(schema_for!(Matrix))
+}
+
+fn to_json_schema(schema: RootSchema) -> Value {
+    let mut schema = serde_json::to_value(schema).expect("cannot serialize json schema");
+
+    // NOTE untagged enum is described as `anyOf`, but location variants are mutually exclusive
+    if let Some(location) = schema.pointer_mut("/definitions/Location").and_then(Value::as_object_mut) {
+        if let Some(variants) = location.remove("anyOf") {
+            location.insert("oneOf".to_string(), variants);
+        }
+    }
+
+    schema
+}
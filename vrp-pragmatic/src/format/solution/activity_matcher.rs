@@ -27,8 +27,11 @@ pub(crate) fn try_match_point_job(
 ) -> Result<Option<JobInfo>, GenericError> {
     let ctx = ActivityContext {
         route_start_time: get_route_start_time(tour)?,
-        location: coord_index
-            .get_by_loc(activity.location.as_ref().unwrap_or(&stop.location))
+        location: activity
+            .location
+            .as_ref()
+            .unwrap_or(&stop.location)
+            .resolve_index(coord_index)
             .ok_or_else(|| format!("cannot get location for activity for job '{}'", activity.job_id))?,
         time: get_activity_time(activity, &stop.time),
         act_type: &activity.activity_type,
@@ -121,6 +124,17 @@ pub(crate) fn try_match_break_activity(
             VehicleBreak::Optional { .. } => None,
         })
         .find(|time| activity_time.intersects(time))
+        .or_else(|| {
+            // NOTE day horizon rests are reported as required breaks
+            problem
+                .fleet
+                .day_horizon
+                .as_ref()
+                .and_then(|horizon| horizon.to_core_horizon().ok())
+                .and_then(|horizon| horizon.get_reserved_times(&activity_time).first().cloned())
+                .map(|rest| rest.to_reserved_time_window(route_start_time))
+                .map(|rest| TimeWindow::new(rest.time.start, rest.time.end + rest.duration))
+        })
         .ok_or_else(|| "cannot match activity to required break".into())
 }
 
@@ -381,6 +381,9 @@ fn get_lng_lat(location: &Location) -> Result<(f64, f64), Error> {
         Location::Custom { r#type: CustomLocationType::Unknown } => {
             Err(Error::new(ErrorKind::InvalidData, "geojson cannot be used with location unknown type"))
         }
+        Location::Custom { r#type: CustomLocationType::Depot } => {
+            Err(Error::new(ErrorKind::InvalidData, "geojson cannot be used with location depot type"))
+        }
     }
 }
 
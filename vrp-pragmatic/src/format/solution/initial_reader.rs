@@ -95,7 +95,15 @@ pub fn read_init_solution<R: Read>(
             .map(|job| (job.clone(), UnassignmentInfo::Unknown)),
     );
 
-    Ok(Solution { cost: Cost::default(), registry, routes, unassigned, telemetry: None })
+    Ok(Solution {
+        cost: Cost::default(),
+        registry,
+        routes,
+        unassigned,
+        telemetry: None,
+        summary: None,
+        is_partial: false,
+    })
 }
 
 fn try_insert_activity(
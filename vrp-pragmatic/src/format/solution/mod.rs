@@ -28,6 +28,7 @@ type ApiActivity = model::Activity;
 type ApiSolution = model::Solution;
 type ApiSchedule = model::Schedule;
 type ApiMetrics = model::Metrics;
+type ApiSummary = model::Summary;
 type ApiGeneration = model::Generation;
 type AppPopulation = model::Population;
 type ApiIndividual = model::Individual;
@@ -53,6 +54,49 @@ impl Default for PragmaticOutputType {
     }
 }
 
+/// Specifies optional solution properties which are calculated only on demand as they are not
+/// needed by most of the users and can be expensive to calculate.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PragmaticOutputOptions {
+    /// Adds a list of unused vehicles with the reason why they are not used.
+    pub unused_vehicles: bool,
+    /// Adds job detour ratios.
+    pub detours: bool,
+    /// Adds tour shift time utilizations.
+    pub utilizations: bool,
+    /// Adds activity slack times.
+    pub slacks: bool,
+    /// Adds tour energy (fuel) consumption estimations.
+    pub energies: bool,
+    /// Adds arrival time confidence intervals.
+    pub arrivals: bool,
+    /// Adds arrival and departure times of all tour activities.
+    pub schedules: bool,
+    /// Adds a summary of the search (e.g. termination reason) to solution extras.
+    pub summary: bool,
+}
+
+impl PragmaticOutputOptions {
+    /// Creates output options from a list of optional property names, e.g. `unused-vehicles`.
+    pub fn from_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Result<Self, GenericError> {
+        names.into_iter().try_fold(Self::default(), |mut options, name| {
+            match name {
+                "unused-vehicles" => options.unused_vehicles = true,
+                "detours" => options.detours = true,
+                "utilizations" => options.utilizations = true,
+                "slacks" => options.slacks = true,
+                "energies" => options.energies = true,
+                "arrivals" => options.arrivals = true,
+                "schedules" => options.schedules = true,
+                "summary" => options.summary = true,
+                _ => return Err(format!("unknown output option: '{name}'").into()),
+            }
+
+            Ok(options)
+        })
+    }
+}
+
 /// Writes solution in pragmatic format variation defined by output type argument.
 pub fn write_pragmatic<W: Write>(
     problem: &DomainProblem,
@@ -60,20 +104,50 @@ pub fn write_pragmatic<W: Write>(
     output_type: PragmaticOutputType,
     writer: &mut BufWriter<W>,
 ) -> Result<(), GenericError> {
-    let solution = create_solution(problem, solution, &output_type);
+    write_pragmatic_with_options(problem, solution, output_type, &PragmaticOutputOptions::default(), writer)
+}
 
+/// Writes solution in pragmatic format variation defined by output type argument. Optional solution
+/// properties are calculated only when requested by output options.
+pub fn write_pragmatic_with_options<W: Write>(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    output_type: PragmaticOutputType,
+    options: &PragmaticOutputOptions,
+    writer: &mut BufWriter<W>,
+) -> Result<(), GenericError> {
     match output_type {
         PragmaticOutputType::OnlyPragmatic { .. } | PragmaticOutputType::Combined { .. } => {
-            serialize_solution(&solution, writer).map_err(|err| err.to_string())?;
+            let api_solution = create_solution(problem, solution, &output_type, options)?;
+            serialize_solution(&api_solution, writer).map_err(|err| err.to_string())?;
         }
         PragmaticOutputType::OnlyGeoJson => {
-            serialize_solution_as_geojson(problem, &solution, writer).map_err(|err| err.to_string())?;
+            let api_solution = create_solution(problem, solution, &output_type, &PragmaticOutputOptions::default())?;
+            serialize_solution_as_geojson(problem, &api_solution, writer).map_err(|err| err.to_string())?;
         }
     }
 
     Ok(())
 }
 
+/// Writes solution in pragmatic format variation defined by output type argument and compresses
+/// it using gzip.
+#[cfg(feature = "compression")]
+pub fn write_solution_gzip<W: Write>(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    output_type: PragmaticOutputType,
+    writer: W,
+) -> Result<(), GenericError> {
+    let mut writer = BufWriter::new(flate2::write::GzEncoder::new(writer, flate2::Compression::default()));
+
+    write_pragmatic(problem, solution, output_type, &mut writer)?;
+
+    writer.into_inner().map_err(|err| err.to_string())?.finish().map_err(|err| err.to_string())?;
+
+    Ok(())
+}
+
 fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
     match code {
         SKILL_CONSTRAINT_CODE => ("SKILL_CONSTRAINT", "cannot serve required skill"),
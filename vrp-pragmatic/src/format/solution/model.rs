@@ -1,5 +1,5 @@
 use super::FeatureCollection;
-use crate::format::{CoordIndex, Location};
+use crate::format::{create_decompressed_reader, CoordIndex, Location};
 use crate::{format_time, parse_time};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
@@ -81,6 +81,18 @@ pub struct CommuteInfo {
     pub time: Interval,
 }
 
+/// Stores information about an original job folded into a cluster.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterMember {
+    /// Job id.
+    pub job_id: String,
+    /// Job location.
+    pub location: Location,
+    /// Service time.
+    pub service_time: Float,
+}
+
 /// An activity is unit of work performed at some place.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -102,6 +114,9 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// Jobs folded into the activity by clustering, listed in visiting order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster: Option<Vec<ClusterMember>>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -266,6 +281,134 @@ pub struct UnassignedJob {
     pub reasons: Vec<UnassignedJobReason>,
 }
 
+/// Specifies a reason why vehicle is not used in the solution.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnusedVehicleReason {
+    /// Vehicle can serve some jobs, but it is not needed (e.g. objective prefers fewer vehicles).
+    NotNeeded,
+    /// Vehicle cannot serve any job feasibly (e.g. due to skills, time windows or capacity).
+    Infeasible,
+}
+
+/// Unused vehicle.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedVehicle {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// A reason why vehicle is not used.
+    pub reason: UnusedVehicleReason,
+}
+
+/// A detour ratio of the served job activity.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobDetour {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// A ratio between distance travelled from the tour start to the activity and a direct distance between them.
+    pub ratio: Float,
+}
+
+/// A shift time utilization of the tour. All values, except shift length, are fractions of the shift length
+/// and they sum up to one.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourUtilization {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Shift length in seconds.
+    pub shift_length: Float,
+    /// A fraction of the shift spent on driving and serving.
+    pub active: Float,
+    /// A fraction of the shift spent on waiting for job time windows.
+    pub waiting: Float,
+    /// A fraction of the shift which is not used: breaks and time before or after the tour.
+    pub idle: Float,
+}
+
+/// An estimated energy (fuel) consumption of the tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourEnergy {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Estimated energy in units of vehicle consumption.
+    pub energy: Float,
+}
+
+/// A confidence interval of the activity arrival time under stochastic travel times.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ArrivalInterval {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// A lower bound of the arrival time in RFC3339 format.
+    pub earliest: String,
+    /// An upper bound of the arrival time in RFC3339 format.
+    pub latest: String,
+}
+
+/// A slack time of the tour activity: how long its service start can be delayed without violating
+/// time windows of this and all downstream activities (including the tour end).
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivitySlack {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// A slack time in seconds.
+    pub slack: Float,
+}
+
+/// A schedule of the tour activity, including the tour start and end, taken from the final route state.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivitySchedule {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Arrival time specified in RFC3339 format.
+    pub arrival: String,
+    /// Departure time specified in RFC3339 format.
+    pub departure: String,
+    /// Waiting time for the time window start in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<Float>,
+    /// Commuting time in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commuting: Option<Float>,
+}
+
 /// Specifies a type of violation.
 #[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -292,6 +435,9 @@ pub struct Metrics {
     pub speed: Float,
     /// Evolution progress.
     pub evolution: Vec<Generation>,
+    /// Best individual fitness per generation, present only when trajectory tracking is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trajectory: Option<Vec<Vec<Float>>>,
 }
 
 /// Represents information about generation.
@@ -330,6 +476,34 @@ pub struct Population {
     pub individuals: Vec<Individual>,
 }
 
+/// Specifies a reason why the search has stopped.
+#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum TerminationReason {
+    /// Search has converged, e.g. a target objective value is reached.
+    Converged,
+    /// A computational budget is exhausted, e.g. max generations, max time or quota.
+    Budget,
+    /// Search has stagnated: no significant improvement is observed for some time.
+    Stagnation,
+    /// Search is cancelled externally.
+    Cancelled,
+}
+
+/// A machine-readable summary of the search.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Summary {
+    /// Total amount of generations.
+    pub generations: usize,
+    /// Total algorithm duration in milliseconds.
+    pub duration: usize,
+    /// A reason why the search has stopped.
+    pub termination: TerminationReason,
+    /// Objective fitness values of the best solution.
+    pub fitness: Vec<Float>,
+}
+
 /// Contains extra information.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Extras {
@@ -337,6 +511,10 @@ pub struct Extras {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
 
+    /// A summary of the search, present when requested and the solution is produced by the solver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Summary>,
+
     /// Represents solution as a collection of geo json features.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub features: Option<FeatureCollection>,
@@ -360,6 +538,39 @@ pub struct Solution {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub violations: Option<Vec<Violation>>,
 
+    /// Set to true when the search was interrupted (e.g. by time budget) with some jobs
+    /// left unassigned, so the solution is the best partial one found so far.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial: Option<bool>,
+
+    /// List of vehicles which are not used in the solution with the reason.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_vehicles: Option<Vec<UnusedVehicle>>,
+
+    /// List of detour ratios of served jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detours: Option<Vec<JobDetour>>,
+
+    /// List of shift time utilizations of tours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utilizations: Option<Vec<TourUtilization>>,
+
+    /// List of activity slack times.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slacks: Option<Vec<ActivitySlack>>,
+
+    /// List of estimated energy consumptions of tours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energies: Option<Vec<TourEnergy>>,
+
+    /// List of activity arrival time confidence intervals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arrivals: Option<Vec<ArrivalInterval>>,
+
+    /// List of activity schedules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedules: Option<Vec<ActivitySchedule>>,
+
     /// An extra information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extras: Option<Extras>,
@@ -370,9 +581,10 @@ pub fn serialize_solution<W: Write>(solution: &Solution, writer: &mut BufWriter<
     serde_json::to_writer_pretty(writer, solution).map_err(Error::from)
 }
 
-/// Deserializes solution from json format.
+/// Deserializes solution from json format. Gzip compressed input is decompressed transparently
+/// when `compression` feature is enabled.
 pub fn deserialize_solution<R: Read>(reader: BufReader<R>) -> Result<Solution, Error> {
-    serde_json::from_reader(reader).map_err(Error::from)
+    serde_json::from_reader(create_decompressed_reader(reader)?).map_err(Error::from)
 }
 
 impl Interval {
@@ -407,7 +619,7 @@ impl Commute {
                 let start = parse_time(&info.time.start);
                 let end = parse_time(&info.time.end);
                 DomainCommuteInfo {
-                    location: coord_index.get_by_loc(&info.location).expect("expect to have coordinate in commute"),
+                    location: info.location.resolve_index(coord_index).expect("expect to have coordinate in commute"),
                     distance: info.distance,
                     duration: end - start,
                 }
@@ -3,17 +3,24 @@
 mod writer_test;
 
 use crate::format::solution::activity_matcher::get_job_tag;
-use crate::format::solution::model::Timing;
+use crate::format::solution::model::{TerminationReason as ApiTerminationReason, Timing};
 use crate::format::solution::*;
-use crate::format::CoordIndex;
+use crate::format::{CoordIndex, TravelTimeVariancesExtraProperty};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use vrp_core::construction::clustering::vicinity::ClusterInfoDimension;
 use vrp_core::construction::enablers::{get_route_intervals, ReservedTimesIndex};
 use vrp_core::construction::features::JobDemandDimension;
-use vrp_core::construction::heuristics::UnassignmentInfo;
+use vrp_core::construction::heuristics::{
+    eval_job_constraint_in_route, BestResultSelector, EvaluationContext, InsertionCost, InsertionPosition,
+    LegSelection, MoveContext, RegistryContext, RouteContext, SolutionContext, UnassignmentInfo,
+};
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{JobIdDimension, Multi, TravelTime, VehicleIdDimension};
-use vrp_core::models::solution::{Activity, Route};
-use vrp_core::prelude::Float;
-use vrp_core::rosomaxa::evolution::TelemetryMetrics;
+use vrp_core::models::problem::{Actor, JobIdDimension, Multi, TravelTime, VehicleIdDimension};
+use vrp_core::models::solution::{Activity, Registry, Route};
+use vrp_core::prelude::{DefaultRandom, Float, GenericResult};
+use vrp_core::rosomaxa::evolution::{SolveSummary, TelemetryMetrics};
+use vrp_core::rosomaxa::termination::TerminationReason;
 use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
 use vrp_core::utils::CollectGroupBy;
 
@@ -33,13 +40,14 @@ impl Leg {
     }
 }
 
-/// Creates solution.
+/// Creates solution. Optional solution properties are created only when requested by output options.
 pub(crate) fn create_solution(
     problem: &DomainProblem,
     solution: &DomainSolution,
     output_type: &PragmaticOutputType,
-) -> ApiSolution {
-    let coord_index = problem.extras.get_coord_index().expect("no coord index");
+    options: &PragmaticOutputOptions,
+) -> GenericResult<ApiSolution> {
+    let coord_index = problem.extras.get_coord_index().ok_or_else(|| GenericError::from("no coord index"))?;
 
     let empty_reserved_times = Default::default();
     let reserved_times_index = problem.extras.get_reserved_times();
@@ -56,11 +64,35 @@ pub(crate) fn create_solution(
     let unassigned = create_unassigned(solution);
     let violations = create_violations(solution);
 
-    let api_solution = ApiSolution { statistic, tours, unassigned, violations, extras: None };
+    let partial = if solution.is_partial { Some(true) } else { None };
+
+    let unused_vehicles = if options.unused_vehicles { create_unused_vehicles(problem, solution) } else { None };
+    let detours = if options.detours { create_job_detours(problem, solution, &coord_index) } else { None };
+    let utilizations = if options.utilizations { create_tour_utilizations(solution) } else { None };
+    let slacks = if options.slacks { create_activity_slacks(solution) } else { None };
+    let energies = if options.energies { create_tour_energies(problem, &tours) } else { None };
+    let arrivals = if options.arrivals { create_arrival_intervals(problem, solution) } else { None };
+    let schedules = if options.schedules { create_activity_schedules(solution) } else { None };
+
+    let api_solution = ApiSolution {
+        statistic,
+        tours,
+        unassigned,
+        violations,
+        partial,
+        unused_vehicles,
+        detours,
+        utilizations,
+        energies,
+        slacks,
+        arrivals,
+        schedules,
+        extras: None,
+    };
 
-    let extras = create_extras(problem, &api_solution, solution.telemetry.as_ref(), output_type);
+    let extras = create_extras(problem, &api_solution, solution, output_type, options);
 
-    ApiSolution { extras, ..api_solution }
+    Ok(ApiSolution { extras, ..api_solution })
 }
 
 fn create_tour(
@@ -123,6 +155,7 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    cluster: None,
                 }],
                 parking: None,
             }));
@@ -151,14 +184,7 @@ fn create_tour(
                     get_job_tag(single, (act.place.location, (act.place.time.clone(), start.schedule.departure)))
                         .cloned()
                 });
-                let job_id = match activity_type.as_str() {
-                    "pickup" | "delivery" | "replacement" | "service" => {
-                        let single = act.job.as_ref().unwrap();
-                        let id = single.dimens.get_job_id().cloned();
-                        id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_job_id().unwrap().clone())
-                    }
-                    _ => activity_type.clone(),
-                };
+                let job_id = get_activity_job_id(act, activity_type.as_str());
 
                 let commute = act.commute.clone().unwrap_or_default();
                 let commuting = commute.duration();
@@ -186,7 +212,12 @@ fn create_tour(
                 let waiting = service_start - activity_arrival;
                 let serving = act.place.duration - parking;
                 let service_end = service_start + serving;
-                let activity_departure = service_end;
+                // NOTE activity can be done simultaneously with the previous one (e.g. break combined with recharge)
+                let activity_departure = if act.commute.is_none() {
+                    service_end.min(act.schedule.departure).max(service_start)
+                } else {
+                    service_end
+                };
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
@@ -244,6 +275,7 @@ fn create_tour(
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    cluster: get_cluster_members(act, coord_index),
                 });
 
                 // NOTE detect when vehicle returns after activity to stop point
@@ -253,7 +285,7 @@ fn create_tour(
                     tour.stops
                         .last()
                         .and_then(|stop| stop.as_point())
-                        .and_then(|stop| coord_index.get_by_loc(&stop.location))
+                        .and_then(|stop| stop.location.resolve_index(coord_index))
                         .expect("expect to have at least one stop")
                 };
 
@@ -384,6 +416,400 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
+/// Creates a list of unused vehicles with the reason why they are not used. A vehicle is considered
+/// as infeasible if none of its shifts can serve any plan job alone.
+fn create_unused_vehicles(problem: &DomainProblem, solution: &DomainSolution) -> Option<Vec<UnusedVehicle>> {
+    let used_ids =
+        solution.routes.iter().filter_map(|route| route.actor.vehicle.dimens.get_vehicle_id()).collect::<HashSet<_>>();
+
+    let unused_actors = problem
+        .fleet
+        .actors
+        .iter()
+        .filter_map(|actor| {
+            actor.vehicle.dimens.get_vehicle_id().filter(|id| !used_ids.contains(id)).map(|id| (id.clone(), actor))
+        })
+        .collect_group_by();
+
+    if unused_actors.is_empty() {
+        return None;
+    }
+
+    let goal = problem.goal.as_ref();
+    let registry = Registry::new(problem.fleet.as_ref(), Arc::new(DefaultRandom::default()));
+    let solution_ctx = SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        routes: vec![],
+        registry: RegistryContext::new(goal, registry),
+        state: Default::default(),
+    };
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    // NOTE exclude vehicle specific jobs such as breaks or reloads
+    let jobs = problem.jobs.all().iter().filter(|job| job.dimens().get_vehicle_id().is_none()).collect::<Vec<_>>();
+
+    let can_serve_any_job = |actor: &Arc<Actor>| {
+        let mut route_ctx = RouteContext::new(actor.clone());
+        goal.accept_route_state(&mut route_ctx);
+
+        jobs.iter().any(|&job| {
+            let eval_ctx =
+                EvaluationContext { goal, job, leg_selection: &leg_selection, result_selector: &result_selector };
+
+            goal.evaluate(&MoveContext::route(&solution_ctx, &route_ctx, job)).is_none()
+                && eval_job_constraint_in_route(
+                    &eval_ctx,
+                    &route_ctx,
+                    InsertionPosition::Any,
+                    InsertionCost::default(),
+                    None,
+                )
+                .as_success()
+                .is_some()
+        })
+    };
+
+    let mut unused_vehicles = unused_actors
+        .into_iter()
+        .map(|(vehicle_id, actors)| {
+            let reason = if actors.iter().any(|&actor| can_serve_any_job(actor)) {
+                UnusedVehicleReason::NotNeeded
+            } else {
+                UnusedVehicleReason::Infeasible
+            };
+
+            UnusedVehicle { vehicle_id, reason }
+        })
+        .collect::<Vec<_>>();
+    // NOTE sort to have consistent order
+    unused_vehicles.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id));
+
+    Some(unused_vehicles)
+}
+
+/// Creates detour ratios of served jobs: a distance travelled from the tour start to the job activity
+/// divided by a direct distance between them. Jobs with reference-only locations are skipped.
+fn create_job_detours(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    coord_index: &CoordIndex,
+) -> Option<Vec<JobDetour>> {
+    let transport = problem.transport.as_ref();
+
+    let detours = solution
+        .routes
+        .iter()
+        .flat_map(|route| {
+            let start = route.tour.start().unwrap();
+            let vehicle_id = route.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap_or_default();
+
+            route
+                .tour
+                .all_activities()
+                .zip(route.tour.all_activities().skip(1))
+                .scan(Distance::default(), move |travelled, (prev, activity)| {
+                    *travelled += transport.distance(
+                        route,
+                        prev.place.location,
+                        activity.place.location,
+                        TravelTime::Departure(prev.schedule.departure),
+                    );
+
+                    Some((*travelled, activity))
+                })
+                .filter_map(move |(travelled, activity)| {
+                    let single = activity.job.as_ref()?;
+                    let job_id = single.dimens.get_job_id()?;
+                    let activity_type = get_activity_type(activity)
+                        .filter(|t| matches!(t.as_str(), "pickup" | "delivery" | "service" | "replacement"))?;
+
+                    if matches!(
+                        coord_index.get_by_idx(activity.place.location),
+                        Some(crate::format::Location::Reference { .. })
+                    ) {
+                        return None;
+                    }
+
+                    let direct = transport.distance(
+                        route,
+                        start.place.location,
+                        activity.place.location,
+                        TravelTime::Departure(start.schedule.departure),
+                    );
+
+                    if direct > 0. {
+                        Some(JobDetour {
+                            job_id: job_id.clone(),
+                            activity_type: activity_type.clone(),
+                            vehicle_id: vehicle_id.clone(),
+                            ratio: travelled / direct,
+                        })
+                    } else {
+                        None
+                    }
+                })
+        })
+        .collect::<Vec<_>>();
+
+    if detours.is_empty() {
+        None
+    } else {
+        Some(detours)
+    }
+}
+
+/// Creates shift time utilizations of tours: active (driving and serving), waiting and idle time
+/// as fractions of the shift length. For open shifts, the shift ends at the last activity departure.
+fn create_tour_utilizations(solution: &DomainSolution) -> Option<Vec<TourUtilization>> {
+    let utilizations = solution
+        .routes
+        .iter()
+        .filter_map(|route| {
+            let last = route.tour.end()?;
+            let detail = &route.actor.detail;
+
+            let shift_start = detail.time.start;
+            let shift_end = if detail.end.is_some() { detail.time.end } else { last.schedule.departure };
+            let shift_length = shift_end - shift_start;
+
+            if shift_length <= 0. {
+                return None;
+            }
+
+            let (active, waiting) = route.tour.all_activities().zip(route.tour.all_activities().skip(1)).fold(
+                (0., 0.),
+                |(active, waiting), (prev, activity)| {
+                    let driving = activity.schedule.arrival - prev.schedule.departure;
+                    let service_start = activity.schedule.arrival.max(activity.place.time.start);
+                    let wait = service_start - activity.schedule.arrival;
+                    let serving = if get_activity_type(activity).map_or(false, |t| t == "break") {
+                        0.
+                    } else {
+                        activity.schedule.departure - service_start
+                    };
+
+                    (active + driving + serving, waiting + wait)
+                },
+            );
+
+            let active = active / shift_length;
+            let waiting = waiting / shift_length;
+
+            Some(TourUtilization {
+                vehicle_id: route.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap_or_default(),
+                shift_index: route.actor.vehicle.dimens.get_shift_index().copied().unwrap_or_default(),
+                shift_length,
+                active,
+                waiting,
+                idle: 1. - active - waiting,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if utilizations.is_empty() {
+        None
+    } else {
+        Some(utilizations)
+    }
+}
+
+/// Creates estimated energy consumptions of tours which vehicles have consumption specified. Energy of each
+/// leg is its distance multiplied by the distance coefficient plus the load coefficient times the load
+/// (first capacity dimension) carried on the leg.
+fn create_tour_energies(problem: &DomainProblem, tours: &[Tour]) -> Option<Vec<TourEnergy>> {
+    let consumptions = problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            let vehicle_id = vehicle.dimens.get_vehicle_id()?;
+            let shift_index = vehicle.dimens.get_shift_index().copied().unwrap_or_default();
+            let consumption = vehicle.dimens.get_vehicle_consumption()?;
+
+            Some(((vehicle_id.as_str(), shift_index), consumption))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let energies = tours
+        .iter()
+        .filter_map(|tour| {
+            let consumption = consumptions.get(&(tour.vehicle_id.as_str(), tour.shift_index))?;
+            let per_load = consumption.load.unwrap_or_default();
+
+            let (_, _, energy) = tour.stops.iter().fold((0, None::<i32>, 0.), |(last_distance, load, energy), stop| {
+                let next_load = stop.load().first().copied();
+                match stop {
+                    Stop::Point(point) => {
+                        let distance = (point.distance - last_distance) as Float;
+                        let load = load.unwrap_or_default() as Float;
+
+                        (point.distance, next_load, energy + distance * (consumption.distance + per_load * load))
+                    }
+                    Stop::Transit(_) => (last_distance, next_load, energy),
+                }
+            });
+
+            Some(TourEnergy { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index, energy })
+        })
+        .collect::<Vec<_>>();
+
+    if energies.is_empty() {
+        None
+    } else {
+        Some(energies)
+    }
+}
+
+/// Creates activity slack times using a backward pass over the tour: a slack of the activity is limited
+/// by its own time window end and by a slack of the next activity plus a waiting time there.
+fn create_activity_slacks(solution: &DomainSolution) -> Option<Vec<ActivitySlack>> {
+    let slacks = solution
+        .routes
+        .iter()
+        .flat_map(|route| {
+            let vehicle_id = route.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap_or_default();
+            let shift_index = route.actor.vehicle.dimens.get_shift_index().copied().unwrap_or_default();
+
+            let mut slacks = route
+                .tour
+                .all_activities()
+                .rev()
+                .scan(Float::MAX, |next_slack, activity| {
+                    let service_start = activity.schedule.arrival.max(activity.place.time.start);
+                    let slack = (activity.place.time.end - service_start).min(*next_slack).max(0.);
+                    *next_slack = slack + (service_start - activity.schedule.arrival);
+
+                    Some((activity, slack))
+                })
+                .filter_map(|(activity, slack)| {
+                    let single = activity.job.as_ref()?;
+
+                    Some(ActivitySlack {
+                        job_id: single.dimens.get_job_id()?.clone(),
+                        activity_type: get_activity_type(activity)?.clone(),
+                        vehicle_id: vehicle_id.clone(),
+                        shift_index,
+                        slack,
+                    })
+                })
+                .collect::<Vec<_>>();
+            slacks.reverse();
+
+            slacks
+        })
+        .collect::<Vec<_>>();
+
+    if slacks.is_empty() {
+        None
+    } else {
+        Some(slacks)
+    }
+}
+
+/// Creates arrival time confidence intervals using a forward pass over the tour: travel time variances of
+/// legs are assumed to be independent, so they are accumulated from the tour start. The interval is symmetric
+/// around the scheduled arrival and covers 90% of arrival times assuming normally distributed travel times.
+/// NOTE waiting time is not considered as a buffer which absorbs delays, so the intervals are conservative.
+fn create_arrival_intervals(problem: &DomainProblem, solution: &DomainSolution) -> Option<Vec<ArrivalInterval>> {
+    // NOTE a z-score of the two-sided 90% confidence interval
+    const Z_SCORE: Float = 1.645;
+
+    let variances = problem.extras.get_travel_time_variances()?;
+    let variances = variances.as_ref();
+
+    let intervals = solution
+        .routes
+        .iter()
+        .flat_map(|route| {
+            let vehicle = route.actor.vehicle.as_ref();
+            let vehicle_id = vehicle.dimens.get_vehicle_id().cloned().unwrap_or_default();
+            let shift_index = vehicle.dimens.get_shift_index().copied().unwrap_or_default();
+            let scale = vehicle.profile.scale * vehicle.profile.scale;
+
+            route
+                .tour
+                .legs()
+                .filter_map(|(activities, _)| match activities {
+                    [from, to] => Some((from, to)),
+                    _ => None,
+                })
+                .scan(0., move |variance: &mut Float, (from, to)| {
+                    *variance += variances.get(vehicle.profile.index, from.place.location, to.place.location) * scale;
+                    Some((to, variance.sqrt() * Z_SCORE))
+                })
+                .filter_map(|(activity, deviation)| {
+                    let single = activity.job.as_ref()?;
+
+                    Some(ArrivalInterval {
+                        job_id: single.dimens.get_job_id()?.clone(),
+                        activity_type: get_activity_type(activity)?.clone(),
+                        vehicle_id: vehicle_id.clone(),
+                        shift_index,
+                        earliest: format_time(activity.schedule.arrival - deviation),
+                        latest: format_time(activity.schedule.arrival + deviation),
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if intervals.is_empty() {
+        None
+    } else {
+        Some(intervals)
+    }
+}
+
+/// Creates activity schedules using the final route state: each tour activity, including the tour start and end,
+/// gets its arrival and departure time together with waiting and commuting durations if they are not zero.
+fn create_activity_schedules(solution: &DomainSolution) -> Option<Vec<ActivitySchedule>> {
+    let schedules = solution
+        .routes
+        .iter()
+        .flat_map(|route| {
+            let vehicle_id = route.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap_or_default();
+            let shift_index = route.actor.vehicle.dimens.get_shift_index().copied().unwrap_or_default();
+
+            route
+                .tour
+                .all_activities()
+                .enumerate()
+                .map(|(idx, activity)| {
+                    // NOTE activities without job are the tour start and end
+                    let activity_type = get_activity_type(activity)
+                        .cloned()
+                        .unwrap_or_else(|| if idx == 0 { "departure" } else { "arrival" }.to_string());
+                    let commute = activity.commute.clone().unwrap_or_default();
+                    let waiting =
+                        (activity.place.time.start - (activity.schedule.arrival + commute.forward.duration)).max(0.);
+                    let commuting = commute.duration();
+
+                    ActivitySchedule {
+                        job_id: get_activity_job_id(activity, activity_type.as_str()),
+                        activity_type,
+                        vehicle_id: vehicle_id.clone(),
+                        shift_index,
+                        arrival: format_time(activity.schedule.arrival),
+                        departure: format_time(activity.schedule.departure),
+                        waiting: Some(waiting).filter(|&waiting| waiting > 0.),
+                        commuting: Some(commuting).filter(|&commuting| commuting > 0.),
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if schedules.is_empty() {
+        None
+    } else {
+        Some(schedules)
+    }
+}
+
 fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     // NOTE at the moment only break violation is mapped
     let violations = solution
@@ -407,6 +833,39 @@ fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
 }
 
+fn get_activity_job_id(activity: &Activity, activity_type: &str) -> String {
+    match activity_type {
+        "pickup" | "delivery" | "replacement" | "service" => {
+            let single = activity.job.as_ref().unwrap();
+            let id = single.dimens.get_job_id().cloned();
+            id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_job_id().unwrap().clone())
+        }
+        _ => activity_type.to_string(),
+    }
+}
+
+fn get_cluster_members(activity: &Activity, coord_index: &CoordIndex) -> Option<Vec<ClusterMember>> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_cluster_info()).filter(|infos| !infos.is_empty()).map(
+        |infos| {
+            infos
+                .iter()
+                .map(|info| ClusterMember {
+                    job_id: info.job.dimens().get_job_id().cloned().expect("cluster member has no job id"),
+                    location: info
+                        .job
+                        .to_single()
+                        .places
+                        .get(info.place_idx)
+                        .and_then(|place| place.location)
+                        .and_then(|location| coord_index.get_by_idx(location))
+                        .expect("cluster member has no location"),
+                    service_time: info.service_time,
+                })
+                .collect()
+        },
+    )
+}
+
 fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
     // NOTE: try to detect whether dimensions stores multidimensional demand
     let demand: Option<Demand<MultiDimLoad>> = dimens.get_job_demand().cloned();
@@ -433,25 +892,44 @@ fn get_parking_time(extras: &DomainExtras) -> Float {
 
 fn create_extras(
     problem: &DomainProblem,
-    solution: &ApiSolution,
-    metrics: Option<&TelemetryMetrics>,
+    api_solution: &ApiSolution,
+    solution: &DomainSolution,
     output_type: &PragmaticOutputType,
+    options: &PragmaticOutputOptions,
 ) -> Option<Extras> {
+    let metrics = get_api_metrics(solution.telemetry.as_ref());
+    let summary = if options.summary { get_api_summary(solution.summary.as_ref()) } else { None };
+
     match output_type {
         PragmaticOutputType::OnlyPragmatic => {
-            get_api_metrics(metrics).map(|metrics| Extras { metrics: Some(metrics), features: None })
+            (metrics.is_some() || summary.is_some()).then_some(Extras { metrics, summary, features: None })
         }
         PragmaticOutputType::OnlyGeoJson => None,
         PragmaticOutputType::Combined => {
             Some(Extras {
-                metrics: get_api_metrics(metrics),
+                metrics,
+                summary,
                 // TODO do not hide error here, propagate it to the caller
-                features: create_feature_collection(problem, solution).ok(),
+                features: create_feature_collection(problem, api_solution).ok(),
             })
         }
     }
 }
 
+fn get_api_summary(summary: Option<&SolveSummary>) -> Option<ApiSummary> {
+    summary.map(|summary| ApiSummary {
+        generations: summary.generations,
+        duration: summary.duration,
+        termination: match summary.termination {
+            TerminationReason::Converged => ApiTerminationReason::Converged,
+            TerminationReason::Budget => ApiTerminationReason::Budget,
+            TerminationReason::Stagnation => ApiTerminationReason::Stagnation,
+            TerminationReason::Cancelled => ApiTerminationReason::Cancelled,
+        },
+        fitness: summary.fitness.clone(),
+    })
+}
+
 fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
     metrics.as_ref().map(|metrics| ApiMetrics {
         duration: metrics.duration,
@@ -476,5 +954,6 @@ fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
                 },
             })
             .collect(),
+        trajectory: if metrics.trajectory.is_empty() { None } else { Some(metrics.trajectory.clone()) },
     })
 }
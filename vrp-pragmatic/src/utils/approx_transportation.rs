@@ -2,7 +2,7 @@
 #[path = "../../tests/unit/utils/approx_transportation_test.rs"]
 mod approx_transportation_test;
 
-use crate::format::{CustomLocationType, Location};
+use crate::format::Location;
 use vrp_core::models::common::Distance;
 use vrp_core::utils::{parallel_collect, Float};
 
@@ -27,9 +27,7 @@ pub fn get_approx_transportation(locations: &[Location], speeds: &[Float]) -> Ve
 
 /// Gets distance between two points using haversine formula.
 pub(crate) fn get_haversine_distance(p1: &Location, p2: &Location) -> Float {
-    if matches!(p1, Location::Custom { r#type: CustomLocationType::Unknown })
-        || matches!(p2, Location::Custom { r#type: CustomLocationType::Unknown })
-    {
+    if matches!(p1, Location::Custom { .. }) || matches!(p2, Location::Custom { .. }) {
         return Distance::default();
     }
 
@@ -3,8 +3,12 @@
 mod jobs_test;
 
 use super::*;
+use crate::format::Location;
 use crate::utils::combine_error_results;
+use std::collections::HashSet;
+use vrp_core::construction::features::JobSkills as FeatureJobSkills;
 use vrp_core::models::common::MultiDimLoad;
+use vrp_core::prelude::Float;
 
 /// Checks that plan has no jobs with duplicate ids.
 fn check_e1100_no_jobs_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -179,6 +183,138 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job location indices are within routing matrix dimension when matrix is supplied.
+fn check_e1108_location_index_out_of_range(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrix_size = if let Some(matrix) = ctx.matrices.and_then(|matrices| matrices.first()) {
+        (matrix.distances.len() as Float).sqrt().round() as usize
+    } else {
+        return Ok(());
+    };
+
+    let references = ctx
+        .jobs()
+        .flat_map(|job| {
+            ctx.tasks(job)
+                .into_iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| match place.location {
+                    Location::Reference { index } if index >= matrix_size => Some(index),
+                    _ => None,
+                })
+                .map(move |index| format!("{} (index {index})", job.id))
+        })
+        .collect::<Vec<_>>();
+
+    if references.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job location index is out of routing matrix range".to_string(),
+            format!(
+                "fix location indices to be less than matrix size ('{matrix_size}') in jobs: '{}'",
+                references.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job skills can be satisfied by at least one vehicle type.
+fn check_e1109_no_vehicle_with_job_skills(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let fleet_skills = ctx.problem.fleet.skills.iter().flatten().cloned().collect::<HashSet<_>>();
+    let vehicle_skills = ctx
+        .vehicles()
+        .map(|vehicle| vehicle.skills.iter().flatten().cloned().chain(fleet_skills.iter().cloned()).collect())
+        .collect::<Vec<HashSet<_>>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.skills
+                .as_ref()
+                .map(|skills| {
+                    FeatureJobSkills::new(skills.all_of.clone(), skills.one_of.clone(), skills.none_of.clone())
+                })
+                .is_some_and(|skills| {
+                    !vehicle_skills.iter().any(|vehicle_skills| skills.is_satisfied_by(Some(vehicle_skills)))
+                })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new_warning(
+            "E1109".to_string(),
+            "no vehicle with compatible skills".to_string(),
+            format!(
+                "add vehicle with required skills or change skills of jobs, they cannot be assigned: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job locations are reachable from at least one vehicle shift start location using
+/// routing matrix error codes. Only problems with location indices are checked.
+fn check_e1110_unreachable_job_locations(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrices = match ctx.matrices {
+        Some(matrices) if !matrices.is_empty() => matrices,
+        _ => return Ok(()),
+    };
+
+    // NOTE location is proved to be unreachable only when every matrix marks it so
+    let error_codes = if let Some(error_codes) =
+        matrices.iter().map(|matrix| matrix.error_codes.as_ref()).collect::<Option<Vec<_>>>()
+    {
+        error_codes
+    } else {
+        return Ok(());
+    };
+
+    let starts = if let Some(starts) = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| match shift.start.location {
+            Location::Reference { index } => Some(index),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+    {
+        starts
+    } else {
+        return Ok(());
+    };
+
+    let matrix_size = (matrices[0].distances.len() as Float).sqrt().round() as usize;
+    let is_unreachable = |place: &JobPlace| match place.location {
+        Location::Reference { index } => {
+            !starts.is_empty()
+                && error_codes.iter().all(|codes| {
+                    starts.iter().all(|start| codes.get(start * matrix_size + index).is_some_and(|code| *code != 0))
+                })
+        }
+        _ => false,
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| ctx.tasks(job).iter().any(|task| task.places.iter().all(is_unreachable)))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new_warning(
+            "E1110".to_string(),
+            "unreachable job location".to_string(),
+            format!("check routing matrix error codes or job locations, jobs cannot be assigned: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +326,9 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_location_index_out_of_range(ctx),
+        check_e1109_no_vehicle_with_job_skills(ctx),
+        check_e1110_unreachable_job_locations(ctx),
     ])
     .map_err(From::from)
 }
@@ -1,5 +1,9 @@
 //! This module provides functionality to validate problem definition for logical correctness.
 
+#[cfg(test)]
+#[path = "../../tests/unit/validation/validation_test.rs"]
+mod validation_test;
+
 use crate::format::problem::*;
 use crate::format::{CoordIndex, FormatError, MultiFormatError};
 
@@ -12,6 +16,7 @@ pub struct ValidationContext<'a> {
 
     coord_index: &'a CoordIndex,
     job_index: HashMap<String, Job>,
+    objective_registry: Option<&'a ObjectiveRegistry>,
 }
 
 mod common;
@@ -41,12 +46,32 @@ impl<'a> ValidationContext<'a> {
             matrices,
             coord_index,
             job_index: problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect(),
+            objective_registry: None,
+        }
+    }
+
+    /// Sets a registry of custom objectives which can be referenced by name from the problem.
+    pub fn with_objective_registry(mut self, registry: &'a ObjectiveRegistry) -> Self {
+        self.objective_registry = Some(registry);
+        self
+    }
+
+    /// Validates problem on set of rules. Fails only when at least one error has error severity,
+    /// otherwise returns a list of warnings which do not prevent problem from being solved. Errors and
+    /// warnings are deduplicated, so they can be rendered individually or serialized to json using
+    /// `MultiFormatError::to_json`.
+    pub fn validate(&self) -> Result<Vec<FormatError>, MultiFormatError> {
+        match self.validate_structured().map_err(|errors| MultiFormatError::from(errors).deduplicated()) {
+            Ok(()) => Ok(vec![]),
+            Err(errors) if errors.errors.iter().all(|error| error.is_warning()) => Ok(errors.errors),
+            Err(errors) => Err(errors),
         }
     }
 
-    /// Validates problem on set of rules.
-    pub fn validate(&self) -> Result<(), MultiFormatError> {
-        let multi_err: MultiFormatError = validate_jobs(self)
+    /// Validates problem on set of rules and returns a raw list of errors. Unlike `validate`,
+    /// the errors can be inspected or serialized individually.
+    pub fn validate_structured(&self) -> Result<(), Vec<FormatError>> {
+        let errors = validate_jobs(self)
             .err()
             .into_iter()
             .chain(validate_vehicles(self).err())
@@ -54,13 +79,12 @@ impl<'a> ValidationContext<'a> {
             .chain(validate_routing(self).err())
             .chain(validate_relations(self).err())
             .flatten()
-            .collect::<Vec<_>>()
-            .into();
+            .collect::<Vec<_>>();
 
-        if multi_err.errors.is_empty() {
+        if errors.is_empty() {
             Ok(())
         } else {
-            Err(multi_err)
+            Err(errors)
         }
     }
 
@@ -24,7 +24,15 @@ fn check_e1600_empty_objective(objectives: &[&Objective]) -> Result<(), FormatEr
 /// Checks that each objective type specified only once.
 fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), FormatError> {
     let original_count = get_objectives_flattened(objectives).count();
-    let unique = get_objectives_flattened(objectives).map(std::mem::discriminant).collect::<HashSet<_>>();
+    let unique = get_objectives_flattened(objectives)
+        .map(|objective| {
+            let name = match objective {
+                Custom { name } => Some(name.as_str()),
+                _ => None,
+            };
+            (std::mem::discriminant(objective), name)
+        })
+        .collect::<HashSet<_>>();
 
     if unique.len() == original_count {
         Ok(())
@@ -166,6 +174,113 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that the same property is not optimized in opposite directions.
+fn check_e1608_conflicting_objective_directions(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let directions = get_objectives_flattened(objectives)
+        .filter(|objective| matches!(objective, MinimizeTours | MaximizeTours))
+        .filter_map(|objective| objective.direction())
+        .collect::<HashSet<_>>();
+
+    if directions.len() > 1 {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "conflicting objective directions".to_string(),
+            "keep only one of 'minimize-tours' or 'maximize-tours' objectives".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that vehicle types objective is specified only when fleet has more than one vehicle type.
+fn check_e1609_single_vehicle_type_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_vehicle_types_objective =
+        get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeVehicleTypes));
+    let vehicle_types = ctx.problem.fleet.vehicles.iter().map(|vehicle| &vehicle.type_id).collect::<HashSet<_>>();
+
+    if has_vehicle_types_objective && vehicle_types.len() < 2 {
+        Err(FormatError::new_warning(
+            "E1609".to_string(),
+            "redundant vehicle types objective".to_string(),
+            "specify at least two vehicle types or delete 'minimize-vehicle-types' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that custom objectives are registered.
+fn check_e1610_unknown_custom_objective(ctx: &ValidationContext, objectives: &[&Objective]) -> Result<(), FormatError> {
+    let names = get_objectives_flattened(objectives)
+        .filter_map(|objective| match objective {
+            Custom { name } => Some(name.as_str()),
+            _ => None,
+        })
+        .filter(|name| !ctx.objective_registry.is_some_and(|registry| registry.contains(name)))
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "unknown custom objective".to_string(),
+            format!("register custom objectives or remove them from the objective list: '{}'", names.join(", ")),
+        ))
+    }
+}
+
+/// Checks that waiting time objective is specified only when jobs have time windows.
+fn check_e1611_no_jobs_with_times_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_waiting_objective =
+        get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeWaitingTime));
+    let has_no_jobs_with_times = !ctx
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.all_tasks_iter())
+        .flat_map(|task| task.places.iter())
+        .any(|place| place.times.as_ref().is_some_and(|times| !times.is_empty()));
+
+    if has_waiting_objective && has_no_jobs_with_times {
+        Err(FormatError::new_warning(
+            "E1611".to_string(),
+            "redundant waiting time objective".to_string(),
+            "specify at least one job with time windows or delete 'minimize-waiting-time' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that activities or tour size balance objective is specified only when there are at least two vehicles.
+fn check_e1612_single_vehicle_balance_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_balance_activities_objective =
+        get_objectives_flattened(objectives).any(|objective| matches!(objective, BalanceActivities | BalanceTourSize));
+    let total_vehicles = ctx.problem.fleet.vehicles.iter().map(|vehicle| vehicle.vehicle_ids.len()).sum::<usize>();
+
+    if has_balance_activities_objective && total_vehicles < 2 {
+        Err(FormatError::new_warning(
+            "E1612".to_string(),
+            "redundant balance activities objective".to_string(),
+            "specify at least two vehicles or delete 'balance-activities' and 'balance-tour-size' objectives"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().collect())
 }
@@ -188,6 +303,11 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_conflicting_objective_directions(&objectives),
+            check_e1609_single_vehicle_type_objective(ctx, &objectives),
+            check_e1610_unknown_custom_objective(ctx, &objectives),
+            check_e1611_no_jobs_with_times_objective(ctx, &objectives),
+            check_e1612_single_vehicle_balance_objective(ctx, &objectives),
         ])
         .map_err(From::from)
     } else {
@@ -115,6 +115,19 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that routing matrix is supplied when custom locations are used.
+fn check_e1506_no_matrix_when_custom_locations_used(ctx: &ValidationContext) -> Result<(), FormatError> {
+    if ctx.coord_index.has_custom() && ctx.matrices.is_none_or(|matrices| matrices.is_empty()) {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "custom locations requires routing matrix to be specified".to_string(),
+            "either use coordinates instead of custom locations or specify routing matrix".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let location_types = (ctx.coord_index.has_coordinates(), ctx.coord_index.has_indices());
@@ -126,6 +139,7 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_no_matrix_when_custom_locations_used(ctx),
     ])
     .map_err(From::from)
 }
@@ -259,6 +259,52 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+fn check_e1309_vehicle_day_horizon(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let horizon = if let Some(horizon) = ctx.problem.fleet.day_horizon.as_ref() {
+        horizon.to_core_horizon().map_err(|err| {
+            FormatError::new(
+                "E1309".to_string(),
+                "invalid day horizon".to_string(),
+                format!("make sure that day horizon has positive day length and rest within a day: '{err}'"),
+            )
+        })?
+    } else {
+        return Ok(());
+    };
+
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(move |_, shift, _| {
+            shift.end.is_some()
+                && shift.breaks.iter().flat_map(|breaks| breaks.iter()).all(|br| match br {
+                    VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::ExactTime { earliest, latest },
+                        duration,
+                    } => parse_time_safe(earliest)
+                        .ok()
+                        .zip(parse_time_safe(latest).ok())
+                        .is_none_or(|(start, end)| !horizon.intersects_rest(start, end + *duration)),
+                    VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime { .. }, .. } => false,
+                    VehicleBreak::Optional { .. } => true,
+                })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid day horizon".to_string(),
+            format!(
+                "when day horizon is used, make sure that shifts have end and required breaks are defined \
+                 with exact time outside of rests, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -306,6 +352,7 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_day_horizon(ctx),
     ])
     .map_err(From::from)
 }
@@ -63,6 +63,7 @@ prop_compose! {
                         duration,
                         distance,
                         min_shared_time: None,
+                        min_shared_time_ratio: None,
                         smallest_time_window: None,
                         max_jobs_per_cluster: None,
                     },
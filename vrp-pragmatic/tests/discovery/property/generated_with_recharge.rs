@@ -40,7 +40,8 @@ prop_compose! {
         vehicle.vehicle_ids = vec![format!("{}_1", vehicle.type_id)];
 
         vehicle.shifts.first_mut().unwrap().end = None;
-        vehicle.shifts.first_mut().unwrap().recharges = Some(VehicleRecharges { max_distance, stations });
+        vehicle.shifts.first_mut().unwrap().recharges =
+            Some(VehicleRecharges { max_distance, stations, combine_breaks: None });
 
         vehicle
     }
@@ -76,3 +76,43 @@ fn can_assign_break_between_jobs() {
             .build()
     );
 }
+
+#[test]
+fn can_itemize_route_duration_with_break() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job_with_times("job2", (10., 0.), vec![(20, 30)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: create_default_vehicle_costs(),
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
+                        places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let statistic = &solution.tours[0].statistic;
+    let times = &statistic.times;
+    assert_eq!(times.break_time, 2);
+    assert_eq!(times.serving, 2);
+    assert!(times.waiting > 0);
+    assert_eq!(times.driving + times.serving + times.waiting + times.break_time, statistic.duration);
+}
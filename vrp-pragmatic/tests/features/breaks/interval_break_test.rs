@@ -117,6 +117,7 @@ fn can_assign_interval_break_with_reload() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    limits: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -65,6 +65,7 @@ fn can_cluster_simple_jobs_impl(
                 duration: 3.,
                 distance: 3.,
                 min_shared_time: None,
+                min_shared_time_ratio: None,
                 smallest_time_window: None,
                 max_jobs_per_cluster: None,
             },
@@ -181,6 +182,7 @@ fn can_handle_two_clusters_impl(
                 duration: 5.,
                 distance: 5.,
                 min_shared_time: None,
+                min_shared_time_ratio: None,
                 smallest_time_window: None,
                 max_jobs_per_cluster: Some(2),
             },
@@ -28,6 +28,7 @@ fn can_mix_pickup_delivery_jobs() {
                 duration: 3.,
                 distance: 3.,
                 min_shared_time: None,
+                min_shared_time_ratio: None,
                 smallest_time_window: None,
                 max_jobs_per_cluster: None,
             },
@@ -115,6 +116,7 @@ fn can_vary_cluster_size_based_on_capacity_impl(
                 duration: 5.,
                 distance: 5.,
                 min_shared_time: None,
+                min_shared_time_ratio: None,
                 smallest_time_window: None,
                 max_jobs_per_cluster: None,
             },
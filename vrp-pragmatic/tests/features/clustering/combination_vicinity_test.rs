@@ -20,6 +20,7 @@ fn can_handle_job_in_relation_with_vicinity_cluster_impl(type_field: RelationTyp
                     duration: 10.,
                     distance: 10.,
                     min_shared_time: None,
+                    min_shared_time_ratio: None,
                     smallest_time_window: None,
                     max_jobs_per_cluster: None,
                 },
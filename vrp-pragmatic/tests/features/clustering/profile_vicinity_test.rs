@@ -19,6 +19,7 @@ fn can_use_scale_on_profile() {
                 duration: 3.,
                 distance: 3.,
                 min_shared_time: None,
+                min_shared_time_ratio: None,
                 smallest_time_window: None,
                 max_jobs_per_cluster: None,
             },
@@ -14,6 +14,7 @@ fn can_handle_parking_with_no_clusters_and_job_time_windows() {
                     duration: 30.,
                     distance: 16.,
                     min_shared_time: None,
+                    min_shared_time_ratio: None,
                     smallest_time_window: None,
                     max_jobs_per_cluster: None,
                 },
@@ -89,6 +90,7 @@ fn can_handle_waiting_time_with_parking_impl(
                     duration: threshold.0,
                     distance: threshold.1,
                     min_shared_time: None,
+                    min_shared_time_ratio: None,
                     smallest_time_window: None,
                     max_jobs_per_cluster: None,
                 },
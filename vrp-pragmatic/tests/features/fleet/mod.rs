@@ -3,3 +3,5 @@ mod basic_open_end;
 mod multi_dimens;
 mod profile_variation;
 mod unreachable_jobs;
+mod unused_vehicles;
+mod vehicle_types;
@@ -0,0 +1,98 @@
+use crate::format::problem::*;
+use crate::format::solution::{UnusedVehicle, UnusedVehicleReason};
+use crate::helpers::*;
+
+fn create_unused_vehicle(vehicle_id: &str, reason: UnusedVehicleReason) -> UnusedVehicle {
+    UnusedVehicle { vehicle_id: vehicle_id.to_string(), reason }
+}
+
+#[test]
+fn can_report_infeasible_vehicle_restricted_by_skills() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", (1., 0.), all_of_skills(vec!["unique_skill".to_string()])),
+                create_delivery_job_with_skills("job2", (2., 0.), all_of_skills(vec!["unique_skill".to_string()])),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("vehicle_without_skill"),
+                VehicleType {
+                    skills: Some(vec!["unique_skill".to_string()]),
+                    ..create_default_vehicle("vehicle_with_skill")
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_unused_vehicles(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unused_vehicles,
+        Some(vec![create_unused_vehicle("vehicle_without_skill_1", UnusedVehicleReason::Infeasible)])
+    );
+}
+
+#[test]
+fn can_report_not_needed_vehicle() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_unused_vehicles(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unused_vehicles = solution.unused_vehicles.expect("no unused vehicles");
+    assert_eq!(unused_vehicles.len(), 1);
+    assert_eq!(unused_vehicles[0].reason, UnusedVehicleReason::NotNeeded);
+}
+
+#[test]
+fn can_skip_unused_vehicles_when_all_used() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_unused_vehicles(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unused_vehicles, None);
+}
+
+#[test]
+fn can_skip_unused_vehicles_when_not_requested() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unused_vehicles, None);
+}
@@ -0,0 +1,49 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::collections::HashSet;
+
+fn create_vehicle_type(type_id: &str, vehicle_ids: Vec<&str>) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+        capacity: vec![1],
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_prefer_less_vehicle_types_with_minimize_vehicle_types_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (1., 0.)),
+                create_delivery_job("job3", (1., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeVehicleTypes,
+            Objective::MinimizeCost,
+        ]),
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_type("a", vec!["a_1", "a_2"]),
+                create_vehicle_type("b", vec!["b_1"]),
+                create_vehicle_type("c", vec!["c_1"]),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 3);
+    let vehicle_types = solution.tours.iter().map(|tour| tour.type_id.as_str()).collect::<HashSet<_>>();
+    assert_eq!(vehicle_types.len(), 2);
+    assert!(vehicle_types.contains("a"));
+}
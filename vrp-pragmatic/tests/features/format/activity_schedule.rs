@@ -0,0 +1,76 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_report_activity_schedules_with_tour_start_and_end() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job_with_times("job2", (10., 0.), vec![(20, 30)], 2.),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: to_strings(vec!["departure", "job1", "job2"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_schedules(problem, Some(vec![matrix]));
+
+    let schedules = solution
+        .schedules
+        .expect("no schedules")
+        .into_iter()
+        .map(|schedule| {
+            (schedule.job_id, schedule.activity_type, schedule.arrival, schedule.departure, schedule.waiting)
+        })
+        .collect::<Vec<_>>();
+    // NOTE job1: travel 5 + service 1, job2: travel 5, waiting 9 + service 2, arrival: travel 10
+    assert_eq!(
+        schedules,
+        vec![
+            ("departure".to_string(), "departure".to_string(), format_time(0.), format_time(0.), None),
+            ("job1".to_string(), "delivery".to_string(), format_time(5.), format_time(6.), None),
+            ("job2".to_string(), "delivery".to_string(), format_time(11.), format_time(22.), Some(9.)),
+            ("arrival".to_string(), "arrival".to_string(), format_time(32.), format_time(32.), None),
+        ]
+    );
+    let arrivals = schedules.iter().map(|(_, _, arrival, ..)| arrival.clone()).collect::<Vec<_>>();
+    assert!(arrivals.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn can_skip_activity_schedules_when_not_requested() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.schedules, None);
+}
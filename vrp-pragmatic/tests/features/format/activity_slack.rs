@@ -0,0 +1,71 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_report_activity_slack_limited_by_downstream_time_windows() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![(0, 30)], 1.),
+                create_delivery_job_with_times("job2", (6., 0.), vec![(10, 30)], 1.),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: to_strings(vec!["departure", "job1", "job2"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_slacks(problem, Some(vec![matrix]));
+
+    let slacks = solution
+        .slacks
+        .expect("no slacks")
+        .into_iter()
+        .map(|slack| (slack.job_id, slack.activity_type, slack.vehicle_id, slack.slack))
+        .collect::<Vec<_>>();
+    // NOTE job1: service starts at 5, own window allows 25, but job2 absorbs only 3 (waiting) + 20 (own window)
+    //      job2: service starts at 10 after waiting, own window allows 20
+    assert_eq!(
+        slacks,
+        vec![
+            ("job1".to_string(), "delivery".to_string(), "my_vehicle_1".to_string(), 23.),
+            ("job2".to_string(), "delivery".to_string(), "my_vehicle_1".to_string(), 20.),
+        ]
+    );
+}
+
+#[test]
+fn can_skip_activity_slacks_when_not_requested() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.slacks, None);
+}
@@ -0,0 +1,114 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+use vrp_core::prelude::Float;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_index("job1", 1),
+                create_delivery_job_with_index("job2", 2),
+                create_delivery_job_with_index("job3", 3),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: to_strings(vec!["departure", "job1", "job2", "job3"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: Location::Reference { index: 0 },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_matrix(travel_time_variances: Option<Vec<i64>>) -> Matrix {
+    #[rustfmt::skip]
+    let travel_times = vec![
+        0, 10, 20, 30,
+        10, 0, 10, 20,
+        20, 10, 0, 10,
+        30, 20, 10, 0,
+    ];
+
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: travel_times.clone(),
+        distances: travel_times,
+        error_codes: None,
+        travel_time_variances,
+    }
+}
+
+fn get_interval_widths(problem: Problem, matrix: Matrix) -> Vec<(String, Float)> {
+    let solution = solve_with_cheapest_insertion_and_arrivals(problem, Some(vec![matrix]));
+
+    solution
+        .arrivals
+        .expect("no arrival intervals")
+        .into_iter()
+        .map(|interval| (interval.job_id, parse_time(&interval.latest) - parse_time(&interval.earliest)))
+        .collect()
+}
+
+#[test]
+fn can_widen_arrival_intervals_downstream_of_high_variance_leg() {
+    #[rustfmt::skip]
+    let variances = vec![
+        0, 100, 0, 0,
+        0, 0, 10000, 0,
+        0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+
+    let widths = get_interval_widths(create_test_problem(), create_test_matrix(Some(variances)));
+
+    assert_eq!(widths.len(), 3);
+    let (job1, job2, job3) = (widths[0].1, widths[1].1, widths[2].1);
+    assert_eq!(widths.iter().map(|(id, _)| id.as_str()).collect::<Vec<_>>(), vec!["job1", "job2", "job3"]);
+    assert!(job1 > 0.);
+    assert!(job2 > 5. * job1);
+    assert_eq!(job2, job3);
+}
+
+#[test]
+fn can_skip_arrival_intervals_without_variances() {
+    let solution =
+        solve_with_cheapest_insertion_and_arrivals(create_test_problem(), Some(vec![create_test_matrix(None)]));
+
+    assert!(solution.arrivals.is_none());
+}
+
+#[test]
+fn can_skip_arrival_intervals_when_not_requested() {
+    #[rustfmt::skip]
+    let variances = vec![
+        0, 100, 0, 0,
+        0, 0, 100, 0,
+        0, 0, 0, 100,
+        0, 0, 0, 0,
+    ];
+
+    let solution =
+        solve_with_cheapest_insertion(create_test_problem(), Some(vec![create_test_matrix(Some(variances))]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.arrivals, None);
+}
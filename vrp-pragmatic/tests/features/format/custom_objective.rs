@@ -0,0 +1,76 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{InsertionContext, MoveContext};
+use vrp_core::models::common::Cost;
+use vrp_core::models::{FeatureBuilder, FeatureObjective};
+
+struct RouteCountObjective {
+    is_called: Arc<AtomicBool>,
+}
+
+impl FeatureObjective for RouteCountObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.len() as Cost
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        self.is_called.store(true, Ordering::Relaxed);
+
+        match move_ctx {
+            MoveContext::Route { route_ctx, .. } if !route_ctx.route().tour.has_jobs() => 1.,
+            _ => Cost::default(),
+        }
+    }
+}
+
+#[test]
+fn can_solve_problem_with_custom_objective() {
+    let is_called = Arc::new(AtomicBool::new(false));
+    let registry = ObjectiveRegistry::default().register("route_count", {
+        let is_called = is_called.clone();
+        move |name| {
+            FeatureBuilder::default()
+                .with_name(name)
+                .with_objective(RouteCountObjective { is_called: is_called.clone() })
+                .build()
+        }
+    });
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("type1"), create_default_vehicle("type2")],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::Custom { name: "route_count".to_string() },
+            Objective::MinimizeCost,
+        ]),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_objectives(problem, Some(vec![matrix]), &registry);
+
+    assert!(is_called.load(Ordering::Relaxed));
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+}
+
+#[test]
+fn can_reject_problem_with_unknown_custom_objective() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        objectives: Some(vec![Objective::Custom { name: "unknown".to_string() }, Objective::MinimizeCost]),
+    };
+
+    let result = (problem, None, &ObjectiveRegistry::default()).read_pragmatic();
+
+    let errors = result.expect_err("expected validation error").errors;
+    assert_eq!(errors.iter().map(|err| err.code.as_str()).collect::<Vec<_>>(), vec!["E1610"]);
+}
@@ -0,0 +1,91 @@
+use crate::format::problem::*;
+use crate::format::solution::JobDetour;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_job_detour(job_id: &str, ratio: Float) -> JobDetour {
+    JobDetour {
+        job_id: job_id.to_string(),
+        activity_type: "delivery".to_string(),
+        vehicle_id: "my_vehicle_1".to_string(),
+        ratio,
+    }
+}
+
+#[test]
+fn can_report_job_detours_on_zig_zag_route() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (2., 0.)), create_delivery_job("job2", (1., 0.))],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: to_strings(vec!["departure", "job1", "job2"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_detours(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.detours, Some(vec![create_job_detour("job1", 1.), create_job_detour("job2", 3.)]));
+}
+
+#[test]
+fn can_skip_job_detours_when_not_requested() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (2., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.detours, None);
+}
+
+#[test]
+fn can_skip_job_detours_for_reference_locations() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 1), create_delivery_job_with_index("job2", 2)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: Location::Reference { index: 0 },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 1, 3, 1, 0, 1, 3, 1, 0],
+        distances: vec![0, 1, 3, 1, 0, 1, 3, 1, 0],
+        error_codes: None,
+        travel_time_variances: None,
+    };
+
+    let solution = solve_with_cheapest_insertion_and_detours(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.detours, None);
+}
@@ -1,2 +1,9 @@
+mod activity_schedule;
+mod activity_slack;
+mod arrival_intervals;
+mod custom_objective;
+mod job_detours;
 mod location_custom;
 mod location_index;
+mod tour_energy;
+mod tour_utilization;
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem_with_consumption(consumption: Option<VehicleConsumption>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { consumption, ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_report_tour_energy_as_distance_times_coefficient() {
+    let problem = create_problem_with_consumption(Some(VehicleConsumption { distance: 0.25, load: None }));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_energies(problem, Some(vec![matrix]));
+
+    let energies = solution.energies.expect("no energies");
+    assert_eq!(energies.len(), 1);
+    let energy = energies.first().unwrap();
+    assert_eq!(energy.vehicle_id, "my_vehicle_1");
+    assert_eq!(energy.shift_index, 0);
+    assert_eq!(solution.tours.first().unwrap().statistic.distance, 10);
+    assert!((energy.energy - 10. * 0.25).abs() < 1E-9);
+}
+
+#[test]
+fn can_report_tour_energy_with_load_coefficient() {
+    let problem = create_problem_with_consumption(Some(VehicleConsumption { distance: 0.25, load: Some(0.5) }));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_energies(problem, Some(vec![matrix]));
+
+    let energy = solution.energies.expect("no energies").first().unwrap().energy;
+    // NOTE one unit of load is carried only on the way to the job
+    assert!((energy - (10. * 0.25 + 5. * 0.5)).abs() < 1E-9);
+}
+
+#[test]
+fn can_skip_tour_energy_without_consumption() {
+    let problem = create_problem_with_consumption(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_energies(problem, Some(vec![matrix]));
+
+    assert!(solution.energies.is_none());
+}
+
+#[test]
+fn can_skip_tour_energy_when_not_requested() {
+    let problem = create_problem_with_consumption(Some(VehicleConsumption { distance: 0.25, load: None }));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.energies, None);
+}
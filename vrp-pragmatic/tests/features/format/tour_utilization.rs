@@ -0,0 +1,66 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_report_tour_utilization_with_waiting_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", (5., 0.), 1.),
+                create_delivery_job_with_times("job2", (6., 0.), vec![(100, 200)], 1.),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: to_strings(vec!["departure", "job1", "job2"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_utilizations(problem, Some(vec![matrix]));
+
+    let utilizations = solution.utilizations.expect("no utilizations");
+    assert_eq!(utilizations.len(), 1);
+    let utilization = utilizations.first().unwrap();
+    assert_eq!(utilization.vehicle_id, "my_vehicle_1");
+    assert_eq!(utilization.shift_length, 1000.);
+    // NOTE driving: 5 + 1 + 6, serving: 1 + 1, waiting: 100 - 7
+    assert!((utilization.active - 0.014).abs() < 1E-9);
+    assert!((utilization.waiting - 0.093).abs() < 1E-9);
+    assert!((utilization.idle - 0.893).abs() < 1E-9);
+    assert!((utilization.active + utilization.waiting + utilization.idle - 1.).abs() < 1E-9);
+}
+
+#[test]
+fn can_skip_tour_utilization_when_not_requested() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.utilizations, None);
+}
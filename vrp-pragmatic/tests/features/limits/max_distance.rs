@@ -22,6 +22,7 @@ fn can_limit_by_max_distance() {
         travel_times: vec![1, 1, 1, 1],
         distances: vec![1, 100, 100, 1],
         error_codes: None,
+        travel_time_variances: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
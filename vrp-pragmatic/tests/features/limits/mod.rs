@@ -1,3 +1,4 @@
 mod max_distance;
 mod max_duration;
+mod shift_limits;
 mod tour_size;
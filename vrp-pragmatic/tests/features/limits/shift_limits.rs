@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_shift_with_limits(start: f64, end: f64, max_distance: f64) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: (0., 0.).to_loc() }),
+        limits: Some(VehicleShiftLimits { max_distance: Some(max_distance), max_duration: None }),
+        ..create_default_vehicle_shift()
+    }
+}
+
+parameterized_test! {can_use_shift_specific_max_distance, (first_shift_limit, second_shift_limit, expected), {
+    can_use_shift_specific_max_distance_impl(first_shift_limit, second_shift_limit, expected);
+}}
+
+can_use_shift_specific_max_distance! {
+    case01_both_assigned: (25., 70., (vec![("job1", 0), ("job2", 1)], vec![])),
+    case02_swapped_limits: (70., 25., (vec![("job1", 0)], vec!["job2"])),
+}
+
+fn can_use_shift_specific_max_distance_impl(
+    first_shift_limit: f64,
+    second_shift_limit: f64,
+    expected: (Vec<(&str, usize)>, Vec<&str>),
+) {
+    let (expected_assigned, expected_unassigned) = expected;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job2", (30., 0.), vec![(200, 300)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![
+                    create_vehicle_shift_with_limits(0., 100., first_shift_limit),
+                    create_vehicle_shift_with_limits(200., 300., second_shift_limit),
+                ],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut assigned = solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| activity.activity_type == "delivery")
+                .map(move |activity| (activity.job_id.as_str(), tour.shift_index))
+        })
+        .collect::<Vec<_>>();
+    assigned.sort();
+    let unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(assigned, expected_assigned);
+    assert_eq!(unassigned, expected_unassigned);
+}
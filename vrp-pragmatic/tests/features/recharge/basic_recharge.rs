@@ -20,6 +20,7 @@ fn can_use_recharge_trivial_case() {
                             times: None,
                             tag: None,
                         }],
+                        combine_breaks: None,
                     }),
                     ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
                 }],
@@ -67,6 +68,7 @@ fn can_still_skip_jobs_with_recharge() {
                             times: None,
                             tag: None,
                         }],
+                        combine_breaks: None,
                     }),
                     ..create_default_vehicle_shift_with_locations((52.5189, 13.4011), (52.5189, 13.4011))
                 }],
@@ -101,6 +103,7 @@ fn can_use_recharge_with_ten_jobs() {
                             times: None,
                             tag: None,
                         }],
+                        combine_breaks: None,
                     }),
                     ..create_default_open_vehicle_shift()
                 }],
@@ -108,6 +111,8 @@ fn can_use_recharge_with_ten_jobs() {
             }],
             profiles: create_default_matrix_profiles(),
             resources: None,
+            skills: None,
+            day_horizon: None,
         },
         ..create_empty_problem()
     };
@@ -0,0 +1,67 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+use vrp_core::prelude::Float;
+
+fn create_problem_with_break_and_recharge(combine_breaks: Option<bool>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (30., 0.)), create_delivery_job("job2", (70., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(40.), format_time(100.)]),
+                        places: vec![VehicleOptionalBreakPlace { duration: 20., location: None, tag: None }],
+                        policy: None,
+                    }]),
+                    recharges: Some(VehicleRecharges {
+                        max_distance: 55.,
+                        stations: vec![JobPlace {
+                            location: (50., 0.).to_loc(),
+                            duration: 30.,
+                            times: None,
+                            tag: None,
+                        }],
+                        combine_breaks,
+                    }),
+                    ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_recharge_stop_duration(combine_breaks: Option<bool>) -> Float {
+    let problem = create_problem_with_break_and_recharge(combine_breaks);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let stop = solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.activity_type == "recharge"))
+        .expect("cannot find recharge stop");
+    let activity_types = stop.activities().iter().map(|activity| activity.activity_type.as_str()).collect::<Vec<_>>();
+    assert_eq!(activity_types, vec!["recharge", "break"]);
+
+    let schedule = stop.schedule();
+    parse_time(&schedule.departure) - parse_time(&schedule.arrival)
+}
+
+#[test]
+fn can_combine_break_with_recharge_when_requested() {
+    assert_eq!(get_recharge_stop_duration(Some(true)), 30.);
+}
+
+#[test]
+fn can_keep_break_separate_from_recharge_by_default() {
+    assert_eq!(get_recharge_stop_duration(None), 50.);
+}
@@ -1 +1,2 @@
 mod basic_recharge;
+mod combined_break_recharge;
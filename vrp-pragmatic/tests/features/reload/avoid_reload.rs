@@ -36,6 +36,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    limits: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -44,6 +44,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    limits: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
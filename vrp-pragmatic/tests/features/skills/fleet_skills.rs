@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_use_fleet_skills_inherited_by_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills(
+                    "job1",
+                    (1., 0.),
+                    all_of_skills(vec!["fleet_skill".to_string(), "own_skill".to_string()]),
+                ),
+                create_delivery_job_with_skills(
+                    "job2",
+                    (2., 0.),
+                    JobSkills { all_of: None, one_of: None, none_of: Some(vec!["fleet_skill".to_string()]) },
+                ),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                skills: Some(vec!["own_skill".to_string()]),
+                ..create_default_vehicle_type()
+            }],
+            skills: Some(vec!["fleet_skill".to_string()]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["arrival"]]);
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+    assert_eq!(unassigned[0].reasons[0].code, "SKILL_CONSTRAINT");
+}
@@ -1,5 +1,6 @@
 use crate::format::problem::*;
 use crate::format::Location;
+use crate::format_time;
 use crate::helpers::*;
 
 #[test]
@@ -158,3 +159,43 @@ fn can_consider_latest_departure_time() {
             .build()
     );
 }
+
+#[test]
+fn can_prefer_order_with_less_waiting_time_using_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(10, 20)], 10.),
+                create_delivery_job_with_times("job2", (1., 0.), vec![(0, 100)], 10.),
+            ],
+            ..create_empty_plan()
+        },
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeDistance,
+            Objective::MinimizeWaitingTime,
+        ]),
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job2", "job1"], vec!["arrival"]]);
+    assert_eq!(solution.statistic.times.waiting, 0);
+}
@@ -0,0 +1,83 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_push_late_job_to_next_day_with_day_horizon() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (80., 0.), vec![(0, 130)], 0.),
+                create_delivery_job_with_times("job2", (85., 0.), vec![(0, 60)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(300.), location: (0., 0.).to_loc() }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            day_horizon: Some(DayHorizon { day_length: Some(100.), rest_start: 70., rest_duration: 30. }),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        SolutionBuilder::default()
+            .tour(
+                TourBuilder::default()
+                    .stops(vec![
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(0., 0.)
+                            .load(vec![1])
+                            .build_departure(),
+                        StopBuilder::new_transit()
+                            .schedule_stamp(70., 100.)
+                            .load(vec![1])
+                            .build_single("break", "break"),
+                        StopBuilder::default()
+                            .coordinate((80., 0.))
+                            .schedule_stamp(110., 110.)
+                            .load(vec![0])
+                            .distance(80)
+                            .build_single("job1", "delivery"),
+                        StopBuilder::new_transit()
+                            .schedule_stamp(170., 200.)
+                            .load(vec![0])
+                            .build_single("break", "break"),
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(220., 220.)
+                            .load(vec![0])
+                            .distance(160)
+                            .build_arrival(),
+                    ])
+                    .statistic(StatisticBuilder::default().driving(160).break_time(60).build())
+                    .build()
+            )
+            .unassigned(Some(vec![UnassignedJob {
+                job_id: "job2".to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: "TIME_WINDOW_CONSTRAINT".to_string(),
+                    description: "cannot be visited within time window".to_string(),
+                    details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+                }]
+            }]))
+            .build()
+    );
+}
@@ -1,2 +1,3 @@
 mod multi_reasons;
+mod partial_solution;
 mod single_reason;
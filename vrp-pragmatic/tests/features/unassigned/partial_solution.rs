@@ -0,0 +1,39 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job_with_demand("job3", (3., 0.), vec![11]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_mark_solution_as_partial_when_interrupted_by_time_budget() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_time_quota(problem, Some(vec![matrix]), 0);
+
+    assert_eq!(solution.partial, Some(true));
+    assert!(solution.unassigned.map_or(false, |unassigned| !unassigned.is_empty()));
+}
+
+#[test]
+fn can_omit_partial_flag_when_search_is_not_interrupted() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 10);
+
+    assert_eq!(solution.partial, None);
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
@@ -10,8 +10,16 @@ fn get_activities_count(tour: &Tour) -> usize {
         .sum()
 }
 
-#[test]
-fn can_balance_activities() {
+parameterized_test! {can_balance_activities, objective, {
+    can_balance_activities_impl(objective);
+}}
+
+can_balance_activities! {
+    case01_balance_activities: BalanceActivities,
+    case02_balance_tour_size: BalanceTourSize,
+}
+
+fn can_balance_activities_impl(objective: Objective) {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
@@ -42,7 +50,7 @@ fn can_balance_activities() {
             ],
             ..create_default_fleet()
         },
-        objectives: Some(vec![MinimizeUnassigned { breaks: None }, BalanceActivities, MinimizeCost]),
+        objectives: Some(vec![MinimizeUnassigned { breaks: None }, objective, MinimizeCost]),
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -34,6 +34,7 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            consumption: None,
         }
     }
 }
@@ -110,6 +111,7 @@ prop_compose! {
           breaks,
           reloads,
           recharges,
+          limits: None,
         }
     }
 }
@@ -130,7 +132,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, profiles, resources: None, skills: None, day_horizon: None }
     }
 }
 
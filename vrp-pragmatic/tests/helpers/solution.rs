@@ -262,6 +262,7 @@ impl Default for ActivityBuilder {
                 time: None,
                 job_tag: None,
                 commute: None,
+                cluster: None,
             },
         }
     }
@@ -416,6 +417,14 @@ impl Default for SolutionBuilder {
                 tours: vec![],
                 unassigned: None,
                 violations: None,
+                partial: None,
+                unused_vehicles: None,
+                detours: None,
+                utilizations: None,
+                slacks: None,
+                energies: None,
+                arrivals: None,
+                schedules: None,
                 extras: None,
             },
         }
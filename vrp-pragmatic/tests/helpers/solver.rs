@@ -1,6 +1,6 @@
 use crate::checker::CheckerContext;
-use crate::format::problem::{Matrix, PragmaticProblem, Problem};
-use crate::format::solution::{create_solution, Solution};
+use crate::format::problem::{Matrix, ObjectiveRegistry, PragmaticProblem, Problem};
+use crate::format::solution::{create_solution, PragmaticOutputOptions, Solution};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::Problem as CoreProblem;
@@ -13,16 +13,73 @@ use vrp_core::utils::{Environment, GenericError, Parallelism};
 
 /// Runs solver with cheapest insertion heuristic.
 pub fn solve_with_cheapest_insertion(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
-    let environment = Arc::new(Environment::default());
-    get_core_solution(problem, matrices, true, |problem: Arc<CoreProblem>| {
-        let population = create_elitism_population(problem.goal.clone(), environment.clone());
-        let refinement_ctx =
-            RefinementContext::new(problem.clone(), Box::new(population), TelemetryMode::None, environment.clone());
-
-        RecreateWithCheapest::new(environment.random.clone())
-            .run(&refinement_ctx, InsertionContext::new(problem, environment))
-            .into()
-    })
+    get_core_solution(problem, matrices, true, solve_core_with_cheapest_insertion)
+}
+
+/// Runs solver with cheapest insertion heuristic and creates optional solution properties requested by options.
+pub fn solve_with_cheapest_insertion_and_options(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    options: PragmaticOutputOptions,
+) -> Solution {
+    let core_problem = get_core_problem(problem, matrices);
+    let core_solution = solve_core_with_cheapest_insertion(core_problem.clone());
+
+    sort_all_data(create_solution(&core_problem, &core_solution, &Default::default(), &options).unwrap())
+}
+
+/// Runs solver with cheapest insertion heuristic and reports unused vehicles.
+pub fn solve_with_cheapest_insertion_and_unused_vehicles(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let options = PragmaticOutputOptions { unused_vehicles: true, ..Default::default() };
+    solve_with_cheapest_insertion_and_options(problem, matrices, options)
+}
+
+/// Runs solver with cheapest insertion heuristic and reports job detours.
+pub fn solve_with_cheapest_insertion_and_detours(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let options = PragmaticOutputOptions { detours: true, ..Default::default() };
+    solve_with_cheapest_insertion_and_options(problem, matrices, options)
+}
+
+/// Runs solver with cheapest insertion heuristic and reports tour utilizations.
+pub fn solve_with_cheapest_insertion_and_utilizations(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let options = PragmaticOutputOptions { utilizations: true, ..Default::default() };
+    solve_with_cheapest_insertion_and_options(problem, matrices, options)
+}
+
+/// Runs solver with cheapest insertion heuristic and reports activity slack times.
+pub fn solve_with_cheapest_insertion_and_slacks(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let options = PragmaticOutputOptions { slacks: true, ..Default::default() };
+    solve_with_cheapest_insertion_and_options(problem, matrices, options)
+}
+
+/// Runs solver with cheapest insertion heuristic and reports activity schedules.
+pub fn solve_with_cheapest_insertion_and_schedules(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let options = PragmaticOutputOptions { schedules: true, ..Default::default() };
+    solve_with_cheapest_insertion_and_options(problem, matrices, options)
+}
+
+/// Runs solver with cheapest insertion heuristic and reports tour energy consumptions.
+pub fn solve_with_cheapest_insertion_and_energies(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let options = PragmaticOutputOptions { energies: true, ..Default::default() };
+    solve_with_cheapest_insertion_and_options(problem, matrices, options)
+}
+
+/// Runs solver with cheapest insertion heuristic and reports arrival time confidence intervals.
+pub fn solve_with_cheapest_insertion_and_arrivals(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let options = PragmaticOutputOptions { arrivals: true, ..Default::default() };
+    solve_with_cheapest_insertion_and_options(problem, matrices, options)
+}
+
+/// Runs solver with cheapest insertion heuristic using custom objectives from the registry.
+pub fn solve_with_cheapest_insertion_and_objectives(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    registry: &ObjectiveRegistry,
+) -> Solution {
+    let core_problem = Arc::new((problem, matrices, registry).read_pragmatic().unwrap());
+    let core_solution = solve_core_with_cheapest_insertion(core_problem.clone());
+
+    sort_all_data(create_solution(&core_problem, &core_solution, &Default::default(), &Default::default()).unwrap())
 }
 
 /// Runs solver with default metaheuristic and default amount of generations.
@@ -69,6 +126,35 @@ pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize
     })
 }
 
+/// Runs solver with default metaheuristic and given time quota (in seconds) without feasibility check.
+pub fn solve_with_time_quota(problem: Problem, matrices: Option<Vec<Matrix>>, max_time: usize) -> Solution {
+    get_core_solution(problem, matrices, false, |problem: Arc<CoreProblem>| {
+        let environment = Arc::new(Environment::new_with_time_quota(Some(max_time)));
+
+        VrpConfigBuilder::new(problem.clone())
+            .set_environment(environment)
+            .prebuild()
+            .expect("cannot prebuild vrp configuration")
+            .with_max_generations(Some(200))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .expect("cannot build solver")
+            .solve()
+            .expect("cannot solve the problem")
+    })
+}
+
+fn solve_core_with_cheapest_insertion(problem: Arc<CoreProblem>) -> CoreSolution {
+    let environment = Arc::new(Environment::default());
+    let population = create_elitism_population(problem.goal.clone(), environment.clone());
+    let refinement_ctx =
+        RefinementContext::new(problem.clone(), Box::new(population), TelemetryMode::None, environment.clone());
+
+    RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment))
+        .into()
+}
+
 fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
     Arc::new(
         if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
@@ -89,7 +175,9 @@ fn get_core_solution<F: FnOnce(Arc<CoreProblem>) -> CoreSolution>(
 
     let core_solution = solve_func(core_problem.clone());
 
-    let format_solution = sort_all_data(create_solution(&core_problem, &core_solution, &Default::default()));
+    let format_solution = sort_all_data(
+        create_solution(&core_problem, &core_solution, &Default::default(), &Default::default()).unwrap(),
+    );
 
     if perform_check {
         if let Some(errs) =
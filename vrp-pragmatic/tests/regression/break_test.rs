@@ -138,10 +138,12 @@ fn can_handle_properly_invalid_break_removal() {
                         }]),
                         reloads: None,
                         recharges: None,
+                        limits: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    consumption: None,
                 }],
                 ..create_default_fleet()
             },
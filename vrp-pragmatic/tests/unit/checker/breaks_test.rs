@@ -86,6 +86,7 @@ fn can_check_breaks_impl(
                     }]),
                     reloads: None,
                     recharges: None,
+                    limits: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -102,6 +103,7 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        cluster: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -111,6 +113,7 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            cluster: None,
         });
     }
 
@@ -150,3 +153,89 @@ fn can_check_breaks_impl(
 
     assert_eq!(result, expected_result);
 }
+
+parameterized_test! {can_get_break_compliance_report, (has_break, break_time, expected), {
+    can_get_break_compliance_report_impl(has_break, break_time, expected);
+}}
+
+can_get_break_compliance_report! {
+    case01_compliant: (true, (4., 6.), (1, 1, 0, true)),
+    case02_missing_break: (false, (4., 6.), (1, 0, 0, false)),
+    case03_out_of_window: (true, (0., 1.), (1, 1, 1, false)),
+}
+
+fn can_get_break_compliance_report_impl(
+    has_break: bool,
+    break_time: (Float, Float),
+    expected: (usize, usize, usize, bool),
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 2., latest: 5. },
+                        duration: 2.,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let mut activities = vec![Activity {
+        job_id: "job1".to_string(),
+        activity_type: "delivery".to_string(),
+        location: None,
+        time: Some(Interval { start: format_time(1.), end: format_time(2.) }),
+        job_tag: None,
+        commute: None,
+        cluster: None,
+    }];
+    if has_break {
+        activities.push(Activity {
+            job_id: "break".to_string(),
+            activity_type: "break".to_string(),
+            location: None,
+            time: Some(Interval { start: format_time(break_time.0), end: format_time(break_time.1) }),
+            job_tag: None,
+            commute: None,
+            cluster: None,
+        });
+    }
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 6.)
+                        .load(vec![0])
+                        .distance(1)
+                        .activities(activities)
+                        .build(),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(10., 10.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+
+    let report = get_break_compliance_report(&problem, &solution).unwrap();
+
+    assert_eq!(report.len(), 1);
+    let compliance = report.first().unwrap();
+    assert_eq!(compliance.vehicle_id, "my_vehicle_1");
+    assert_eq!(
+        (compliance.expected, compliance.scheduled, compliance.out_of_window, compliance.is_compliant),
+        expected
+    );
+}
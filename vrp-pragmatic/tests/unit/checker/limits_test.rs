@@ -185,6 +185,7 @@ fn can_check_recharge_distance() {
                             times: None,
                             tag: None,
                         }],
+                        combine_breaks: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -99,10 +99,12 @@ mod single {
                             ..create_default_reload()
                         }]),
                         recharges: None,
+                        limits: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    consumption: None,
                 }],
                 ..create_default_fleet()
             },
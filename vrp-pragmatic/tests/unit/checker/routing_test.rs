@@ -139,3 +139,87 @@ fn can_check_solution_statistic() {
         .into()])
     );
 }
+
+fn create_test_solution_with_transits(transits: Vec<(Float, Float)>) -> Solution {
+    SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(
+                    vec![
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(0., 0.)
+                            .load(vec![2])
+                            .build_departure(),
+                        StopBuilder::default()
+                            .coordinate((1., 0.))
+                            .schedule_stamp(1., 2.)
+                            .load(vec![1])
+                            .distance(1)
+                            .build_single("job1", "delivery"),
+                    ]
+                    .into_iter()
+                    .chain(transits.into_iter().map(|(arrival, departure)| {
+                        StopBuilder::new_transit()
+                            .schedule_stamp(arrival, departure)
+                            .load(vec![1])
+                            .build_single("break", "break")
+                    }))
+                    .chain(vec![
+                        StopBuilder::default()
+                            .coordinate((4., 0.))
+                            .schedule_stamp(7., 8.)
+                            .load(vec![0])
+                            .distance(4)
+                            .build_single("job2", "delivery"),
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(12., 12.)
+                            .load(vec![0])
+                            .distance(8)
+                            .build_arrival(),
+                    ])
+                    .collect(),
+                )
+                .statistic(Statistic {
+                    cost: 10.,
+                    distance: 8,
+                    duration: 12,
+                    times: Timing { driving: 8, serving: 2, break_time: 2, ..Timing::default() },
+                })
+                .build(),
+        )
+        .build()
+}
+
+parameterized_test! {can_check_transit_stop, (transits, expected_result), {
+    can_check_transit_stop_impl(transits, expected_result);
+}}
+
+can_check_transit_stop! {
+    case_01_between_neighbours: (vec![(3., 5.)], Ok(())),
+    case_02_at_previous_departure: (vec![(2., 4.)], Ok(())),
+    case_03_wrong_next_arrival: (vec![(3., 7.)], Err(vec![duration_error(3, 7, 9)])),
+    case_04_after_next_arrival: (vec![(6., 8.)],
+        Err(vec!["transit stop 2 in the tour 'my_vehicle_1' is not between its neighbours".into()])),
+    case_05_two_transits: (vec![(3., 4.), (4., 5.)],
+        Err(vec!["transit stop should be followed by point stop".into()])),
+}
+
+fn can_check_transit_stop_impl(transits: Vec<(Float, Float)>, expected_result: Result<(), Vec<GenericError>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (4., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = create_test_solution_with_transits(transits);
+    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution).unwrap();
+
+    let result = check_routing(&ctx);
+
+    assert_eq!(result, expected_result);
+}
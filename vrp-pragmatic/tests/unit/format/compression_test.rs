@@ -0,0 +1,73 @@
+use super::*;
+
+#[test]
+fn can_read_plain_json_input() {
+    let reader = create_decompressed_reader(BufReader::new(r#"{"tours":[]}"#.as_bytes())).unwrap();
+
+    let result: serde_json::Value = serde_json::from_reader(reader).unwrap();
+
+    assert_eq!(result, serde_json::json!({ "tours": [] }));
+}
+
+#[cfg(feature = "compression")]
+mod gzip {
+    use super::*;
+    use crate::format::problem::*;
+    use crate::format::solution::*;
+    use crate::helpers::*;
+    use std::io::BufWriter;
+    use std::sync::Arc;
+    use vrp_core::construction::heuristics::InsertionContext;
+    use vrp_core::models::Problem as CoreProblem;
+    use vrp_core::models::Solution as CoreSolution;
+    use vrp_core::rosomaxa::evolution::TelemetryMode;
+    use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+    use vrp_core::solver::{create_elitism_population, RefinementContext};
+    use vrp_core::utils::Environment;
+
+    fn create_core_problem_and_solution() -> (Arc<CoreProblem>, CoreSolution) {
+        let problem = Problem {
+            plan: Plan {
+                jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+                ..create_empty_plan()
+            },
+            fleet: create_default_fleet(),
+            ..create_empty_problem()
+        };
+        let matrix = create_matrix_from_problem(&problem);
+        let problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read problem"));
+        let environment = Arc::new(Environment::default());
+        let population = create_elitism_population(problem.goal.clone(), environment.clone());
+        let refinement_ctx =
+            RefinementContext::new(problem.clone(), Box::new(population), TelemetryMode::None, environment.clone());
+
+        let solution = RecreateWithCheapest::new(environment.random.clone())
+            .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment))
+            .into();
+
+        (problem, solution)
+    }
+
+    #[test]
+    fn can_write_and_read_gzip_solution() {
+        let (problem, solution) = create_core_problem_and_solution();
+        let mut plain = BufWriter::new(Vec::new());
+        write_pragmatic(&problem, &solution, Default::default(), &mut plain).unwrap();
+        let plain = plain.into_inner().unwrap();
+        let mut compressed = Vec::new();
+
+        write_solution_gzip(&problem, &solution, Default::default(), &mut compressed).unwrap();
+
+        assert!(compressed.starts_with(&[0x1f, 0x8b]));
+        let mut decompressed = Vec::new();
+        create_decompressed_reader(BufReader::new(compressed.as_slice()))
+            .unwrap()
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, plain);
+        let compressed = deserialize_solution(BufReader::new(compressed.as_slice())).unwrap();
+        let plain = deserialize_solution(BufReader::new(plain.as_slice())).unwrap();
+        assert_eq!(compressed, plain);
+        assert_eq!(compressed.tours.len(), 1);
+    }
+}
@@ -49,6 +49,7 @@ fn can_use_index_with_coordinate_an_unknown_location_types() {
     assert!(!index.is_special_index(2));
     // Location::Custom
     assert_eq!(index.get_by_loc(&unknown_location), Some(9));
+    assert_eq!(unknown_location.resolve_index(&index), Some(9));
     assert_eq!(index.get_by_idx(9), Some(unknown_location));
     assert!(index.is_special_index(9));
     // out of range
@@ -58,3 +59,93 @@ fn can_use_index_with_coordinate_an_unknown_location_types() {
     assert_eq!(index.get_by_idx(10), None);
     assert!(!index.is_special_index(3));
 }
+
+#[test]
+fn can_resolve_location_index() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let index = CoordIndex::new(&problem);
+
+    assert_eq!((2., 0.).to_loc().resolve_index(&index), Some(1));
+    assert_eq!((3., 0.).to_loc().resolve_index(&index), None);
+    assert_eq!(Location::new_reference(7).resolve_index(&index), Some(7));
+    assert_eq!(Location::new_unknown().resolve_index(&index), None);
+    assert_eq!(Location::Custom { r#type: CustomLocationType::Depot }.resolve_index(&index), None);
+}
+
+#[test]
+fn can_add_deduplicated_close_coordinates() {
+    let problem = Problem { fleet: create_default_fleet(), ..create_empty_problem() };
+    let mut index = CoordIndex::new(&problem);
+
+    let first = index.add_deduplicated(&Location::new_coordinate(52.5, 13.4));
+    let second = index.add_deduplicated(&Location::new_coordinate(52.5 + 1E-9, 13.4 - 1E-9));
+
+    assert_eq!(first, 1);
+    assert_eq!(second, first);
+    assert_eq!(index.max_matrix_index(), 1);
+    assert_eq!(index.unique().len(), 2);
+}
+
+#[test]
+fn can_add_deduplicated_distinct_coordinates() {
+    let problem = Problem { fleet: create_default_fleet(), ..create_empty_problem() };
+    let mut index = CoordIndex::new(&problem);
+
+    let first = index.add_deduplicated(&Location::new_coordinate(52.5, 13.4));
+    let second = index.add_deduplicated(&Location::new_coordinate(52.6, 13.4));
+
+    assert_eq!(first, 1);
+    assert_eq!(second, 2);
+    assert_eq!(index.max_matrix_index(), 2);
+    assert_eq!(index.get_by_idx(second), Some(Location::new_coordinate(52.6, 13.4)));
+}
+
+#[test]
+fn can_add_deduplicated_with_custom_epsilon() {
+    let problem = Problem { fleet: create_default_fleet(), ..create_empty_problem() };
+    let mut index = CoordIndex::new(&problem).with_dedup_epsilon(0.5);
+
+    assert_eq!(index.add_deduplicated(&(0.3, 0.2).to_loc()), 0);
+    assert_eq!(index.add_deduplicated(&(1., 0.).to_loc()), 1);
+    assert_eq!(index.max_matrix_index(), 1);
+}
+
+parameterized_test! {can_compare_and_hash_locations, (left, right, expected), {
+    can_compare_and_hash_locations_impl(left, right, expected);
+}}
+
+can_compare_and_hash_locations! {
+    case01_same_coordinate: ((52.5, 13.4).to_loc(), (52.5, 13.4).to_loc(), true),
+    case02_within_precision: ((52.5, 13.4).to_loc(), (52.5 + 1E-9, 13.4 - 2E-8).to_loc(), true),
+    case03_negative_zero: ((0., -0.).to_loc(), (-0., 0.).to_loc(), true),
+    case04_distinct_lat: ((52.5, 13.4).to_loc(), (52.5 + 1E-6, 13.4).to_loc(), false),
+    case05_distinct_lng: ((52.5, 13.4).to_loc(), (52.5, 13.41).to_loc(), false),
+    case06_same_reference: (Location::new_reference(1), Location::new_reference(1), true),
+    case07_distinct_reference: (Location::new_reference(1), Location::new_reference(2), false),
+    case08_same_custom: (Location::new_unknown(), Location::new_unknown(), true),
+    case09_distinct_custom: (Location::new_unknown(), Location::Custom { r#type: CustomLocationType::Depot }, false),
+    case10_distinct_variants: (Location::new_reference(0), (0., 0.).to_loc(), false),
+}
+
+fn can_compare_and_hash_locations_impl(left: Location, right: Location, expected: bool) {
+    let get_hash = |location: &Location| {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        location.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    assert_eq!(left == right, expected);
+    if expected {
+        assert_eq!(get_hash(&left), get_hash(&right));
+        assert_eq!(HashSet::from([left, right]).len(), 1);
+    } else {
+        assert_ne!(get_hash(&left), get_hash(&right));
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+use crate::helpers::create_single;
+use vrp_core::models::problem::JobIdDimension;
+
+fn create_error(code: &str, cause: &str, details: Option<&str>) -> FormatError {
+    FormatError {
+        code: code.to_string(),
+        cause: cause.to_string(),
+        action: "action".to_string(),
+        details: details.map(|details| details.to_string()),
+        severity: FormatSeverity::Error,
+    }
+}
+
+#[test]
+fn can_deduplicate_identical_errors() {
+    let errors = MultiFormatError::from(vec![
+        create_error("E1000", "cause", Some("job1")),
+        create_error("E1001", "other cause", None),
+        create_error("E1000", "cause", Some("job1")),
+    ]);
+
+    let errors = errors.deduplicated();
+
+    assert_eq!(
+        errors,
+        MultiFormatError::from(vec![
+            create_error("E1000", "cause", Some("job1")),
+            create_error("E1001", "other cause", None)
+        ])
+    );
+}
+
+#[test]
+fn can_merge_details_of_duplicated_errors() {
+    let errors = MultiFormatError::from(vec![
+        create_error("E1000", "cause", Some("job1")),
+        create_error("E1000", "cause", None),
+        create_error("E1000", "cause", Some("job2")),
+        create_error("E1000", "another cause", Some("job3")),
+    ]);
+
+    let errors = errors.deduplicated();
+
+    assert_eq!(
+        errors,
+        MultiFormatError::from(vec![
+            create_error("E1000", "cause", Some("job1\njob2")),
+            create_error("E1000", "another cause", Some("job3"))
+        ])
+    );
+}
+
+#[test]
+fn can_serialize_error_severity() {
+    let error = FormatError::new("E1000".to_string(), "cause".to_string(), "action".to_string());
+    let warning = FormatError::new_warning("E1001".to_string(), "cause".to_string(), "action".to_string());
+
+    assert!(!error.is_warning());
+    assert!(warning.is_warning());
+    assert!(error.to_json().contains(r#""severity": "error""#));
+    assert!(warning.to_json().contains(r#""severity": "warning""#));
+}
+
+#[test]
+fn can_get_jobs_by_prefix_from_job_index() {
+    let job_index: JobIndex = ["cust-1#1", "cust-1#2", "cust-2#1", "cust-10#1"]
+        .into_iter()
+        .map(|id| (id.to_string(), CoreJob::Single(create_single(id))))
+        .collect();
+
+    let jobs = job_index.get_jobs_by_prefix("cust-1");
+
+    let ids = jobs.iter().filter_map(|job| job.dimens().get_job_id().cloned()).collect::<Vec<_>>();
+    assert_eq!(ids, vec!["cust-1#1".to_string(), "cust-1#2".to_string()]);
+}
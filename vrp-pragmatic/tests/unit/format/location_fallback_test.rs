@@ -0,0 +1,60 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_job_with_location(id: &str, location: ApiLocation) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace { location, duration: 0., times: None, tag: None }],
+            demand: None,
+            order: None,
+        }]),
+        ..create_job(id)
+    }
+}
+
+#[test]
+fn can_serialize_and_deserialize_depot_location() {
+    let location = ApiLocation::Custom { r#type: CustomLocationType::Depot };
+
+    let serialized = serde_json::to_string(&location).unwrap();
+    let deserialized: ApiLocation = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(serialized, r#"{"type":"depot"}"#);
+    assert_eq!(deserialized, location);
+    assert_eq!(deserialized.to_string(), "custom=depot");
+}
+
+#[test]
+fn can_use_depot_location_semantics() {
+    let depot = ApiLocation::Custom { r#type: CustomLocationType::Depot };
+    let unknown = ApiLocation::Custom { r#type: CustomLocationType::Unknown };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_index("job1", 0),
+                create_delivery_job_with_index("job2", 1),
+                create_job_with_location("job3", depot.clone()),
+                create_job_with_location("job4", unknown.clone()),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let coord_index = Arc::new(CoordIndex::new(&problem));
+    let depot_idx = coord_index.get_by_loc(&depot).unwrap();
+    let unknown_idx = coord_index.get_by_loc(&unknown).unwrap();
+    let reference_idx = coord_index.get_by_loc(&ApiLocation::new_reference(1)).unwrap();
+    let fallback = UnknownLocationFallback::new(coord_index.clone());
+    let profile = Profile::new(0, None);
+
+    assert!(coord_index.has_custom_depot());
+    assert_eq!(fallback.distance(&profile, depot_idx, reference_idx), 0.);
+    assert_eq!(fallback.distance(&profile, reference_idx, depot_idx), 0.);
+    assert_eq!(fallback.duration(&profile, depot_idx, reference_idx), 0.);
+    assert_eq!(fallback.distance(&profile, depot_idx, depot_idx), 0.);
+    assert!(fallback.distance(&profile, depot_idx, unknown_idx) < 0.);
+    assert!(fallback.duration(&profile, unknown_idx, depot_idx) < 0.);
+    assert_eq!(fallback.distance(&profile, unknown_idx, reference_idx), 0.);
+}
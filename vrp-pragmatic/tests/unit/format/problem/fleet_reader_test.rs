@@ -15,6 +15,7 @@ fn matrix(profile: Option<&str>, timestamp: Option<Float>, fill_value: i64, size
         travel_times: vec![fill_value; size],
         distances: vec![fill_value; size],
         error_codes: None,
+        travel_time_variances: None,
     }
 }
 
@@ -25,6 +26,7 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
         travel_times: vec![1; 4],
         distances: vec![2; 3],
         error_codes: None,
+        travel_time_variances: None,
     }
 }
 
@@ -173,3 +175,42 @@ fn can_create_transport_costs_positive_cases_impl(
         assert_eq!(result, distance);
     });
 }
+
+parameterized_test! {can_merge_fleet_skills_into_vehicle_skills, (vehicle_skills, fleet_skills, expected), {
+    can_merge_fleet_skills_into_vehicle_skills_impl(vehicle_skills, fleet_skills, expected);
+}}
+
+can_merge_fleet_skills_into_vehicle_skills! {
+    case01_no_skills: (None, None, None),
+    case02_only_vehicle: (Some(vec!["own"]), None, Some(vec!["own"])),
+    case03_only_fleet: (None, Some(vec!["fleet"]), Some(vec!["fleet"])),
+    case04_both: (Some(vec!["own"]), Some(vec!["fleet"]), Some(vec!["fleet", "own"])),
+}
+
+fn can_merge_fleet_skills_into_vehicle_skills_impl(
+    vehicle_skills: Option<Vec<&str>>,
+    fleet_skills: Option<Vec<&str>>,
+    expected: Option<Vec<&str>>,
+) {
+    use vrp_core::construction::features::VehicleSkillsDimension;
+
+    let to_skills = |skills: Option<Vec<&str>>| skills.map(|skills| skills.iter().map(|s| s.to_string()).collect());
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { skills: to_skills(vehicle_skills), ..create_default_vehicle_type() }],
+            skills: to_skills(fleet_skills),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let core_problem = problem.read_pragmatic().unwrap();
+
+    let actual = core_problem.fleet.vehicles[0].dimens.get_vehicle_skills().map(|skills| {
+        let mut skills = skills.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        skills.sort();
+        skills
+    });
+    assert_eq!(actual, expected);
+}
@@ -64,3 +64,76 @@ fn can_deserialize_matrix() {
     assert_eq!(matrix.distances.len(), 16);
     assert_eq!(matrix.travel_times.len(), 16);
 }
+
+#[test]
+fn can_read_matrix_from_bytes_same_as_json() {
+    let json = r#"{ "profile": "car", "travelTimes": [0, 5, 7, 0], "distances": [0, 50, -70, 0] }"#;
+    let expected = deserialize_matrix(BufReader::new(json.as_bytes())).unwrap();
+
+    let bytes = write_matrix_to_bytes(&expected).unwrap();
+    let actual = read_matrix_from_bytes(bytes.as_slice()).unwrap();
+
+    assert_eq!(bytes.len(), 4 + 3 + 4 + 8 * 8);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_read_matrix_without_profile_from_bytes() {
+    let matrix = Matrix {
+        profile: None,
+        timestamp: None,
+        travel_times: vec![1],
+        distances: vec![2],
+        error_codes: None,
+        travel_time_variances: None,
+    };
+
+    let actual = read_matrix_from_bytes(write_matrix_to_bytes(&matrix).unwrap().as_slice()).unwrap();
+
+    assert_eq!(actual, matrix);
+}
+
+parameterized_test! {can_detect_malformed_binary_matrix, modify_fn, {
+    can_detect_malformed_binary_matrix_impl(modify_fn);
+}}
+
+can_detect_malformed_binary_matrix! {
+    case01_empty: |bytes: Vec<u8>| bytes[..0].to_vec(),
+    case02_truncated_header: |bytes: Vec<u8>| bytes[..6].to_vec(),
+    case03_truncated_values: |bytes: Vec<u8>| bytes[..bytes.len() - 1].to_vec(),
+    case04_trailing_bytes: |bytes: Vec<u8>| bytes.into_iter().chain(std::iter::once(0)).collect(),
+    case05_invalid_profile: |mut bytes: Vec<u8>| { bytes[4] = 0xff; bytes },
+    case06_huge_rows: |mut bytes: Vec<u8>| { bytes[7..11].copy_from_slice(&u32::MAX.to_le_bytes()); bytes },
+}
+
+fn can_detect_malformed_binary_matrix_impl(modify_fn: fn(Vec<u8>) -> Vec<u8>) {
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 2, 2, 0],
+        error_codes: None,
+        travel_time_variances: None,
+    };
+    let bytes = modify_fn(write_matrix_to_bytes(&matrix).unwrap());
+
+    let result = read_matrix_from_bytes(bytes.as_slice());
+
+    assert_eq!(result.err().map(|err| err.code), Some("E0001".to_string()));
+}
+
+#[test]
+fn can_detect_non_square_matrix_on_write() {
+    let matrix = Matrix {
+        profile: None,
+        timestamp: None,
+        travel_times: vec![0, 1],
+        distances: vec![0, 1],
+        error_codes: None,
+        travel_time_variances: None,
+    };
+
+    let result = write_matrix_to_bytes(&matrix);
+
+    assert_eq!(result.err().map(|err| err.code), Some("E0005".to_string()));
+}
@@ -0,0 +1,37 @@
+use super::*;
+use crate::format::CoordIndex;
+use crate::validation::ValidationContext;
+
+#[test]
+fn can_generate_same_problem_with_same_seed() {
+    let config = ProblemGeneratorConfig::default();
+
+    let first = serde_json::to_string(&generate_problem(&config, 42)).unwrap();
+    let second = serde_json::to_string(&generate_problem(&config, 42)).unwrap();
+    let other = serde_json::to_string(&generate_problem(&config, 7)).unwrap();
+
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+}
+
+#[test]
+fn can_generate_problem_with_requested_size() {
+    let config = ProblemGeneratorConfig { job_count: 13, vehicle_count: 3, ..ProblemGeneratorConfig::default() };
+
+    let problem = generate_problem(&config, 0);
+
+    assert_eq!(problem.plan.jobs.len(), 13);
+    assert_eq!(problem.fleet.vehicles.iter().map(|vehicle| vehicle.vehicle_ids.len()).sum::<usize>(), 3);
+}
+
+#[test]
+fn can_generate_valid_problem() {
+    (0..10).for_each(|seed| {
+        let problem = generate_problem(&ProblemGeneratorConfig::default(), seed);
+        let coord_index = CoordIndex::new(&problem);
+
+        let result = ValidationContext::new(&problem, None, &coord_index).validate();
+
+        assert!(result.is_ok(), "seed {seed}: {}", result.err().unwrap());
+    });
+}
@@ -164,10 +164,12 @@ fn can_read_complex_problem() {
                     }]),
                     reloads: None,
                     recharges: None,
+                    limits: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
                 limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                consumption: None,
             }],
             ..create_default_fleet()
         },
@@ -179,6 +181,7 @@ fn can_read_complex_problem() {
         travel_times: vec![1; 25],
         distances: vec![2; 25],
         error_codes: None,
+        travel_time_variances: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -315,3 +318,80 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+#[test]
+fn can_read_problem_with_coordinates_without_matrix() {
+    let (berlin, paris, london) = ((52.5200, 13.4050), (48.8566, 2.3522), (51.5074, -0.1278));
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", paris), create_delivery_job("job2", london)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations(berlin, berlin)],
+                    ..create_vehicle_with_capacity("car", vec![10])
+                },
+                VehicleType {
+                    profile: create_vehicle_profile_with_name("truck"),
+                    shifts: vec![create_default_vehicle_shift_with_locations(berlin, berlin)],
+                    ..create_vehicle_with_capacity("truck", vec![10])
+                },
+            ],
+            profiles: vec![
+                MatrixProfile { name: "car".to_string(), speed: Some(25.) },
+                MatrixProfile { name: "truck".to_string(), speed: Some(20.) },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = crate::CoordIndex::new(&problem);
+    let get_index = |(lat, lng)| coord_index.get_by_loc(&crate::format::Location::Coordinate { lat, lng }).unwrap();
+
+    let core_problem = problem.read_pragmatic().ok().unwrap();
+
+    for (from, to, expected_km) in [(berlin, paris, 877.5), (paris, london, 343.5)] {
+        let (from, to) = (get_index(from), get_index(to));
+        for (profile, speed) in [(Profile::new(0, None), 25.), (Profile::new(1, None), 20.)] {
+            let distance = core_problem.transport.distance_approx(&profile, from, to);
+            let duration = core_problem.transport.duration_approx(&profile, from, to);
+
+            assert!((distance / 1000. - expected_km).abs() / expected_km < 0.01, "unexpected distance {distance}");
+            // NOTE both values are rounded independently
+            assert!((duration - distance / speed).abs() <= 1., "unexpected duration {duration}");
+        }
+    }
+}
+
+#[test]
+fn can_read_problem_from_reader_same_as_from_string() {
+    let config = ProblemGeneratorConfig { job_count: 500, vehicle_count: 20, ..ProblemGeneratorConfig::default() };
+    let problem = serde_json::to_string(&generate_problem(&config, 42)).unwrap();
+    let get_job_ids =
+        |jobs: &Jobs| jobs.all().iter().map(|job| job.dimens().get_job_id().cloned().unwrap()).collect::<Vec<_>>();
+
+    let streamed = read_problem_from_reader(problem.as_bytes(), None).unwrap();
+    let buffered = problem.read_pragmatic().unwrap();
+
+    assert_eq!(streamed.jobs.size(), 500);
+    assert_eq!(get_job_ids(&streamed.jobs), get_job_ids(&buffered.jobs));
+    assert_eq!(streamed.fleet.actors.len(), buffered.fleet.actors.len());
+    assert_eq!(
+        streamed.transport.distance_approx(&Profile::new(0, None), 0, 1),
+        buffered.transport.distance_approx(&Profile::new(0, None), 0, 1)
+    );
+}
+
+#[test]
+fn can_report_malformed_json_from_reader() {
+    let problem = serde_json::to_string(&generate_problem(&ProblemGeneratorConfig::default(), 42)).unwrap();
+    let truncated = &problem[..problem.len() / 2];
+
+    let errors = read_problem_from_reader(truncated.as_bytes(), None).err().unwrap();
+
+    let error = errors.into_iter().next().unwrap();
+    assert_eq!(error.code, "E0000");
+    assert!(error.action.contains("line 1"));
+}
@@ -0,0 +1,56 @@
+use super::*;
+use crate::helpers::{SIMPLE_MATRIX, SIMPLE_PROBLEM};
+
+fn get_first_job_location(problem: &mut Value) -> &mut Value {
+    problem.pointer_mut("/plan/jobs/0/deliveries/0/places/0/location").expect("no job location")
+}
+
+#[test]
+fn can_describe_location_as_one_of_variants() {
+    let schema = problem_json_schema();
+
+    let variants = schema.pointer("/definitions/Location/oneOf").and_then(Value::as_array).expect("no oneOf");
+
+    assert_eq!(variants.len(), 3);
+    assert!(schema.pointer("/definitions/Location/anyOf").is_none());
+    assert!(schema.pointer("/definitions/CustomLocationType").is_some());
+}
+
+parameterized_test! {can_validate_problem_location, (location, expected), {
+    can_validate_problem_location_impl(location, expected);
+}}
+
+can_validate_problem_location! {
+    case01_coordinate: (Some(r#"{ "lat": 52.5, "lng": 13.4 }"#), true),
+    case02_reference: (Some(r#"{ "index": 1 }"#), true),
+    case03_custom_unknown: (Some(r#"{ "type": "unknown" }"#), true),
+    case04_custom_depot: (Some(r#"{ "type": "depot" }"#), true),
+    case05_original: (None, true),
+    case06_missing_lng: (Some(r#"{ "lat": 52.5 }"#), false),
+    case07_negative_index: (Some(r#"{ "index": -1 }"#), false),
+    case08_unknown_custom_type: (Some(r#"{ "type": "hub" }"#), false),
+    case09_string: (Some(r#""52.5,13.4""#), false),
+}
+
+fn can_validate_problem_location_impl(location: Option<&str>, expected: bool) {
+    let schema = problem_json_schema();
+    let mut problem: Value = serde_json::from_str(SIMPLE_PROBLEM).unwrap();
+    if let Some(location) = location {
+        *get_first_job_location(&mut problem) = serde_json::from_str(location).unwrap();
+    }
+
+    let result = jsonschema::is_valid(&schema, &problem);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_validate_matrix() {
+    let schema = matrix_json_schema();
+    let mut matrix: Value = serde_json::from_str(SIMPLE_MATRIX).unwrap();
+
+    assert!(jsonschema::is_valid(&schema, &matrix));
+
+    matrix["distances"] = Value::String("invalid".to_string());
+    assert!(!jsonschema::is_valid(&schema, &matrix));
+}
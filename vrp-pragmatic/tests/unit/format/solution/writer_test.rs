@@ -4,8 +4,16 @@ use crate::format::solution::*;
 use crate::helpers::*;
 use std::sync::Arc;
 use vrp_core::construction::enablers::ReservedTimeSpan;
+use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::{TimeSpan, TimeWindow};
 use vrp_core::models::examples::create_example_problem;
+use vrp_core::prelude::Float;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::rosomaxa::prelude::HeuristicContextProcessing;
+use vrp_core::solver::processing::VicinityClustering;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{create_elitism_population, RefinementContext};
+use vrp_core::utils::Environment;
 
 type DomainProblem = vrp_core::models::Problem;
 type DomainActivity = vrp_core::models::solution::Activity;
@@ -208,3 +216,149 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
 }
+
+#[test]
+fn can_create_activity_with_cluster_members() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", (1., 0.), 1.),
+                create_delivery_job_with_duration("job2", (2., 0.), 2.),
+                create_delivery_job("job3", (10., 0.)),
+            ],
+            clustering: Some(Clustering::Vicinity {
+                profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+                threshold: VicinityThresholdPolicy {
+                    duration: 3.,
+                    distance: 3.,
+                    min_shared_time: None,
+                    min_shared_time_ratio: None,
+                    smallest_time_window: None,
+                    max_jobs_per_cluster: None,
+                },
+                visiting: VicinityVisitPolicy::Continue,
+                serving: VicinityServingPolicy::Original { parking: 0. },
+                filtering: None,
+            }),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let population = create_elitism_population(problem.goal.clone(), environment.clone());
+    let refinement_ctx = VicinityClustering::default().pre_process(RefinementContext::new(
+        problem,
+        Box::new(population),
+        TelemetryMode::None,
+        environment.clone(),
+    ));
+    let problem = refinement_ctx.problem.clone();
+    let insertion_ctx = InsertionContext::new(problem.clone(), environment.clone());
+    let solution = RecreateWithCheapest::new(environment.random.clone()).run(&refinement_ctx, insertion_ctx).into();
+
+    let solution = create_solution(&problem, &solution, &Default::default(), &Default::default()).unwrap();
+
+    let activities = solution.tours[0].stops.iter().flat_map(|stop| stop.activities()).collect::<Vec<_>>();
+    let clustered = activities.iter().filter(|activity| activity.cluster.is_some()).collect::<Vec<_>>();
+    assert_eq!(clustered.len(), 1);
+    let members = clustered[0].cluster.as_ref().unwrap();
+    let mut member_ids = members.iter().map(|member| member.job_id.as_str()).collect::<Vec<_>>();
+    member_ids.sort();
+    assert_eq!(member_ids, vec!["job1", "job2"]);
+    assert_eq!(members.iter().map(|member| member.service_time).sum::<Float>(), 3.);
+    assert!(activities.iter().filter(|activity| activity.job_id == "job3").all(|activity| activity.cluster.is_none()));
+}
+
+#[test]
+fn can_return_error_when_coord_index_is_missing() {
+    let problem = create_example_problem();
+    let solution = InsertionContext::new(problem.clone(), Arc::new(Environment::default())).into();
+
+    let result = create_solution(&problem, &solution, &Default::default(), &Default::default());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_create_unassigned_reasons_from_codes() {
+    use crate::format::solution::solution_writer::create_unassigned;
+    use crate::format::{CAPACITY_CONSTRAINT_CODE, SKILL_CONSTRAINT_CODE};
+    use vrp_core::construction::heuristics::UnassignmentInfo;
+    use vrp_core::models::problem::Job;
+    use vrp_core::models::solution::Registry;
+    use vrp_core::models::Solution as DomainSolution;
+    use vrp_core::prelude::{DefaultRandom, ViolationCode};
+
+    let solution = DomainSolution {
+        cost: 0.,
+        registry: Registry::new(&test_fleet(), Arc::new(DefaultRandom::default())),
+        routes: vec![],
+        unassigned: vec![
+            (Job::Single(create_single("job1")), UnassignmentInfo::Simple(CAPACITY_CONSTRAINT_CODE)),
+            (Job::Single(create_single("job2")), UnassignmentInfo::Simple(SKILL_CONSTRAINT_CODE)),
+            (Job::Single(create_single("job3")), UnassignmentInfo::Simple(ViolationCode(999))),
+        ],
+        telemetry: None,
+        summary: None,
+        is_partial: false,
+    };
+
+    let unassigned = create_unassigned(&solution).expect("should have unassigned jobs");
+
+    let reasons = unassigned
+        .iter()
+        .map(|job| {
+            assert_eq!(job.reasons.len(), 1);
+            (job.job_id.as_str(), job.reasons[0].code.as_str(), job.reasons[0].description.as_str())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        reasons,
+        vec![
+            ("job1", "CAPACITY_CONSTRAINT", "does not fit into any vehicle due to capacity"),
+            ("job2", "SKILL_CONSTRAINT", "cannot serve required skill"),
+            ("job3", "NO_REASON_FOUND", "unknown"),
+        ]
+    );
+}
+
+#[test]
+fn can_write_solve_summary_only_when_requested() {
+    use vrp_core::models::Solution as DomainSolution;
+    use vrp_core::rosomaxa::evolution::SolveSummary;
+    use vrp_core::rosomaxa::termination::TerminationReason as DomainTerminationReason;
+
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let mut solution: DomainSolution =
+        InsertionContext::new(core_problem.clone(), Arc::new(Environment::default())).into();
+    solution.summary = Some(SolveSummary {
+        generations: 10,
+        duration: 5,
+        termination: DomainTerminationReason::Stagnation,
+        fitness: vec![1., 2.],
+    });
+    let create_options = |summary| PragmaticOutputOptions { summary, ..Default::default() };
+
+    let requested = create_solution(&core_problem, &solution, &Default::default(), &create_options(true)).unwrap();
+    let skipped = create_solution(&core_problem, &solution, &Default::default(), &create_options(false)).unwrap();
+
+    assert_eq!(
+        requested.extras.and_then(|extras| extras.summary),
+        Some(Summary {
+            generations: 10,
+            duration: 5,
+            termination: TerminationReason::Stagnation,
+            fitness: vec![1., 2.]
+        })
+    );
+    assert!(skipped.extras.is_none());
+}
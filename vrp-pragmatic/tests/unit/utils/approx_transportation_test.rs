@@ -44,3 +44,19 @@ fn can_use_approximated_with_matrix_costs() {
         assert_eq!(duration.round(), (distance / speed).round());
     });
 }
+
+parameterized_test! {can_approximate_distance_between_cities, (from, to, expected_km), {
+    let from = Location::Coordinate { lat: from.0, lng: from.1 };
+    let to = Location::Coordinate { lat: to.0, lng: to.1 };
+
+    let distance = get_haversine_distance(&from, &to) / 1000.;
+
+    assert!((distance - expected_km).abs() / expected_km < 0.01, "distance {distance}km, expected {expected_km}km");
+}}
+
+can_approximate_distance_between_cities! {
+    case01_berlin_paris: ((52.5200, 13.4050), (48.8566, 2.3522), 877.5),
+    case02_london_paris: ((51.5074, -0.1278), (48.8566, 2.3522), 343.5),
+    case03_new_york_los_angeles: ((40.7128, -74.0060), (34.0522, -118.2437), 3935.7),
+    case04_sydney_melbourne: ((-33.8688, 151.2093), (-37.8136, 144.9631), 713.4),
+}
@@ -73,3 +73,123 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_location_index_out_of_range, (index, matrix_size, expected), {
+    can_detect_location_index_out_of_range_impl(index, matrix_size, expected);
+}}
+
+can_detect_location_index_out_of_range! {
+    case01_out_of_range: (3, Some(2), Some("job2 (index 3)")),
+    case02_boundary: (2, Some(2), Some("job2 (index 2)")),
+    case03_valid: (1, Some(2), None),
+    case04_no_matrix: (3, None, None),
+}
+
+fn can_detect_location_index_out_of_range_impl(index: usize, matrix_size: Option<usize>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", index)],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = matrix_size.map(|size| vec![create_matrix(vec![1; size * size])]);
+
+    let result = check_e1108_location_index_out_of_range(&ValidationContext::new(
+        &problem,
+        matrices.as_ref(),
+        &CoordIndex::new(&problem),
+    ))
+    .err();
+
+    if let Some(expected) = expected {
+        assert_result("E1108", expected, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_jobs_without_vehicle_with_skills, (vehicle_skills, fleet_skills, expected), {
+    can_detect_jobs_without_vehicle_with_skills_impl(vehicle_skills, fleet_skills, expected);
+}}
+
+can_detect_jobs_without_vehicle_with_skills! {
+    case01_no_vehicle_skills: (None, None, Some("job2")),
+    case02_other_vehicle_skills: (Some(vec!["other"]), None, Some("job2")),
+    case03_matched_vehicle_skills: (Some(vec!["unique"]), None, None),
+    case04_matched_fleet_skills: (None, Some(vec!["unique"]), None),
+}
+
+fn can_detect_jobs_without_vehicle_with_skills_impl(
+    vehicle_skills: Option<Vec<&str>>,
+    fleet_skills: Option<Vec<&str>>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job_with_skills("job2", (2., 0.), all_of_skills(vec!["unique".to_string()])),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { skills: vehicle_skills.map(to_strings), ..create_default_vehicle_type() }],
+            skills: fleet_skills.map(to_strings),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_no_vehicle_with_job_skills(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(expected) = expected {
+        assert_result("E1109", expected, result.clone());
+        assert!(result.is_some_and(|err| err.is_warning() && !err.action.contains("job1")));
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_unreachable_job_locations, (error_codes, expected), {
+    can_detect_unreachable_job_locations_impl(error_codes, expected);
+}}
+
+can_detect_unreachable_job_locations! {
+    case01_unreachable: (Some(vec![0, 0, 1, 0, 0, 1, 0, 0, 0]), Some("job2")),
+    case02_reachable: (Some(vec![0, 0, 0, 0, 0, 1, 0, 0, 0]), None),
+    case03_no_error_codes: (None, None),
+}
+
+fn can_detect_unreachable_job_locations_impl(error_codes: Option<Vec<i64>>, expected: Option<&str>) {
+    let mut shift = create_default_open_vehicle_shift();
+    shift.start.location = Location::Reference { index: 0 };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 1), create_delivery_job_with_index("job2", 2)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix { error_codes, ..create_matrix(vec![1; 9]) }];
+
+    let result = check_e1110_unreachable_job_locations(&ValidationContext::new(
+        &problem,
+        Some(&matrices),
+        &CoordIndex::new(&problem),
+    ))
+    .err();
+
+    if let Some(expected) = expected {
+        assert_result("E1110", expected, result.clone());
+        assert!(result.is_some_and(|err| err.is_warning() && !err.action.contains("job1")));
+    } else {
+        assert!(result.is_none());
+    }
+}
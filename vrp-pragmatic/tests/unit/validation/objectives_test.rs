@@ -50,6 +50,8 @@ can_detect_duplicates! {
                     objectives: vec![MinimizeCost, BalanceDistance],}
             ]),
         Some("balance-distance,minimize-cost".to_owned())),
+    case04: (Some(vec![MinimizeCost, Custom { name: "a".to_string() }, Custom { name: "b".to_string() }]), None),
+    case05: (Some(vec![MinimizeCost, Custom { name: "a".to_string() }, Custom { name: "a".to_string() }]), Some("a".to_owned())),
 }
 
 fn can_detect_duplicates_impl(objectives: Option<Vec<Objective>>, expected: Option<String>) {
@@ -207,3 +209,155 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Objective>>, e
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_conflicting_objective_directions, (objectives, expected), {
+    can_detect_conflicting_objective_directions_impl(objectives, expected);
+}}
+
+can_detect_conflicting_objective_directions! {
+    case01_max_objective: (vec![MaximizeTours, MinimizeCost], None),
+    case02_min_objective: (vec![MinimizeTours, MinimizeCost], None),
+    case03_conflict: (vec![MinimizeTours, MaximizeTours, MinimizeCost], Some("E1608".to_string())),
+    case04_conflict_in_multi: (vec![
+                MinimizeTours,
+                MultiObjective { strategy: MultiStrategy::Sum, objectives: vec![MaximizeTours, MinimizeCost] },
+            ], Some("E1608".to_string())),
+}
+
+fn can_detect_conflicting_objective_directions_impl(objectives: Vec<Objective>, expected: Option<String>) {
+    let problem = Problem { objectives: Some(objectives), ..create_empty_problem() };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1608_conflicting_objective_directions(&objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_single_vehicle_type_objective, (type_ids, expected), {
+    can_detect_single_vehicle_type_objective_impl(type_ids, expected);
+}}
+
+can_detect_single_vehicle_type_objective! {
+    case01_single_type: (vec!["type1"], Some("E1609".to_string())),
+    case02_two_types: (vec!["type1", "type2"], None),
+}
+
+fn can_detect_single_vehicle_type_objective_impl(type_ids: Vec<&str>, expected: Option<String>) {
+    let problem = Problem {
+        objectives: Some(vec![MinimizeUnassigned { breaks: None }, MinimizeVehicleTypes, MinimizeCost]),
+        fleet: Fleet { vehicles: type_ids.into_iter().map(create_default_vehicle).collect(), ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1609_single_vehicle_type_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_unknown_custom_objective, (registered, expected), {
+    can_detect_unknown_custom_objective_impl(registered, expected);
+}}
+
+can_detect_unknown_custom_objective! {
+    case01_registered: (Some(vec!["custom1", "custom2"]), None),
+    case02_not_registered: (Some(vec!["custom1"]), Some("E1610".to_string())),
+    case03_no_registry: (None, Some("E1610".to_string())),
+}
+
+fn can_detect_unknown_custom_objective_impl(registered: Option<Vec<&str>>, expected: Option<String>) {
+    let problem = Problem {
+        objectives: Some(vec![
+            MinimizeUnassigned { breaks: None },
+            Custom { name: "custom1".to_string() },
+            Custom { name: "custom2".to_string() },
+            MinimizeCost,
+        ]),
+        ..create_empty_problem()
+    };
+    let registry = registered.as_ref().map(|names| {
+        names
+            .iter()
+            .fold(ObjectiveRegistry::default(), |registry, name| registry.register(name, |_| Err("not used".into())))
+    });
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let ctx = if let Some(registry) = registry.as_ref() { ctx.with_objective_registry(registry) } else { ctx };
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1610_unknown_custom_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_waiting_time_objective_without_times, (times, expected), {
+    can_detect_waiting_time_objective_without_times_impl(times, expected);
+}}
+
+can_detect_waiting_time_objective_without_times! {
+    case01_with_times: (vec![(10, 20)], None),
+    case02_without_times: (vec![], Some("E1611".to_string())),
+}
+
+fn can_detect_waiting_time_objective_without_times_impl(times: Vec<(i32, i32)>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_times("job1", (1., 0.), times, 1.)], ..create_empty_plan() },
+        objectives: Some(vec![MinimizeUnassigned { breaks: None }, MinimizeWaitingTime, MinimizeCost]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1611_no_jobs_with_times_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_single_vehicle_balance_objective, (objective, vehicle_ids, expected), {
+    can_detect_single_vehicle_balance_objective_impl(objective, vehicle_ids, expected);
+}}
+
+can_detect_single_vehicle_balance_objective! {
+    case01_single_vehicle: (BalanceActivities, vec!["v1"], Some("E1612".to_string())),
+    case02_two_vehicles: (BalanceActivities, vec!["v1", "v2"], None),
+    case03_single_vehicle_tour_size: (BalanceTourSize, vec!["v1"], Some("E1612".to_string())),
+    case04_two_vehicles_tour_size: (BalanceTourSize, vec!["v1", "v2"], None),
+}
+
+fn can_detect_single_vehicle_balance_objective_impl(
+    objective: Objective,
+    vehicle_ids: Vec<&str>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        objectives: Some(vec![MinimizeUnassigned { breaks: None }, objective, MinimizeCost]),
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1612_single_vehicle_balance_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+#[test]
+fn can_get_objective_direction() {
+    assert_eq!(MaximizeTours.direction(), Some(ObjectiveDirection::Maximize));
+    assert_eq!(MaximizeValue { breaks: None }.direction(), Some(ObjectiveDirection::Maximize));
+    assert_eq!(MinimizeCost.direction(), Some(ObjectiveDirection::Minimize));
+    assert_eq!(MultiObjective { strategy: MultiStrategy::Sum, objectives: vec![] }.direction(), None);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format::{CustomLocationType, Location};
 use crate::helpers::*;
 
 #[test]
@@ -66,6 +67,19 @@ fn can_detect_missing_matrix_when_indices_used() {
     assert_eq!(result.err().map(|err| err.code), Some("E1503".to_string()));
 }
 
+#[test]
+fn can_detect_missing_matrix_when_custom_locations_used() {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap()[0].places[0].location = Location::Custom { r#type: CustomLocationType::Unknown };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1506_no_matrix_when_custom_locations_used(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1506".to_string()));
+}
+
 #[test]
 fn can_detect_index_mismatch() {
     let problem = Problem {
@@ -85,6 +99,7 @@ fn can_detect_index_mismatch() {
         travel_times: vec![1; 4],
         distances: vec![1; 4],
         error_codes: None,
+        travel_time_variances: None,
     }];
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
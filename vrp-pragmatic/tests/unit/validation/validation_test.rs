@@ -0,0 +1,123 @@
+use super::*;
+use crate::format::problem::Objective::*;
+use crate::format::FormatSeverity;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+#[test]
+fn can_return_structured_errors_same_as_multi_format_error() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("departure", (1., 0.)),
+                create_delivery_job_with_duration("job1", (1., 0.), -10.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let structured = ctx.validate_structured().expect_err("expected validation errors");
+    let multi = ctx.validate().expect_err("expected validation errors");
+
+    let codes = structured.iter().map(|err| err.code.clone()).collect::<Vec<_>>();
+    assert_eq!(codes, vec!["E1104".to_string(), "E1106".to_string()]);
+    assert_eq!(multi.errors.iter().map(|err| err.code.clone()).collect::<Vec<_>>(), codes);
+    assert_eq!(multi.to_string(), MultiFormatError::from(structured).to_string());
+}
+
+#[test]
+fn can_return_no_structured_errors_for_valid_problem() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+
+    let result = ValidationContext::new(&problem, None, &coord_index).validate_structured();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn can_return_detailed_errors_for_job_and_vehicle() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", (1., 0.), -10.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { fixed: None, distance: 0., time: 0. },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+
+    let result = ValidationContext::new(&problem, None, &coord_index).validate();
+
+    let errors = result.expect_err("expected validation errors").errors;
+    assert_eq!(errors.iter().map(|err| err.code.as_str()).collect::<Vec<_>>(), vec!["E1106", "E1306"]);
+    assert!(errors.iter().all(|err| !err.cause.is_empty() && !err.action.is_empty()));
+}
+
+fn create_problem_with_single_vehicle_type_objective(job_duration: Float) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_duration("job1", (1., 0.), job_duration)],
+            ..create_empty_plan()
+        },
+        objectives: Some(vec![MinimizeUnassigned { breaks: None }, MinimizeVehicleTypes, MinimizeCost]),
+        fleet: create_default_fleet(),
+    }
+}
+
+#[test]
+fn can_pass_validation_with_warnings_only() {
+    let problem = create_problem_with_single_vehicle_type_objective(10.);
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let warnings = ctx.validate().expect("expected only validation warnings");
+
+    assert_eq!(warnings.iter().map(|err| err.code.as_str()).collect::<Vec<_>>(), vec!["E1609"]);
+    assert!(warnings.iter().all(|err| err.severity == FormatSeverity::Warning));
+}
+
+#[test]
+fn can_fail_validation_with_errors_and_warnings() {
+    let problem = create_problem_with_single_vehicle_type_objective(-10.);
+    let coord_index = CoordIndex::new(&problem);
+
+    let result = ValidationContext::new(&problem, None, &coord_index).validate();
+
+    let errors = result.expect_err("expected validation errors").errors;
+    assert_eq!(
+        errors.iter().map(|err| (err.code.as_str(), err.severity)).collect::<Vec<_>>(),
+        vec![("E1106", FormatSeverity::Error), ("E1609", FormatSeverity::Warning)]
+    );
+}
+
+#[test]
+fn can_return_job_skills_warning() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills("job1", (1., 0.), all_of_skills(vec!["unique".to_string()]))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+
+    let warnings = ValidationContext::new(&problem, None, &coord_index).validate().expect("expected only warnings");
+
+    assert_eq!(
+        warnings.iter().map(|err| (err.code.as_str(), err.severity)).collect::<Vec<_>>(),
+        vec![("E1109", FormatSeverity::Warning)]
+    );
+}
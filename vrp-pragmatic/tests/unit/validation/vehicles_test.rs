@@ -137,3 +137,47 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_handle_day_horizon, (rest_duration, is_open, vehicle_break, expected), {
+    can_handle_day_horizon_impl(rest_duration, is_open, vehicle_break, expected);
+}}
+
+can_handle_day_horizon! {
+    case01_valid: (30., false, None, None),
+    case02_invalid_horizon: (0., false, None, Some("E1309".to_string())),
+    case03_open_shift: (30., true, None, Some("E1309".to_string())),
+    case04_offset_break: (30., false, Some(VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 20. }),
+        Some("E1309".to_string())),
+    case05_exact_break_intersects_rest: (30., false,
+        Some(VehicleRequiredBreakTime::ExactTime { earliest: format_time(60.), latest: format_time(68.) }),
+        Some("E1309".to_string())),
+    case06_exact_break_outside_rest: (30., false,
+        Some(VehicleRequiredBreakTime::ExactTime { earliest: format_time(10.), latest: format_time(20.) }), None),
+}
+
+fn can_handle_day_horizon_impl(
+    rest_duration: Float,
+    is_open: bool,
+    vehicle_break: Option<VehicleRequiredBreakTime>,
+    expected: Option<String>,
+) {
+    let shift = if is_open { create_default_open_vehicle_shift() } else { create_default_vehicle_shift() };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: vehicle_break.map(|time| vec![VehicleBreak::Required { time, duration: 5. }]),
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            day_horizon: Some(DayHorizon { day_length: Some(100.), rest_start: 70., rest_duration }),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_day_horizon(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
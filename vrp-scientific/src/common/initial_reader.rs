@@ -27,6 +27,8 @@ pub fn read_init_solution<R: Read>(
         routes: vec![],
         unassigned: Default::default(),
         telemetry: None,
+        summary: None,
+        is_partial: false,
     };
 
     let mut not_used_jobs = problem.jobs.all().iter().collect::<HashSet<_>>();
@@ -6,6 +6,8 @@ use std::io::{BufWriter, Error, ErrorKind, Write};
 use vrp_core::models::problem::JobIdDimension;
 use vrp_core::models::Solution;
 
+/// Writes solution in the text format used for scientific benchmarks' best known solutions: a line
+/// with customer ids per route followed by a line with total cost. It can be read back as initial solution.
 pub(crate) fn write_text_solution<W: Write>(solution: &Solution, writer: &mut BufWriter<W>) -> Result<(), Error> {
     if !solution.unassigned.is_empty() {
         return Err(Error::new(ErrorKind::Other, "cannot write text solution with unassigned jobs."));
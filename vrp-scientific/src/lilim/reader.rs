@@ -146,7 +146,8 @@ impl<R: Read> LilimReader<R> {
         } else {
             Demand::<SingleDimLoad> {
                 pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
-                delivery: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand)),
+                // NOTE delivery demand is specified as negative value in the format
+                delivery: (SingleDimLoad::default(), SingleDimLoad::new(-customer.demand)),
             }
         });
 
@@ -156,7 +157,7 @@ impl<R: Read> LilimReader<R> {
                 duration: customer.service as Float,
                 times: vec![TimeSpan::Window(customer.tw.clone())],
             }],
-            dimens: Default::default(),
+            dimens,
         })
     }
 
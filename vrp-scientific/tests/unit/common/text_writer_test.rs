@@ -1,6 +1,8 @@
 use super::*;
-use crate::helpers::SolomonBuilder;
+use crate::common::read_init_solution;
+use crate::helpers::{create_c101_25_problem, get_customer_ids_from_routes, SolomonBuilder};
 use crate::solomon::{SolomonProblem, SolomonSolution};
+use std::io::BufReader;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::rosomaxa::evolution::TelemetryMode;
@@ -38,3 +40,33 @@ fn can_write_solomon_solution() {
 
     assert_eq!(result, "Route 1: 1\nCost 2.00");
 }
+
+#[test]
+fn can_read_written_solomon_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_c101_25_problem());
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(ElitismPopulation::new(problem.goal.clone(), environment.random.clone(), 1, 1)),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+    let insertion_ctx = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment.clone()));
+    let expected_routes = get_customer_ids_from_routes(&insertion_ctx);
+    let expected_cost = insertion_ctx.get_total_cost().unwrap_or_default();
+    let solution: Solution = insertion_ctx.into();
+
+    let mut writer = BufWriter::new(Vec::new());
+    solution.write_solomon(&mut writer).unwrap();
+    let written = writer.into_inner().unwrap();
+    assert!(String::from_utf8(written.clone()).unwrap().ends_with(format!("Cost {expected_cost:.2}").as_str()));
+
+    let solution = read_init_solution(BufReader::new(written.as_slice()), problem.clone(), environment.random.clone())
+        .expect("cannot read written solution");
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), expected_routes);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert!((insertion_ctx.get_total_cost().unwrap_or_default() - expected_cost).abs() < 1E-6);
+}
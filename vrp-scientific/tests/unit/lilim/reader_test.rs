@@ -1,4 +1,5 @@
-use crate::helpers::{create_lc101_problem, get_job_ids, get_vehicle_capacity};
+use super::*;
+use crate::helpers::{create_lc101_problem, get_job_ids, get_vehicle_capacity, LilimBuilder};
 
 #[test]
 fn can_read_lilim_format_from_test_file() {
@@ -9,3 +10,45 @@ fn can_read_lilim_format_from_test_file() {
     assert_eq!(problem.fleet.vehicles.len(), 25);
     assert_eq!(get_vehicle_capacity(&problem), 200);
 }
+
+#[test]
+fn can_read_paired_pickup_and_delivery_jobs() {
+    let problem = LilimBuilder::default()
+        .set_vehicle((2, 10))
+        .add_customer((0, 0, 0, 0, 0, 1000, 0, 0, 0))
+        .add_customer((1, 1, 0, 5, 0, 100, 1, 0, 3))
+        .add_customer((2, 2, 0, 7, 0, 100, 1, 0, 4))
+        .add_customer((3, 3, 0, -5, 50, 200, 1, 1, 0))
+        .add_customer((4, 4, 0, -7, 50, 200, 1, 2, 0))
+        .build()
+        .read_lilim(false)
+        .unwrap();
+
+    assert_eq!(problem.fleet.vehicles.len(), 2);
+    assert_eq!(get_vehicle_capacity(&problem), 10);
+    assert_eq!(get_job_ids(&problem), vec!["0".to_string(), "1".to_string()]);
+
+    let pairs = problem
+        .jobs
+        .all()
+        .iter()
+        .map(|job| {
+            let multi = job.as_multi().expect("expected pickup and delivery job");
+            assert_eq!(multi.jobs.len(), 2);
+
+            let get_info = |single: &Single| {
+                let demand: &Demand<SingleDimLoad> = single.dimens.get_job_demand().unwrap();
+                (single.dimens.get_job_id().unwrap().clone(), demand.pickup.1.value, demand.delivery.1.value)
+            };
+
+            (get_info(&multi.jobs[0]), get_info(&multi.jobs[1]))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pairs,
+        vec![
+            (("c1".to_string(), 5, 0), ("c3".to_string(), 0, 5)),
+            (("c2".to_string(), 7, 0), ("c4".to_string(), 0, 7)),
+        ]
+    );
+}